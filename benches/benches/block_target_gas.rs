@@ -343,6 +343,7 @@ fn service_with_many_contracts(
                 Default::default(),
                 Default::default(),
                 Default::default(),
+                Default::default(),
             ),
             config.clone(),
         )
@@ -4,7 +4,10 @@ use crate::{
     cli::{
         default_db_path,
         run::{
-            consensus::PoATriggerArgs,
+            consensus::{
+                LeaderLeaseArgs,
+                PoATriggerArgs,
+            },
             graphql::GraphQLArgs,
             tx_pool::TxPoolArgs,
         },
@@ -20,7 +23,10 @@ use fuel_core::{
         CombinedDatabase,
         CombinedDatabaseConfig,
     },
-    fuel_core_graphql_api::ServiceConfig as GraphQLConfig,
+    fuel_core_graphql_api::{
+        AdminToken,
+        ServiceConfig as GraphQLConfig,
+    },
     producer::Config as ProducerConfig,
     service::{
         config::Trigger,
@@ -31,7 +37,11 @@ use fuel_core::{
         VMConfig,
     },
     txpool::{
-        config::BlackList,
+        config::{
+            BlackList,
+            GossipValidationConfig,
+            Quota,
+        },
         Config as TxPoolConfig,
     },
     types::{
@@ -40,10 +50,14 @@ use fuel_core::{
         secrecy::Secret,
     },
 };
+#[cfg(feature = "remote-signer")]
+use fuel_core_chain_config::ConsensusConfig;
 use fuel_core_chain_config::{
     SnapshotMetadata,
     SnapshotReader,
 };
+#[cfg(feature = "remote-signer")]
+use fuel_core_poa::signer::remote::RemoteSigner;
 use fuel_core_poa::signer::SignMode;
 use fuel_core_types::blockchain::header::StateTransitionBytecodeVersion;
 use pyroscope::{
@@ -190,6 +204,11 @@ pub struct Command {
     #[cfg(feature = "aws-kms")]
     pub consensus_aws_kms: Option<String>,
 
+    /// Use the remote signer for signing blocks.
+    #[clap(flatten)]
+    #[cfg(feature = "remote-signer")]
+    pub consensus_remote_signer: consensus::RemoteSignerArgs,
+
     /// A new block is produced instantly when transactions are available.
     #[clap(flatten)]
     pub poa_trigger: PoATriggerArgs,
@@ -198,6 +217,10 @@ pub struct Command {
     #[arg(long = "predefined-blocks-path", env)]
     pub predefined_blocks_path: Option<PathBuf>,
 
+    /// The leader lease for running hot standby block producers.
+    #[clap(flatten)]
+    pub leader_lease: LeaderLeaseArgs,
+
     /// The block's fee recipient public key.
     ///
     /// If not set, `consensus_key` is used as the provider of the `Address`.
@@ -272,8 +295,11 @@ impl Command {
             consensus_key,
             #[cfg(feature = "aws-kms")]
             consensus_aws_kms,
+            #[cfg(feature = "remote-signer")]
+            consensus_remote_signer,
             poa_trigger,
             predefined_blocks_path,
+            leader_lease,
             coinbase_recipient,
             #[cfg(feature = "relayer")]
             relayer_args,
@@ -309,7 +335,7 @@ impl Command {
         #[cfg(feature = "p2p")]
         let p2p_cfg = p2p_args.into_config(chain_config.chain_name.clone(), metrics)?;
 
-        let trigger: Trigger = poa_trigger.into();
+        let trigger: Trigger = poa_trigger.try_into()?;
 
         if trigger != Trigger::Never {
             info!("Block production mode: {:?}", &trigger);
@@ -340,6 +366,17 @@ impl Command {
             };
         }
 
+        #[cfg(feature = "remote-signer")]
+        if let Some(config) = consensus_remote_signer.into_config()? {
+            // Refuse signing blocks at heights of other producers.
+            let signing_keys = match &chain_config.consensus {
+                ConsensusConfig::PoAV2(poa) => Some(poa.clone()),
+                _ => None,
+            };
+            let signer = RemoteSigner::connect(config, signing_keys).await?;
+            consensus_signer = SignMode::Remote(signer);
+        }
+
         if matches!(consensus_signer, SignMode::Unavailable) {
             if let Some(consensus_key) = consensus_key {
                 let key = SecretKey::from_str(&consensus_key)
@@ -407,17 +444,6 @@ impl Command {
             }
         };
 
-        let combined_db_config = CombinedDatabaseConfig {
-            database_path,
-            database_type,
-            max_database_cache_size,
-            #[cfg(feature = "rocksdb")]
-            state_rewind_policy,
-        };
-
-        let block_importer =
-            fuel_core::service::config::fuel_core_importer::Config::new();
-
         let TxPoolArgs {
             tx_pool_ttl,
             tx_max_number,
@@ -427,8 +453,41 @@ impl Command {
             tx_blacklist_coins,
             tx_blacklist_messages,
             tx_blacklist_contracts,
+            tx_pool_selection_strategy,
+            tx_min_replacement_tip_bump_percent,
+            tx_pool_journal,
+            tx_owner_max_pending,
+            tx_owner_max_gas,
+            tx_owner_max_submissions_per_minute,
+            tx_peer_max_pending,
+            tx_peer_max_gas,
+            tx_peer_max_submissions_per_minute,
+            tx_gossip_batch_size,
+            tx_gossip_batch_timeout,
+            tx_gossip_validation_timeout,
+            tx_gossip_validation_threads,
+            tx_gossip_validation_queue_size,
+            tx_gossip_rejected_cache_size,
         } = tx_pool;
 
+        let tx_pool_journal_path =
+            tx_pool_journal.then(|| database_path.join("txpool.journal"));
+        // The blacklist changes are persisted only next to the persistent database,
+        // so in-memory nodes don't share them through the default database path.
+        let tx_pool_blacklist_path = (database_type == DbType::RocksDb)
+            .then(|| database_path.join("txpool.blacklist"));
+
+        let combined_db_config = CombinedDatabaseConfig {
+            database_path,
+            database_type,
+            max_database_cache_size,
+            #[cfg(feature = "rocksdb")]
+            state_rewind_policy,
+        };
+
+        let block_importer =
+            fuel_core::service::config::fuel_core_importer::Config::new();
+
         let blacklist = BlackList::new(
             tx_blacklist_addresses,
             tx_blacklist_coins,
@@ -445,6 +504,7 @@ impl Command {
                 request_body_bytes_limit: graphql.graphql_request_body_bytes_limit,
                 api_request_timeout: graphql.api_request_timeout.into(),
                 query_log_threshold_time: graphql.query_log_threshold_time.into(),
+                admin_token: graphql.admin_token.map(AdminToken::new),
             },
             combined_db_config,
             snapshot_reader,
@@ -465,6 +525,28 @@ impl Command {
                 tx_pool_ttl.into(),
                 tx_number_active_subscriptions,
                 blacklist,
+                tx_pool_selection_strategy,
+                tx_min_replacement_tip_bump_percent,
+                tx_pool_journal_path,
+                Quota {
+                    max_txs: tx_owner_max_pending,
+                    max_gas: tx_owner_max_gas,
+                    max_submissions_per_minute: tx_owner_max_submissions_per_minute,
+                },
+                Quota {
+                    max_txs: tx_peer_max_pending,
+                    max_gas: tx_peer_max_gas,
+                    max_submissions_per_minute: tx_peer_max_submissions_per_minute,
+                },
+                tx_pool_blacklist_path,
+                GossipValidationConfig {
+                    batch_size: tx_gossip_batch_size,
+                    batch_timeout: tx_gossip_batch_timeout.into(),
+                    validation_timeout: tx_gossip_validation_timeout.into(),
+                    number_of_threads: tx_gossip_validation_threads,
+                    number_of_pending_tasks: tx_gossip_validation_queue_size,
+                    rejected_cache_size: tx_gossip_rejected_cache_size,
+                },
             ),
             block_producer: ProducerConfig {
                 coinbase_recipient,
@@ -482,6 +564,7 @@ impl Command {
             #[cfg(feature = "p2p")]
             sync: sync_args.into(),
             consensus_signer,
+            leader_lease: leader_lease.into_config()?,
             name,
             relayer_consensus_config: verifier,
             min_connected_reserved_peers,
@@ -5,7 +5,17 @@ use clap::{
     ValueEnum,
 };
 use fuel_core::service::config::Trigger as PoATrigger;
+use fuel_core_poa::leader_lease::{
+    LeaderLeaseConfig,
+    MIN_LEASE_DURATION,
+};
+#[cfg(feature = "remote-signer")]
+use fuel_core_poa::signer::remote::{
+    parse_public_key,
+    RemoteSignerConfig,
+};
 use humantime::Duration;
+use std::path::PathBuf;
 
 #[derive(Debug, Clone, clap::Args)]
 pub struct PoATriggerArgs {
@@ -13,12 +23,29 @@ pub struct PoATriggerArgs {
     instant: Instant,
     #[clap(flatten)]
     interval: Interval,
+    #[clap(flatten)]
+    hybrid: Hybrid,
 }
 
 // Convert from arg struct to PoATrigger enum
-impl From<PoATriggerArgs> for PoATrigger {
-    fn from(value: PoATriggerArgs) -> Self {
-        match value {
+impl TryFrom<PoATriggerArgs> for PoATrigger {
+    type Error = anyhow::Error;
+
+    fn try_from(value: PoATriggerArgs) -> anyhow::Result<Self> {
+        let trigger = match value {
+            PoATriggerArgs {
+                hybrid:
+                    Hybrid {
+                        hybrid_gas_threshold: Some(gas_threshold),
+                        hybrid_min_block_time,
+                        hybrid_max_block_time: Some(max_block_time),
+                    },
+                ..
+            } => PoATrigger::hybrid(
+                gas_threshold,
+                hybrid_min_block_time.map(Into::into).unwrap_or_default(),
+                max_block_time.into(),
+            )?,
             PoATriggerArgs {
                 interval: Interval { period: Some(p) },
                 ..
@@ -29,13 +56,14 @@ impl From<PoATriggerArgs> for PoATrigger {
                 PoATrigger::Instant
             }
             _ => PoATrigger::Never,
-        }
+        };
+        Ok(trigger)
     }
 }
 
 #[derive(Debug, Clone, clap::Args)]
 #[clap(
-    group = ArgGroup::new("instant-mode").args(&["instant"]).conflicts_with_all(&["interval-mode"]),
+    group = ArgGroup::new("instant-mode").args(&["instant"]).conflicts_with_all(&["interval-mode", "hybrid-mode"]),
 )]
 struct Instant {
     /// Use instant block production mode.
@@ -53,7 +81,7 @@ enum Boolean {
 
 #[derive(Debug, Clone, clap::Args)]
 #[clap(
-    group = ArgGroup::new("interval-mode").args(&["period"]).conflicts_with_all(&["instant-mode"]),
+    group = ArgGroup::new("interval-mode").args(&["period"]).conflicts_with_all(&["instant-mode", "hybrid-mode"]),
 )]
 struct Interval {
     /// Interval trigger option.
@@ -63,6 +91,134 @@ struct Interval {
     pub period: Option<Duration>,
 }
 
+#[derive(Debug, Clone, clap::Args)]
+#[clap(
+    group = ArgGroup::new("hybrid-mode")
+        .args(&["hybrid_gas_threshold", "hybrid_min_block_time", "hybrid_max_block_time"])
+        .multiple(true)
+        .conflicts_with_all(&["instant-mode", "interval-mode"]),
+)]
+struct Hybrid {
+    /// Hybrid trigger option.
+    /// Produces a block when the consumable gas of pending txs reaches the threshold,
+    /// or when the max block time elapses, whichever comes first.
+    /// Cannot be combined with other poa flags.
+    #[clap(
+        long = "poa-hybrid-gas-threshold",
+        requires = "hybrid_max_block_time",
+        env
+    )]
+    pub hybrid_gas_threshold: Option<u64>,
+    /// The minimum time between blocks in the hybrid mode.
+    #[clap(
+        long = "poa-hybrid-min-block-time",
+        requires = "hybrid_gas_threshold",
+        env
+    )]
+    pub hybrid_min_block_time: Option<Duration>,
+    /// The maximum time between blocks in the hybrid mode.
+    #[clap(
+        long = "poa-hybrid-max-block-time",
+        requires = "hybrid_gas_threshold",
+        env
+    )]
+    pub hybrid_max_block_time: Option<Duration>,
+}
+
+#[derive(Debug, Clone, clap::Args)]
+pub struct LeaderLeaseArgs {
+    /// The path to the leader lease file shared by the block producer and its hot standbys.
+    /// Only the holder of the lease produces blocks, the standbys take over when it expires.
+    #[arg(long = "leader-lease-path", requires = "leader_lease_holder", env)]
+    pub leader_lease_path: Option<PathBuf>,
+
+    /// The unique name of this node in the leader lease.
+    #[arg(long = "leader-lease-holder", requires = "leader_lease_path", env)]
+    pub leader_lease_holder: Option<String>,
+
+    /// The time after which the standby takes over the lease if the leader stops renewing it.
+    #[arg(long = "leader-lease-duration", default_value = "10s", env)]
+    pub leader_lease_duration: Duration,
+}
+
+impl LeaderLeaseArgs {
+    pub fn into_config(self) -> anyhow::Result<Option<LeaderLeaseConfig>> {
+        let Some((path, holder)) = self.leader_lease_path.zip(self.leader_lease_holder)
+        else {
+            return Ok(None)
+        };
+        let duration = self.leader_lease_duration.into();
+        if duration < MIN_LEASE_DURATION {
+            return Err(anyhow::anyhow!(
+                "The leader lease duration should be at least {:?}",
+                MIN_LEASE_DURATION
+            ))
+        }
+        Ok(Some(LeaderLeaseConfig {
+            path,
+            holder,
+            duration,
+        }))
+    }
+}
+
+#[cfg(feature = "remote-signer")]
+#[derive(Debug, Clone, clap::Args)]
+pub struct RemoteSignerArgs {
+    /// The URL of the remote signer with the web3signer-style API used for signing blocks.
+    #[arg(
+        long = "consensus-remote-signer-url",
+        requires = "remote_signer_public_key",
+        conflicts_with = "consensus_key",
+        env
+    )]
+    pub remote_signer_url: Option<String>,
+
+    /// The public key of the block producer in the remote signer.
+    #[arg(
+        long = "consensus-remote-signer-public-key",
+        requires = "remote_signer_url",
+        env
+    )]
+    pub remote_signer_public_key: Option<String>,
+
+    /// The timeout of requests to the remote signer.
+    #[arg(long = "consensus-remote-signer-timeout", default_value = "5s", env)]
+    pub remote_signer_timeout: Duration,
+
+    /// The PEM encoded CA certificate of the remote signer.
+    #[arg(long = "consensus-remote-signer-ca-cert", env)]
+    pub remote_signer_ca_certificate: Option<PathBuf>,
+
+    /// The PEM encoded client certificate and private key for mTLS with the remote signer.
+    #[arg(long = "consensus-remote-signer-client-identity", env)]
+    pub remote_signer_client_identity: Option<PathBuf>,
+
+    /// The file with the last signed block, used to never sign
+    /// two different blocks at the same height after restarts.
+    #[arg(long = "consensus-remote-signer-slashing-protection", env)]
+    pub remote_signer_slashing_protection: Option<PathBuf>,
+}
+
+#[cfg(feature = "remote-signer")]
+impl RemoteSignerArgs {
+    pub fn into_config(self) -> anyhow::Result<Option<RemoteSignerConfig>> {
+        let (Some(url), Some(public_key)) =
+            (self.remote_signer_url, self.remote_signer_public_key)
+        else {
+            return Ok(None)
+        };
+        Ok(Some(RemoteSignerConfig {
+            url,
+            public_key: parse_public_key(&public_key)?,
+            timeout: self.remote_signer_timeout.into(),
+            ca_certificate: self.remote_signer_ca_certificate,
+            client_identity: self.remote_signer_client_identity,
+            slashing_protection: self.remote_signer_slashing_protection,
+        }))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -75,15 +231,33 @@ mod tests {
     pub struct Command {
         #[clap(flatten)]
         trigger: PoATriggerArgs,
+        #[clap(flatten)]
+        leader_lease: LeaderLeaseArgs,
     }
 
     #[test_case(&[] => Ok(Trigger::Instant); "defaults to instant trigger")]
     #[test_case(&["", "--poa-instant=false"] => Ok(Trigger::Never); "never trigger if instant is explicitly disabled")]
     #[test_case(&["", "--poa-interval-period=1s"] => Ok(Trigger::Interval { block_time: StdDuration::from_secs(1)}); "uses interval mode if set")]
     #[test_case(&["", "--poa-instant=true", "--poa-interval-period=1s"] => Err(()); "can't set interval and instant at the same time")]
+    #[test_case(&["", "--poa-hybrid-gas-threshold=1000", "--poa-hybrid-max-block-time=10s"] => Ok(Trigger::Hybrid { gas_threshold: 1000, min_block_time: StdDuration::ZERO, max_block_time: StdDuration::from_secs(10)}); "uses hybrid mode if set")]
+    #[test_case(&["", "--poa-hybrid-gas-threshold=1000", "--poa-hybrid-min-block-time=1s", "--poa-hybrid-max-block-time=10s"] => Ok(Trigger::Hybrid { gas_threshold: 1000, min_block_time: StdDuration::from_secs(1), max_block_time: StdDuration::from_secs(10)}); "uses the min block time in hybrid mode")]
+    #[test_case(&["", "--poa-hybrid-gas-threshold=1000", "--poa-hybrid-min-block-time=10s", "--poa-hybrid-max-block-time=1s"] => Err(()); "hybrid mode requires the min block time below the max block time")]
+    #[test_case(&["", "--poa-hybrid-gas-threshold=1000"] => Err(()); "hybrid mode requires the max block time")]
+    #[test_case(&["", "--poa-interval-period=1s", "--poa-hybrid-gas-threshold=1000", "--poa-hybrid-max-block-time=10s"] => Err(()); "can't set interval and hybrid at the same time")]
     fn parse(args: &[&str]) -> Result<Trigger, ()> {
         Command::try_parse_from(args)
             .map_err(|_| ())
-            .map(|c| c.trigger.into())
+            .and_then(|c| c.trigger.try_into().map_err(|_| ()))
+    }
+
+    #[test_case(&[] => Ok(None); "no leader lease by default")]
+    #[test_case(&["", "--leader-lease-path=lease.json", "--leader-lease-holder=a"] => Ok(Some((StdDuration::from_secs(10), "a".to_string()))); "uses the default lease duration")]
+    #[test_case(&["", "--leader-lease-path=lease.json"] => Err(()); "requires the lease holder")]
+    #[test_case(&["", "--leader-lease-path=lease.json", "--leader-lease-holder=a", "--leader-lease-duration=0s"] => Err(()); "rejects the zero lease duration")]
+    #[test_case(&["", "--leader-lease-path=lease.json", "--leader-lease-holder=a", "--leader-lease-duration=10ms"] => Err(()); "rejects too short lease duration")]
+    fn parse_leader_lease(args: &[&str]) -> Result<Option<(StdDuration, String)>, ()> {
+        let command = Command::try_parse_from(args).map_err(|_| ())?;
+        let config = command.leader_lease.into_config().map_err(|_| ())?;
+        Ok(config.map(|config| (config.duration, config.holder)))
     }
 }
@@ -39,4 +39,9 @@ pub struct GraphQLArgs {
     /// Timeout before drop the request.
     #[clap(long = "api-request-timeout", default_value = "30s", env)]
     pub api_request_timeout: humantime::Duration,
+
+    /// The token that authorizes the admin operations of the GraphQL API, like the
+    /// management of the `TxPool` blacklist. Admin operations are disabled if not set.
+    #[clap(long = "graphql-admin-token", env)]
+    pub admin_token: Option<String>,
 }
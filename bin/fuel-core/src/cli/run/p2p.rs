@@ -13,6 +13,7 @@ use fuel_core::{
         },
         gossipsub_config::default_gossipsub_builder,
         heartbeat,
+        IpNetwork,
         Multiaddr,
     },
     types::{
@@ -58,6 +59,16 @@ pub struct P2PArgs {
     #[clap(long = "peering-port", default_value = "30333", env)]
     pub peering_port: u16,
 
+    /// p2p network's UDP Port for QUIC connections.
+    /// QUIC is disabled if it is not set.
+    #[clap(long = "quic-port", env)]
+    pub quic_port: Option<u16>,
+
+    /// p2p network's TCP Port for WebSocket connections.
+    /// WebSocket is disabled if it is not set.
+    #[clap(long = "websocket-port", env)]
+    pub websocket_port: Option<u16>,
+
     /// Max Block size
     #[clap(long = "max-block-size", default_value = MAX_RESPONSE_SIZE_STR, env)]
     pub max_block_size: usize,
@@ -80,6 +91,20 @@ pub struct P2PArgs {
     #[clap(long = "reserved-nodes-only-mode", env)]
     pub reserved_nodes_only_mode: bool,
 
+    /// Networks in CIDR notation that peers are allowed to connect from and to.
+    /// If it's not set, all networks are allowed unless denied.
+    #[clap(long = "allowed-ip-networks", value_delimiter = ',', env)]
+    pub allowed_ip_networks: Vec<IpNetwork>,
+
+    /// Networks in CIDR notation that peers are never allowed to connect from and to.
+    #[clap(long = "denied-ip-networks", value_delimiter = ',', env)]
+    pub denied_ip_networks: Vec<IpNetwork>,
+
+    /// Max number of inbound connections from a single IP address.
+    /// If it's not set, the number of connections is not limited.
+    #[clap(long = "max-connections-per-ip", env)]
+    pub max_connections_per_ip: Option<u32>,
+
     /// Allow nodes to be discoverable on the local network
     #[clap(long = "enable-mdns", env)]
     pub enable_mdns: bool,
@@ -195,6 +220,10 @@ pub struct SyncArgs {
     /// The maximum number of headers to request in a single batch.
     #[clap(long = "sync-header-batch-size", default_value = "10", env)]
     pub header_batch_size: u32,
+    /// The maximum number of times to request the rest of the failed batch
+    /// from another peer before giving up on the range.
+    #[clap(long = "sync-max-batch-retries", default_value = "3", env)]
+    pub max_batch_retries: usize,
 }
 
 #[derive(Clone, Debug)]
@@ -233,6 +262,7 @@ impl From<SyncArgs> for fuel_core::sync::Config {
         Self {
             block_stream_buffer_size: value.block_stream_buffer_size,
             header_batch_size: value.header_batch_size as usize,
+            max_batch_retries: value.max_batch_retries,
         }
     }
 }
@@ -302,11 +332,16 @@ impl P2PArgs {
                 .unwrap_or_else(|| IpAddr::V4(Ipv4Addr::from([0, 0, 0, 0]))),
             public_address: self.public_address,
             tcp_port: self.peering_port,
+            quic_port: self.quic_port,
+            websocket_port: self.websocket_port,
             max_block_size: self.max_block_size,
             max_headers_per_request: self.max_headers_per_request,
             bootstrap_nodes: self.bootstrap_nodes,
             reserved_nodes: self.reserved_nodes,
             reserved_nodes_only_mode: self.reserved_nodes_only_mode,
+            allowed_ip_networks: self.allowed_ip_networks,
+            denied_ip_networks: self.denied_ip_networks,
+            max_connections_per_ip: self.max_connections_per_ip,
             enable_mdns: self.enable_mdns,
             max_peers_connected: self.max_peers_connected,
             max_connections_per_peer: self.max_connections_per_peer,
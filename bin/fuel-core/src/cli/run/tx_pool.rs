@@ -1,6 +1,9 @@
 //! Clap configuration related to TxPool service.
 
-use fuel_core::txpool::types::ContractId;
+use fuel_core::txpool::{
+    transaction_selector::SelectionStrategy,
+    types::ContractId,
+};
 use fuel_core_types::{
     fuel_tx::{
         Address,
@@ -42,13 +45,87 @@ pub struct TxPoolArgs {
     /// The list of banned contracts ignored by the `TxPool`.
     #[clap(long = "tx-blacklist-contracts", value_delimiter = ',', env)]
     pub tx_blacklist_contracts: Vec<ContractId>,
+
+    /// The strategy used by the `TxPool` to select transactions for the next block.
+    /// `tip-per-gas` greedily takes transactions with the highest tip per gas,
+    /// `knapsack` maximizes the total tip respecting the gas and the size of the block,
+    /// `fair-share` gives each owner of transactions an equal share of the block.
+    #[clap(
+        long = "tx-pool-selection-strategy",
+        default_value = "tip-per-gas",
+        env
+    )]
+    pub tx_pool_selection_strategy: SelectionStrategy,
+
+    /// The minimum increase of the tip in percents required to replace a transaction
+    /// in the `TxPool` with another transaction spending the same inputs.
+    #[clap(long = "tx-min-replacement-tip-bump-percent", default_value = "0", env)]
+    pub tx_min_replacement_tip_bump_percent: u64,
+
+    /// Persists the pending transactions in the journal under the database path,
+    /// so they are restored after the restart of the node.
+    #[clap(long = "tx-pool-journal", env)]
+    pub tx_pool_journal: bool,
+
+    /// The max number of pending transactions of one owner of inputs.
+    #[clap(long = "tx-owner-max-pending", env)]
+    pub tx_owner_max_pending: Option<usize>,
+
+    /// The max total gas of pending transactions of one owner of inputs.
+    #[clap(long = "tx-owner-max-gas", env)]
+    pub tx_owner_max_gas: Option<u64>,
+
+    /// The max number of transactions of one owner of inputs accepted per minute.
+    #[clap(long = "tx-owner-max-submissions-per-minute", env)]
+    pub tx_owner_max_submissions_per_minute: Option<usize>,
+
+    /// The max number of pending transactions gossiped by one peer.
+    #[clap(long = "tx-peer-max-pending", env)]
+    pub tx_peer_max_pending: Option<usize>,
+
+    /// The max total gas of pending transactions gossiped by one peer.
+    #[clap(long = "tx-peer-max-gas", env)]
+    pub tx_peer_max_gas: Option<u64>,
+
+    /// The max number of transactions gossiped by one peer accepted per minute.
+    #[clap(long = "tx-peer-max-submissions-per-minute", env)]
+    pub tx_peer_max_submissions_per_minute: Option<usize>,
+
+    /// The max number of gossiped transactions verified together.
+    #[clap(long = "tx-gossip-batch-size", default_value = "64", env)]
+    pub tx_gossip_batch_size: usize,
+
+    /// The max time to wait for more gossiped transactions before verifying an incomplete batch.
+    #[clap(long = "tx-gossip-batch-timeout", default_value = "10ms", env)]
+    pub tx_gossip_batch_timeout: humantime::Duration,
+
+    /// The max time to report the acceptance of the gossiped transaction to the p2p layer.
+    /// Transactions that are not verified in time are ignored.
+    #[clap(long = "tx-gossip-validation-timeout", default_value = "5s", env)]
+    pub tx_gossip_validation_timeout: humantime::Duration,
+
+    /// The number of threads verifying signatures and predicates of gossiped transactions.
+    #[clap(long = "tx-gossip-validation-threads", default_value = "2", env)]
+    pub tx_gossip_validation_threads: usize,
+
+    /// The max number of gossiped transactions waiting for the verification.
+    #[clap(long = "tx-gossip-validation-queue-size", default_value = "10240", env)]
+    pub tx_gossip_validation_queue_size: usize,
+
+    /// The number of recently rejected gossiped transactions remembered
+    /// to drop their duplicates without the verification.
+    #[clap(long = "tx-gossip-rejected-cache-size", default_value = "10000", env)]
+    pub tx_gossip_rejected_cache_size: usize,
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use clap::Parser;
-    use fuel_core::txpool::config::BlackList;
+    use fuel_core::txpool::config::{
+        BlackList,
+        Quota,
+    };
     use test_case::test_case;
 
     #[derive(Debug, Clone, Parser)]
@@ -101,4 +178,36 @@ mod tests {
 
         Ok(blacklist)
     }
+
+    #[test_case(&[""] => Ok(SelectionStrategy::TipPerGas); "defaults to tip per gas")]
+    #[test_case(&["", "--tx-pool-selection-strategy=knapsack"] => Ok(SelectionStrategy::Knapsack); "knapsack works")]
+    #[test_case(&["", "--tx-pool-selection-strategy=fair-share"] => Ok(SelectionStrategy::FairShare); "fair share works")]
+    #[test_case(&["", "--tx-pool-selection-strategy=unknown"] => Err(()); "unknown strategy fails")]
+    fn parse_selection_strategy(args: &[&str]) -> Result<SelectionStrategy, ()> {
+        Command::try_parse_from(args)
+            .map_err(|_| ())
+            .map(|c| c.tx_pool.tx_pool_selection_strategy)
+    }
+
+    #[test_case(&[""] => Ok((Quota::default(), Quota::default())); "defaults to unlimited")]
+    #[test_case(&["", "--tx-owner-max-pending=1", "--tx-owner-max-gas=2", "--tx-owner-max-submissions-per-minute=3"]
+        => Ok((Quota { max_txs: Some(1), max_gas: Some(2), max_submissions_per_minute: Some(3) }, Quota::default())); "owner quota works")]
+    #[test_case(&["", "--tx-peer-max-pending=1", "--tx-peer-max-gas=2", "--tx-peer-max-submissions-per-minute=3"]
+        => Ok((Quota::default(), Quota { max_txs: Some(1), max_gas: Some(2), max_submissions_per_minute: Some(3) })); "peer quota works")]
+    fn parse_quotas(args: &[&str]) -> Result<(Quota, Quota), ()> {
+        Command::try_parse_from(args).map_err(|_| ()).map(|c| {
+            let args = c.tx_pool;
+            let owner_quota = Quota {
+                max_txs: args.tx_owner_max_pending,
+                max_gas: args.tx_owner_max_gas,
+                max_submissions_per_minute: args.tx_owner_max_submissions_per_minute,
+            };
+            let peer_quota = Quota {
+                max_txs: args.tx_peer_max_pending,
+                max_gas: args.tx_peer_max_gas,
+                max_submissions_per_minute: args.tx_peer_max_submissions_per_minute,
+            };
+            (owner_quota, peer_quota)
+        })
+    }
 }
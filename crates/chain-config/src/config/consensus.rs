@@ -1,10 +1,14 @@
-use alloc::collections::BTreeMap;
+use alloc::{
+    collections::BTreeMap,
+    vec::Vec,
+};
 use fuel_core_types::{
     fuel_tx::Input,
     fuel_types::{
         Address,
         BlockHeight,
     },
+    tai64::Tai64,
 };
 use serde::{
     Deserialize,
@@ -16,8 +20,15 @@ use fuel_core_chain_config::default_consensus_dev_key;
 
 #[derive(Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
 pub enum ConsensusConfig {
-    PoA { signing_key: Address },
+    PoA {
+        signing_key: Address,
+    },
     PoAV2(PoAV2),
+    PoAV3(PoAV3),
+    /// The block is committed when more than two thirds of `validators` sign it.
+    Bft {
+        validators: Vec<Address>,
+    },
 }
 
 impl ConsensusConfig {
@@ -71,6 +82,112 @@ impl PoAV2 {
     }
 }
 
+/// The producers sign blocks in turns. The producer of the height is picked by the height.
+/// If it doesn't produce the block within `slot_duration_secs` seconds after
+/// the previous block, the next producer in the order takes over, and so on.
+#[derive(Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(try_from = "UncheckedPoAV3")]
+pub struct PoAV3 {
+    producers: Vec<Address>,
+    slot_duration_secs: u64,
+}
+
+/// The `PoAV3` as it is stored in the chain config, before the validation.
+#[derive(Deserialize)]
+struct UncheckedPoAV3 {
+    producers: Vec<Address>,
+    slot_duration_secs: u64,
+}
+
+impl TryFrom<UncheckedPoAV3> for PoAV3 {
+    type Error = anyhow::Error;
+
+    fn try_from(value: UncheckedPoAV3) -> Result<Self, Self::Error> {
+        PoAV3::new(value.producers, value.slot_duration_secs)
+    }
+}
+
+impl PoAV3 {
+    pub fn new(producers: Vec<Address>, slot_duration_secs: u64) -> anyhow::Result<Self> {
+        anyhow::ensure!(
+            !producers.is_empty(),
+            "The PoAV3 requires at least one producer"
+        );
+        anyhow::ensure!(
+            slot_duration_secs > 0,
+            "The slot duration of the PoAV3 should be positive"
+        );
+        Ok(PoAV3 {
+            producers,
+            slot_duration_secs,
+        })
+    }
+
+    /// Returns the ordered set of producers.
+    pub fn producers(&self) -> &[Address] {
+        &self.producers
+    }
+
+    /// Returns the duration of the slot in seconds.
+    pub fn slot_duration_secs(&self) -> u64 {
+        self.slot_duration_secs
+    }
+
+    /// Returns the number of whole slots passed since the `prev_time` until the `time`.
+    fn slots_since(&self, prev_time: Tai64, time: Tai64) -> u64 {
+        time.0
+            .saturating_sub(prev_time.0)
+            .checked_div(self.slot_duration_secs)
+            .unwrap_or_default()
+    }
+
+    /// Returns the producer of the block at the `height` with the `time`,
+    /// following the block with the `prev_time`.
+    pub fn producer_at(
+        &self,
+        height: BlockHeight,
+        prev_time: Tai64,
+        time: Tai64,
+    ) -> Option<Address> {
+        let len = u64::try_from(self.producers.len()).ok()?;
+        let turn = u64::from(*height).checked_add(self.slots_since(prev_time, time))?;
+        let index = usize::try_from(turn.checked_rem(len)?).ok()?;
+        self.producers.get(index).copied()
+    }
+
+    /// Returns the earliest time not before the `time` when the `producer`
+    /// can produce the block at the `height` following the block with the `prev_time`.
+    pub fn next_slot_of(
+        &self,
+        producer: &Address,
+        height: BlockHeight,
+        prev_time: Tai64,
+        time: Tai64,
+    ) -> Option<Tai64> {
+        let slot = self.slots_since(prev_time, time);
+        let len = u64::try_from(self.producers.len()).ok()?;
+        (0..len).find_map(|offset| {
+            let time = if offset == 0 {
+                time
+            } else {
+                let next_slot = slot.checked_add(offset)?;
+                Tai64(
+                    prev_time
+                        .0
+                        .checked_add(next_slot.checked_mul(self.slot_duration_secs)?)?,
+                )
+            };
+            (self.producer_at(height, prev_time, time).as_ref() == Some(producer))
+                .then_some(time)
+        })
+    }
+
+    #[cfg(feature = "test-helpers")]
+    pub fn set_producers(&mut self, producers: Vec<Address>) {
+        self.producers = producers;
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -108,4 +225,82 @@ mod tests {
             signing_key_after_30
         );
     }
+
+    #[test]
+    fn producer_at_rotates_producers_by_heights_and_slots() {
+        // Given
+        let producers: Vec<_> = (1..=3).map(|i| Address::from([i; 32])).collect();
+        let poa = PoAV3::new(producers.clone(), 2).unwrap();
+        let prev_time = Tai64(10);
+
+        // When/Then
+        assert_eq!(
+            poa.producer_at(0u32.into(), prev_time, Tai64(10)),
+            Some(producers[0])
+        );
+        assert_eq!(
+            poa.producer_at(0u32.into(), prev_time, Tai64(11)),
+            Some(producers[0])
+        );
+        assert_eq!(
+            poa.producer_at(1u32.into(), prev_time, Tai64(11)),
+            Some(producers[1])
+        );
+        assert_eq!(
+            poa.producer_at(0u32.into(), prev_time, Tai64(12)),
+            Some(producers[1])
+        );
+        assert_eq!(
+            poa.producer_at(1u32.into(), prev_time, Tai64(15)),
+            Some(producers[0])
+        );
+        // The time before the previous block counts as the first slot.
+        assert_eq!(
+            poa.producer_at(2u32.into(), prev_time, Tai64(5)),
+            Some(producers[2])
+        );
+    }
+
+    #[test]
+    fn next_slot_of_returns_start_of_next_own_slot() {
+        // Given
+        let producers: Vec<_> = (1..=3).map(|i| Address::from([i; 32])).collect();
+        let poa = PoAV3::new(producers.clone(), 2).unwrap();
+        let outsider = Address::from([4; 32]);
+        let height = 0u32.into();
+        let prev_time = Tai64(10);
+
+        // When/Then
+        assert_eq!(
+            poa.next_slot_of(&producers[0], height, prev_time, Tai64(11)),
+            Some(Tai64(11))
+        );
+        assert_eq!(
+            poa.next_slot_of(&producers[1], height, prev_time, Tai64(11)),
+            Some(Tai64(12))
+        );
+        assert_eq!(
+            poa.next_slot_of(&producers[0], height, prev_time, Tai64(13)),
+            Some(Tai64(16))
+        );
+        assert_eq!(
+            poa.next_slot_of(&outsider, height, prev_time, Tai64(13)),
+            None
+        );
+    }
+
+    #[test]
+    fn poa_v3_requires_producers_and_positive_slot_duration() {
+        let producers = vec![Address::from([1; 32])];
+
+        assert!(PoAV3::new(producers.clone(), 1).is_ok());
+        assert!(PoAV3::new(vec![], 1).is_err());
+        assert!(PoAV3::new(producers.clone(), 0).is_err());
+        let zero_duration = ConsensusConfig::PoAV3(PoAV3 {
+            producers,
+            slot_duration_secs: 0,
+        });
+        let json = serde_json::to_string(&zero_duration).unwrap();
+        assert!(serde_json::from_str::<ConsensusConfig>(&json).is_err());
+    }
 }
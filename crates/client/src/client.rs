@@ -57,7 +57,11 @@ use fuel_core_types::{
         BlockHeight,
         Nonce,
     },
-    services::executor::TransactionExecutionStatus,
+    services::executor::{
+        StateOverrides,
+        TransactionExecutionStatus,
+        TransactionTrace,
+    },
 };
 #[cfg(feature = "subscriptions")]
 use futures::{
@@ -139,6 +143,7 @@ pub struct FuelClient {
     #[cfg(feature = "subscriptions")]
     cookie: std::sync::Arc<reqwest::cookie::Jar>,
     url: reqwest::Url,
+    admin_token: Option<String>,
 }
 
 impl FromStr for FuelClient {
@@ -166,13 +171,18 @@ impl FromStr for FuelClient {
                 client,
                 cookie,
                 url,
+                admin_token: None,
             })
         }
 
         #[cfg(not(feature = "subscriptions"))]
         {
             let client = reqwest::Client::new();
-            Ok(Self { client, url })
+            Ok(Self {
+                client,
+                url,
+                admin_token: None,
+            })
         }
     }
 }
@@ -205,6 +215,12 @@ impl FuelClient {
         Self::from_str(url.as_ref())
     }
 
+    /// Sets the token passed with each request to authorize admin operations.
+    pub fn with_admin_token(mut self, token: impl Into<String>) -> Self {
+        self.admin_token = Some(token.into());
+        self
+    }
+
     /// Send the GraphQL query to the client.
     pub async fn query<ResponseData, Vars>(
         &self,
@@ -214,9 +230,11 @@ impl FuelClient {
         Vars: serde::Serialize,
         ResponseData: serde::de::DeserializeOwned + 'static,
     {
-        let response = self
-            .client
-            .post(self.url.clone())
+        let mut request = self.client.post(self.url.clone());
+        if let Some(token) = &self.admin_token {
+            request = request.bearer_auth(token);
+        }
+        let response = request
             .run_graphql(q)
             .await
             .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?;
@@ -446,7 +464,7 @@ impl FuelClient {
         &self,
         txs: &[Transaction],
     ) -> io::Result<Vec<TransactionExecutionStatus>> {
-        self.dry_run_opt(txs, None, None).await
+        self.dry_run_opt(txs, None, None, None, None).await
     }
 
     /// Dry run with options to override the node behavior
@@ -456,6 +474,10 @@ impl FuelClient {
         // Disable utxo input checks (exists, unspent, and valid signature)
         utxo_validation: Option<bool>,
         gas_price: Option<u64>,
+        // Execute on top of the state at this block height instead of the latest state
+        at_height: Option<BlockHeight>,
+        // Modify the state before the execution, the modifications are not committed
+        state_overrides: Option<StateOverrides>,
     ) -> io::Result<Vec<TransactionExecutionStatus>> {
         let txs = txs
             .iter()
@@ -466,6 +488,40 @@ impl FuelClient {
                 txs,
                 utxo_validation,
                 gas_price: gas_price.map(|gp| gp.into()),
+                at_height: at_height.map(|height| U32(height.into())),
+                state_overrides: state_overrides.map(Into::into),
+            });
+        let tx_statuses = self.query(query).await.map(|r| r.dry_run)?;
+        tx_statuses
+            .into_iter()
+            .map(|tx_status| tx_status.try_into().map_err(Into::into))
+            .collect()
+    }
+
+    /// Dry run with options to override the node behavior that also returns
+    /// the trace of the execution of each transaction
+    pub async fn dry_run_with_traces(
+        &self,
+        txs: &[Transaction],
+        // Disable utxo input checks (exists, unspent, and valid signature)
+        utxo_validation: Option<bool>,
+        gas_price: Option<u64>,
+        // Execute on top of the state at this block height instead of the latest state
+        at_height: Option<BlockHeight>,
+        // Modify the state before the execution, the modifications are not committed
+        state_overrides: Option<StateOverrides>,
+    ) -> io::Result<Vec<(TransactionExecutionStatus, Option<TransactionTrace>)>> {
+        let txs = txs
+            .iter()
+            .map(|tx| HexString(Bytes(tx.to_bytes())))
+            .collect::<Vec<HexString>>();
+        let query: Operation<schema::tx::DryRunWithTraces, DryRunArg> =
+            schema::tx::DryRunWithTraces::build(DryRunArg {
+                txs,
+                utxo_validation,
+                gas_price: gas_price.map(|gp| gp.into()),
+                at_height: at_height.map(|height| U32(height.into())),
+                state_overrides: state_overrides.map(Into::into),
             });
         let tx_statuses = self.query(query).await.map(|r| r.dry_run)?;
         tx_statuses
@@ -715,6 +771,58 @@ impl FuelClient {
         Ok(stream)
     }
 
+    #[tracing::instrument(skip(self), level = "debug")]
+    #[cfg(feature = "subscriptions")]
+    /// Subscribe to the blocks of the chain.
+    ///
+    /// If the `start_height` is specified, the stream returns all blocks
+    /// starting from this height and then follows new blocks.
+    /// Otherwise, the stream returns only new blocks.
+    pub async fn subscribe_blocks(
+        &self,
+        start_height: Option<BlockHeight>,
+    ) -> io::Result<impl futures::Stream<Item = io::Result<types::Block>>> {
+        use cynic::SubscriptionBuilder;
+        let s = schema::block::NewBlocksSubscription::build(
+            schema::block::BlocksSubscriptionArgs {
+                start_height: start_height.map(|height| U32(height.into())),
+            },
+        );
+
+        let stream = self.subscribe(s).await?.map(|r| {
+            let block = r?.new_blocks.try_into()?;
+            Ok(block)
+        });
+
+        Ok(stream)
+    }
+
+    #[tracing::instrument(skip(self), level = "debug")]
+    #[cfg(feature = "subscriptions")]
+    /// Subscribe to the block headers of the chain.
+    ///
+    /// If the `start_height` is specified, the stream returns all headers
+    /// starting from this height and then follows new headers.
+    /// Otherwise, the stream returns only new headers.
+    pub async fn subscribe_headers(
+        &self,
+        start_height: Option<BlockHeight>,
+    ) -> io::Result<impl futures::Stream<Item = io::Result<types::block::Header>>> {
+        use cynic::SubscriptionBuilder;
+        let s = schema::block::NewHeadersSubscription::build(
+            schema::block::BlocksSubscriptionArgs {
+                start_height: start_height.map(|height| U32(height.into())),
+            },
+        );
+
+        let stream = self.subscribe(s).await?.map(|r| {
+            let header = r?.new_headers.try_into()?;
+            Ok(header)
+        });
+
+        Ok(stream)
+    }
+
     #[cfg(feature = "subscriptions")]
     /// Awaits for the transaction to be committed into a block
     ///
@@ -829,6 +937,88 @@ impl FuelClient {
         Ok(new_height.into())
     }
 
+    /// Returns the blacklist of the `TxPool`. Requires the admin token.
+    pub async fn tx_pool_blacklist(&self) -> io::Result<types::TxPoolBlacklist> {
+        let query = schema::admin::TxPoolBlacklistQuery::build(());
+        self.query(query).await.map(|r| r.tx_pool_blacklist.into())
+    }
+
+    /// Adds entries to the blacklist of the `TxPool`. Requires the admin token.
+    /// Returns ids of pending transactions squeezed out by new entries.
+    pub async fn add_to_tx_pool_blacklist(
+        &self,
+        entries: types::TxPoolBlacklist,
+    ) -> io::Result<Vec<TxId>> {
+        let query = schema::admin::AddToTxPoolBlacklist::build(entries.into());
+        let removed = self.query(query).await?.add_to_tx_pool_blacklist;
+        Ok(removed.into_iter().map(Into::into).collect())
+    }
+
+    /// Removes entries from the blacklist of the `TxPool`. Requires the admin token.
+    pub async fn remove_from_tx_pool_blacklist(
+        &self,
+        entries: types::TxPoolBlacklist,
+    ) -> io::Result<()> {
+        let query = schema::admin::RemoveFromTxPoolBlacklist::build(entries.into());
+        self.query(query).await?;
+        Ok(())
+    }
+
+    /// Disconnects the peer with the given id. Requires the admin token.
+    pub async fn disconnect_peer(&self, peer_id: &str) -> io::Result<()> {
+        let query = schema::admin::DisconnectPeer::build(schema::admin::PeerIdArgs {
+            peer_id: peer_id.to_string(),
+        });
+        self.query(query).await?;
+        Ok(())
+    }
+
+    /// Bans the peer with the given id and disconnects it. Requires the admin token.
+    pub async fn ban_peer(&self, peer_id: &str) -> io::Result<()> {
+        let query = schema::admin::BanPeer::build(schema::admin::PeerIdArgs {
+            peer_id: peer_id.to_string(),
+        });
+        self.query(query).await?;
+        Ok(())
+    }
+
+    /// Lifts the ban of the peer with the given id. Requires the admin token.
+    pub async fn unban_peer(&self, peer_id: &str) -> io::Result<()> {
+        let query = schema::admin::UnbanPeer::build(schema::admin::PeerIdArgs {
+            peer_id: peer_id.to_string(),
+        });
+        self.query(query).await?;
+        Ok(())
+    }
+
+    /// Adds the reserved node by its multiaddress with the peer id. Requires the admin token.
+    pub async fn add_reserved_node(&self, address: &str) -> io::Result<()> {
+        let query =
+            schema::admin::AddReservedNode::build(schema::admin::PeerAddressArgs {
+                address: address.to_string(),
+            });
+        self.query(query).await?;
+        Ok(())
+    }
+
+    /// Removes the peer with the given id from reserved nodes. Requires the admin token.
+    pub async fn remove_reserved_node(&self, peer_id: &str) -> io::Result<()> {
+        let query = schema::admin::RemoveReservedNode::build(schema::admin::PeerIdArgs {
+            peer_id: peer_id.to_string(),
+        });
+        self.query(query).await?;
+        Ok(())
+    }
+
+    /// Dials the peer by its multiaddress. Requires the admin token.
+    pub async fn dial_peer(&self, address: &str) -> io::Result<()> {
+        let query = schema::admin::DialPeer::build(schema::admin::PeerAddressArgs {
+            address: address.to_string(),
+        });
+        self.query(query).await?;
+        Ok(())
+    }
+
     pub async fn block(&self, id: &BlockId) -> io::Result<Option<types::Block>> {
         let query = schema::block::BlockByIdQuery::build(BlockByIdArgs {
             id: Some((*id).into()),
@@ -882,8 +1072,26 @@ impl FuelClient {
     }
 
     pub async fn coin(&self, id: &UtxoId) -> io::Result<Option<types::Coin>> {
+        self.coin_by_id(id, None).await
+    }
+
+    /// Retrieve a coin by its utxo id as it was at the block `height`.
+    pub async fn coin_at_height(
+        &self,
+        id: &UtxoId,
+        height: BlockHeight,
+    ) -> io::Result<Option<types::Coin>> {
+        self.coin_by_id(id, Some(height)).await
+    }
+
+    async fn coin_by_id(
+        &self,
+        id: &UtxoId,
+        at_height: Option<BlockHeight>,
+    ) -> io::Result<Option<types::Coin>> {
         let query = schema::coins::CoinByIdQuery::build(CoinByIdArgs {
             utxo_id: (*id).into(),
+            at_height: at_height.map(|height| U32(height.into())),
         });
         let coin = self.query(query).await?.coin.map(Into::into);
         Ok(coin)
@@ -951,8 +1159,26 @@ impl FuelClient {
     }
 
     pub async fn contract(&self, id: &ContractId) -> io::Result<Option<types::Contract>> {
+        self.contract_by_id(id, None).await
+    }
+
+    /// Retrieve a contract as it was at the block `height`.
+    pub async fn contract_at_height(
+        &self,
+        id: &ContractId,
+        height: BlockHeight,
+    ) -> io::Result<Option<types::Contract>> {
+        self.contract_by_id(id, Some(height)).await
+    }
+
+    async fn contract_by_id(
+        &self,
+        id: &ContractId,
+        at_height: Option<BlockHeight>,
+    ) -> io::Result<Option<types::Contract>> {
         let query = schema::contract::ContractByIdQuery::build(ContractByIdArgs {
             id: (*id).into(),
+            at_height: at_height.map(|height| U32(height.into())),
         });
         let contract = self.query(query).await?.contract.map(Into::into);
         Ok(contract)
@@ -962,6 +1188,25 @@ impl FuelClient {
         &self,
         id: &ContractId,
         asset: Option<&AssetId>,
+    ) -> io::Result<u64> {
+        self.contract_balance_query(id, asset, None).await
+    }
+
+    /// Retrieve the balance of the contract as it was at the block `height`.
+    pub async fn contract_balance_at_height(
+        &self,
+        id: &ContractId,
+        asset: Option<&AssetId>,
+        height: BlockHeight,
+    ) -> io::Result<u64> {
+        self.contract_balance_query(id, asset, Some(height)).await
+    }
+
+    async fn contract_balance_query(
+        &self,
+        id: &ContractId,
+        asset: Option<&AssetId>,
+        at_height: Option<BlockHeight>,
     ) -> io::Result<u64> {
         let asset_id: schema::AssetId = match asset {
             Some(asset) => (*asset).into(),
@@ -972,6 +1217,7 @@ impl FuelClient {
             schema::contract::ContractBalanceQuery::build(ContractBalanceQueryArgs {
                 id: (*id).into(),
                 asset: asset_id,
+                at_height: at_height.map(|height| U32(height.into())),
             });
 
         let balance: types::ContractBalance =
@@ -983,13 +1229,36 @@ impl FuelClient {
         &self,
         owner: &Address,
         asset_id: Option<&AssetId>,
+    ) -> io::Result<u64> {
+        self.balance_query(owner, asset_id, None).await
+    }
+
+    /// Retrieve the balance of the `owner` as it was at the block `height`.
+    pub async fn balance_at_height(
+        &self,
+        owner: &Address,
+        asset_id: Option<&AssetId>,
+        height: BlockHeight,
+    ) -> io::Result<u64> {
+        self.balance_query(owner, asset_id, Some(height)).await
+    }
+
+    async fn balance_query(
+        &self,
+        owner: &Address,
+        asset_id: Option<&AssetId>,
+        at_height: Option<BlockHeight>,
     ) -> io::Result<u64> {
         let owner: schema::Address = (*owner).into();
         let asset_id: schema::AssetId = match asset_id {
             Some(asset_id) => (*asset_id).into(),
             None => schema::AssetId::default(),
         };
-        let query = schema::balance::BalanceQuery::build(BalanceArgs { owner, asset_id });
+        let query = schema::balance::BalanceQuery::build(BalanceArgs {
+            owner,
+            asset_id,
+            at_height: at_height.map(|height| U32(height.into())),
+        });
         let balance: types::Balance = self.query(query).await?.balance.into();
         Ok(balance.amount)
     }
@@ -1021,6 +1290,24 @@ impl FuelClient {
         Ok(balances)
     }
 
+    /// Retrieve a page of the contract balances as they were at the block `height`.
+    pub async fn contract_balances_at_height(
+        &self,
+        contract: &ContractId,
+        request: PaginationRequest<String>,
+        height: BlockHeight,
+    ) -> io::Result<PaginatedResult<types::ContractBalance, String>> {
+        let contract_id: schema::ContractId = (*contract).into();
+        let mut args: schema::contract::ContractBalancesConnectionArgs =
+            (contract_id, request).into();
+        args.at_height = Some(U32(height.into()));
+        let query = schema::contract::ContractBalancesQuery::build(args);
+
+        let balances = self.query(query).await?.contract_balances.into();
+
+        Ok(balances)
+    }
+
     // Retrieve a message by its nonce
     pub async fn message(&self, nonce: &Nonce) -> io::Result<Option<types::Message>> {
         let query = schema::message::MessageQuery::build(NonceArgs {
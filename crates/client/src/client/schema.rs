@@ -26,6 +26,7 @@ use crate::client::pagination::{
 };
 pub use primitives::*;
 
+pub mod admin;
 pub mod balance;
 pub mod blob;
 pub mod block;
@@ -0,0 +1,182 @@
+use crate::client::schema::{
+    schema,
+    Address,
+    ContractId,
+    Nonce,
+    TransactionId,
+    UtxoId,
+};
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct TxPoolBlacklist {
+    pub owners: Vec<Address>,
+    pub utxo_ids: Vec<UtxoId>,
+    pub messages: Vec<Nonce>,
+    pub contracts: Vec<ContractId>,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl", graphql_type = "Query")]
+pub struct TxPoolBlacklistQuery {
+    pub tx_pool_blacklist: TxPoolBlacklist,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct TxPoolBlacklistArgs {
+    pub owners: Vec<Address>,
+    pub utxo_ids: Vec<UtxoId>,
+    pub messages: Vec<Nonce>,
+    pub contracts: Vec<ContractId>,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    variables = "TxPoolBlacklistArgs",
+    graphql_type = "Mutation"
+)]
+pub struct AddToTxPoolBlacklist {
+    #[arguments(owners: $owners, utxoIds: $utxo_ids, messages: $messages, contracts: $contracts)]
+    pub add_to_tx_pool_blacklist: Vec<TransactionId>,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    variables = "TxPoolBlacklistArgs",
+    graphql_type = "Mutation"
+)]
+pub struct RemoveFromTxPoolBlacklist {
+    #[arguments(owners: $owners, utxoIds: $utxo_ids, messages: $messages, contracts: $contracts)]
+    pub remove_from_tx_pool_blacklist: bool,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct PeerIdArgs {
+    pub peer_id: String,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct PeerAddressArgs {
+    pub address: String,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    variables = "PeerIdArgs",
+    graphql_type = "Mutation"
+)]
+pub struct DisconnectPeer {
+    #[arguments(peerId: $peer_id)]
+    pub disconnect_peer: bool,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    variables = "PeerIdArgs",
+    graphql_type = "Mutation"
+)]
+pub struct BanPeer {
+    #[arguments(peerId: $peer_id)]
+    pub ban_peer: bool,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    variables = "PeerIdArgs",
+    graphql_type = "Mutation"
+)]
+pub struct UnbanPeer {
+    #[arguments(peerId: $peer_id)]
+    pub unban_peer: bool,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    variables = "PeerAddressArgs",
+    graphql_type = "Mutation"
+)]
+pub struct AddReservedNode {
+    #[arguments(address: $address)]
+    pub add_reserved_node: bool,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    variables = "PeerIdArgs",
+    graphql_type = "Mutation"
+)]
+pub struct RemoveReservedNode {
+    #[arguments(peerId: $peer_id)]
+    pub remove_reserved_node: bool,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    variables = "PeerAddressArgs",
+    graphql_type = "Mutation"
+)]
+pub struct DialPeer {
+    #[arguments(address: $address)]
+    pub dial_peer: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args() -> TxPoolBlacklistArgs {
+        TxPoolBlacklistArgs {
+            owners: vec![],
+            utxo_ids: vec![],
+            messages: vec![],
+            contracts: vec![],
+        }
+    }
+
+    #[test]
+    fn tx_pool_blacklist_query_gql_output() {
+        use cynic::QueryBuilder;
+        let operation = TxPoolBlacklistQuery::build(());
+        insta::assert_snapshot!(operation.query)
+    }
+
+    #[test]
+    fn add_to_tx_pool_blacklist_gql_output() {
+        use cynic::MutationBuilder;
+        let operation = AddToTxPoolBlacklist::build(args());
+        insta::assert_snapshot!(operation.query)
+    }
+
+    #[test]
+    fn remove_from_tx_pool_blacklist_gql_output() {
+        use cynic::MutationBuilder;
+        let operation = RemoveFromTxPoolBlacklist::build(args());
+        insta::assert_snapshot!(operation.query)
+    }
+
+    #[test]
+    fn ban_peer_gql_output() {
+        use cynic::MutationBuilder;
+        let operation = BanPeer::build(PeerIdArgs {
+            peer_id: "peer".to_string(),
+        });
+        insta::assert_snapshot!(operation.query)
+    }
+
+    #[test]
+    fn add_reserved_node_gql_output() {
+        use cynic::MutationBuilder;
+        let operation = AddReservedNode::build(PeerAddressArgs {
+            address: "/ip4/127.0.0.1/tcp/4001".to_string(),
+        });
+        insta::assert_snapshot!(operation.query)
+    }
+}
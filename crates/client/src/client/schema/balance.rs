@@ -4,6 +4,7 @@ use crate::client::{
         Address,
         AssetId,
         PageInfo,
+        U32,
         U64,
     },
     PageDirection,
@@ -14,6 +15,7 @@ use crate::client::{
 pub struct BalanceArgs {
     pub owner: Address,
     pub asset_id: AssetId,
+    pub at_height: Option<U32>,
 }
 
 #[derive(cynic::QueryFragment, Clone, Debug)]
@@ -23,7 +25,7 @@ pub struct BalanceArgs {
     variables = "BalanceArgs"
 )]
 pub struct BalanceQuery {
-    #[arguments(owner: $owner, assetId: $asset_id)]
+    #[arguments(owner: $owner, assetId: $asset_id, atHeight: $at_height)]
     pub balance: Balance,
 }
 
@@ -113,6 +115,7 @@ mod tests {
         let operation = BalanceQuery::build(BalanceArgs {
             owner: Address::default(),
             asset_id: AssetId::default(),
+            at_height: None,
         });
         insta::assert_snapshot!(operation.query)
     }
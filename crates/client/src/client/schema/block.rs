@@ -102,6 +102,33 @@ pub struct BlockHeightFragment {
     pub height: U32,
 }
 
+#[derive(cynic::QueryVariables, Debug)]
+pub struct BlocksSubscriptionArgs {
+    pub start_height: Option<U32>,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Subscription",
+    variables = "BlocksSubscriptionArgs"
+)]
+pub struct NewBlocksSubscription {
+    #[arguments(startHeight: $start_height)]
+    pub new_blocks: Block,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Subscription",
+    variables = "BlocksSubscriptionArgs"
+)]
+pub struct NewHeadersSubscription {
+    #[arguments(startHeight: $start_height)]
+    pub new_headers: Header,
+}
+
 #[derive(cynic::QueryVariables, Debug)]
 pub struct ProduceBlockArgs {
     pub start_timestamp: Option<Tai64Timestamp>,
@@ -149,6 +176,7 @@ pub struct Header {
 pub enum Consensus {
     Genesis(Genesis),
     PoAConsensus(PoAConsensus),
+    BftConsensus(BftConsensus),
     #[cynic(fallback)]
     Unknown,
 }
@@ -169,6 +197,14 @@ pub struct PoAConsensus {
     pub signature: Signature,
 }
 
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct BftConsensus {
+    pub round: U32,
+    pub producer_signature: Signature,
+    pub signatures: Vec<Signature>,
+}
+
 impl Block {
     /// Returns the block producer public key, if any.
     pub fn block_producer(&self) -> Option<fuel_crypto::PublicKey> {
@@ -180,6 +216,11 @@ impl Block {
                 let producer_pub_key = signature.recover(&message);
                 producer_pub_key.ok()
             }
+            Consensus::BftConsensus(bft) => {
+                let signature = bft.producer_signature.clone().into_signature();
+                let producer_pub_key = signature.recover(&message);
+                producer_pub_key.ok()
+            }
             Consensus::Unknown => None,
         }
     }
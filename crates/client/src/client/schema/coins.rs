@@ -17,6 +17,7 @@ use crate::client::{
 #[derive(cynic::QueryVariables, Debug)]
 pub struct CoinByIdArgs {
     pub utxo_id: UtxoId,
+    pub at_height: Option<U32>,
 }
 
 #[derive(cynic::QueryFragment, Clone, Debug)]
@@ -26,7 +27,7 @@ pub struct CoinByIdArgs {
     variables = "CoinByIdArgs"
 )]
 pub struct CoinByIdQuery {
-    #[arguments(utxoId: $ utxo_id)]
+    #[arguments(utxoId: $ utxo_id, atHeight: $at_height)]
     pub coin: Option<Coin>,
 }
 
@@ -222,6 +223,7 @@ mod tests {
         use cynic::QueryBuilder;
         let operation = CoinByIdQuery::build(CoinByIdArgs {
             utxo_id: UtxoId::default(),
+            at_height: None,
         });
         insta::assert_snapshot!(operation.query)
     }
@@ -6,6 +6,7 @@ use crate::client::{
         HexString,
         PageInfo,
         Salt,
+        U32,
         U64,
     },
     PageDirection,
@@ -15,6 +16,7 @@ use crate::client::{
 #[derive(cynic::QueryVariables, Debug)]
 pub struct ContractByIdArgs {
     pub id: ContractId,
+    pub at_height: Option<U32>,
 }
 
 #[derive(cynic::QueryFragment, Clone, Debug)]
@@ -24,7 +26,7 @@ pub struct ContractByIdArgs {
     variables = "ContractByIdArgs"
 )]
 pub struct ContractByIdQuery {
-    #[arguments(id: $id)]
+    #[arguments(id: $id, atHeight: $at_height)]
     pub contract: Option<Contract>,
 }
 
@@ -40,6 +42,7 @@ pub struct ContractBalance {
 pub struct ContractBalanceQueryArgs {
     pub id: ContractId,
     pub asset: AssetId,
+    pub at_height: Option<U32>,
 }
 
 #[derive(cynic::QueryFragment, Clone, Debug)]
@@ -49,7 +52,7 @@ pub struct ContractBalanceQueryArgs {
     variables = "ContractBalanceQueryArgs"
 )]
 pub struct ContractBalanceQuery {
-    #[arguments(contract: $id, asset: $asset)]
+    #[arguments(contract: $id, asset: $asset, atHeight: $at_height)]
     pub contract_balance: ContractBalance,
 }
 
@@ -87,6 +90,8 @@ pub struct ContractBalancesConnectionArgs {
     /// Retrieve the last n asset balances in order (backward pagination).
     /// Can't be used at the same time as `first`.
     pub last: Option<i32>,
+    /// The height of the block at which the state is read
+    pub at_height: Option<U32>,
 }
 
 #[derive(cynic::QueryFragment, Clone, Debug)]
@@ -110,7 +115,7 @@ pub struct ContractBalanceConnection {
     variables = "ContractBalancesConnectionArgs"
 )]
 pub struct ContractBalancesQuery {
-    #[arguments(filter: $filter, after: $after, before: $before, first: $first, last: $last)]
+    #[arguments(filter: $filter, after: $after, before: $before, first: $first, last: $last, atHeight: $at_height)]
     pub contract_balances: ContractBalanceConnection,
 }
 
@@ -123,6 +128,7 @@ impl From<(ContractId, PaginationRequest<String>)> for ContractBalancesConnectio
                 before: None,
                 first: Some(r.1.results),
                 last: None,
+                at_height: None,
             },
             PageDirection::Backward => ContractBalancesConnectionArgs {
                 filter: ContractBalanceFilterInput { contract: r.0 },
@@ -130,6 +136,7 @@ impl From<(ContractId, PaginationRequest<String>)> for ContractBalancesConnectio
                 before: r.1.cursor,
                 first: None,
                 last: Some(r.1.results),
+                at_height: None,
             },
         }
     }
@@ -144,6 +151,7 @@ mod tests {
         use cynic::QueryBuilder;
         let operation = ContractByIdQuery::build(ContractByIdArgs {
             id: ContractId::default(),
+            at_height: None,
         });
         insta::assert_snapshot!(operation.query)
     }
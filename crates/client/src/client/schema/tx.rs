@@ -1,16 +1,23 @@
 use crate::client::{
     schema::{
+        primitives::HexFormatted,
         schema,
         tx::transparent_receipt::Receipt,
         Address,
+        AssetId,
+        Bytes,
         ConnectionArgs,
+        ContractId,
         ConversionError,
         HexString,
         PageInfo,
         Tai64Timestamp,
         TransactionId,
+        TxPointer,
+        UtxoId,
         U32,
         U64,
+        U8,
     },
     types::TransactionResponse,
     PageDirection,
@@ -25,8 +32,14 @@ use fuel_core_types::{
     },
     fuel_vm,
     services::executor::{
+        ContractStateOverride,
+        StateOverrides,
+        StorageAccess,
+        StorageAccessKind,
+        TraceStep,
         TransactionExecutionResult,
         TransactionExecutionStatus,
+        TransactionTrace,
     },
 };
 use std::convert::{
@@ -316,6 +329,134 @@ impl TryFrom<DryRunTransactionExecutionStatus> for TransactionExecutionStatus {
     }
 }
 
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "DryRunTransactionExecutionStatus"
+)]
+pub struct DryRunTransactionExecutionStatusWithTrace {
+    pub id: TransactionId,
+    pub status: DryRunTransactionStatus,
+    pub trace: Option<TransactionTraceFragment>,
+}
+
+impl TryFrom<DryRunTransactionExecutionStatusWithTrace>
+    for (TransactionExecutionStatus, Option<TransactionTrace>)
+{
+    type Error = ConversionError;
+
+    fn try_from(
+        schema: DryRunTransactionExecutionStatusWithTrace,
+    ) -> Result<Self, Self::Error> {
+        let id = schema.id.into();
+        let status = schema.status.try_into()?;
+        let trace = schema.trace.map(TryInto::try_into).transpose()?;
+
+        Ok((TransactionExecutionStatus { id, result: status }, trace))
+    }
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl", graphql_type = "TransactionTrace")]
+pub struct TransactionTraceFragment {
+    pub steps: Vec<TraceStepFragment>,
+    pub storage_accesses: Vec<StorageAccessFragment>,
+    pub truncated: bool,
+}
+
+impl TryFrom<TransactionTraceFragment> for TransactionTrace {
+    type Error = ConversionError;
+
+    fn try_from(schema: TransactionTraceFragment) -> Result<Self, Self::Error> {
+        let steps = schema.steps.into_iter().map(Into::into).collect();
+        let storage_accesses = schema
+            .storage_accesses
+            .into_iter()
+            .map(TryInto::try_into)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(TransactionTrace {
+            steps,
+            storage_accesses,
+            truncated: schema.truncated,
+        })
+    }
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl", graphql_type = "TraceStep")]
+pub struct TraceStepFragment {
+    pub pc: U64,
+    pub instruction: U32,
+    pub gas_remaining: U64,
+    pub contract_id: Option<ContractId>,
+    pub changed_registers: Vec<RegisterValue>,
+}
+
+impl From<TraceStepFragment> for TraceStep {
+    fn from(schema: TraceStepFragment) -> Self {
+        TraceStep {
+            pc: schema.pc.into(),
+            instruction: schema.instruction.into(),
+            gas_remaining: schema.gas_remaining.into(),
+            contract_id: schema.contract_id.map(Into::into),
+            changed_registers: schema
+                .changed_registers
+                .into_iter()
+                .map(|register| (register.register.into(), register.value.into()))
+                .collect(),
+        }
+    }
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct RegisterValue {
+    pub register: U8,
+    pub value: U64,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl", graphql_type = "StorageAccess")]
+pub struct StorageAccessFragment {
+    pub step: U32,
+    pub kind: StorageAccessKindFragment,
+    pub contract_id: ContractId,
+    pub key: crate::client::schema::Bytes32,
+    pub value: Option<HexString>,
+}
+
+impl TryFrom<StorageAccessFragment> for StorageAccess {
+    type Error = ConversionError;
+
+    fn try_from(schema: StorageAccessFragment) -> Result<Self, Self::Error> {
+        let kind = match schema.kind {
+            StorageAccessKindFragment::Read => StorageAccessKind::Read,
+            StorageAccessKindFragment::Write => StorageAccessKind::Write,
+            StorageAccessKindFragment::Clear => StorageAccessKind::Clear,
+        };
+
+        Ok(StorageAccess {
+            step: schema.step.into(),
+            kind,
+            contract_id: schema.contract_id.into(),
+            key: schema.key.into(),
+            value: schema.value.map(Into::into),
+        })
+    }
+}
+
+#[derive(cynic::Enum, Clone, Copy, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "StorageAccessKind"
+)]
+pub enum StorageAccessKindFragment {
+    Read,
+    Write,
+    Clear,
+}
+
 #[derive(cynic::QueryVariables, Debug)]
 pub struct TransactionsByOwnerConnectionArgs {
     /// Select transactions based on related `owner`s
@@ -392,11 +533,103 @@ pub struct EstimatePredicates {
     pub estimate_predicates: OpaqueTransaction,
 }
 
+#[derive(cynic::InputObject, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct StateOverridesInput {
+    /// The overrides of the contracts' state.
+    pub contracts: Option<Vec<ContractStateOverrideInput>>,
+    /// The coins to insert or replace.
+    pub coins: Option<Vec<CoinOverrideInput>>,
+}
+
+#[derive(cynic::InputObject, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct ContractStateOverrideInput {
+    pub contract_id: ContractId,
+    pub bytecode: Option<HexString>,
+    pub balances: Option<Vec<ContractBalanceOverrideInput>>,
+    pub storage: Option<Vec<ContractStorageSlotOverrideInput>>,
+}
+
+#[derive(cynic::InputObject, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct ContractBalanceOverrideInput {
+    pub asset_id: AssetId,
+    pub amount: U64,
+}
+
+#[derive(cynic::InputObject, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct ContractStorageSlotOverrideInput {
+    pub key: crate::client::schema::Bytes32,
+    pub value: HexString,
+}
+
+#[derive(cynic::InputObject, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct CoinOverrideInput {
+    pub utxo_id: UtxoId,
+    pub owner: Address,
+    pub amount: U64,
+    pub asset_id: AssetId,
+    pub tx_pointer: Option<TxPointer>,
+}
+
+impl From<StateOverrides> for StateOverridesInput {
+    fn from(overrides: StateOverrides) -> Self {
+        let contracts = overrides.contracts.into_iter().map(Into::into).collect();
+        let coins = overrides
+            .coins
+            .into_iter()
+            .map(|coin| CoinOverrideInput {
+                utxo_id: coin.utxo_id.into(),
+                owner: coin.owner.into(),
+                amount: coin.amount.into(),
+                asset_id: coin.asset_id.into(),
+                tx_pointer: Some(TxPointer(HexFormatted(coin.tx_pointer))),
+            })
+            .collect();
+        Self {
+            contracts: Some(contracts),
+            coins: Some(coins),
+        }
+    }
+}
+
+impl From<ContractStateOverride> for ContractStateOverrideInput {
+    fn from(contract: ContractStateOverride) -> Self {
+        let balances = contract
+            .balances
+            .into_iter()
+            .map(|(asset_id, amount)| ContractBalanceOverrideInput {
+                asset_id: asset_id.into(),
+                amount: amount.into(),
+            })
+            .collect();
+        let storage = contract
+            .storage
+            .into_iter()
+            .map(|(key, value)| ContractStorageSlotOverrideInput {
+                key: key.into(),
+                value: HexString(Bytes(value)),
+            })
+            .collect();
+        Self {
+            contract_id: contract.contract_id.into(),
+            bytecode: contract.bytecode.map(|bytecode| HexString(Bytes(bytecode))),
+            balances: Some(balances),
+            storage: Some(storage),
+        }
+    }
+}
+
 #[derive(cynic::QueryVariables)]
 pub struct DryRunArg {
     pub txs: Vec<HexString>,
     pub utxo_validation: Option<bool>,
     pub gas_price: Option<U64>,
+    pub at_height: Option<U32>,
+    pub state_overrides: Option<StateOverridesInput>,
 }
 
 #[derive(cynic::QueryFragment, Clone, Debug)]
@@ -406,10 +639,22 @@ pub struct DryRunArg {
     variables = "DryRunArg"
 )]
 pub struct DryRun {
-    #[arguments(txs: $txs, utxoValidation: $utxo_validation, gasPrice: $gas_price)]
+    #[arguments(txs: $txs, utxoValidation: $utxo_validation, gasPrice: $gas_price, atHeight: $at_height, stateOverrides: $state_overrides)]
     pub dry_run: Vec<DryRunTransactionExecutionStatus>,
 }
 
+/// The dry run that also returns the traces of the execution.
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Mutation",
+    variables = "DryRunArg"
+)]
+pub struct DryRunWithTraces {
+    #[arguments(txs: $txs, utxoValidation: $utxo_validation, gasPrice: $gas_price, atHeight: $at_height, stateOverrides: $state_overrides, trace: true)]
+    pub dry_run: Vec<DryRunTransactionExecutionStatusWithTrace>,
+}
+
 #[derive(cynic::QueryFragment, Clone, Debug)]
 #[cynic(
     schema_path = "./assets/schema.sdl",
@@ -511,6 +756,22 @@ pub mod tests {
             txs: vec![HexString(Bytes(tx.to_bytes()))],
             utxo_validation: Some(true),
             gas_price: Some(123u64.into()),
+            at_height: None,
+            state_overrides: None,
+        });
+        insta::assert_snapshot!(query.query)
+    }
+
+    #[test]
+    fn dry_run_with_traces_tx_gql_output() {
+        use cynic::MutationBuilder;
+        let tx = fuel_tx::Transaction::default_test_tx();
+        let query = DryRunWithTraces::build(DryRunArg {
+            txs: vec![HexString(Bytes(tx.to_bytes()))],
+            utxo_validation: Some(true),
+            gas_price: Some(123u64.into()),
+            at_height: None,
+            state_overrides: None,
         });
         insta::assert_snapshot!(query.query)
     }
@@ -1,3 +1,4 @@
+pub mod admin;
 pub mod balance;
 pub mod blob;
 pub mod block;
@@ -12,6 +13,7 @@ pub mod merkle_proof;
 pub mod message;
 pub mod node_info;
 
+pub use admin::TxPoolBlacklist;
 pub use balance::Balance;
 pub use blob::Blob;
 pub use block::{
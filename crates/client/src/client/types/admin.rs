@@ -0,0 +1,42 @@
+use crate::client::schema;
+use fuel_core_types::{
+    fuel_tx::UtxoId,
+    fuel_types::{
+        Address,
+        ContractId,
+        Nonce,
+    },
+};
+
+/// The blacklist of the `TxPool`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TxPoolBlacklist {
+    pub owners: Vec<Address>,
+    pub utxo_ids: Vec<UtxoId>,
+    pub messages: Vec<Nonce>,
+    pub contracts: Vec<ContractId>,
+}
+
+// GraphQL Translation
+
+impl From<schema::admin::TxPoolBlacklist> for TxPoolBlacklist {
+    fn from(value: schema::admin::TxPoolBlacklist) -> Self {
+        Self {
+            owners: value.owners.into_iter().map(Into::into).collect(),
+            utxo_ids: value.utxo_ids.into_iter().map(Into::into).collect(),
+            messages: value.messages.into_iter().map(Into::into).collect(),
+            contracts: value.contracts.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl From<TxPoolBlacklist> for schema::admin::TxPoolBlacklistArgs {
+    fn from(value: TxPoolBlacklist) -> Self {
+        Self {
+            owners: value.owners.into_iter().map(Into::into).collect(),
+            utxo_ids: value.utxo_ids.into_iter().map(Into::into).collect(),
+            messages: value.messages.into_iter().map(Into::into).collect(),
+            contracts: value.contracts.into_iter().map(Into::into).collect(),
+        }
+    }
+}
@@ -54,6 +54,7 @@ pub struct Header {
 pub enum Consensus {
     Genesis(Genesis),
     PoAConsensus(PoAConsensus),
+    BftConsensus(BftConsensus),
     Unknown,
 }
 
@@ -71,6 +72,13 @@ pub struct PoAConsensus {
     pub signature: Signature,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BftConsensus {
+    pub round: u32,
+    pub producer_signature: Signature,
+    pub signatures: Vec<Signature>,
+}
+
 // GraphQL Translation
 
 impl TryFrom<schema::block::Header> for Header {
@@ -108,6 +116,9 @@ impl From<schema::block::Consensus> for Consensus {
             schema::block::Consensus::PoAConsensus(poa) => {
                 Consensus::PoAConsensus(poa.into())
             }
+            schema::block::Consensus::BftConsensus(bft) => {
+                Consensus::BftConsensus(bft.into())
+            }
             schema::block::Consensus::Unknown => Consensus::Unknown,
         }
     }
@@ -134,6 +145,20 @@ impl From<schema::block::PoAConsensus> for PoAConsensus {
     }
 }
 
+impl From<schema::block::BftConsensus> for BftConsensus {
+    fn from(value: schema::block::BftConsensus) -> Self {
+        let into_signature = |signature: schema::Signature| {
+            let bytes: [u8; 64] = signature.0 .0.into();
+            Signature::from_bytes(bytes)
+        };
+        Self {
+            round: value.round.into(),
+            producer_signature: into_signature(value.producer_signature),
+            signatures: value.signatures.into_iter().map(into_signature).collect(),
+        }
+    }
+}
+
 impl TryFrom<schema::block::Block> for Block {
     type Error = ConversionError;
 
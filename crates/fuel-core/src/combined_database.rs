@@ -6,6 +6,7 @@ use crate::{
             gas_price::GasPriceDatabase,
             off_chain::OffChain,
             on_chain::OnChain,
+            peers::Peers,
             relayer::Relayer,
         },
         Database,
@@ -41,13 +42,15 @@ pub struct CombinedDatabaseConfig {
     pub state_rewind_policy: StateRewindPolicy,
 }
 
-/// A database that combines the on-chain, off-chain and relayer databases into one entity.
+/// A database that combines the on-chain, off-chain, relayer, gas price and peers
+/// databases into one entity.
 #[derive(Default, Clone)]
 pub struct CombinedDatabase {
     on_chain: Database<OnChain>,
     off_chain: Database<OffChain>,
     relayer: Database<Relayer>,
     gas_price: Database<GasPriceDatabase>,
+    peers: Database<Peers>,
 }
 
 impl CombinedDatabase {
@@ -56,12 +59,14 @@ impl CombinedDatabase {
         off_chain: Database<OffChain>,
         relayer: Database<Relayer>,
         gas_price: Database<GasPriceDatabase>,
+        peers: Database<Peers>,
     ) -> Self {
         Self {
             on_chain,
             off_chain,
             relayer,
             gas_price,
+            peers,
         }
     }
 
@@ -71,6 +76,7 @@ impl CombinedDatabase {
         crate::state::rocks_db::RocksDb::<OffChain>::prune(path)?;
         crate::state::rocks_db::RocksDb::<Relayer>::prune(path)?;
         crate::state::rocks_db::RocksDb::<GasPriceDatabase>::prune(path)?;
+        crate::state::rocks_db::RocksDb::<Peers>::prune(path)?;
         Ok(())
     }
 
@@ -86,11 +92,13 @@ impl CombinedDatabase {
         let relayer =
             Database::open_rocksdb(path, capacity, StateRewindPolicy::NoRewind)?;
         let gas_price = Database::open_rocksdb(path, capacity, state_rewind_policy)?;
+        let peers = Database::open_rocksdb(path, capacity, StateRewindPolicy::NoRewind)?;
         Ok(Self {
             on_chain,
             off_chain,
             relayer,
             gas_price,
+            peers,
         })
     }
 
@@ -132,6 +140,7 @@ impl CombinedDatabase {
             Database::in_memory(),
             Database::in_memory(),
             Database::in_memory(),
+            Database::in_memory(),
         )
     }
 
@@ -139,6 +148,7 @@ impl CombinedDatabase {
         self.on_chain.check_version()?;
         self.off_chain.check_version()?;
         self.relayer.check_version()?;
+        self.peers.check_version()?;
         Ok(())
     }
 
@@ -178,6 +188,10 @@ impl CombinedDatabase {
         &mut self.gas_price
     }
 
+    pub fn peers(&self) -> &Database<Peers> {
+        &self.peers
+    }
+
     #[cfg(feature = "test-helpers")]
     pub fn read_state_config(&self) -> StorageResult<StateConfig> {
         use fuel_core_chain_config::AddTable;
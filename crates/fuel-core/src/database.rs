@@ -3,6 +3,7 @@ use crate::{
         database_description::{
             off_chain::OffChain,
             on_chain::OnChain,
+            peers::Peers,
             relayer::Relayer,
             DatabaseDescription,
             DatabaseHeight,
@@ -22,7 +23,6 @@ use crate::{
         ChangesIterator,
         ColumnType,
         IterableKeyValueView,
-        KeyValueView,
     },
 };
 use fuel_core_chain_config::TableEntry;
@@ -311,7 +311,7 @@ where
     Description: DatabaseDescription,
 {
     type Height = Description::Height;
-    type ViewAtHeight = KeyValueView<ColumnType<Description>>;
+    type ViewAtHeight = IterableKeyValueView<ColumnType<Description>>;
 
     fn latest_height(&self) -> Option<Self::Height> {
         *self.inner_storage().stage.height.lock()
@@ -321,9 +321,9 @@ where
         let lock = self.inner_storage().stage.height.lock();
 
         match *lock {
-            None => return self.latest_view().map(|view| view.into_key_value_view()),
+            None => return self.latest_view(),
             Some(current_height) if &current_height == height => {
-                return self.latest_view().map(|view| view.into_key_value_view())
+                return self.latest_view()
             }
             _ => {}
         };
@@ -379,6 +379,12 @@ impl Modifiable for Database<Relayer> {
     }
 }
 
+impl Modifiable for Database<Peers> {
+    fn commit_changes(&mut self, changes: Changes) -> StorageResult<()> {
+        commit_changes_with_height_update(self, changes, |_| Ok(vec![]))
+    }
+}
+
 impl Modifiable for GenesisDatabase<OnChain> {
     fn commit_changes(&mut self, changes: Changes) -> StorageResult<()> {
         self.data.as_ref().commit_changes(None, changes)
@@ -1025,6 +1031,60 @@ mod tests {
         }
     }
 
+    #[cfg(feature = "p2p")]
+    mod peers {
+        use super::*;
+        use crate::database::database_description::peers::Peers;
+        use fuel_core_p2p::{
+            ports::{
+                PeersReputation,
+                PeersReputationDb,
+            },
+            storage::{
+                KnownPeer,
+                PeerBan,
+            },
+            PeerId,
+        };
+        use fuel_core_storage::structured_storage::StructuredStorage;
+        use fuel_core_types::tai64::Tai64;
+
+        #[test]
+        fn column_keys_not_exceed_count_test() {
+            column_keys_not_exceed_count::<Peers>();
+        }
+
+        #[test]
+        fn stored_peers_reputation_replaces_the_previous_one() {
+            // Given
+            let mut database = StructuredStorage::new(Database::<Peers>::default());
+            let ban = PeerBan {
+                expires_at: Tai64(100),
+            };
+            let known_peer = KnownPeer {
+                addresses: vec!["/ip4/127.0.0.1/tcp/4001".parse().unwrap()],
+                score: 42.0,
+                last_seen: Tai64(50),
+            };
+            let old_reputation = PeersReputation {
+                banned_peers: [(PeerId::random(), ban)].into(),
+                known_peers: [(PeerId::random(), known_peer.clone())].into(),
+            };
+            database.store_peers_reputation(&old_reputation).unwrap();
+            let new_reputation = PeersReputation {
+                banned_peers: [(PeerId::random(), ban)].into(),
+                known_peers: [(PeerId::random(), known_peer)].into(),
+            };
+
+            // When
+            database.store_peers_reputation(&new_reputation).unwrap();
+
+            // Then
+            assert_eq!(database.get_peers_reputation().unwrap(), new_reputation);
+            assert_eq!(database.as_ref().latest_height(), None);
+        }
+    }
+
     #[cfg(feature = "rocksdb")]
     #[test]
     fn database_iter_all_by_prefix_works() {
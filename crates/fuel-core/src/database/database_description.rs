@@ -8,6 +8,7 @@ use fuel_core_types::{
 pub mod gas_price;
 pub mod off_chain;
 pub mod on_chain;
+pub mod peers;
 pub mod relayer;
 
 pub trait DatabaseHeight: PartialEq + Default + Debug + Copy + Send + Sync {
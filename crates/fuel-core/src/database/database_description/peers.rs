@@ -0,0 +1,36 @@
+use crate::database::database_description::DatabaseDescription;
+use fuel_core_types::fuel_types::BlockHeight;
+
+#[cfg(not(feature = "p2p"))]
+use crate::database::database_description::relayer::DummyColumn;
+
+/// The database with the reputation of peers that survives restarts of the node.
+#[derive(Copy, Clone, Debug)]
+pub struct Peers;
+
+impl DatabaseDescription for Peers {
+    #[cfg(feature = "p2p")]
+    type Column = fuel_core_p2p::storage::Column;
+
+    #[cfg(not(feature = "p2p"))]
+    type Column = DummyColumn;
+
+    /// The reputation of peers is not tied to blocks, so the height never changes.
+    type Height = BlockHeight;
+
+    fn version() -> u32 {
+        0
+    }
+
+    fn name() -> String {
+        "peers".to_string()
+    }
+
+    fn metadata_column() -> Self::Column {
+        Self::Column::Metadata
+    }
+
+    fn prefix(_: &Self::Column) -> Option<usize> {
+        None
+    }
+}
@@ -78,6 +78,17 @@ impl OnChainIterableKeyValueView {
         }
     }
 
+    pub fn get_sealed_blocks(
+        &self,
+        block_height_range: Range<u32>,
+    ) -> StorageResult<Option<Vec<SealedBlock>>> {
+        let blocks = block_height_range
+            .map(BlockHeight::from)
+            .map(|height| self.get_sealed_block_by_height(&height))
+            .collect::<StorageResult<Option<Vec<_>>>>()?;
+        Ok(blocks)
+    }
+
     pub fn get_sealed_block_headers(
         &self,
         block_height_range: Range<u32>,
@@ -386,6 +386,49 @@ mod tests {
         }
     }
 
+    #[test]
+    fn dry_run_with_traces__records_executed_instructions() {
+        let script = [op::movi(0x10, 7), op::addi(0x11, 0x10, 1), op::ret(0x11)]
+            .into_iter()
+            .collect::<Vec<u8>>();
+        let tx = TransactionBuilder::script(script, vec![])
+            .script_gas_limit(1_000_000)
+            .add_random_fee_input()
+            .finalize_as_transaction();
+        let components = || Components {
+            header_to_produce: Default::default(),
+            transactions_source: vec![tx.clone()],
+            coinbase_recipient: Default::default(),
+            gas_price: 0,
+        };
+        let executor = create_executor(Default::default(), Default::default());
+
+        // When
+        let result = executor
+            .dry_run_with_traces(components(), Some(false), None, Default::default())
+            .unwrap();
+
+        // Then
+        let expected_statuses = executor
+            .dry_run(components(), Some(false), None, Default::default())
+            .unwrap();
+        assert_eq!(result.len(), 1);
+        let (status, trace) = &result[0];
+        assert_eq!(status.id, expected_statuses[0].id);
+        assert_eq!(
+            status.result.receipts(),
+            expected_statuses[0].result.receipts()
+        );
+        assert!(!trace.truncated);
+        assert!(trace.storage_accesses.is_empty());
+        let pcs = trace.steps.iter().map(|step| step.pc).collect::<Vec<_>>();
+        assert_eq!(pcs, vec![0, 4, 8]);
+        assert!(trace.steps.iter().all(|step| step.contract_id.is_none()));
+        assert_eq!(trace.steps[0].instruction, u32::from(op::movi(0x10, 7)));
+        assert!(trace.steps[0].changed_registers.contains(&(0x10, 7)));
+        assert!(trace.steps[1].changed_registers.contains(&(0x11, 8)));
+    }
+
     mod coinbase {
         use crate::graphql_api::ports::DatabaseContracts;
 
@@ -2,7 +2,9 @@ use fuel_core_storage::{
     Error as StorageError,
     IsNotFound,
 };
+use fuel_core_types::fuel_crypto::Hasher;
 use std::{
+    fmt,
     net::SocketAddr,
     time::Duration,
 };
@@ -25,6 +27,30 @@ pub struct ServiceConfig {
     /// Time to wait after submitting a query before debug info will be logged about query.
     pub query_log_threshold_time: Duration,
     pub api_request_timeout: Duration,
+    /// The token required to perform the admin operations. If not set, admin operations are disabled.
+    pub admin_token: Option<AdminToken>,
+}
+
+/// The secret token authorizing the admin operations of the GraphQL API.
+/// Requests pass it in the `Authorization: Bearer <token>` header.
+#[derive(Clone, PartialEq, Eq)]
+pub struct AdminToken(String);
+
+impl AdminToken {
+    pub fn new(token: String) -> Self {
+        Self(token)
+    }
+
+    /// Compares hashes of tokens, so the time of the comparison doesn't reveal the token.
+    pub fn matches(&self, other: &AdminToken) -> bool {
+        Hasher::hash(self.0.as_bytes()) == Hasher::hash(other.0.as_bytes())
+    }
+}
+
+impl fmt::Debug for AdminToken {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("AdminToken(***)")
+    }
 }
 
 pub struct Costs {
@@ -36,6 +62,7 @@ pub struct Costs {
     pub submit: usize,
     pub submit_and_await: usize,
     pub status_change: usize,
+    pub block_subscription: usize,
     pub raw_payload: usize,
     pub storage_read: usize,
     pub storage_iterator: usize,
@@ -55,6 +82,7 @@ pub const QUERY_COSTS: Costs = Costs {
     submit: 10001,
     submit_and_await: 10001,
     status_change: 10001,
+    block_subscription: 10001,
     raw_payload: 10,
     storage_read: 10,
     storage_iterator: 100,
@@ -3,6 +3,7 @@ use crate::{
         metrics_extension::MetricsExtension,
         ports::{
             BlockProducerPort,
+            BlockSubscriptionPort,
             ConsensusModulePort,
             ConsensusProvider as ConsensusProviderTrait,
             GasPriceEstimate,
@@ -12,6 +13,7 @@ use crate::{
             TxPoolPort,
         },
         view_extension::ViewExtension,
+        AdminToken,
         Config,
     },
     schema::{
@@ -41,7 +43,9 @@ use axum::{
             ACCESS_CONTROL_ALLOW_HEADERS,
             ACCESS_CONTROL_ALLOW_METHODS,
             ACCESS_CONTROL_ALLOW_ORIGIN,
+            AUTHORIZATION,
         },
+        HeaderMap,
         HeaderValue,
     },
     response::{
@@ -62,7 +66,7 @@ use fuel_core_services::{
     RunnableTask,
     StateWatcher,
 };
-use fuel_core_storage::transactional::AtomicView;
+use fuel_core_storage::transactional::HistoricalView;
 use fuel_core_types::fuel_types::BlockHeight;
 use futures::Stream;
 use serde_json::json;
@@ -91,6 +95,7 @@ pub type BlockProducer = Box<dyn BlockProducerPort>;
 pub type TxPool = Box<dyn TxPoolPort>;
 pub type ConsensusModule = Box<dyn ConsensusModulePort>;
 pub type P2pService = Box<dyn P2pPort>;
+pub type BlockSubscriptions = Box<dyn BlockSubscriptionPort>;
 
 pub type GasPriceProvider = Box<dyn GasPriceEstimate>;
 
@@ -182,15 +187,18 @@ pub fn new_service<OnChain, OffChain>(
     producer: BlockProducer,
     consensus_module: ConsensusModule,
     p2p_service: P2pService,
+    block_subscriptions: BlockSubscriptions,
     gas_price_provider: GasPriceProvider,
     consensus_parameters_provider: ConsensusProvider,
     memory_pool: SharedMemoryPool,
 ) -> anyhow::Result<Service>
 where
-    OnChain: AtomicView + 'static,
-    OffChain: AtomicView + 'static,
+    OnChain: HistoricalView<Height = BlockHeight> + 'static,
+    OffChain: HistoricalView<Height = BlockHeight> + 'static,
     OnChain::LatestView: OnChainDatabase,
+    OnChain::ViewAtHeight: OnChainDatabase,
     OffChain::LatestView: OffChainDatabase,
+    OffChain::ViewAtHeight: OffChainDatabase,
 {
     let network_addr = config.config.addr;
     let combined_read_database =
@@ -211,6 +219,7 @@ where
         .data(producer)
         .data(consensus_module)
         .data(p2p_service)
+        .data(block_subscriptions)
         .data(gas_price_provider)
         .data(consensus_parameters_provider)
         .data(memory_pool)
@@ -268,9 +277,18 @@ async fn health() -> Json<serde_json::Value> {
 
 async fn graphql_handler(
     schema: Extension<CoreSchema>,
+    headers: HeaderMap,
     req: Json<Request>,
 ) -> Json<Response> {
-    schema.execute(req.0).await.into()
+    let mut request = req.0;
+    let token = headers
+        .get(AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "));
+    if let Some(token) = token {
+        request = request.data(AdminToken::new(token.to_string()));
+    }
+    schema.execute(request).await.into()
 }
 
 async fn graphql_subscription_handler(
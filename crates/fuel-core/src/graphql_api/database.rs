@@ -10,6 +10,7 @@ use crate::fuel_core_graphql_api::{
         OffChainDatabase,
         OnChainDatabase,
     },
+    storage::balances::TotalBalanceAmount,
 };
 use fuel_core_storage::{
     iter::{
@@ -19,7 +20,7 @@ use fuel_core_storage::{
     },
     not_found,
     tables::Transactions,
-    transactional::AtomicView,
+    transactional::HistoricalView,
     Error as StorageError,
     IsNotFound,
     Mappable,
@@ -76,15 +77,32 @@ pub type OnChainView = Arc<dyn OnChainDatabase>;
 /// The off-chain view of the database used by the [`ReadView`] to fetch off-chain data.
 pub type OffChainView = Arc<dyn OffChainDatabase>;
 
+/// The provider of the on-chain views at the latest or historical heights.
+type OnChainViewProvider = Box<
+    dyn HistoricalView<
+        Height = BlockHeight,
+        LatestView = OnChainView,
+        ViewAtHeight = OnChainView,
+    >,
+>;
+/// The provider of the off-chain views at the latest or historical heights.
+type OffChainViewProvider = Box<
+    dyn HistoricalView<
+        Height = BlockHeight,
+        LatestView = OffChainView,
+        ViewAtHeight = OffChainView,
+    >,
+>;
+
 /// The container of the on-chain and off-chain database view provides.
 /// It is used only by `ViewExtension` to create a [`ReadView`].
 pub struct ReadDatabase {
     /// The height of the genesis block.
     genesis_height: BlockHeight,
     /// The on-chain database view provider.
-    on_chain: Box<dyn AtomicView<LatestView = OnChainView>>,
+    on_chain: OnChainViewProvider,
     /// The off-chain database view provider.
-    off_chain: Box<dyn AtomicView<LatestView = OffChainView>>,
+    off_chain: OffChainViewProvider,
 }
 
 impl ReadDatabase {
@@ -95,10 +113,12 @@ impl ReadDatabase {
         off_chain: OffChain,
     ) -> Self
     where
-        OnChain: AtomicView + 'static,
-        OffChain: AtomicView + 'static,
+        OnChain: HistoricalView<Height = BlockHeight> + 'static,
+        OffChain: HistoricalView<Height = BlockHeight> + 'static,
         OnChain::LatestView: OnChainDatabase,
+        OnChain::ViewAtHeight: OnChainDatabase,
         OffChain::LatestView: OffChainDatabase,
+        OffChain::ViewAtHeight: OffChainDatabase,
     {
         Self {
             genesis_height,
@@ -119,6 +139,41 @@ impl ReadDatabase {
         })
     }
 
+    /// Creates a view of the database at the given `height`.
+    ///
+    /// The state is available only for heights retained by
+    /// the `StateRewindPolicy` of the database.
+    pub fn view_at(&self, height: BlockHeight) -> StorageResult<ReadView> {
+        if height < self.genesis_height {
+            return Err(anyhow::anyhow!(
+                "The state at height {height} is unavailable, \
+                because it is before the genesis height {}",
+                self.genesis_height
+            )
+            .into());
+        }
+
+        if let Some(latest_height) = self.on_chain.latest_height() {
+            if height > latest_height {
+                return Err(anyhow::anyhow!(
+                    "The state at height {height} is unavailable, \
+                    because the latest height is {latest_height}"
+                )
+                .into());
+            }
+        }
+
+        let unavailable = |error: StorageError| -> StorageError {
+            anyhow::anyhow!("The state at height {height} is unavailable: {error}").into()
+        };
+
+        Ok(ReadView {
+            genesis_height: self.genesis_height,
+            on_chain: self.on_chain.view_at(&height).map_err(unavailable)?,
+            off_chain: self.off_chain.view_at(&height).map_err(unavailable)?,
+        })
+    }
+
     #[cfg(feature = "test-helpers")]
     pub fn test_view(&self) -> ReadView {
         self.view().expect("The latest view always should exist")
@@ -356,4 +411,28 @@ impl OffChainDatabase for ReadView {
     fn message_is_spent(&self, nonce: &Nonce) -> StorageResult<bool> {
         self.off_chain.message_is_spent(nonce)
     }
+
+    fn balances_indexation_enabled(&self) -> StorageResult<bool> {
+        self.off_chain.balances_indexation_enabled()
+    }
+
+    fn coin_balance(
+        &self,
+        owner: &Address,
+        asset_id: &AssetId,
+    ) -> StorageResult<TotalBalanceAmount> {
+        self.off_chain.coin_balance(owner, asset_id)
+    }
+
+    fn coin_balances(
+        &self,
+        owner: &Address,
+        direction: IterDirection,
+    ) -> BoxedIter<'_, StorageResult<(AssetId, TotalBalanceAmount)>> {
+        self.off_chain.coin_balances(owner, direction)
+    }
+
+    fn message_balance(&self, owner: &Address) -> StorageResult<TotalBalanceAmount> {
+        self.off_chain.message_balance(owner)
+    }
 }
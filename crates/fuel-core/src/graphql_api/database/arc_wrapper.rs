@@ -9,9 +9,13 @@ use crate::fuel_core_graphql_api::{
     },
 };
 use fuel_core_storage::{
-    transactional::AtomicView,
+    transactional::{
+        AtomicView,
+        HistoricalView,
+    },
     Result as StorageResult,
 };
+use fuel_core_types::fuel_types::BlockHeight;
 use std::sync::Arc;
 
 /// The GraphQL can't work with the generics in [`async_graphql::Context::data_unchecked`] and requires a known type.
@@ -42,6 +46,28 @@ where
     }
 }
 
+impl<Provider, View, ViewAtHeight> HistoricalView for ArcWrapper<Provider, OnChainView>
+where
+    Provider: HistoricalView<
+        Height = BlockHeight,
+        LatestView = View,
+        ViewAtHeight = ViewAtHeight,
+    >,
+    View: OnChainDatabase + 'static,
+    ViewAtHeight: OnChainDatabase + 'static,
+{
+    type Height = BlockHeight;
+    type ViewAtHeight = OnChainView;
+
+    fn latest_height(&self) -> Option<Self::Height> {
+        self.inner.latest_height()
+    }
+
+    fn view_at(&self, height: &Self::Height) -> StorageResult<Self::ViewAtHeight> {
+        Ok(Arc::new(self.inner.view_at(height)?))
+    }
+}
+
 impl<Provider, View> AtomicView for ArcWrapper<Provider, OffChainView>
 where
     Provider: AtomicView<LatestView = View>,
@@ -53,3 +79,25 @@ where
         Ok(Arc::new(self.inner.latest_view()?))
     }
 }
+
+impl<Provider, View, ViewAtHeight> HistoricalView for ArcWrapper<Provider, OffChainView>
+where
+    Provider: HistoricalView<
+        Height = BlockHeight,
+        LatestView = View,
+        ViewAtHeight = ViewAtHeight,
+    >,
+    View: OffChainDatabase + 'static,
+    ViewAtHeight: OffChainDatabase + 'static,
+{
+    type Height = BlockHeight;
+    type ViewAtHeight = OffChainView;
+
+    fn latest_height(&self) -> Option<Self::Height> {
+        self.inner.latest_height()
+    }
+
+    fn view_at(&self, height: &Self::Height) -> StorageResult<Self::ViewAtHeight> {
+        Ok(Arc::new(self.inner.view_at(height)?))
+    }
+}
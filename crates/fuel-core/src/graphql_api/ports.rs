@@ -1,3 +1,4 @@
+use crate::fuel_core_graphql_api::storage::balances::TotalBalanceAmount;
 use async_trait::async_trait;
 use fuel_core_services::stream::BoxStream;
 use fuel_core_storage::{
@@ -18,7 +19,10 @@ use fuel_core_storage::{
     Result as StorageResult,
     StorageInspect,
 };
-use fuel_core_txpool::service::TxStatusMessage;
+use fuel_core_txpool::{
+    config::BlackList,
+    service::TxStatusMessage,
+};
 use fuel_core_types::{
     blockchain::{
         block::CompressedBlock,
@@ -54,9 +58,16 @@ use fuel_core_types::{
     },
     fuel_vm::interpreter::Memory,
     services::{
-        executor::TransactionExecutionStatus,
+        executor::{
+            StateOverrides,
+            TransactionExecutionStatus,
+            TransactionTrace,
+        },
         graphql_api::ContractBalance,
-        p2p::PeerInfo,
+        p2p::{
+            PeerId,
+            PeerInfo,
+        },
         txpool::{
             InsertionResult,
             TransactionStatus,
@@ -112,6 +123,26 @@ pub trait OffChainDatabase: Send + Sync {
     ) -> StorageResult<Option<RelayedTransactionStatus>>;
 
     fn message_is_spent(&self, nonce: &Nonce) -> StorageResult<bool>;
+
+    /// Returns `true` if the balances are indexed by the off-chain database.
+    fn balances_indexation_enabled(&self) -> StorageResult<bool>;
+
+    /// Returns the total amount of unspent coins of the `asset_id` owned by the `owner`.
+    fn coin_balance(
+        &self,
+        owner: &Address,
+        asset_id: &AssetId,
+    ) -> StorageResult<TotalBalanceAmount>;
+
+    /// Returns the total amount of unspent coins per asset owned by the `owner`.
+    fn coin_balances(
+        &self,
+        owner: &Address,
+        direction: IterDirection,
+    ) -> BoxedIter<'_, StorageResult<(AssetId, TotalBalanceAmount)>>;
+
+    /// Returns the total amount of unspent non-retryable messages owned by the `owner`.
+    fn message_balance(&self, owner: &Address) -> StorageResult<TotalBalanceAmount>;
 }
 
 /// The on chain database port expected by GraphQL API service.
@@ -201,6 +232,13 @@ pub trait TxPoolPort: Send + Sync {
         &self,
         tx_id: TxId,
     ) -> anyhow::Result<BoxStream<TxStatusMessage>>;
+
+    fn blacklist(&self) -> BlackList;
+
+    /// Adds entries to the blacklist and returns ids of evicted transactions.
+    fn add_to_blacklist(&self, entries: BlackList) -> anyhow::Result<Vec<TxId>>;
+
+    fn remove_from_blacklist(&self, entries: BlackList) -> anyhow::Result<()>;
 }
 
 #[async_trait]
@@ -211,7 +249,9 @@ pub trait BlockProducerPort: Send + Sync {
         height: Option<BlockHeight>,
         utxo_validation: Option<bool>,
         gas_price: Option<u64>,
-    ) -> anyhow::Result<Vec<TransactionExecutionStatus>>;
+        state_overrides: StateOverrides,
+        trace: bool,
+    ) -> anyhow::Result<Vec<(TransactionExecutionStatus, Option<TransactionTrace>)>>;
 }
 
 #[async_trait::async_trait]
@@ -234,9 +274,35 @@ pub trait DatabaseMessageProof: Send + Sync {
     ) -> StorageResult<MerkleProof>;
 }
 
+/// Trait that provides the blocks processed by the node for GraphQL subscriptions.
+pub trait BlockSubscriptionPort: Send + Sync {
+    /// Returns a stream of the blocks that are committed to the on-chain and off-chain
+    /// databases. The stream skips blocks if the subscriber falls behind,
+    /// so the subscriber should fetch missing blocks from the database.
+    fn subscribe_blocks(&self) -> BoxStream<Arc<CompressedBlock>>;
+}
+
 #[async_trait::async_trait]
 pub trait P2pPort: Send + Sync {
     async fn all_peer_info(&self) -> anyhow::Result<Vec<PeerInfo>>;
+
+    /// Closes all connections to the peer.
+    async fn disconnect_peer(&self, peer_id: PeerId) -> anyhow::Result<()>;
+
+    /// Bans the peer until the ban expires or is lifted.
+    async fn ban_peer(&self, peer_id: PeerId) -> anyhow::Result<()>;
+
+    /// Lifts the ban of the peer.
+    async fn unban_peer(&self, peer_id: PeerId) -> anyhow::Result<()>;
+
+    /// Adds the reserved node. The `address` is a multiaddr with the peer id.
+    async fn add_reserved_node(&self, address: String) -> anyhow::Result<()>;
+
+    /// Makes the reserved node a regular peer.
+    async fn remove_reserved_node(&self, peer_id: PeerId) -> anyhow::Result<()>;
+
+    /// Dials the peer at the multiaddr `address`.
+    async fn dial(&self, address: String) -> anyhow::Result<()>;
 }
 
 /// Trait for defining how to estimate gas price for future blocks
@@ -259,6 +325,10 @@ pub mod worker {
     use super::super::storage::blocks::FuelBlockIdsToHeights;
     use crate::{
         fuel_core_graphql_api::storage::{
+            balances::{
+                CoinBalances,
+                MessageBalances,
+            },
             coins::OwnedCoins,
             contracts::ContractsInfo,
             messages::{
@@ -320,6 +390,8 @@ pub mod worker {
         + StorageMutate<OldTransactions, Error = StorageError>
         + StorageMutate<SpentMessages, Error = StorageError>
         + StorageMutate<RelayedTransactionStatuses, Error = StorageError>
+        + StorageMutate<CoinBalances, Error = StorageError>
+        + StorageMutate<MessageBalances, Error = StorageError>
     {
         fn record_tx_id_owner(
             &mut self,
@@ -342,6 +414,13 @@ pub mod worker {
         /// Gets the total number of transactions on the chain from metadata.
         fn get_tx_count(&self) -> StorageResult<u64>;
 
+        /// Returns `true` if the balances of owners are indexed by the database.
+        fn balances_indexation_enabled(&self) -> StorageResult<bool>;
+
+        /// Marks the database as one that indexes the balances of owners.
+        /// It should be done only on a fresh database before the genesis import.
+        fn enable_balances_indexation(&mut self) -> StorageResult<()>;
+
         /// Commits the underlying changes into the database.
         fn commit(self) -> StorageResult<()>;
     }
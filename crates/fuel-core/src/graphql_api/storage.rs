@@ -36,6 +36,7 @@ use fuel_core_types::{
 };
 use statistic::StatisticTable;
 
+pub mod balances;
 pub mod blocks;
 pub mod coins;
 pub mod contracts;
@@ -48,6 +49,10 @@ pub mod relayed_transactions;
 /// Tracks the total number of transactions written to the chain
 /// It's useful for analyzing TPS or other metrics.
 const TX_COUNT: &str = "total_tx_count";
+/// Marks the database where [`balances::CoinBalances`] and [`balances::MessageBalances`]
+/// are populated since genesis. Databases created before the balances indexation
+/// don't have this flag and calculate balances by iterating over all coins and messages.
+pub const BALANCES_INDEXATION_ENABLED: &str = "balances_indexation_enabled";
 
 /// GraphQL database tables column ids to the corresponding [`fuel_core_storage::Mappable`] table.
 #[repr(u32)]
@@ -93,6 +98,10 @@ pub enum Column {
     /// Existence of a key in this column means that the message has been spent.
     /// See [`SpentMessages`](messages::SpentMessages)
     SpentMessages = 13,
+    /// See [`CoinBalances`](balances::CoinBalances)
+    CoinBalances = 14,
+    /// See [`MessageBalances`](balances::MessageBalances)
+    MessageBalances = 15,
 }
 
 impl Column {
@@ -162,6 +171,23 @@ where
         Ok(tx_count)
     }
 
+    fn balances_indexation_enabled(&self) -> StorageResult<bool> {
+        let enabled = self
+            .storage::<StatisticTable<bool>>()
+            .get(BALANCES_INDEXATION_ENABLED)?
+            .map(|enabled| enabled.into_owned())
+            .unwrap_or(false);
+        Ok(enabled)
+    }
+
+    fn enable_balances_indexation(&mut self) -> StorageResult<()> {
+        <_ as StorageMutate<StatisticTable<bool>>>::insert(
+            self,
+            BALANCES_INDEXATION_ENABLED,
+            &true,
+        )
+    }
+
     fn commit(self) -> StorageResult<()> {
         self.commit()?;
         Ok(())
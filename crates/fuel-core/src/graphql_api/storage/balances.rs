@@ -0,0 +1,95 @@
+use fuel_core_storage::{
+    blueprint::plain::Plain,
+    codec::{
+        postcard::Postcard,
+        raw::Raw,
+    },
+    structured_storage::TableWithBlueprint,
+    Mappable,
+};
+use fuel_core_types::fuel_types::{
+    Address,
+    AssetId,
+};
+use rand::{
+    distributions::{
+        Distribution,
+        Standard,
+    },
+    Rng,
+};
+
+/// The total amount of the asset owned by the address.
+/// It is wider than `u64` because the sum of all coins can exceed `u64::MAX`.
+pub type TotalBalanceAmount = u128;
+
+fuel_core_types::fuel_vm::double_key!(
+    CoinBalancesKey,
+    Address,
+    address,
+    AssetId,
+    asset_id
+);
+
+impl Distribution<CoinBalancesKey> for Standard {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> CoinBalancesKey {
+        let mut bytes = [0u8; CoinBalancesKey::LEN];
+
+        rng.fill_bytes(bytes.as_mut());
+
+        CoinBalancesKey::from_array(bytes)
+    }
+}
+
+/// The table that stores the total amount of unspent coins per owner and asset.
+pub struct CoinBalances;
+
+impl Mappable for CoinBalances {
+    type Key = CoinBalancesKey;
+    type OwnedKey = Self::Key;
+    type Value = TotalBalanceAmount;
+    type OwnedValue = Self::Value;
+}
+
+impl TableWithBlueprint for CoinBalances {
+    type Blueprint = Plain<Raw, Postcard>;
+    type Column = super::Column;
+
+    fn column() -> Self::Column {
+        Self::Column::CoinBalances
+    }
+}
+
+/// The table that stores the total amount of unspent non-retryable messages per owner.
+/// Messages are always denominated in the base asset, so the key is only the owner.
+pub struct MessageBalances;
+
+impl Mappable for MessageBalances {
+    type Key = Address;
+    type OwnedKey = Self::Key;
+    type Value = TotalBalanceAmount;
+    type OwnedValue = Self::Value;
+}
+
+impl TableWithBlueprint for MessageBalances {
+    type Blueprint = Plain<Raw, Postcard>;
+    type Column = super::Column;
+
+    fn column() -> Self::Column {
+        Self::Column::MessageBalances
+    }
+}
+
+#[cfg(test)]
+fuel_core_storage::basic_storage_tests!(
+    CoinBalances,
+    <CoinBalances as Mappable>::Key::default(),
+    <CoinBalances as Mappable>::Value::default()
+);
+
+#[cfg(test)]
+fuel_core_storage::basic_storage_tests!(
+    MessageBalances,
+    <MessageBalances as Mappable>::Key::default(),
+    <MessageBalances as Mappable>::Value::default()
+);
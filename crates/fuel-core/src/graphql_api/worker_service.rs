@@ -8,6 +8,12 @@ use crate::{
         ports,
         ports::worker::OffChainDatabaseTransaction,
         storage::{
+            balances::{
+                CoinBalances,
+                CoinBalancesKey,
+                MessageBalances,
+                TotalBalanceAmount,
+            },
             blocks::FuelBlockIdsToHeights,
             coins::{
                 owner_coin_id_key,
@@ -26,7 +32,6 @@ use crate::{
 use fuel_core_metrics::graphql_metrics::graphql_metrics;
 use fuel_core_services::{
     stream::BoxStream,
-    EmptyShared,
     RunnableService,
     RunnableTask,
     ServiceRunner,
@@ -34,8 +39,10 @@ use fuel_core_services::{
 };
 use fuel_core_storage::{
     Error as StorageError,
+    Mappable,
     Result as StorageResult,
     StorageAsMut,
+    StorageMutate,
 };
 use fuel_core_txpool::types::TxId;
 use fuel_core_types::{
@@ -88,11 +95,37 @@ use futures::{
 use std::{
     borrow::Cow,
     ops::Deref,
+    sync::Arc,
 };
+use tokio::sync::broadcast;
 
 #[cfg(test)]
 mod tests;
 
+/// The number of processed blocks buffered for GraphQL subscribers.
+/// Subscribers that fall behind the buffer miss the notifications
+/// and should fetch the skipped blocks from the database.
+const BLOCK_NOTIFICATIONS_BUFFER: usize = 1024;
+
+/// The shared state of the off-chain worker.
+#[derive(Clone)]
+pub struct SharedState {
+    /// Notifies about the blocks once their off-chain data is committed.
+    block_notifier: broadcast::Sender<Arc<CompressedBlock>>,
+}
+
+impl SharedState {
+    fn new() -> Self {
+        let (block_notifier, _) = broadcast::channel(BLOCK_NOTIFICATIONS_BUFFER);
+        Self { block_notifier }
+    }
+
+    /// Subscribes to the blocks processed by the off-chain worker.
+    pub fn subscribe_blocks(&self) -> broadcast::Receiver<Arc<CompressedBlock>> {
+        self.block_notifier.subscribe()
+    }
+}
+
 /// The initialization task recovers the state of the GraphQL service database on startup.
 pub struct InitializeTask<TxPool, BlockImporter, OnChain, OffChain> {
     chain_id: ChainId,
@@ -102,6 +135,7 @@ pub struct InitializeTask<TxPool, BlockImporter, OnChain, OffChain> {
     block_importer: BlockImporter,
     on_chain_database: OnChain,
     off_chain_database: OffChain,
+    shared_state: SharedState,
 }
 
 /// The off-chain GraphQL API worker task processes the imported blocks
@@ -112,6 +146,7 @@ pub struct Task<TxPool, D> {
     database: D,
     chain_id: ChainId,
     continue_on_error: bool,
+    shared_state: SharedState,
 }
 
 impl<TxPool, D> Task<TxPool, D>
@@ -157,6 +192,13 @@ where
         // update the importer metrics after the block is successfully committed
         graphql_metrics().total_txs_count.set(total_tx_count as i64);
 
+        // The compression requires calculation of transaction ids,
+        // so skip it if nobody is subscribed.
+        if self.shared_state.block_notifier.receiver_count() > 0 {
+            let compressed_block = Arc::new(block.compress(&self.chain_id));
+            let _ = self.shared_state.block_notifier.send(compressed_block);
+        }
+
         Ok(())
     }
 }
@@ -170,6 +212,8 @@ where
     Iter: Iterator<Item = Cow<'a, Event>>,
     T: OffChainDatabaseTransaction,
 {
+    let balances_indexation_enabled =
+        block_st_transaction.balances_indexation_enabled()?;
     for event in events {
         match event.deref() {
             Event::MessageImported(message) => {
@@ -179,6 +223,16 @@ where
                         &OwnedMessageKey::new(message.recipient(), message.nonce()),
                         &(),
                     )?;
+                if balances_indexation_enabled && message.data().is_empty() {
+                    update_balance::<MessageBalances, _>(
+                        message.recipient(),
+                        |balance| {
+                            balance
+                                .checked_add(TotalBalanceAmount::from(message.amount()))
+                        },
+                        block_st_transaction,
+                    )?;
+                }
             }
             Event::MessageConsumed(message) => {
                 block_st_transaction
@@ -190,18 +244,46 @@ where
                 block_st_transaction
                     .storage::<SpentMessages>()
                     .insert(message.nonce(), &())?;
+                if balances_indexation_enabled && message.data().is_empty() {
+                    update_balance::<MessageBalances, _>(
+                        message.recipient(),
+                        |balance| {
+                            balance
+                                .checked_sub(TotalBalanceAmount::from(message.amount()))
+                        },
+                        block_st_transaction,
+                    )?;
+                }
             }
             Event::CoinCreated(coin) => {
                 let coin_by_owner = owner_coin_id_key(&coin.owner, &coin.utxo_id);
                 block_st_transaction
                     .storage_as_mut::<OwnedCoins>()
                     .insert(&coin_by_owner, &())?;
+                if balances_indexation_enabled {
+                    update_balance::<CoinBalances, _>(
+                        &CoinBalancesKey::new(&coin.owner, &coin.asset_id),
+                        |balance| {
+                            balance.checked_add(TotalBalanceAmount::from(coin.amount))
+                        },
+                        block_st_transaction,
+                    )?;
+                }
             }
             Event::CoinConsumed(coin) => {
                 let key = owner_coin_id_key(&coin.owner, &coin.utxo_id);
                 block_st_transaction
                     .storage_as_mut::<OwnedCoins>()
                     .remove(&key)?;
+                if balances_indexation_enabled {
+                    update_balance::<CoinBalances, _>(
+                        &CoinBalancesKey::new(&coin.owner, &coin.asset_id),
+                        |balance| {
+                            balance.checked_sub(TotalBalanceAmount::from(coin.amount))
+                        },
+                        block_st_transaction,
+                    )?;
+                }
             }
             Event::ForcedTransactionFailed {
                 id,
@@ -222,6 +304,39 @@ where
     Ok(())
 }
 
+/// Applies the `update` to the indexed balance under the `key`.
+/// The entry is removed when the balance drops to zero to keep the table compact.
+fn update_balance<M, T>(
+    key: &M::Key,
+    update: impl FnOnce(TotalBalanceAmount) -> Option<TotalBalanceAmount>,
+    block_st_transaction: &mut T,
+) -> StorageResult<()>
+where
+    M: Mappable<Value = TotalBalanceAmount, OwnedValue = TotalBalanceAmount>,
+    T: OffChainDatabaseTransaction + StorageMutate<M, Error = StorageError>,
+{
+    let current_balance = block_st_transaction
+        .storage::<M>()
+        .get(key)?
+        .map(|balance| balance.into_owned())
+        .unwrap_or_default();
+    let new_balance = update(current_balance).ok_or_else(|| {
+        anyhow::anyhow!(
+            "The indexed balance {current_balance} can't be updated because of an overflow or underflow"
+        )
+    })?;
+
+    if new_balance == 0 {
+        block_st_transaction.storage::<M>().remove(key)?;
+    } else {
+        block_st_transaction
+            .storage::<M>()
+            .insert(key, &new_balance)?;
+    }
+
+    Ok(())
+}
+
 /// Associate all transactions within a block to their respective UTXO owners
 fn index_tx_owners_for_block<T>(
     block: &Block,
@@ -434,12 +549,12 @@ where
     OffChain: ports::worker::OffChainDatabase,
 {
     const NAME: &'static str = "GraphQL_Off_Chain_Worker";
-    type SharedData = EmptyShared;
+    type SharedData = SharedState;
     type Task = Task<TxPool, OffChain>;
     type TaskParams = ();
 
     fn shared_data(&self) -> Self::SharedData {
-        EmptyShared
+        self.shared_state.clone()
     }
 
     async fn into_task(
@@ -461,6 +576,7 @@ where
             on_chain_database,
             off_chain_database,
             continue_on_error,
+            shared_state,
         } = self;
 
         let mut task = Task {
@@ -469,6 +585,7 @@ where
             database: off_chain_database,
             chain_id,
             continue_on_error,
+            shared_state,
         };
 
         let mut target_chain_height = on_chain_database.latest_height()?;
@@ -595,5 +712,6 @@ where
         off_chain_database,
         chain_id,
         continue_on_error,
+        shared_state: SharedState::new(),
     })
 }
@@ -2,14 +2,35 @@
 
 use super::*;
 use crate::{
-    database::Database,
+    database::{
+        database_description::off_chain::OffChain,
+        Database,
+    },
     graphql_api::storage::relayed_transactions::RelayedTransactionStatuses,
 };
 use fuel_core_services::stream::IntoBoxStream;
-use fuel_core_storage::StorageAsRef;
+use fuel_core_storage::{
+    transactional::IntoTransaction,
+    StorageAsRef,
+};
 use fuel_core_types::{
-    fuel_tx::Bytes32,
-    fuel_types::BlockHeight,
+    entities::{
+        coins::coin::Coin,
+        relayer::message::{
+            Message,
+            MessageV1,
+        },
+    },
+    fuel_tx::{
+        AssetId,
+        Bytes32,
+        UtxoId,
+    },
+    fuel_types::{
+        Address,
+        BlockHeight,
+        Nonce,
+    },
     services::txpool::TransactionStatus,
 };
 use std::sync::Arc;
@@ -59,6 +80,141 @@ async fn run__relayed_transaction_events_are_added_to_storage() {
     assert_eq!(*actual, expected);
 }
 
+#[test]
+fn process_executor_events__coin_events_update_indexed_balance() {
+    let mut database = Database::<OffChain>::in_memory();
+    let owner = Address::from([1; 32]);
+    let asset_id = AssetId::from([2; 32]);
+    let first_coin = coin(owner, asset_id, 1, 100);
+    let second_coin = coin(owner, asset_id, 2, 50);
+    enable_balances_indexation(&mut database);
+
+    // given
+    let events = [
+        Event::CoinCreated(first_coin),
+        Event::CoinCreated(second_coin),
+    ];
+    process_events(&mut database, events);
+
+    // when
+    process_events(&mut database, [Event::CoinConsumed(first_coin)]);
+
+    // then
+    let balance = database
+        .storage_as_ref::<CoinBalances>()
+        .get(&CoinBalancesKey::new(&owner, &asset_id))
+        .unwrap()
+        .unwrap()
+        .into_owned();
+    assert_eq!(balance, 50);
+}
+
+#[test]
+fn process_executor_events__spent_balance_is_removed_from_index() {
+    let mut database = Database::<OffChain>::in_memory();
+    let owner = Address::from([1; 32]);
+    let asset_id = AssetId::from([2; 32]);
+    let coin = coin(owner, asset_id, 1, 100);
+    enable_balances_indexation(&mut database);
+
+    // given
+    process_events(&mut database, [Event::CoinCreated(coin)]);
+
+    // when
+    process_events(&mut database, [Event::CoinConsumed(coin)]);
+
+    // then
+    let balance = database
+        .storage_as_ref::<CoinBalances>()
+        .get(&CoinBalancesKey::new(&owner, &asset_id))
+        .unwrap();
+    assert!(balance.is_none());
+}
+
+#[test]
+fn process_executor_events__only_non_retryable_messages_are_indexed() {
+    let mut database = Database::<OffChain>::in_memory();
+    let owner = Address::from([1; 32]);
+    enable_balances_indexation(&mut database);
+
+    // given
+    let spendable = message(owner, 1, 100, vec![]);
+    let retryable = message(owner, 2, 1_000, vec![1, 2, 3]);
+
+    // when
+    process_events(
+        &mut database,
+        [
+            Event::MessageImported(spendable),
+            Event::MessageImported(retryable),
+        ],
+    );
+
+    // then
+    let balance = database
+        .storage_as_ref::<MessageBalances>()
+        .get(&owner)
+        .unwrap()
+        .unwrap()
+        .into_owned();
+    assert_eq!(balance, 100);
+}
+
+#[test]
+fn process_executor_events__balances_are_not_indexed_without_flag() {
+    let mut database = Database::<OffChain>::in_memory();
+    let owner = Address::from([1; 32]);
+    let asset_id = AssetId::from([2; 32]);
+
+    // when
+    process_events(
+        &mut database,
+        [Event::CoinConsumed(coin(owner, asset_id, 1, 100))],
+    );
+
+    // then
+    let balance = database
+        .storage_as_ref::<CoinBalances>()
+        .get(&CoinBalancesKey::new(&owner, &asset_id))
+        .unwrap();
+    assert!(balance.is_none());
+}
+
+fn coin(owner: Address, asset_id: AssetId, tx_id: u8, amount: u64) -> Coin {
+    Coin {
+        utxo_id: UtxoId::new([tx_id; 32].into(), 0),
+        owner,
+        amount,
+        asset_id,
+        tx_pointer: Default::default(),
+    }
+}
+
+fn message(recipient: Address, nonce: u8, amount: u64, data: Vec<u8>) -> Message {
+    MessageV1 {
+        sender: Default::default(),
+        recipient,
+        nonce: Nonce::from([nonce; 32]),
+        amount,
+        data,
+        da_height: Default::default(),
+    }
+    .into()
+}
+
+fn enable_balances_indexation(database: &mut Database<OffChain>) {
+    let mut transaction = database.into_transaction();
+    transaction.enable_balances_indexation().unwrap();
+    transaction.commit().unwrap();
+}
+
+fn process_events<const N: usize>(database: &mut Database<OffChain>, events: [Event; N]) {
+    let mut transaction = database.into_transaction();
+    process_executor_events(events.into_iter().map(Cow::Owned), &mut transaction)
+        .unwrap();
+    transaction.commit().unwrap();
+}
+
 fn block_importer_for_event(event: Event) -> BoxStream<SharedImportResult> {
     let block = Arc::new(ImportResult {
         sealed_block: Default::default(),
@@ -82,5 +238,6 @@ fn worker_task_with_block_importer_and_db<D: ports::worker::OffChainDatabase>(
         database,
         chain_id,
         continue_on_error: false,
+        shared_state: SharedState::new(),
     }
 }
@@ -1,4 +1,8 @@
-use crate::fuel_core_graphql_api::database::ReadView;
+use crate::fuel_core_graphql_api::{
+    database::ReadView,
+    ports::OffChainDatabase,
+    storage::balances::TotalBalanceAmount,
+};
 use asset_query::{
     AssetQuery,
     AssetSpendTarget,
@@ -22,7 +26,11 @@ use fuel_core_types::{
 use itertools::Itertools;
 use std::{
     cmp::Ordering,
-    collections::HashMap,
+    collections::{
+        BTreeMap,
+        HashMap,
+    },
+    iter,
 };
 
 pub mod asset_query;
@@ -49,6 +57,90 @@ impl BalanceQueryData for ReadView {
         owner: Address,
         asset_id: AssetId,
         base_asset_id: AssetId,
+    ) -> StorageResult<AddressBalance> {
+        if !self.balances_indexation_enabled()? {
+            return self.balance_without_index(owner, asset_id, base_asset_id)
+        }
+
+        let mut amount = self.coin_balance(&owner, &asset_id)?;
+        if asset_id == base_asset_id {
+            amount = amount.saturating_add(self.message_balance(&owner)?);
+        }
+
+        Ok(AddressBalance {
+            owner,
+            amount: saturating_balance(amount),
+            asset_id,
+        })
+    }
+
+    fn balances(
+        &self,
+        owner: Address,
+        direction: IterDirection,
+        base_asset_id: AssetId,
+    ) -> BoxedIter<StorageResult<AddressBalance>> {
+        match self.balances_indexation_enabled() {
+            Ok(true) => self.balances_with_index(owner, direction, base_asset_id),
+            Ok(false) => self.balances_without_index(owner, direction, base_asset_id),
+            Err(err) => iter::once(Err(err)).into_boxed(),
+        }
+    }
+}
+
+impl ReadView {
+    fn balances_with_index(
+        &self,
+        owner: Address,
+        direction: IterDirection,
+        base_asset_id: AssetId,
+    ) -> BoxedIter<StorageResult<AddressBalance>> {
+        let message_balance = match self.message_balance(&owner) {
+            Ok(balance) => balance,
+            Err(err) => return iter::once(Err(err)).into_boxed(),
+        };
+
+        // The messages are denominated in the base asset, so their balance is merged
+        // with the base asset coins. The base asset may not have coins at all.
+        let mut amounts_per_asset = BTreeMap::new();
+        if message_balance > 0 {
+            amounts_per_asset.insert(base_asset_id, message_balance);
+        }
+
+        for result in self.coin_balances(&owner, IterDirection::Forward) {
+            match result {
+                Ok((asset_id, amount)) => {
+                    let total: &mut TotalBalanceAmount =
+                        amounts_per_asset.entry(asset_id).or_default();
+                    *total = total.saturating_add(amount);
+                }
+                Err(err) => return iter::once(Err(err)).into_boxed(),
+            }
+        }
+
+        let mut balances = amounts_per_asset
+            .into_iter()
+            .map(|(asset_id, amount)| AddressBalance {
+                owner,
+                amount: saturating_balance(amount),
+                asset_id,
+            })
+            .collect_vec();
+
+        if direction == IterDirection::Reverse {
+            balances.reverse();
+        }
+
+        balances.into_iter().map(Ok).into_boxed()
+    }
+
+    /// Calculates the balance by iterating over all coins and messages of the `owner`.
+    /// It is used by databases created before the balances indexation.
+    fn balance_without_index(
+        &self,
+        owner: Address,
+        asset_id: AssetId,
+        base_asset_id: AssetId,
     ) -> StorageResult<AddressBalance> {
         let amount = AssetQuery::new(
             &owner,
@@ -75,7 +167,9 @@ impl BalanceQueryData for ReadView {
         })
     }
 
-    fn balances(
+    /// Calculates the balances by iterating over all coins and messages of the `owner`.
+    /// It is used by databases created before the balances indexation.
+    fn balances_without_index(
         &self,
         owner: Address,
         direction: IterDirection,
@@ -126,3 +220,8 @@ impl BalanceQueryData for ReadView {
             .into_boxed()
     }
 }
+
+/// The GraphQL API reports balances as `u64`, so a bigger total is capped.
+fn saturating_balance(amount: TotalBalanceAmount) -> u64 {
+    u64::try_from(amount).unwrap_or(u64::MAX)
+}
@@ -13,6 +13,12 @@ use fuel_core_types::{
     },
     fuel_types::BlockHeight,
 };
+use futures::{
+    stream::BoxStream,
+    Stream,
+    StreamExt,
+};
+use std::sync::Arc;
 
 pub trait SimpleBlockData: Send + Sync {
     fn block(&self, id: &BlockHeight) -> StorageResult<CompressedBlock>;
@@ -65,3 +71,179 @@ where
         self.consensus(id)
     }
 }
+
+/// Returns a stream of blocks starting from the `start_height`.
+/// The blocks that already exist are fetched via `get_block`, after that the stream
+/// follows the `new_blocks`. If the `new_blocks` skips some blocks because
+/// the subscriber is too slow, the skipped blocks are fetched via `get_block`
+/// as well, so the stream always returns blocks in order and without gaps.
+///
+/// If the `start_height` is `None`, the stream begins with the next new block.
+/// The stream ends after the first error.
+pub(crate) fn blocks_subscription<'a, F>(
+    start_height: Option<BlockHeight>,
+    latest_height: BlockHeight,
+    new_blocks: BoxStream<'a, Arc<CompressedBlock>>,
+    get_block: F,
+) -> impl Stream<Item = StorageResult<CompressedBlock>> + 'a
+where
+    F: Fn(&BlockHeight) -> StorageResult<CompressedBlock> + Send + 'a,
+{
+    let state = BlocksSubscriptionState {
+        next_height: start_height,
+        known_height: latest_height,
+        new_blocks,
+        get_block,
+    };
+
+    futures::stream::unfold(Some(state), |state| async move {
+        let mut state = state?;
+        loop {
+            if let Some(next_height) = state.next_height {
+                if next_height <= state.known_height {
+                    let result = (state.get_block)(&next_height);
+                    state.next_height = next_height.succ();
+                    let state = result.is_ok().then_some(state);
+                    return Some((result, state))
+                }
+            }
+
+            let block = state.new_blocks.next().await?;
+            let height = *block.header().height();
+            state.known_height = state.known_height.max(height);
+
+            match state.next_height {
+                // The block was already returned.
+                Some(next_height) if height < next_height => continue,
+                // Some blocks were skipped, fetch them from the database first.
+                Some(next_height) if height > next_height => continue,
+                _ => {
+                    state.next_height = height.succ();
+                    let block = Arc::unwrap_or_clone(block);
+                    return Some((Ok(block), Some(state)))
+                }
+            }
+        }
+    })
+}
+
+struct BlocksSubscriptionState<'a, F> {
+    /// The height of the next block to return.
+    next_height: Option<BlockHeight>,
+    /// The highest height of the block that is known to exist in the database.
+    known_height: BlockHeight,
+    new_blocks: BoxStream<'a, Arc<CompressedBlock>>,
+    get_block: F,
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use super::*;
+    use fuel_core_storage::not_found;
+
+    fn block_at(height: u32) -> CompressedBlock {
+        let mut block = CompressedBlock::default();
+        block.header_mut().set_block_height(height.into());
+        block
+    }
+
+    fn stored_blocks(
+        latest_height: u32,
+    ) -> impl Fn(&BlockHeight) -> StorageResult<CompressedBlock> {
+        move |height| {
+            if **height <= latest_height {
+                Ok(block_at(**height))
+            } else {
+                Err(not_found!("FuelBlocks"))
+            }
+        }
+    }
+
+    fn new_blocks(heights: Vec<u32>) -> BoxStream<'static, Arc<CompressedBlock>> {
+        futures::stream::iter(
+            heights.into_iter().map(|height| Arc::new(block_at(height))),
+        )
+        .boxed()
+    }
+
+    async fn heights(
+        stream: impl Stream<Item = StorageResult<CompressedBlock>>,
+    ) -> Vec<u32> {
+        stream
+            .map(|result| **result.unwrap().header().height())
+            .collect()
+            .await
+    }
+
+    #[tokio::test]
+    async fn blocks_subscription__returns_existing_blocks_before_new_ones() {
+        // given
+        let latest_height = 3;
+        let new_blocks = new_blocks(vec![3, 4, 5]);
+
+        // when
+        let stream = blocks_subscription(
+            Some(1.into()),
+            latest_height.into(),
+            new_blocks,
+            stored_blocks(5),
+        );
+
+        // then
+        assert_eq!(heights(stream).await, vec![1, 2, 3, 4, 5]);
+    }
+
+    #[tokio::test]
+    async fn blocks_subscription__without_start_height_returns_only_new_blocks() {
+        // given
+        let latest_height = 3;
+        let new_blocks = new_blocks(vec![4, 5]);
+
+        // when
+        let stream =
+            blocks_subscription(None, latest_height.into(), new_blocks, stored_blocks(5));
+
+        // then
+        assert_eq!(heights(stream).await, vec![4, 5]);
+    }
+
+    #[tokio::test]
+    async fn blocks_subscription__fetches_skipped_blocks_from_storage() {
+        // given
+        let latest_height = 1;
+        let new_blocks = new_blocks(vec![2, 5, 6]);
+
+        // when
+        let stream = blocks_subscription(
+            Some(1.into()),
+            latest_height.into(),
+            new_blocks,
+            stored_blocks(6),
+        );
+
+        // then
+        assert_eq!(heights(stream).await, vec![1, 2, 3, 4, 5, 6]);
+    }
+
+    #[tokio::test]
+    async fn blocks_subscription__ends_after_storage_error() {
+        // given
+        let latest_height = 3;
+        let new_blocks = new_blocks(vec![4]);
+
+        // when
+        let stream = blocks_subscription(
+            Some(1.into()),
+            latest_height.into(),
+            new_blocks,
+            stored_blocks(1),
+        );
+
+        // then
+        let results: Vec<_> = stream.collect().await;
+        assert_eq!(results.len(), 2);
+        assert!(results[0].is_ok());
+        assert!(results[1].is_err());
+    }
+}
@@ -23,9 +23,11 @@ use fuel_core_storage::{
     iter::IterDirection,
     Result as StorageResult,
 };
+use fuel_core_types::fuel_types::BlockHeight;
 use itertools::Itertools;
 use std::borrow::Cow;
 
+pub mod admin;
 pub mod balance;
 pub mod blob;
 pub mod block;
@@ -47,6 +49,7 @@ pub mod relayed_tx;
 #[derive(MergedObject, Default)]
 pub struct Query(
     dap::DapQuery,
+    admin::AdminQuery,
     balance::BalanceQuery,
     blob::BlobQuery,
     block::BlockQuery,
@@ -65,10 +68,15 @@ pub struct Query(
 );
 
 #[derive(MergedObject, Default)]
-pub struct Mutation(dap::DapMutation, tx::TxMutation, block::BlockMutation);
+pub struct Mutation(
+    dap::DapMutation,
+    admin::AdminMutation,
+    tx::TxMutation,
+    block::BlockMutation,
+);
 
 #[derive(MergedSubscription, Default)]
-pub struct Subscription(tx::TxStatusSubscription);
+pub struct Subscription(tx::TxStatusSubscription, block::BlockSubscription);
 
 pub type CoreSchema = Schema<Query, Mutation, Subscription>;
 pub type CoreSchemaBuilder = SchemaBuilder<Query, Mutation, Subscription>;
@@ -210,6 +218,10 @@ where
 pub trait ReadViewProvider {
     /// Returns the read view for the current operation.
     fn read_view(&self) -> StorageResult<Cow<ReadView>>;
+
+    /// Returns the read view at the given `height` or
+    /// the read view for the current operation if the `height` is not specified.
+    fn read_view_at(&self, height: Option<BlockHeight>) -> StorageResult<Cow<ReadView>>;
 }
 
 impl<'a> ReadViewProvider for Context<'a> {
@@ -226,4 +238,14 @@ impl<'a> ReadViewProvider for Context<'a> {
             Ok(Cow::Borrowed(read_view))
         }
     }
+
+    fn read_view_at(&self, height: Option<BlockHeight>) -> StorageResult<Cow<ReadView>> {
+        match height {
+            Some(height) => {
+                let database: &ReadDatabase = self.data_unchecked();
+                database.view_at(height).map(Cow::Owned)
+            }
+            None => self.read_view(),
+        }
+    }
 }
@@ -0,0 +1,212 @@
+use crate::{
+    fuel_core_graphql_api::{
+        api_service::{
+            P2pService,
+            TxPool,
+        },
+        AdminToken,
+        Config,
+    },
+    schema::scalars::{
+        Address,
+        ContractId,
+        Nonce,
+        TransactionId,
+        UtxoId,
+    },
+};
+use async_graphql::{
+    Context,
+    Object,
+};
+use fuel_core_txpool::config::BlackList;
+use fuel_core_types::services::p2p::PeerId;
+use std::str::FromStr;
+
+fn require_admin(ctx: &Context<'_>) -> async_graphql::Result<()> {
+    let config = ctx.data_unchecked::<Config>();
+    let Some(admin_token) = config.config.admin_token.as_ref() else {
+        return Err(async_graphql::Error::new(
+            "The admin operations are disabled",
+        ))
+    };
+
+    match ctx.data_opt::<AdminToken>() {
+        Some(token) if admin_token.matches(token) => Ok(()),
+        _ => Err(async_graphql::Error::new("Invalid admin token")),
+    }
+}
+
+fn blacklist(
+    owners: Vec<Address>,
+    utxo_ids: Vec<UtxoId>,
+    messages: Vec<Nonce>,
+    contracts: Vec<ContractId>,
+) -> BlackList {
+    BlackList::new(
+        owners.into_iter().map(Into::into).collect(),
+        utxo_ids.into_iter().map(Into::into).collect(),
+        messages.into_iter().map(Into::into).collect(),
+        contracts.into_iter().map(Into::into).collect(),
+    )
+}
+
+fn parse_peer_id(peer_id: &str) -> async_graphql::Result<PeerId> {
+    PeerId::from_str(peer_id)
+        .map_err(|e| async_graphql::Error::new(format!("Invalid peer id: {e}")))
+}
+
+pub struct TxPoolBlacklist(BlackList);
+
+#[Object]
+impl TxPoolBlacklist {
+    async fn owners(&self) -> Vec<Address> {
+        self.0.owners().copied().map(Into::into).collect()
+    }
+
+    async fn utxo_ids(&self) -> Vec<UtxoId> {
+        self.0.coins().copied().map(Into::into).collect()
+    }
+
+    async fn messages(&self) -> Vec<Nonce> {
+        self.0.messages().copied().map(Into::into).collect()
+    }
+
+    async fn contracts(&self) -> Vec<ContractId> {
+        self.0.contracts().copied().map(Into::into).collect()
+    }
+}
+
+#[derive(Default)]
+pub struct AdminQuery;
+
+#[Object]
+impl AdminQuery {
+    /// Returns the blacklist of the `TxPool`. Requires the admin token.
+    async fn tx_pool_blacklist(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<TxPoolBlacklist> {
+        require_admin(ctx)?;
+        let txpool = ctx.data_unchecked::<TxPool>();
+        Ok(TxPoolBlacklist(txpool.blacklist()))
+    }
+}
+
+#[derive(Default)]
+pub struct AdminMutation;
+
+#[Object]
+impl AdminMutation {
+    /// Adds entries to the blacklist of the `TxPool`. Requires the admin token.
+    ///
+    /// Pending transactions matching new entries are squeezed out.
+    /// Returns ids of squeezed out transactions.
+    async fn add_to_tx_pool_blacklist(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(default)] owners: Vec<Address>,
+        #[graphql(default)] utxo_ids: Vec<UtxoId>,
+        #[graphql(default)] messages: Vec<Nonce>,
+        #[graphql(default)] contracts: Vec<ContractId>,
+    ) -> async_graphql::Result<Vec<TransactionId>> {
+        require_admin(ctx)?;
+        let txpool = ctx.data_unchecked::<TxPool>();
+        let removed =
+            txpool.add_to_blacklist(blacklist(owners, utxo_ids, messages, contracts))?;
+        Ok(removed.into_iter().map(Into::into).collect())
+    }
+
+    /// Removes entries from the blacklist of the `TxPool`. Requires the admin token.
+    async fn remove_from_tx_pool_blacklist(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(default)] owners: Vec<Address>,
+        #[graphql(default)] utxo_ids: Vec<UtxoId>,
+        #[graphql(default)] messages: Vec<Nonce>,
+        #[graphql(default)] contracts: Vec<ContractId>,
+    ) -> async_graphql::Result<bool> {
+        require_admin(ctx)?;
+        let txpool = ctx.data_unchecked::<TxPool>();
+        txpool.remove_from_blacklist(blacklist(owners, utxo_ids, messages, contracts))?;
+        Ok(true)
+    }
+
+    /// Closes all connections to the peer. Requires the admin token.
+    ///
+    /// Reserved peers are reconnected later.
+    async fn disconnect_peer(
+        &self,
+        ctx: &Context<'_>,
+        peer_id: String,
+    ) -> async_graphql::Result<bool> {
+        require_admin(ctx)?;
+        let p2p = ctx.data_unchecked::<P2pService>();
+        p2p.disconnect_peer(parse_peer_id(&peer_id)?).await?;
+        Ok(true)
+    }
+
+    /// Bans the peer and closes all connections to it. Requires the admin token.
+    ///
+    /// The ban expires after some time, like bans of misbehaving peers.
+    async fn ban_peer(
+        &self,
+        ctx: &Context<'_>,
+        peer_id: String,
+    ) -> async_graphql::Result<bool> {
+        require_admin(ctx)?;
+        let p2p = ctx.data_unchecked::<P2pService>();
+        p2p.ban_peer(parse_peer_id(&peer_id)?).await?;
+        Ok(true)
+    }
+
+    /// Lifts the ban of the peer. Requires the admin token.
+    async fn unban_peer(
+        &self,
+        ctx: &Context<'_>,
+        peer_id: String,
+    ) -> async_graphql::Result<bool> {
+        require_admin(ctx)?;
+        let p2p = ctx.data_unchecked::<P2pService>();
+        p2p.unban_peer(parse_peer_id(&peer_id)?).await?;
+        Ok(true)
+    }
+
+    /// Adds the reserved node and connects to it. Requires the admin token.
+    ///
+    /// The `address` is a multiaddr ending with the peer id, like `/ip4/127.0.0.1/tcp/30333/p2p/<peer id>`.
+    async fn add_reserved_node(
+        &self,
+        ctx: &Context<'_>,
+        address: String,
+    ) -> async_graphql::Result<bool> {
+        require_admin(ctx)?;
+        let p2p = ctx.data_unchecked::<P2pService>();
+        p2p.add_reserved_node(address).await?;
+        Ok(true)
+    }
+
+    /// Makes the reserved node a regular peer. Requires the admin token.
+    async fn remove_reserved_node(
+        &self,
+        ctx: &Context<'_>,
+        peer_id: String,
+    ) -> async_graphql::Result<bool> {
+        require_admin(ctx)?;
+        let p2p = ctx.data_unchecked::<P2pService>();
+        p2p.remove_reserved_node(parse_peer_id(&peer_id)?).await?;
+        Ok(true)
+    }
+
+    /// Dials the peer at the multiaddr `address`. Requires the admin token.
+    async fn dial_peer(
+        &self,
+        ctx: &Context<'_>,
+        address: String,
+    ) -> async_graphql::Result<bool> {
+        require_admin(ctx)?;
+        let p2p = ctx.data_unchecked::<P2pService>();
+        p2p.dial(address).await?;
+        Ok(true)
+    }
+}
@@ -8,6 +8,7 @@ use crate::{
         scalars::{
             Address,
             AssetId,
+            U32,
             U64,
         },
         ReadViewProvider,
@@ -59,8 +60,10 @@ impl BalanceQuery {
         ctx: &Context<'_>,
         #[graphql(desc = "address of the owner")] owner: Address,
         #[graphql(desc = "asset_id of the coin")] asset_id: AssetId,
+        #[graphql(desc = "the height of the block at which the state is read")]
+        at_height: Option<U32>,
     ) -> async_graphql::Result<Balance> {
-        let query = ctx.read_view()?;
+        let query = ctx.read_view_at(at_height.map(Into::into))?;
         let base_asset_id = *ctx
             .data_unchecked::<ConsensusProvider>()
             .latest_consensus_params()
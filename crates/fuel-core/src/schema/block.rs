@@ -5,7 +5,10 @@ use super::scalars::{
 };
 use crate::{
     fuel_core_graphql_api::{
-        api_service::ConsensusModule,
+        api_service::{
+            BlockSubscriptions,
+            ConsensusModule,
+        },
         database::ReadView,
         ports::OffChainDatabase,
         Config as GraphQLConfig,
@@ -13,6 +16,7 @@ use crate::{
         QUERY_COSTS,
     },
     query::{
+        blocks_subscription,
         BlockQueryData,
         SimpleBlockData,
         SimpleTransactionData,
@@ -39,6 +43,7 @@ use async_graphql::{
     Enum,
     Object,
     SimpleObject,
+    Subscription,
     Union,
 };
 use fuel_core_storage::{
@@ -57,6 +62,10 @@ use fuel_core_types::{
     fuel_types,
     fuel_types::BlockHeight,
 };
+use futures::{
+    Stream,
+    StreamExt,
+};
 
 pub struct Block(pub(crate) CompressedBlock);
 
@@ -67,6 +76,7 @@ pub struct Header(pub(crate) BlockHeader);
 pub enum Consensus {
     Genesis(Genesis),
     PoA(PoAConsensus),
+    Bft(BftConsensus),
 }
 
 type CoreGenesis = fuel_core_types::blockchain::consensus::Genesis;
@@ -91,6 +101,12 @@ pub struct PoAConsensus {
     signature: Signature,
 }
 
+pub struct BftConsensus {
+    round: U32,
+    producer_signature: Signature,
+    signatures: Vec<Signature>,
+}
+
 #[derive(Clone, Copy, Debug, Enum, Eq, PartialEq)]
 pub enum BlockVersion {
     V1,
@@ -241,6 +257,24 @@ impl PoAConsensus {
     }
 }
 
+#[Object]
+impl BftConsensus {
+    /// Gets the round in which validators committed the block.
+    async fn round(&self) -> U32 {
+        self.round
+    }
+
+    /// Gets the signature of the validator that proposed the block.
+    async fn producer_signature(&self) -> Signature {
+        self.producer_signature
+    }
+
+    /// Gets the precommit signatures of validators that committed the block.
+    async fn signatures(&self) -> Vec<Signature> {
+        self.signatures.clone()
+    }
+}
+
 #[derive(Default)]
 pub struct BlockQuery;
 
@@ -359,6 +393,64 @@ where
     blocks.into_boxed()
 }
 
+#[derive(Default)]
+pub struct BlockSubscription;
+
+#[Subscription]
+impl BlockSubscription {
+    /// Returns a stream of blocks. If the `start_height` is specified, the stream
+    /// returns all blocks from this height, including already existing ones,
+    /// and continues with the new blocks. Otherwise, the stream returns only new blocks.
+    ///
+    /// The blocks are returned in order and without gaps.
+    #[graphql(complexity = "QUERY_COSTS.block_subscription + child_complexity")]
+    async fn new_blocks<'a>(
+        &self,
+        ctx: &'a Context<'a>,
+        #[graphql(desc = "Height of the first block")] start_height: Option<U32>,
+    ) -> async_graphql::Result<impl Stream<Item = async_graphql::Result<Block>> + 'a>
+    {
+        blocks_subscription_query(ctx, start_height)
+    }
+
+    /// Returns a stream of block headers. If the `start_height` is specified, the stream
+    /// returns all headers from this height, including already existing ones,
+    /// and continues with the new headers. Otherwise, the stream returns only new headers.
+    ///
+    /// The headers are returned in order and without gaps.
+    #[graphql(complexity = "QUERY_COSTS.block_subscription + child_complexity")]
+    async fn new_headers<'a>(
+        &self,
+        ctx: &'a Context<'a>,
+        #[graphql(desc = "Height of the first block header")] start_height: Option<U32>,
+    ) -> async_graphql::Result<impl Stream<Item = async_graphql::Result<Header>> + 'a>
+    {
+        blocks_subscription_query(ctx, start_height)
+    }
+}
+
+fn blocks_subscription_query<'a, T>(
+    ctx: &'a Context<'a>,
+    start_height: Option<U32>,
+) -> async_graphql::Result<impl Stream<Item = async_graphql::Result<T>> + 'a>
+where
+    T: From<CompressedBlock> + 'a,
+{
+    // Subscribe before reading the latest height to not miss any blocks.
+    let new_blocks = ctx
+        .data_unchecked::<BlockSubscriptions>()
+        .subscribe_blocks();
+    let latest_height = ctx.read_view()?.latest_block_height()?;
+    let start_height = start_height.map(|height| BlockHeight::new(height.into()));
+
+    let stream = blocks_subscription(start_height, latest_height, new_blocks, |height| {
+        ctx.read_view()?.block(height)
+    })
+    .map(|result| result.map(Into::into).map_err(Into::into));
+
+    Ok(stream)
+}
+
 #[derive(Default)]
 pub struct BlockMutation;
 
@@ -433,6 +525,11 @@ impl TryFrom<CoreConsensus> for Consensus {
             CoreConsensus::PoA(poa) => Ok(Consensus::PoA(PoAConsensus {
                 signature: poa.signature.into(),
             })),
+            CoreConsensus::Bft(bft) => Ok(Consensus::Bft(BftConsensus {
+                round: bft.round.into(),
+                producer_signature: bft.producer_signature.into(),
+                signatures: bft.signatures.into_iter().map(Into::into).collect(),
+            })),
             _ => Err(format!("Unknown consensus type: {:?}", consensus)),
         }
     }
@@ -156,8 +156,10 @@ impl CoinQuery {
         &self,
         ctx: &Context<'_>,
         #[graphql(desc = "The ID of the coin")] utxo_id: UtxoId,
+        #[graphql(desc = "The height of the block at which the state is read")]
+        at_height: Option<U32>,
     ) -> async_graphql::Result<Option<Coin>> {
-        let query = ctx.read_view()?;
+        let query = ctx.read_view_at(at_height.map(Into::into))?;
         query.coin(utxo_id.0).into_api_result()
     }
 
@@ -10,6 +10,7 @@ use crate::{
             ContractId,
             HexString,
             Salt,
+            U32,
             U64,
         },
         ReadViewProvider,
@@ -29,38 +30,49 @@ use fuel_core_storage::{
     tables::ContractsRawCode,
 };
 use fuel_core_types::{
-    fuel_types,
+    fuel_types::{
+        self,
+        BlockHeight,
+    },
     services::graphql_api,
 };
 
-pub struct Contract(pub(crate) fuel_types::ContractId);
+pub struct Contract {
+    pub(crate) id: fuel_types::ContractId,
+    /// The height of the block at which the contract's state is read.
+    /// If it is `None`, the state is read from the current view.
+    pub(crate) at_height: Option<BlockHeight>,
+}
 
 impl From<fuel_types::ContractId> for Contract {
     fn from(id: fuel_types::ContractId) -> Self {
-        Self(id)
+        Self {
+            id,
+            at_height: None,
+        }
     }
 }
 
 #[Object]
 impl Contract {
     async fn id(&self) -> ContractId {
-        self.0.into()
+        self.id.into()
     }
 
     #[graphql(complexity = "QUERY_COSTS.bytecode_read")]
     async fn bytecode(&self, ctx: &Context<'_>) -> async_graphql::Result<HexString> {
-        let query = ctx.read_view()?;
+        let query = ctx.read_view_at(self.at_height)?;
         query
-            .contract_bytecode(self.0)
+            .contract_bytecode(self.id)
             .map(HexString)
             .map_err(Into::into)
     }
 
     #[graphql(complexity = "QUERY_COSTS.storage_read")]
     async fn salt(&self, ctx: &Context<'_>) -> async_graphql::Result<Salt> {
-        let query = ctx.read_view()?;
+        let query = ctx.read_view_at(self.at_height)?;
         query
-            .contract_salt(self.0)
+            .contract_salt(self.id)
             .map(Into::into)
             .map_err(Into::into)
     }
@@ -76,13 +88,19 @@ impl ContractQuery {
         &self,
         ctx: &Context<'_>,
         #[graphql(desc = "ID of the Contract")] id: ContractId,
+        #[graphql(desc = "the height of the block at which the state is read")]
+        at_height: Option<U32>,
     ) -> async_graphql::Result<Option<Contract>> {
-        let query = ctx.read_view()?;
+        let at_height = at_height.map(Into::into);
+        let query = ctx.read_view_at(at_height)?;
         query
             .contract_exists(id.0)
             .and_then(|contract_exists| {
                 if contract_exists {
-                    Ok(id.0)
+                    Ok(Contract {
+                        id: id.0,
+                        at_height,
+                    })
                 } else {
                     Err(not_found!(ContractsRawCode))
                 }
@@ -125,10 +143,12 @@ impl ContractBalanceQuery {
         ctx: &Context<'_>,
         contract: ContractId,
         asset: AssetId,
+        #[graphql(desc = "the height of the block at which the state is read")]
+        at_height: Option<U32>,
     ) -> async_graphql::Result<ContractBalance> {
         let contract_id = contract.into();
         let asset_id = asset.into();
-        let query = ctx.read_view()?;
+        let query = ctx.read_view_at(at_height.map(Into::into))?;
         query
             .contract_balance(contract_id, asset_id)
             .into_api_result()
@@ -149,6 +169,7 @@ impl ContractBalanceQuery {
         + (QUERY_COSTS.storage_read + first.unwrap_or_default() as usize) * child_complexity \
         + (QUERY_COSTS.storage_read + last.unwrap_or_default() as usize) * child_complexity\
     }")]
+    #[allow(clippy::too_many_arguments)]
     async fn contract_balances(
         &self,
         ctx: &Context<'_>,
@@ -157,10 +178,12 @@ impl ContractBalanceQuery {
         after: Option<String>,
         last: Option<i32>,
         before: Option<String>,
+        #[graphql(desc = "the height of the block at which the state is read")]
+        at_height: Option<U32>,
     ) -> async_graphql::Result<
         Connection<AssetId, ContractBalance, EmptyFields, EmptyFields>,
     > {
-        let query = ctx.read_view()?;
+        let query = ctx.read_view_at(at_height.map(Into::into))?;
 
         crate::schema::query_pagination(after, before, first, last, |start, direction| {
             let balances = query
@@ -22,6 +22,7 @@ use crate::{
             SortedTxCursor,
             TransactionId,
             TxPointer,
+            U32,
         },
         tx::types::TransactionStatus,
         ReadViewProvider,
@@ -55,6 +56,7 @@ use fuel_core_types::{
     fuel_types::{
         self,
         canonical::Deserialize,
+        BlockHeight,
     },
     fuel_vm::checked_transaction::{
         CheckPredicateParams,
@@ -67,6 +69,7 @@ use futures::{
     TryStreamExt,
 };
 use itertools::Itertools;
+use state_overrides::StateOverridesInput;
 use std::{
     iter,
     sync::Arc,
@@ -82,6 +85,8 @@ use super::scalars::U64;
 pub mod input;
 pub mod output;
 pub mod receipt;
+pub mod state_overrides;
+pub mod trace;
 pub mod types;
 pub mod upgrade_purpose;
 
@@ -265,6 +270,7 @@ impl TxMutation {
     #[graphql(
         complexity = "QUERY_COSTS.dry_run * txs.len() + child_complexity * txs.len()"
     )]
+    #[allow(clippy::too_many_arguments)]
     async fn dry_run(
         &self,
         ctx: &Context<'_>,
@@ -274,12 +280,34 @@ impl TxMutation {
         // for read-only calls.
         utxo_validation: Option<bool>,
         gas_price: Option<U64>,
+        // If set, the transactions are executed on top of the state at this block height
+        // instead of the latest state. The state should be retained by the node.
+        at_height: Option<U32>,
+        // The modifications of the state applied before the execution of transactions.
+        // The modifications are visible only to this dry run and are never committed.
+        state_overrides: Option<StateOverridesInput>,
+        // If set to true, the execution of each transaction is traced instruction
+        // by instruction, and the trace is returned alongside the status.
+        trace: Option<bool>,
     ) -> async_graphql::Result<Vec<DryRunTransactionExecutionStatus>> {
         let block_producer = ctx.data_unchecked::<BlockProducer>();
         let params = ctx
             .data_unchecked::<ConsensusProvider>()
             .latest_consensus_params();
 
+        let block_height = match at_height {
+            Some(at_height) => {
+                let at_height: BlockHeight = at_height.into();
+                // Fails with a descriptive error if the state is not available.
+                ctx.read_view_at(Some(at_height))?;
+                let block_height = at_height
+                    .succ()
+                    .ok_or(anyhow::anyhow!("The `at_height` is too big"))?;
+                Some(block_height)
+            }
+            None => None,
+        };
+
         let mut transactions = txs
             .iter()
             .map(|tx| FuelTx::from_bytes(&tx.0))
@@ -291,14 +319,16 @@ impl TxMutation {
         let tx_statuses = block_producer
             .dry_run_txs(
                 transactions,
-                None,
+                block_height,
                 utxo_validation,
                 gas_price.map(|x| x.into()),
+                state_overrides.unwrap_or_default().into(),
+                trace.unwrap_or(false),
             )
             .await?;
         let tx_statuses = tx_statuses
             .into_iter()
-            .map(DryRunTransactionExecutionStatus)
+            .map(|(status, trace)| DryRunTransactionExecutionStatus(status, trace))
             .collect();
 
         Ok(tx_statuses)
@@ -0,0 +1,122 @@
+use crate::schema::scalars::{
+    Address,
+    AssetId,
+    Bytes32,
+    ContractId,
+    HexString,
+    TxPointer,
+    UtxoId,
+    U64,
+};
+use async_graphql::InputObject;
+use fuel_core_types::{
+    entities::coins::coin::Coin,
+    services::executor,
+};
+
+/// The modifications of the state applied before the dry run.
+#[derive(InputObject, Default)]
+pub struct StateOverridesInput {
+    /// The overrides of the contracts' state.
+    contracts: Option<Vec<ContractStateOverrideInput>>,
+    /// The coins to insert or replace.
+    coins: Option<Vec<CoinOverrideInput>>,
+}
+
+/// The override of the contract's state.
+#[derive(InputObject)]
+pub struct ContractStateOverrideInput {
+    /// The id of the contract.
+    contract_id: ContractId,
+    /// The new bytecode of the contract.
+    bytecode: Option<HexString>,
+    /// The new balances of the contract.
+    balances: Option<Vec<ContractBalanceOverrideInput>>,
+    /// The new values of the contract's storage slots.
+    storage: Option<Vec<ContractStorageSlotOverrideInput>>,
+}
+
+/// The override of the contract's balance of the asset.
+#[derive(InputObject)]
+pub struct ContractBalanceOverrideInput {
+    /// The asset of the balance.
+    asset_id: AssetId,
+    /// The new amount of the asset.
+    amount: U64,
+}
+
+/// The override of the contract's storage slot.
+#[derive(InputObject)]
+pub struct ContractStorageSlotOverrideInput {
+    /// The key of the storage slot.
+    key: Bytes32,
+    /// The new value of the storage slot.
+    value: HexString,
+}
+
+/// The coin that is inserted into the state or replaces the existing one.
+#[derive(InputObject)]
+pub struct CoinOverrideInput {
+    /// The utxo id of the coin.
+    utxo_id: UtxoId,
+    /// The owner of the coin.
+    owner: Address,
+    /// The amount of the coin.
+    amount: U64,
+    /// The asset of the coin.
+    asset_id: AssetId,
+    /// The pointer to the transaction that created the coin.
+    tx_pointer: Option<TxPointer>,
+}
+
+impl From<StateOverridesInput> for executor::StateOverrides {
+    fn from(input: StateOverridesInput) -> Self {
+        Self {
+            contracts: input
+                .contracts
+                .unwrap_or_default()
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+            coins: input
+                .coins
+                .unwrap_or_default()
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+        }
+    }
+}
+
+impl From<ContractStateOverrideInput> for executor::ContractStateOverride {
+    fn from(input: ContractStateOverrideInput) -> Self {
+        Self {
+            contract_id: input.contract_id.into(),
+            bytecode: input.bytecode.map(|bytecode| bytecode.0),
+            balances: input
+                .balances
+                .unwrap_or_default()
+                .into_iter()
+                .map(|balance| (balance.asset_id.into(), balance.amount.into()))
+                .collect(),
+            storage: input
+                .storage
+                .unwrap_or_default()
+                .into_iter()
+                .map(|slot| (slot.key.into(), slot.value.0))
+                .collect(),
+        }
+    }
+}
+
+impl From<CoinOverrideInput> for Coin {
+    fn from(input: CoinOverrideInput) -> Self {
+        Self {
+            utxo_id: input.utxo_id.into(),
+            owner: input.owner.into(),
+            amount: input.amount.into(),
+            asset_id: input.asset_id.into(),
+            tx_pointer: input.tx_pointer.map(Into::into).unwrap_or_default(),
+        }
+    }
+}
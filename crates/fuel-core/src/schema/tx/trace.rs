@@ -0,0 +1,154 @@
+use crate::schema::scalars::{
+    Bytes32,
+    ContractId,
+    HexString,
+    U32,
+    U64,
+    U8,
+};
+use async_graphql::{
+    Enum,
+    Object,
+};
+use fuel_core_types::{
+    fuel_asm::Instruction,
+    services::executor,
+};
+
+/// The trace of the transaction execution collected with the debugger of the VM.
+pub struct TransactionTrace(pub executor::TransactionTrace);
+
+#[Object]
+impl TransactionTrace {
+    /// The executed instructions in the order of execution.
+    async fn steps(&self) -> Vec<TraceStep> {
+        self.0.steps.iter().cloned().map(TraceStep).collect()
+    }
+
+    /// The accesses to the contracts' storage slots in the order of execution.
+    async fn storage_accesses(&self) -> Vec<StorageAccess> {
+        self.0
+            .storage_accesses
+            .iter()
+            .cloned()
+            .map(StorageAccess)
+            .collect()
+    }
+
+    /// `true` if the execution was too long, and not all instructions were recorded.
+    async fn truncated(&self) -> bool {
+        self.0.truncated
+    }
+}
+
+pub struct TraceStep(executor::TraceStep);
+
+#[Object]
+impl TraceStep {
+    /// The program counter relative to the start of the executed code.
+    async fn pc(&self) -> U64 {
+        self.0.pc.into()
+    }
+
+    /// The raw executed instruction.
+    async fn instruction(&self) -> U32 {
+        self.0.instruction.into()
+    }
+
+    /// The name of the executed operation.
+    async fn opcode(&self) -> Option<String> {
+        Instruction::try_from(self.0.instruction)
+            .ok()
+            .map(|instruction| format!("{:?}", instruction.opcode()))
+    }
+
+    /// The gas remaining in the current context before the execution of the instruction.
+    async fn gas_remaining(&self) -> U64 {
+        self.0.gas_remaining.into()
+    }
+
+    /// The contract that executed the instruction, `null` for the script.
+    async fn contract_id(&self) -> Option<ContractId> {
+        self.0.contract_id.map(Into::into)
+    }
+
+    /// The registers modified by the instruction with their new values.
+    async fn changed_registers(&self) -> Vec<RegisterValue> {
+        self.0
+            .changed_registers
+            .iter()
+            .map(|(register, value)| RegisterValue {
+                register: (*register).into(),
+                value: (*value).into(),
+            })
+            .collect()
+    }
+}
+
+pub struct RegisterValue {
+    register: U8,
+    value: U64,
+}
+
+#[Object]
+impl RegisterValue {
+    /// The index of the register.
+    async fn register(&self) -> U8 {
+        self.register
+    }
+
+    /// The value of the register.
+    async fn value(&self) -> U64 {
+        self.value
+    }
+}
+
+#[derive(Enum, Copy, Clone, Eq, PartialEq)]
+pub enum StorageAccessKind {
+    /// The slot was read.
+    Read,
+    /// The slot was written.
+    Write,
+    /// The slot was cleared.
+    Clear,
+}
+
+impl From<executor::StorageAccessKind> for StorageAccessKind {
+    fn from(kind: executor::StorageAccessKind) -> Self {
+        match kind {
+            executor::StorageAccessKind::Read => StorageAccessKind::Read,
+            executor::StorageAccessKind::Write => StorageAccessKind::Write,
+            executor::StorageAccessKind::Clear => StorageAccessKind::Clear,
+        }
+    }
+}
+
+pub struct StorageAccess(executor::StorageAccess);
+
+#[Object]
+impl StorageAccess {
+    /// The index of the step that accessed the slot.
+    async fn step(&self) -> U32 {
+        self.0.step.into()
+    }
+
+    /// The kind of the access.
+    async fn kind(&self) -> StorageAccessKind {
+        self.0.kind.into()
+    }
+
+    /// The contract that owns the slot.
+    async fn contract_id(&self) -> ContractId {
+        self.0.contract_id.into()
+    }
+
+    /// The key of the slot.
+    async fn key(&self) -> Bytes32 {
+        self.0.key.into()
+    }
+
+    /// The value of the slot after the access, `null` if the slot is not set.
+    async fn value(&self) -> Option<HexString> {
+        self.0.value.clone().map(HexString)
+    }
+}
@@ -2,6 +2,7 @@ use super::{
     input::Input,
     output::Output,
     receipt::Receipt,
+    trace::TransactionTrace,
 };
 use crate::{
     fuel_core_graphql_api::{
@@ -88,6 +89,7 @@ use fuel_core_types::{
         executor::{
             TransactionExecutionResult,
             TransactionExecutionStatus,
+            TransactionTrace as TransactionTraceModel,
         },
         txpool::{
             self,
@@ -966,7 +968,10 @@ impl DryRunFailureStatus {
     }
 }
 
-pub struct DryRunTransactionExecutionStatus(pub TransactionExecutionStatus);
+pub struct DryRunTransactionExecutionStatus(
+    pub TransactionExecutionStatus,
+    pub Option<TransactionTraceModel>,
+);
 
 #[Object]
 impl DryRunTransactionExecutionStatus {
@@ -981,6 +986,11 @@ impl DryRunTransactionExecutionStatus {
     async fn receipts(&self) -> Vec<Receipt> {
         self.0.result.receipts().iter().map(Into::into).collect()
     }
+
+    /// The trace of the execution. Available only if the tracing was requested.
+    async fn trace(&self) -> Option<TransactionTrace> {
+        self.1.clone().map(TransactionTrace)
+    }
 }
 
 #[tracing::instrument(level = "debug", skip(query, txpool), ret, err)]
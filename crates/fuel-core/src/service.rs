@@ -168,6 +168,7 @@ impl FuelService {
             Default::default(),
             Default::default(),
             Default::default(),
+            Default::default(),
         );
         Self::from_combined_database(combined_database, config).await
     }
@@ -223,6 +224,12 @@ impl FuelService {
             ConsensusConfig::PoA { .. } => {
                 // We don't support overriding of the heights for PoA version 1.
             }
+            ConsensusConfig::Bft { .. } => {
+                // The validator set of BFT doesn't change over time.
+            }
+            ConsensusConfig::PoAV3(_) => {
+                // The producers of PoAV3 are defined by the slots, there are no overrides.
+            }
             ConsensusConfig::PoAV2(poa) => {
                 let on_chain_view = combined_database.on_chain().latest_view()?;
 
@@ -9,7 +9,10 @@ use fuel_core_poa::{
 };
 use fuel_core_services::stream::BoxStream;
 #[cfg(feature = "p2p")]
-use fuel_core_types::services::p2p::peer_reputation::AppScore;
+use fuel_core_types::services::p2p::peer_reputation::{
+    AppScore,
+    PeerReport,
+};
 use fuel_core_types::{
     blockchain::{
         block::Block,
@@ -223,6 +226,19 @@ pub struct PeerReportConfig {
     pub bad_block_header: AppScore,
     pub missing_transactions: AppScore,
     pub invalid_transactions: AppScore,
+    pub transactions_quota_exceeded: AppScore,
+}
+
+#[cfg(feature = "p2p")]
+struct P2PAdapterPeerReport {
+    score: AppScore,
+}
+
+#[cfg(feature = "p2p")]
+impl PeerReport for P2PAdapterPeerReport {
+    fn get_score_from_report(&self) -> AppScore {
+        self.score
+    }
 }
 
 #[cfg(not(feature = "p2p"))]
@@ -31,6 +31,7 @@ use fuel_core_types::{
 };
 use std::sync::Arc;
 
+pub mod bft;
 pub mod poa;
 
 impl VerifierAdapter {
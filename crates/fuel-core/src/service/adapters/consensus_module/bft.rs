@@ -0,0 +1,117 @@
+use crate::service::adapters::{
+    BlockImporterAdapter,
+    P2PAdapter,
+};
+use fuel_core_bft::ports::{
+    BlockImporter,
+    P2pPort,
+};
+use fuel_core_services::stream::BoxStream;
+use fuel_core_types::{
+    blockchain::{
+        consensus::bft::BftMessage,
+        SealedBlock,
+    },
+    services::{
+        block_importer::BlockImportInfo,
+        p2p::{
+            ConsensusGossipData,
+            GossipsubMessageAcceptance,
+            GossipsubMessageInfo,
+        },
+    },
+};
+use std::sync::Arc;
+use tokio_stream::{
+    wrappers::BroadcastStream,
+    StreamExt,
+};
+
+#[async_trait::async_trait]
+impl BlockImporter for BlockImporterAdapter {
+    async fn verify_block(&self, block: SealedBlock) -> anyhow::Result<()> {
+        let importer = self.block_importer.clone();
+        // The result of the execution is dropped, the block is committed
+        // only after the validators decide on it.
+        let _ = tokio_rayon::spawn_fifo(move || importer.verify_and_execute_block(block))
+            .await?;
+        Ok(())
+    }
+
+    async fn execute_and_commit(&self, block: SealedBlock) -> anyhow::Result<()> {
+        self.execute_and_commit(block).await
+    }
+
+    fn block_stream(&self) -> BoxStream<BlockImportInfo> {
+        Box::pin(
+            BroadcastStream::new(self.block_importer.subscribe())
+                .filter_map(|result| result.ok())
+                .map(|result| BlockImportInfo::from(result.shared_result)),
+        )
+    }
+}
+
+#[cfg(feature = "p2p")]
+impl P2pPort for P2PAdapter {
+    fn broadcast_message(&self, message: Arc<BftMessage>) -> anyhow::Result<()> {
+        if let Some(service) = &self.service {
+            service.broadcast_consensus_message(message)
+        } else {
+            Ok(())
+        }
+    }
+
+    fn broadcast_block(&self, block: Arc<SealedBlock>) -> anyhow::Result<()> {
+        if let Some(service) = &self.service {
+            service.broadcast_block(block)
+        } else {
+            Ok(())
+        }
+    }
+
+    fn consensus_messages(&self) -> BoxStream<ConsensusGossipData> {
+        if let Some(service) = &self.service {
+            Box::pin(
+                BroadcastStream::new(service.subscribe_consensus())
+                    .filter_map(|result| result.ok()),
+            )
+        } else {
+            Box::pin(tokio_stream::pending())
+        }
+    }
+
+    fn notify_message_validity(
+        &self,
+        message_info: GossipsubMessageInfo,
+        acceptance: GossipsubMessageAcceptance,
+    ) -> anyhow::Result<()> {
+        if let Some(service) = &self.service {
+            service.notify_gossip_consensus_validity(message_info, acceptance)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+#[cfg(not(feature = "p2p"))]
+impl P2pPort for P2PAdapter {
+    fn broadcast_message(&self, _: Arc<BftMessage>) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn broadcast_block(&self, _: Arc<SealedBlock>) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn consensus_messages(&self) -> BoxStream<ConsensusGossipData> {
+        Box::pin(tokio_stream::pending())
+    }
+
+    fn notify_message_validity(
+        &self,
+        _: GossipsubMessageInfo,
+        _: GossipsubMessageAcceptance,
+    ) -> anyhow::Result<()> {
+        Ok(())
+    }
+}
@@ -25,7 +25,10 @@ use fuel_core_poa::{
 use fuel_core_services::stream::BoxStream;
 use fuel_core_storage::transactional::Changes;
 use fuel_core_types::{
-    blockchain::block::Block,
+    blockchain::{
+        block::Block,
+        SealedBlock,
+    },
     fuel_tx::TxId,
     fuel_types::BlockHeight,
     services::{
@@ -41,9 +44,12 @@ use fuel_core_types::{
     },
     tai64::Tai64,
 };
-use std::path::{
-    Path,
-    PathBuf,
+use std::{
+    path::{
+        Path,
+        PathBuf,
+    },
+    sync::Arc,
 };
 use tokio_stream::{
     wrappers::BroadcastStream,
@@ -149,6 +155,10 @@ impl BlockImporter for BlockImporterAdapter {
             .map_err(Into::into)
     }
 
+    async fn execute_and_commit(&self, block: SealedBlock) -> anyhow::Result<()> {
+        self.execute_and_commit(block).await
+    }
+
     fn block_stream(&self) -> BoxStream<BlockImportInfo> {
         Box::pin(
             BroadcastStream::new(self.block_importer.subscribe())
@@ -170,6 +180,14 @@ impl P2pPort for P2PAdapter {
             Box::pin(tokio_stream::pending())
         }
     }
+
+    fn broadcast_block(&self, block: Arc<SealedBlock>) -> anyhow::Result<()> {
+        if let Some(service) = &self.service {
+            service.broadcast_block(block)
+        } else {
+            Ok(())
+        }
+    }
 }
 
 #[cfg(not(feature = "p2p"))]
@@ -177,6 +195,10 @@ impl P2pPort for P2PAdapter {
     fn reserved_peers_count(&self) -> BoxStream<usize> {
         Box::pin(tokio_stream::pending())
     }
+
+    fn broadcast_block(&self, _: Arc<SealedBlock>) -> anyhow::Result<()> {
+        Ok(())
+    }
 }
 
 pub struct InDirectoryPredefinedBlocks {
@@ -6,14 +6,18 @@ use super::{
 };
 use crate::{
     database::OnChainIterableKeyValueView,
-    fuel_core_graphql_api::ports::{
-        worker,
-        BlockProducerPort,
-        ConsensusProvider,
-        DatabaseMessageProof,
-        GasPriceEstimate,
-        P2pPort,
-        TxPoolPort,
+    fuel_core_graphql_api::{
+        ports::{
+            worker,
+            BlockProducerPort,
+            BlockSubscriptionPort,
+            ConsensusProvider,
+            DatabaseMessageProof,
+            GasPriceEstimate,
+            P2pPort,
+            TxPoolPort,
+        },
+        worker_service,
     },
     service::adapters::{
         import_result_provider::ImportResultProvider,
@@ -25,11 +29,15 @@ use async_trait::async_trait;
 use fuel_core_services::stream::BoxStream;
 use fuel_core_storage::Result as StorageResult;
 use fuel_core_txpool::{
+    config::BlackList,
     service::TxStatusMessage,
     types::TxId,
 };
 use fuel_core_types::{
-    blockchain::header::ConsensusParametersVersion,
+    blockchain::{
+        block::CompressedBlock,
+        header::ConsensusParametersVersion,
+    },
     entities::relayer::message::MerkleProof,
     fuel_tx::{
         Bytes32,
@@ -39,8 +47,15 @@ use fuel_core_types::{
     fuel_types::BlockHeight,
     services::{
         block_importer::SharedImportResult,
-        executor::TransactionExecutionStatus,
-        p2p::PeerInfo,
+        executor::{
+            StateOverrides,
+            TransactionExecutionStatus,
+            TransactionTrace,
+        },
+        p2p::{
+            PeerId,
+            PeerInfo,
+        },
         txpool::{
             InsertionResult,
             TransactionStatus,
@@ -88,6 +103,19 @@ impl TxPoolPort for TxPoolAdapter {
     ) -> anyhow::Result<BoxStream<TxStatusMessage>> {
         self.service.tx_update_subscribe(id)
     }
+
+    fn blacklist(&self) -> BlackList {
+        self.service.blacklist()
+    }
+
+    fn add_to_blacklist(&self, entries: BlackList) -> anyhow::Result<Vec<TxId>> {
+        let removed = self.service.add_to_blacklist(entries)?;
+        Ok(removed.iter().map(|tx| tx.id()).collect())
+    }
+
+    fn remove_from_blacklist(&self, entries: BlackList) -> anyhow::Result<()> {
+        self.service.remove_from_blacklist(entries)
+    }
 }
 
 impl DatabaseMessageProof for OnChainIterableKeyValueView {
@@ -108,9 +136,18 @@ impl BlockProducerPort for BlockProducerAdapter {
         height: Option<BlockHeight>,
         utxo_validation: Option<bool>,
         gas_price: Option<u64>,
-    ) -> anyhow::Result<Vec<TransactionExecutionStatus>> {
+        state_overrides: StateOverrides,
+        trace: bool,
+    ) -> anyhow::Result<Vec<(TransactionExecutionStatus, Option<TransactionTrace>)>> {
         self.block_producer
-            .dry_run(transactions, height, utxo_validation, gas_price)
+            .dry_run(
+                transactions,
+                height,
+                utxo_validation,
+                gas_price,
+                state_overrides,
+                trace,
+            )
             .await
     }
 }
@@ -151,6 +188,113 @@ impl P2pPort for P2PAdapter {
             Ok(vec![])
         }
     }
+
+    async fn disconnect_peer(&self, peer_id: PeerId) -> anyhow::Result<()> {
+        #[cfg(feature = "p2p")]
+        {
+            let peer_id = to_libp2p_peer_id(peer_id)?;
+            self.p2p_service()?.disconnect_peer(peer_id).await
+        }
+        #[cfg(not(feature = "p2p"))]
+        {
+            let _ = peer_id;
+            Err(anyhow::anyhow!(P2P_DISABLED))
+        }
+    }
+
+    async fn ban_peer(&self, peer_id: PeerId) -> anyhow::Result<()> {
+        #[cfg(feature = "p2p")]
+        {
+            let peer_id = to_libp2p_peer_id(peer_id)?;
+            self.p2p_service()?.ban_peer(peer_id).await
+        }
+        #[cfg(not(feature = "p2p"))]
+        {
+            let _ = peer_id;
+            Err(anyhow::anyhow!(P2P_DISABLED))
+        }
+    }
+
+    async fn unban_peer(&self, peer_id: PeerId) -> anyhow::Result<()> {
+        #[cfg(feature = "p2p")]
+        {
+            let peer_id = to_libp2p_peer_id(peer_id)?;
+            self.p2p_service()?.unban_peer(peer_id).await
+        }
+        #[cfg(not(feature = "p2p"))]
+        {
+            let _ = peer_id;
+            Err(anyhow::anyhow!(P2P_DISABLED))
+        }
+    }
+
+    async fn add_reserved_node(&self, address: String) -> anyhow::Result<()> {
+        #[cfg(feature = "p2p")]
+        {
+            let address = address.parse()?;
+            self.p2p_service()?.add_reserved_node(address).await
+        }
+        #[cfg(not(feature = "p2p"))]
+        {
+            let _ = address;
+            Err(anyhow::anyhow!(P2P_DISABLED))
+        }
+    }
+
+    async fn remove_reserved_node(&self, peer_id: PeerId) -> anyhow::Result<()> {
+        #[cfg(feature = "p2p")]
+        {
+            let peer_id = to_libp2p_peer_id(peer_id)?;
+            self.p2p_service()?.remove_reserved_node(peer_id).await
+        }
+        #[cfg(not(feature = "p2p"))]
+        {
+            let _ = peer_id;
+            Err(anyhow::anyhow!(P2P_DISABLED))
+        }
+    }
+
+    async fn dial(&self, address: String) -> anyhow::Result<()> {
+        #[cfg(feature = "p2p")]
+        {
+            let address = address.parse()?;
+            self.p2p_service()?.dial(address).await
+        }
+        #[cfg(not(feature = "p2p"))]
+        {
+            let _ = address;
+            Err(anyhow::anyhow!(P2P_DISABLED))
+        }
+    }
+}
+
+#[cfg(not(feature = "p2p"))]
+const P2P_DISABLED: &str =
+    "Peering is disabled in this build, try using the `p2p` feature flag.";
+
+#[cfg(feature = "p2p")]
+impl P2PAdapter {
+    fn p2p_service(&self) -> anyhow::Result<&fuel_core_p2p::service::SharedState> {
+        self.service
+            .as_ref()
+            .ok_or_else(|| anyhow::anyhow!("The p2p service is disabled"))
+    }
+}
+
+#[cfg(feature = "p2p")]
+fn to_libp2p_peer_id(peer_id: PeerId) -> anyhow::Result<fuel_core_p2p::PeerId> {
+    fuel_core_p2p::PeerId::from_bytes(peer_id.as_ref())
+        .map_err(|e| anyhow::anyhow!("Invalid peer id: {e}"))
+}
+
+impl BlockSubscriptionPort for worker_service::SharedState {
+    fn subscribe_blocks(&self) -> BoxStream<Arc<CompressedBlock>> {
+        use futures::StreamExt;
+        fuel_core_services::stream::IntoBoxStream::into_boxed(
+            tokio_stream::wrappers::BroadcastStream::new(self.subscribe_blocks())
+                .filter_map(|r| futures::future::ready(r.ok())),
+        )
+    }
 }
 
 impl worker::TxPool for TxPoolAdapter {
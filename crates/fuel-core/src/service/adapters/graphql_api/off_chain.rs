@@ -10,9 +10,17 @@ use crate::{
             OffChainDatabase,
         },
         storage::{
+            balances::{
+                CoinBalances,
+                CoinBalancesKey,
+                MessageBalances,
+                TotalBalanceAmount,
+            },
             contracts::ContractsInfo,
             relayed_transactions::RelayedTransactionStatuses,
+            statistic::StatisticTable,
             transactions::OwnedTransactionIndexCursor,
+            BALANCES_INDEXATION_ENABLED,
         },
     },
     graphql_api::storage::old::{
@@ -50,6 +58,7 @@ use fuel_core_types::{
     entities::relayer::transaction::RelayedTransactionStatus,
     fuel_tx::{
         Address,
+        AssetId,
         Bytes32,
         Salt,
         Transaction,
@@ -171,6 +180,50 @@ impl OffChainDatabase for OffChainIterableKeyValueView {
     fn message_is_spent(&self, nonce: &Nonce) -> StorageResult<bool> {
         self.message_is_spent(nonce)
     }
+
+    fn balances_indexation_enabled(&self) -> StorageResult<bool> {
+        let enabled = self
+            .storage_as_ref::<StatisticTable<bool>>()
+            .get(BALANCES_INDEXATION_ENABLED)?
+            .map(|enabled| enabled.into_owned())
+            .unwrap_or(false);
+
+        Ok(enabled)
+    }
+
+    fn coin_balance(
+        &self,
+        owner: &Address,
+        asset_id: &AssetId,
+    ) -> StorageResult<TotalBalanceAmount> {
+        let amount = self
+            .storage_as_ref::<CoinBalances>()
+            .get(&CoinBalancesKey::new(owner, asset_id))?
+            .map(|amount| amount.into_owned())
+            .unwrap_or_default();
+
+        Ok(amount)
+    }
+
+    fn coin_balances(
+        &self,
+        owner: &Address,
+        direction: IterDirection,
+    ) -> BoxedIter<'_, StorageResult<(AssetId, TotalBalanceAmount)>> {
+        self.iter_all_filtered::<CoinBalances, _>(Some(owner), None, Some(direction))
+            .map(|result| result.map(|(key, amount)| (*key.asset_id(), amount)))
+            .into_boxed()
+    }
+
+    fn message_balance(&self, owner: &Address) -> StorageResult<TotalBalanceAmount> {
+        let amount = self
+            .storage_as_ref::<MessageBalances>()
+            .get(owner)?
+            .map(|amount| amount.into_owned())
+            .unwrap_or_default();
+
+        Ok(amount)
+    }
 }
 
 impl worker::OffChainDatabase for Database<OffChain> {
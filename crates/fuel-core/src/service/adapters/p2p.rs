@@ -1,16 +1,25 @@
-use super::BlockImporterAdapter;
+use super::{
+    BlockImporterAdapter,
+    TxPoolAdapter,
+};
 use crate::database::OnChainIterableKeyValueView;
 use fuel_core_p2p::ports::{
     BlockHeightImporter,
     P2pDb,
+    TxPool,
 };
 use fuel_core_services::stream::BoxStream;
 use fuel_core_storage::Result as StorageResult;
 use fuel_core_types::{
     blockchain::{
         consensus::Genesis,
+        SealedBlock,
         SealedBlockHeader,
     },
+    fuel_tx::{
+        Transaction,
+        TxId,
+    },
     fuel_types::BlockHeight,
     services::p2p::Transactions,
 };
@@ -31,6 +40,13 @@ impl P2pDb for OnChainIterableKeyValueView {
         self.get_transactions_on_blocks(block_height_range)
     }
 
+    fn get_sealed_blocks(
+        &self,
+        block_height_range: Range<u32>,
+    ) -> StorageResult<Option<Vec<SealedBlock>>> {
+        self.get_sealed_blocks(block_height_range)
+    }
+
     fn get_genesis(&self) -> StorageResult<Genesis> {
         self.get_genesis()
     }
@@ -49,3 +65,19 @@ impl BlockHeightImporter for BlockImporterAdapter {
         )
     }
 }
+
+impl TxPool for TxPoolAdapter {
+    fn get_tx_ids(&self, max_txs: usize) -> Vec<TxId> {
+        self.service.tx_ids(max_txs)
+    }
+
+    fn get_full_txs(&self, tx_ids: Vec<TxId>) -> Vec<Option<Transaction>> {
+        self.service
+            .find(tx_ids)
+            .into_iter()
+            .map(|tx_info| {
+                tx_info.map(|tx_info| Transaction::from(tx_info.tx().as_ref()))
+            })
+            .collect()
+    }
+}
@@ -58,7 +58,9 @@ use fuel_core_types::{
         block_producer::Components,
         executor::{
             Result as ExecutorResult,
+            StateOverrides,
             TransactionExecutionStatus,
+            TransactionTrace,
             UncommittedResult,
         },
     },
@@ -118,8 +120,26 @@ impl fuel_core_producer::ports::DryRunner for ExecutorAdapter {
         &self,
         block: Components<Vec<fuel_tx::Transaction>>,
         utxo_validation: Option<bool>,
+        at_height: Option<BlockHeight>,
+        state_overrides: StateOverrides,
     ) -> ExecutorResult<Vec<TransactionExecutionStatus>> {
-        self.executor.dry_run(block, utxo_validation)
+        self.executor
+            .dry_run(block, utxo_validation, at_height, state_overrides)
+    }
+
+    fn dry_run_with_traces(
+        &self,
+        block: Components<Vec<fuel_tx::Transaction>>,
+        utxo_validation: Option<bool>,
+        at_height: Option<BlockHeight>,
+        state_overrides: StateOverrides,
+    ) -> ExecutorResult<Vec<(TransactionExecutionStatus, TransactionTrace)>> {
+        self.executor.dry_run_with_traces(
+            block,
+            utxo_validation,
+            at_height,
+            state_overrides,
+        )
     }
 }
 
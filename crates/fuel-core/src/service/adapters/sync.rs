@@ -2,6 +2,7 @@ use super::{
     BlockImporterAdapter,
     ConsensusAdapter,
     P2PAdapter,
+    P2PAdapterPeerReport,
 };
 use fuel_core_poa::ports::RelayerPort;
 use fuel_core_services::stream::BoxStream;
@@ -19,16 +20,18 @@ use fuel_core_types::{
     },
     fuel_types::BlockHeight,
     services::p2p::{
-        peer_reputation::{
-            AppScore,
-            PeerReport,
-        },
+        BlockGossipData,
+        GossipsubMessageAcceptance,
+        GossipsubMessageInfo,
         PeerId,
         SourcePeer,
         Transactions,
     },
 };
-use std::ops::Range;
+use std::{
+    collections::HashSet,
+    ops::Range,
+};
 
 #[async_trait::async_trait]
 impl PeerToPeerPort for P2PAdapter {
@@ -49,9 +52,15 @@ impl PeerToPeerPort for P2PAdapter {
     async fn get_sealed_block_headers(
         &self,
         block_height_range: Range<u32>,
+        excluded_peers: HashSet<PeerId>,
     ) -> anyhow::Result<SourcePeer<Option<Vec<SealedBlockHeader>>>> {
         let result = if let Some(service) = &self.service {
-            service.get_sealed_block_headers(block_height_range).await
+            service
+                .get_sealed_block_headers(
+                    block_height_range,
+                    excluded_peers.into_iter().map(Into::into).collect(),
+                )
+                .await
         } else {
             Err(anyhow::anyhow!("No P2P service available"))
         };
@@ -65,6 +74,31 @@ impl PeerToPeerPort for P2PAdapter {
         }
     }
 
+    async fn get_sealed_blocks(
+        &self,
+        block_height_range: Range<u32>,
+        excluded_peers: HashSet<PeerId>,
+    ) -> anyhow::Result<SourcePeer<Option<Vec<SealedBlock>>>> {
+        let result = if let Some(service) = &self.service {
+            service
+                .get_sealed_blocks(
+                    block_height_range,
+                    excluded_peers.into_iter().map(Into::into).collect(),
+                )
+                .await
+        } else {
+            Err(anyhow::anyhow!("No P2P service available"))
+        };
+        match result {
+            Ok((peer_id, blocks)) => {
+                let peer_id: PeerId = peer_id.into();
+                let blocks = peer_id.bind(blocks);
+                Ok(blocks)
+            }
+            Err(err) => Err(err),
+        }
+    }
+
     async fn get_transactions(
         &self,
         range: SourcePeer<Range<u32>>,
@@ -92,6 +126,30 @@ impl PeerToPeerPort for P2PAdapter {
             Err(anyhow::anyhow!("No P2P service available"))
         }
     }
+
+    fn gossiped_block_stream(&self) -> BoxStream<BlockGossipData> {
+        use futures::StreamExt;
+        if let Some(service) = &self.service {
+            fuel_core_services::stream::IntoBoxStream::into_boxed(
+                tokio_stream::wrappers::BroadcastStream::new(service.subscribe_block())
+                    .filter_map(|r| futures::future::ready(r.ok())),
+            )
+        } else {
+            fuel_core_services::stream::IntoBoxStream::into_boxed(tokio_stream::pending())
+        }
+    }
+
+    fn notify_gossip_block_validity(
+        &self,
+        message_info: GossipsubMessageInfo,
+        validity: GossipsubMessageAcceptance,
+    ) -> anyhow::Result<()> {
+        if let Some(service) = &self.service {
+            service.notify_gossip_block_validity(message_info, validity)
+        } else {
+            Ok(())
+        }
+    }
 }
 
 impl P2PAdapter {
@@ -115,16 +173,6 @@ impl P2PAdapter {
     }
 }
 
-struct P2PAdapterPeerReport {
-    score: AppScore,
-}
-
-impl PeerReport for P2PAdapterPeerReport {
-    fn get_score_from_report(&self) -> AppScore {
-        self.score
-    }
-}
-
 #[async_trait::async_trait]
 impl BlockImporterPort for BlockImporterAdapter {
     fn committed_height_stream(&self) -> BoxStream<BlockHeight> {
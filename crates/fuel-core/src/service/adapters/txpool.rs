@@ -1,3 +1,5 @@
+#[cfg(feature = "p2p")]
+use crate::service::adapters::P2PAdapterPeerReport;
 use crate::{
     database::OnChainIterableKeyValueView,
     service::{
@@ -27,6 +29,7 @@ use fuel_core_txpool::{
         ConsensusParametersProvider as ConsensusParametersProviderTrait,
         GasPriceProvider,
         MemoryPool,
+        PeerReportReason,
     },
     Result as TxPoolResult,
 };
@@ -40,6 +43,7 @@ use fuel_core_types::{
         BlobId,
         ConsensusParameters,
         Transaction,
+        TxId,
         UtxoId,
     },
     fuel_types::{
@@ -52,6 +56,7 @@ use fuel_core_types::{
         p2p::{
             GossipsubMessageAcceptance,
             GossipsubMessageInfo,
+            PeerId,
             TransactionGossipData,
         },
     },
@@ -65,6 +70,7 @@ impl BlockImporter for BlockImporterAdapter {
 }
 
 #[cfg(feature = "p2p")]
+#[async_trait::async_trait]
 impl fuel_core_txpool::ports::PeerToPeer for P2PAdapter {
     type GossipedTransaction = TransactionGossipData;
 
@@ -102,9 +108,76 @@ impl fuel_core_txpool::ports::PeerToPeer for P2PAdapter {
             Ok(())
         }
     }
+
+    fn report_peer(
+        &self,
+        peer_id: PeerId,
+        reason: PeerReportReason,
+    ) -> anyhow::Result<()> {
+        if let Some(service) = &self.service {
+            let score = match reason {
+                PeerReportReason::QuotaExceeded => {
+                    self.peer_report_config.transactions_quota_exceeded
+                }
+                PeerReportReason::InvalidTransactions => {
+                    self.peer_report_config.invalid_transactions
+                }
+            };
+            let service_name = "TxPool";
+            service.report_peer(peer_id, P2PAdapterPeerReport { score }, service_name)
+        } else {
+            Ok(())
+        }
+    }
+
+    fn subscribe_new_peers(&self) -> BoxStream<PeerId> {
+        use tokio_stream::{
+            wrappers::BroadcastStream,
+            StreamExt,
+        };
+        if let Some(service) = &self.service {
+            Box::pin(
+                BroadcastStream::new(service.subscribe_new_peers())
+                    .filter_map(|result| result.ok()),
+            )
+        } else {
+            fuel_core_services::stream::IntoBoxStream::into_boxed(tokio_stream::pending())
+        }
+    }
+
+    async fn request_tx_ids(&self, peer_id: PeerId) -> anyhow::Result<Vec<TxId>> {
+        if let Some(service) = &self.service {
+            service
+                .get_all_transactions_ids_from_peer(peer_id.into())
+                .await
+        } else {
+            Err(anyhow::anyhow!("No P2P service available"))
+        }
+    }
+
+    async fn request_txs(
+        &self,
+        peer_id: PeerId,
+        tx_ids: Vec<TxId>,
+    ) -> anyhow::Result<Vec<Option<Transaction>>> {
+        use fuel_core_p2p::request_response::messages::MAX_TXPOOL_FULL_TRANSACTIONS_PER_REQUEST;
+        let Some(service) = &self.service else {
+            return Err(anyhow::anyhow!("No P2P service available"))
+        };
+        // The peer rejects the requests exceeding the limit, so they are split into chunks.
+        let mut txs = Vec::with_capacity(tx_ids.len());
+        for chunk in tx_ids.chunks(MAX_TXPOOL_FULL_TRANSACTIONS_PER_REQUEST) {
+            let chunk_txs = service
+                .get_full_transactions_from_peer(peer_id.clone().into(), chunk.to_vec())
+                .await?;
+            txs.extend(chunk_txs);
+        }
+        Ok(txs)
+    }
 }
 
 #[cfg(not(feature = "p2p"))]
+#[async_trait::async_trait]
 impl fuel_core_txpool::ports::PeerToPeer for P2PAdapter {
     type GossipedTransaction = TransactionGossipData;
 
@@ -126,6 +199,30 @@ impl fuel_core_txpool::ports::PeerToPeer for P2PAdapter {
     ) -> anyhow::Result<()> {
         Ok(())
     }
+
+    fn report_peer(
+        &self,
+        _peer_id: PeerId,
+        _reason: PeerReportReason,
+    ) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn subscribe_new_peers(&self) -> BoxStream<PeerId> {
+        Box::pin(fuel_core_services::stream::pending())
+    }
+
+    async fn request_tx_ids(&self, _peer_id: PeerId) -> anyhow::Result<Vec<TxId>> {
+        Ok(vec![])
+    }
+
+    async fn request_txs(
+        &self,
+        _peer_id: PeerId,
+        _tx_ids: Vec<TxId>,
+    ) -> anyhow::Result<Vec<Option<Transaction>>> {
+        Ok(vec![])
+    }
 }
 
 impl fuel_core_txpool::ports::TxPoolDb for OnChainIterableKeyValueView {
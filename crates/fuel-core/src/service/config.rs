@@ -4,19 +4,25 @@ use std::{
 };
 
 use clap::ValueEnum;
-use fuel_core_poa::signer::SignMode;
+use fuel_core_poa::{
+    leader_lease::LeaderLeaseConfig,
+    signer::SignMode,
+};
 use strum_macros::{
     Display,
     EnumString,
     EnumVariantNames,
 };
 
-use fuel_core_chain_config::SnapshotReader;
 #[cfg(feature = "test-helpers")]
 use fuel_core_chain_config::{
     ChainConfig,
     StateConfig,
 };
+use fuel_core_chain_config::{
+    ConsensusConfig,
+    SnapshotReader,
+};
 pub use fuel_core_consensus_module::RelayerConsensusConfig;
 pub use fuel_core_importer;
 #[cfg(feature = "p2p")]
@@ -65,6 +71,8 @@ pub struct Config {
     #[cfg(feature = "p2p")]
     pub sync: fuel_core_sync::Config,
     pub consensus_signer: SignMode,
+    /// The leader lease shared with the hot standby block producers.
+    pub leader_lease: Option<LeaderLeaseConfig>,
     pub name: String,
     pub relayer_consensus_config: fuel_core_consensus_module::RelayerConsensusConfig,
     /// The number of reserved peers to connect to before starting to sync.
@@ -139,6 +147,7 @@ impl Config {
                 request_body_bytes_limit: 16 * 1024 * 1024,
                 query_log_threshold_time: Duration::from_secs(2),
                 api_request_timeout: Duration::from_secs(60),
+                admin_token: None,
             },
             combined_db_config,
             continue_on_error: false,
@@ -171,6 +180,7 @@ impl Config {
             consensus_signer: SignMode::Key(fuel_core_types::secrecy::Secret::new(
                 fuel_core_chain_config::default_consensus_dev_key().into(),
             )),
+            leader_lease: None,
             name: String::default(),
             relayer_consensus_config: Default::default(),
             min_connected_reserved_peers: 0,
@@ -199,6 +209,10 @@ impl Config {
 
 impl From<&Config> for fuel_core_poa::Config {
     fn from(config: &Config) -> Self {
+        let round_robin = match &config.snapshot_reader.chain_config().consensus {
+            ConsensusConfig::PoAV3(poa) => Some(poa.clone()),
+            _ => None,
+        };
         fuel_core_poa::Config {
             trigger: config.block_production,
             signer: config.consensus_signer.clone(),
@@ -210,6 +224,40 @@ impl From<&Config> for fuel_core_poa::Config {
                 .chain_config()
                 .consensus_parameters
                 .chain_id(),
+            round_robin,
+        }
+    }
+}
+
+impl Config {
+    /// The configuration of the local validator of the BFT consensus.
+    pub fn bft_config(
+        &self,
+        validators: fuel_core_bft::ValidatorSet,
+    ) -> fuel_core_bft::Config {
+        // Without the interval the validators start the next height right after the commit.
+        let block_time = match self.block_production {
+            Trigger::Interval { block_time } => block_time,
+            // The BFT engine doesn't track the gas of pending transactions.
+            Trigger::Hybrid { min_block_time, .. } => min_block_time,
+            Trigger::Instant | Trigger::Never => Duration::ZERO,
+        };
+        fuel_core_bft::Config {
+            signer: self.consensus_signer.clone(),
+            validators,
+            block_time,
+            timeouts: Default::default(),
+            // The state is kept next to the database, so it survives restarts with it.
+            validator_state: match &self.combined_db_config {
+                CombinedDatabaseConfig {
+                    database_type: DbType::RocksDb,
+                    database_path,
+                    ..
+                } if !database_path.as_os_str().is_empty() => {
+                    Some(database_path.join("bft_validator_state.json"))
+                }
+                _ => None,
+            },
         }
     }
 }
@@ -7,6 +7,7 @@ use crate::{
         },
         genesis_progress::GenesisMetadata,
     },
+    graphql_api::ports::worker::OffChainDatabaseTransaction,
     service::config::Config,
 };
 use fuel_core_chain_config::GenesisCommitment;
@@ -73,6 +74,21 @@ pub async fn execute_genesis_block(
     tracing::info!("Genesis block created: {:?}", genesis_block.header());
     let db = db.clone().into_genesis();
 
+    // The balances can be indexed only if the off-chain database is populated
+    // from the very beginning. The existing genesis progress means that the import
+    // was started before, and the flag is already set if it was supported at that time.
+    let off_chain_import_started = db
+        .off_chain()
+        .iter_all_keys::<GenesisMetadata<OffChain>>(None)
+        .next()
+        .is_some();
+    if !off_chain_import_started {
+        let mut database_transaction_off_chain =
+            db.off_chain().clone().into_transaction();
+        database_transaction_off_chain.enable_balances_indexation()?;
+        database_transaction_off_chain.commit()?;
+    }
+
     SnapshotImporter::import(
         db.clone(),
         genesis_block.clone(),
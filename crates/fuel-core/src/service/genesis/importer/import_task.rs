@@ -207,7 +207,6 @@ mod tests {
         state::{
             in_memory::memory_store::MemoryStore,
             IterableKeyValueView,
-            KeyValueView,
             TransactableStorage,
         },
     };
@@ -578,7 +577,7 @@ mod tests {
         fn view_at_height(
             &self,
             _: &BlockHeight,
-        ) -> StorageResult<KeyValueView<Self::Column>> {
+        ) -> StorageResult<IterableKeyValueView<Self::Column>> {
             Err(anyhow::anyhow!("I refuse to work!").into())
         }
 
@@ -48,6 +48,7 @@ use fuel_core_gas_price_service::fuel_gas_price_updater::{
     V0Metadata,
 };
 use fuel_core_poa::{
+    leader_lease::FileLeaderLease,
     signer::SignMode,
     Trigger,
 };
@@ -70,12 +71,26 @@ pub type PoAService = fuel_core_poa::Service<
     TxPoolAdapter,
     BlockProducerAdapter,
     BlockImporterAdapter,
+    P2PAdapter,
     SignMode,
     InDirectoryPredefinedBlocks,
+    Option<FileLeaderLease>,
+    SystemTime,
+>;
+pub type BftService = fuel_core_bft::Service<
+    BlockProducerAdapter,
+    BlockImporterAdapter,
+    P2PAdapter,
     SystemTime,
 >;
 #[cfg(feature = "p2p")]
-pub type P2PService = fuel_core_p2p::service::Service<Database>;
+pub type P2PService = fuel_core_p2p::service::Service<
+    Database,
+    TxPoolAdapter,
+    fuel_core_storage::structured_storage::StructuredStorage<
+        Database<crate::database::database_description::peers::Peers>,
+    >,
+>;
 pub type TxPoolSharedState = fuel_core_txpool::service::SharedState<
     P2PAdapter,
     Database,
@@ -168,15 +183,13 @@ pub fn init_sub_services(
         ),
     };
 
+    // The network and the transaction pool depend on each other, so the shared
+    // state of the network is created before both services.
     #[cfg(feature = "p2p")]
-    let mut network = config.p2p.clone().map(|p2p_config| {
-        fuel_core_p2p::service::new_service(
-            chain_id,
-            p2p_config,
-            database.on_chain().clone(),
-            importer_adapter.clone(),
-        )
-    });
+    let p2p_externals = config
+        .p2p
+        .as_ref()
+        .map(fuel_core_p2p::service::build_shared_state);
 
     #[cfg(feature = "p2p")]
     let p2p_adapter = {
@@ -190,9 +203,12 @@ pub fn init_sub_services(
             bad_block_header: -100.,
             missing_transactions: -100.,
             invalid_transactions: -100.,
+            transactions_quota_exceeded: -10.,
         };
         P2PAdapter::new(
-            network.as_ref().map(|network| network.shared.clone()),
+            p2p_externals
+                .as_ref()
+                .map(|(shared_state, _)| shared_state.clone()),
             peer_report_config,
         )
     };
@@ -226,9 +242,27 @@ pub fn init_sub_services(
         gas_price_provider.clone(),
         consensus_parameters_provider.clone(),
         SharedMemoryPool::new(config.memory_pool_size),
-    );
+    )?;
     let tx_pool_adapter = TxPoolAdapter::new(txpool.shared.clone());
 
+    #[cfg(feature = "p2p")]
+    let mut network = config.p2p.clone().zip(p2p_externals).map(
+        |(p2p_config, (shared_state, request_receiver))| {
+            fuel_core_p2p::service::new_service(
+                chain_id,
+                p2p_config,
+                shared_state,
+                request_receiver,
+                database.on_chain().clone(),
+                importer_adapter.clone(),
+                tx_pool_adapter.clone(),
+                fuel_core_storage::structured_storage::StructuredStorage::new(
+                    database.peers().clone(),
+                ),
+            )
+        },
+    );
+
     let block_producer = fuel_core_producer::Producer {
         config: config.block_producer.clone(),
         view_provider: database.on_chain().clone(),
@@ -249,9 +283,30 @@ pub fn init_sub_services(
         tracing::info!("Enabled manual block production because of `debug` flag");
     }
 
+    // Blocks of the BFT chain are produced only by the validators.
+    let bft_validators =
+        fuel_core_bft::ValidatorSet::from_consensus_config(&chain_config.consensus);
+    let local_validator = config.consensus_signer.address()?;
+    let bft = bft_validators
+        .as_ref()
+        .filter(|validators| {
+            local_validator.is_some_and(|address| validators.contains(&address))
+        })
+        .map(|validators| {
+            fuel_core_bft::new_service(
+                &last_block_header,
+                config.bft_config(validators.clone()),
+                producer_adapter.clone(),
+                importer_adapter.clone(),
+                p2p_adapter.clone(),
+                SystemTime,
+            )
+        })
+        .transpose()?;
+
     let predefined_blocks =
         InDirectoryPredefinedBlocks::new(config.predefined_blocks_path.clone());
-    let poa = (production_enabled).then(|| {
+    let poa = (production_enabled && bft_validators.is_none()).then(|| {
         fuel_core_poa::new_service(
             &last_block_header,
             poa_config,
@@ -261,6 +316,7 @@ pub fn init_sub_services(
             p2p_adapter.clone(),
             FuelBlockSigner::new(config.consensus_signer.clone()),
             predefined_blocks,
+            config.leader_lease.clone().map(FileLeaderLease::new),
             SystemTime,
         )
     });
@@ -313,6 +369,7 @@ pub fn init_sub_services(
         Box::new(producer_adapter),
         Box::new(poa_adapter.clone()),
         Box::new(p2p_adapter),
+        Box::new(graphql_worker.shared.clone()),
         Box::new(gas_price_provider),
         Box::new(consensus_parameters_provider),
         SharedMemoryPool::new(config.memory_pool_size),
@@ -344,6 +401,10 @@ pub fn init_sub_services(
         services.push(Box::new(poa));
     }
 
+    if let Some(bft) = bft {
+        services.push(Box::new(bft));
+    }
+
     #[cfg(feature = "relayer")]
     if let Some(relayer) = relayer_service {
         services.push(Box::new(relayer));
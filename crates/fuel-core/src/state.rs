@@ -69,7 +69,7 @@ pub trait TransactableStorage<Height>: IterableStore + Debug + Send + Sync {
     fn view_at_height(
         &self,
         height: &Height,
-    ) -> StorageResult<KeyValueView<Self::Column>>;
+    ) -> StorageResult<IterableKeyValueView<Self::Column>>;
 
     fn latest_view(&self) -> StorageResult<IterableKeyValueView<Self::Column>>;
 
@@ -87,7 +87,10 @@ where
         unimplemented!()
     }
 
-    fn view_at_height(&self, _: &Height) -> StorageResult<KeyValueView<Self::Column>> {
+    fn view_at_height(
+        &self,
+        _: &Height,
+    ) -> StorageResult<IterableKeyValueView<Self::Column>> {
         unimplemented!()
     }
 
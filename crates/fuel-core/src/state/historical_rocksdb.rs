@@ -18,11 +18,9 @@ use crate::{
             view_at_height::ViewAtHeight,
         },
         iterable_key_value_view::IterableKeyValueViewWrapper,
-        key_value_view::KeyValueViewWrapper,
         rocks_db::RocksDb,
         ColumnType,
         IterableKeyValueView,
-        KeyValueView,
         TransactableStorage,
     },
 };
@@ -453,9 +451,11 @@ where
     fn view_at_height(
         &self,
         height: &Description::Height,
-    ) -> StorageResult<KeyValueView<ColumnType<Description>>> {
+    ) -> StorageResult<IterableKeyValueView<ColumnType<Description>>> {
         let view = self.create_view_at(height)?;
-        Ok(KeyValueView::from_storage(KeyValueViewWrapper::new(view)))
+        Ok(IterableKeyValueView::from_storage(
+            IterableKeyValueViewWrapper::new(view),
+        ))
     }
 
     fn latest_view(
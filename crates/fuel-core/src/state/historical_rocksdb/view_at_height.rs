@@ -1,5 +1,8 @@
 use crate::{
-    database::database_description::DatabaseDescription,
+    database::{
+        convert_to_rocksdb_direction,
+        database_description::DatabaseDescription,
+    },
     state::{
         historical_rocksdb::{
             description::{
@@ -10,24 +13,39 @@ use crate::{
             height_key,
         },
         rocks_db::{
+            next_prefix,
             KeyAndValue,
+            KeyOnly,
             RocksDb,
         },
     },
 };
 use fuel_core_storage::{
+    iter::{
+        BoxedIter,
+        IntoBoxedIter,
+        IterDirection,
+        IterableStore,
+    },
     kv_store::{
+        KVItem,
+        Key,
+        KeyItem,
         KeyValueInspect,
         Value,
         WriteOperation,
     },
     Result as StorageResult,
 };
+use itertools::Itertools;
 use rocksdb::{
     IteratorMode,
     ReadOptions,
 };
 
+/// The size of the height suffix in the keys of the historical duplicate column.
+const HEIGHT_SUFFIX_SIZE: usize = core::mem::size_of::<u64>();
+
 pub struct ViewAtHeight<Description> {
     height: u64,
     read_db: RocksDb<Historical<Description>>,
@@ -40,6 +58,148 @@ where
     pub fn new(height: u64, read_db: RocksDb<Historical<Description>>) -> Self {
         Self { height, read_db }
     }
+
+    /// Returns keys that were modified at or after the height of the view, in the `direction`.
+    /// These keys may exist at the height of the view while being absent from
+    /// the latest state, or vice versa.
+    ///
+    /// The history keeps a version of the key per modification, so instead of visiting
+    /// each version, the iteration seeks over versions below the height of the view
+    /// and over the remaining versions of the returned key.
+    fn modified_keys(
+        &self,
+        column: Description::Column,
+        prefix: Option<&[u8]>,
+        start: Option<&[u8]>,
+        direction: IterDirection,
+    ) -> BoxedIter<KeyItem> {
+        if let (Some(prefix), Some(start)) = (prefix, start) {
+            if !start.starts_with(prefix) {
+                return core::iter::empty().into_boxed();
+            }
+        }
+
+        // The position of the iteration and whether the key at it should be visited.
+        let mut position = match (start, prefix, direction) {
+            (Some(start), _, IterDirection::Forward) => Some((start.to_vec(), true)),
+            (Some(start), _, IterDirection::Reverse) => {
+                Some((height_key(start, &u64::MAX), true))
+            }
+            (None, Some(prefix), IterDirection::Forward) => Some((prefix.to_vec(), true)),
+            (None, Some(prefix), IterDirection::Reverse) => {
+                next_prefix(prefix.to_vec()).map(|next_prefix| (next_prefix, false))
+            }
+            (None, None, _) => None,
+        };
+        let prefix = prefix.map(<[u8]>::to_vec);
+        let mut finished = false;
+
+        core::iter::from_fn(move || {
+            while !finished {
+                let history_key = match self.seek_history(column, &position, direction) {
+                    Ok(Some(history_key)) => history_key,
+                    Ok(None) => {
+                        finished = true;
+                        return None
+                    }
+                    Err(err) => {
+                        finished = true;
+                        return Some(Err(err))
+                    }
+                };
+                if let Some(prefix) = &prefix {
+                    if !history_key.starts_with(prefix) {
+                        finished = true;
+                        return None
+                    }
+                }
+                let Some(key_len) = history_key.len().checked_sub(HEIGHT_SUFFIX_SIZE)
+                else {
+                    position = Some((history_key, false));
+                    continue;
+                };
+                let (key, height) = history_key.split_at(key_len);
+                let height = u64::from_be_bytes(
+                    height.try_into().expect("The suffix has the size of `u64`"),
+                );
+
+                match direction {
+                    // The iteration stops at the lowest version of the key.
+                    IterDirection::Forward => {
+                        if height < self.height {
+                            position = Some((height_key(key, &self.height), true));
+                            continue;
+                        }
+                        position = Some((height_key(key, &u64::MAX), false));
+                    }
+                    // The iteration stops at the highest version of the key.
+                    IterDirection::Reverse => {
+                        position = Some((height_key(key, &0), false));
+                        if height < self.height {
+                            continue;
+                        }
+                    }
+                }
+                return Some(Ok(key.to_vec()))
+            }
+            None
+        })
+        .into_boxed()
+    }
+
+    /// Returns the first key of the history in the `direction` from the `position`,
+    /// or from the edge of the column without the `position`.
+    fn seek_history(
+        &self,
+        column: Description::Column,
+        position: &Option<(Vec<u8>, bool)>,
+        direction: IterDirection,
+    ) -> StorageResult<Option<Key>> {
+        let mode = match (position, direction) {
+            (Some((key, _)), _) => {
+                IteratorMode::From(key, convert_to_rocksdb_direction(direction))
+            }
+            (None, IterDirection::Forward) => IteratorMode::Start,
+            (None, IterDirection::Reverse) => IteratorMode::End,
+        };
+        let mut history = self.read_db.iterator::<KeyOnly>(
+            Column::HistoricalDuplicateColumn(column),
+            self.read_db.read_options(),
+            mode,
+        );
+        match (history.next().transpose()?, position) {
+            (Some(key), Some((position, false))) if &key == position => {
+                history.next().transpose()
+            }
+            (key, _) => Ok(key),
+        }
+    }
+
+    /// Returns keys that may exist at the height of the view. It is a merge of the keys
+    /// from the latest state and the keys modified since the height of the view.
+    fn candidate_keys(
+        &self,
+        column: Description::Column,
+        prefix: Option<&[u8]>,
+        start: Option<&[u8]>,
+        direction: IterDirection,
+    ) -> BoxedIter<KeyItem> {
+        let modified_keys = self.modified_keys(column, prefix, start, direction);
+
+        self.read_db
+            .iter_store_keys(Column::OriginalColumn(column), prefix, start, direction)
+            .merge_by(modified_keys, move |left, right| match (left, right) {
+                (Ok(left), Ok(right)) => match direction {
+                    IterDirection::Forward => left <= right,
+                    IterDirection::Reverse => left >= right,
+                },
+                // Propagate errors as soon as possible.
+                (Err(_), _) => true,
+                (_, Err(_)) => false,
+            })
+            .dedup_by(|left, right| matches!((left, right), (Ok(left), Ok(right)) if left == right))
+            .into_boxed()
+    }
 }
 
 impl<Description> KeyValueInspect for ViewAtHeight<Description>
@@ -78,6 +238,46 @@ where
     }
 }
 
+/// The iteration over the view is more expensive than over the latest state,
+/// because it also has to visit all modifications of the column since the height of the view.
+impl<Description> IterableStore for ViewAtHeight<Description>
+where
+    Description: DatabaseDescription,
+{
+    fn iter_store(
+        &self,
+        column: Self::Column,
+        prefix: Option<&[u8]>,
+        start: Option<&[u8]>,
+        direction: IterDirection,
+    ) -> BoxedIter<KVItem> {
+        self.candidate_keys(column, prefix, start, direction)
+            .filter_map(move |key| {
+                let key = match key {
+                    Ok(key) => key,
+                    Err(err) => return Some(Err(err)),
+                };
+
+                self.get(&key, column)
+                    .transpose()
+                    .map(|value| value.map(|value| (key, value)))
+            })
+            .into_boxed()
+    }
+
+    fn iter_store_keys(
+        &self,
+        column: Self::Column,
+        prefix: Option<&[u8]>,
+        start: Option<&[u8]>,
+        direction: IterDirection,
+    ) -> BoxedIter<KeyItem> {
+        self.iter_store(column, prefix, start, direction)
+            .map(|item| item.map(|(key, _)| key))
+            .into_boxed()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -92,6 +292,7 @@ mod tests {
         },
     };
     use fuel_core_storage::{
+        iter::IteratorOverTable,
         tables::ContractsAssets,
         transactional::{
             IntoTransaction,
@@ -101,6 +302,7 @@ mod tests {
         StorageAsMut,
         StorageAsRef,
     };
+    use fuel_core_types::fuel_types::ContractId;
 
     fn key() -> ContractsAssetKey {
         ContractsAssetKey::new(&[123; 32].into(), &[213; 32].into())
@@ -261,4 +463,160 @@ mod tests {
         assert_eq!(balance_at_height_two, 321);
         assert_eq!(balance_at_height_three, 321);
     }
+
+    #[test]
+    fn historical_rocksdb_view_at_height_iterates_over_state_at_that_height() {
+        // Given
+        let rocks_db = RocksDb::<Historical<OnChain>>::default_open_temp(None).unwrap();
+        let historical_rocks_db =
+            HistoricalRocksDB::new(rocks_db, StateRewindPolicy::RewindFullRange).unwrap();
+        let first_key = ContractsAssetKey::new(&[1; 32].into(), &[1; 32].into());
+        let second_key = ContractsAssetKey::new(&[1; 32].into(), &[2; 32].into());
+        let third_key = ContractsAssetKey::new(&[1; 32].into(), &[3; 32].into());
+
+        // Insert the first and the third keys at height 1.
+        let mut transaction = historical_rocks_db.read_transaction();
+        transaction
+            .storage_as_mut::<ContractsAssets>()
+            .insert(&first_key, &1)
+            .unwrap();
+        transaction
+            .storage_as_mut::<ContractsAssets>()
+            .insert(&third_key, &3)
+            .unwrap();
+        historical_rocks_db
+            .commit_changes(Some(1u32.into()), transaction.into_changes())
+            .unwrap();
+
+        // Remove the first key and insert the second key at height 2.
+        let mut transaction = historical_rocks_db.read_transaction();
+        transaction
+            .storage_as_mut::<ContractsAssets>()
+            .remove(&first_key)
+            .unwrap();
+        transaction
+            .storage_as_mut::<ContractsAssets>()
+            .insert(&second_key, &2)
+            .unwrap();
+        historical_rocks_db
+            .commit_changes(Some(2u32.into()), transaction.into_changes())
+            .unwrap();
+
+        // When
+        let view_at_height_one =
+            historical_rocks_db.create_view_at(&1u32.into()).unwrap();
+        let view_at_height_two =
+            historical_rocks_db.create_view_at(&2u32.into()).unwrap();
+        let entries_at_height_one = view_at_height_one
+            .iter_all::<ContractsAssets>(Some(IterDirection::Forward))
+            .try_collect::<_, Vec<_>, _>()
+            .unwrap();
+        let reversed_entries_at_height_one = view_at_height_one
+            .iter_all::<ContractsAssets>(Some(IterDirection::Reverse))
+            .try_collect::<_, Vec<_>, _>()
+            .unwrap();
+        let entries_at_height_two = view_at_height_two
+            .iter_all::<ContractsAssets>(Some(IterDirection::Forward))
+            .try_collect::<_, Vec<_>, _>()
+            .unwrap();
+
+        // Then
+        assert_eq!(entries_at_height_one, vec![(first_key, 1), (third_key, 3)]);
+        assert_eq!(
+            reversed_entries_at_height_one,
+            vec![(third_key, 3), (first_key, 1)]
+        );
+        assert_eq!(entries_at_height_two, vec![(second_key, 2), (third_key, 3)]);
+    }
+
+    #[test]
+    fn historical_rocksdb_view_at_height_iterates_with_prefix_and_start_over_many_versions(
+    ) {
+        // Given
+        let rocks_db = RocksDb::<Historical<OnChain>>::default_open_temp(None).unwrap();
+        let historical_rocks_db =
+            HistoricalRocksDB::new(rocks_db, StateRewindPolicy::RewindFullRange).unwrap();
+        let first_key = ContractsAssetKey::new(&[1; 32].into(), &[1; 32].into());
+        let second_key = ContractsAssetKey::new(&[1; 32].into(), &[2; 32].into());
+        let third_key = ContractsAssetKey::new(&[1; 32].into(), &[3; 32].into());
+        let other_contract_key = ContractsAssetKey::new(&[2; 32].into(), &[1; 32].into());
+        let modifications: [&[(ContractsAssetKey, Option<u64>)]; 4] = [
+            &[
+                (first_key, Some(1)),
+                (second_key, Some(2)),
+                (third_key, Some(3)),
+                (other_contract_key, Some(9)),
+            ],
+            &[
+                (first_key, Some(10)),
+                (second_key, Some(20)),
+                (third_key, Some(30)),
+            ],
+            &[(first_key, Some(100)), (second_key, None)],
+            &[(first_key, None), (third_key, Some(300))],
+        ];
+        for (height, modifications) in (1u32..).zip(modifications) {
+            let mut transaction = historical_rocks_db.read_transaction();
+            for (key, value) in modifications {
+                match value {
+                    Some(value) => transaction
+                        .storage_as_mut::<ContractsAssets>()
+                        .insert(key, value)
+                        .unwrap(),
+                    None => transaction
+                        .storage_as_mut::<ContractsAssets>()
+                        .remove(key)
+                        .unwrap(),
+                }
+            }
+            historical_rocks_db
+                .commit_changes(Some(height.into()), transaction.into_changes())
+                .unwrap();
+        }
+
+        // When
+        let view = historical_rocks_db.create_view_at(&2u32.into()).unwrap();
+        let by_prefix = view
+            .iter_all_by_prefix::<ContractsAssets, _>(Some(ContractId::from([1; 32])))
+            .try_collect::<_, Vec<_>, _>()
+            .unwrap();
+        let from_start = view
+            .iter_all_by_start::<ContractsAssets>(
+                Some(&second_key),
+                Some(IterDirection::Forward),
+            )
+            .try_collect::<_, Vec<_>, _>()
+            .unwrap();
+        let reversed_from_start = view
+            .iter_all_by_start::<ContractsAssets>(
+                Some(&second_key),
+                Some(IterDirection::Reverse),
+            )
+            .try_collect::<_, Vec<_>, _>()
+            .unwrap();
+        let reversed = view
+            .iter_all::<ContractsAssets>(Some(IterDirection::Reverse))
+            .try_collect::<_, Vec<_>, _>()
+            .unwrap();
+
+        // Then
+        assert_eq!(
+            by_prefix,
+            vec![(first_key, 10), (second_key, 20), (third_key, 30)]
+        );
+        assert_eq!(
+            from_start,
+            vec![(second_key, 20), (third_key, 30), (other_contract_key, 9)]
+        );
+        assert_eq!(reversed_from_start, vec![(second_key, 20), (first_key, 10)]);
+        assert_eq!(
+            reversed,
+            vec![
+                (other_contract_key, 9),
+                (third_key, 30),
+                (second_key, 20),
+                (first_key, 10)
+            ]
+        );
+    }
 }
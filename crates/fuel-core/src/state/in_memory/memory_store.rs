@@ -8,7 +8,6 @@ use crate::{
         iterable_key_value_view::IterableKeyValueViewWrapper,
         IterDirection,
         IterableKeyValueView,
-        KeyValueView,
         TransactableStorage,
     },
 };
@@ -191,7 +190,7 @@ where
     fn view_at_height(
         &self,
         _: &Description::Height,
-    ) -> StorageResult<KeyValueView<Self::Column>> {
+    ) -> StorageResult<IterableKeyValueView<Self::Column>> {
         // TODO: https://github.com/FuelLabs/fuel-core/issues/1995
         Err(
             anyhow::anyhow!("The historical view is not implemented for `MemoryStore`")
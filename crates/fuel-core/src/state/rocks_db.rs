@@ -368,7 +368,7 @@ where
         opts
     }
 
-    fn read_options(&self) -> ReadOptions {
+    pub(crate) fn read_options(&self) -> ReadOptions {
         Self::generate_read_options(&self.snapshot)
     }
 
@@ -820,7 +820,7 @@ where
 }
 
 /// The `None` means overflow, so there is not following prefix.
-fn next_prefix(mut prefix: Vec<u8>) -> Option<Vec<u8>> {
+pub(crate) fn next_prefix(mut prefix: Vec<u8>) -> Option<Vec<u8>> {
     for byte in prefix.iter_mut().rev() {
         if let Some(new_byte) = byte.checked_add(1) {
             *byte = new_byte;
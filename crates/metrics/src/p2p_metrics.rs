@@ -8,16 +8,19 @@ use std::sync::OnceLock;
 pub struct P2PMetrics {
     pub unique_peers: Counter,
     pub blocks_requested: Gauge,
+    pub rejected_connections: Counter,
 }
 
 impl P2PMetrics {
     fn new() -> Self {
         let unique_peers = Counter::default();
         let blocks_requested = Gauge::default();
+        let rejected_connections = Counter::default();
 
         let metrics = P2PMetrics {
             unique_peers,
             blocks_requested,
+            rejected_connections,
         };
 
         let mut registry = global_registry().registry.lock();
@@ -33,6 +36,12 @@ impl P2PMetrics {
             metrics.blocks_requested.clone()
         );
 
+        registry.register(
+            "Rejected_Connections",
+            "A Counter which keeps track of connections rejected because of the IP filters or the per-IP limit",
+            metrics.rejected_connections.clone(),
+        );
+
         metrics
     }
 }
@@ -50,3 +59,7 @@ pub fn increment_unique_peers() {
 pub fn set_blocks_requested(count: usize) {
     p2p_metrics().blocks_requested.set(count as i64);
 }
+
+pub fn increment_rejected_connections() {
+    p2p_metrics().rejected_connections.inc();
+}
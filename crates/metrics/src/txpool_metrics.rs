@@ -1,9 +1,13 @@
 use crate::global_registry;
-use prometheus_client::metrics::histogram::Histogram;
+use prometheus_client::metrics::{
+    counter::Counter,
+    histogram::Histogram,
+};
 use std::sync::OnceLock;
 
 pub struct TxPoolMetrics {
     pub tx_size_histogram: Histogram,
+    pub replaced_txs: Counter,
 }
 
 impl Default for TxPoolMetrics {
@@ -12,7 +16,12 @@ impl Default for TxPoolMetrics {
 
         let tx_size_histogram = Histogram::new(tx_sizes.into_iter());
 
-        let metrics = TxPoolMetrics { tx_size_histogram };
+        let replaced_txs = Counter::default();
+
+        let metrics = TxPoolMetrics {
+            tx_size_histogram,
+            replaced_txs,
+        };
 
         let mut registry = global_registry().registry.lock();
         registry.register(
@@ -20,6 +29,11 @@ impl Default for TxPoolMetrics {
             "A Histogram keeping track of the size of txs",
             metrics.tx_size_histogram.clone(),
         );
+        registry.register(
+            "Replaced_Txs_Counter",
+            "A Counter which keeps track of the transactions replaced by transactions with a higher tip",
+            metrics.replaced_txs.clone(),
+        );
 
         metrics
     }
@@ -0,0 +1,64 @@
+use crate::validators::ValidatorSet;
+use fuel_core_poa::signer::SignMode;
+use std::path::PathBuf;
+use tokio::time::Duration;
+
+#[derive(Debug, Clone)]
+pub struct Config {
+    /// The signer of proposals and votes of the local validator.
+    pub signer: SignMode,
+    /// The validators that decide on blocks.
+    pub validators: ValidatorSet,
+    /// The minimal time between the commit of the block and the start of the next height.
+    pub block_time: Duration,
+    /// The timeouts of steps of the round.
+    pub timeouts: Timeouts,
+    /// The file with the votes signed by the local validator and its lock.
+    /// Without the file the state is kept only in memory and lost on restart.
+    pub validator_state: Option<PathBuf>,
+}
+
+#[cfg(feature = "test-helpers")]
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            signer: SignMode::Unavailable,
+            validators: ValidatorSet::new(vec![]),
+            block_time: Duration::ZERO,
+            timeouts: Timeouts::default(),
+            validator_state: None,
+        }
+    }
+}
+
+/// How long validators wait for the proposal and for votes before moving on.
+/// Each following round of the same height waits `delta` longer,
+/// so validators eventually synchronize even if the network is slow.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Timeouts {
+    /// The time to wait for the proposal of the round.
+    pub propose: Duration,
+    /// The time to wait for the prevotes of the round after receiving any quorum of prevotes.
+    pub prevote: Duration,
+    /// The time to wait for the precommits of the round after receiving any quorum of precommits.
+    pub precommit: Duration,
+    /// The increase of each timeout with each round.
+    pub delta: Duration,
+}
+
+impl Default for Timeouts {
+    fn default() -> Self {
+        Self {
+            propose: Duration::from_secs(3),
+            prevote: Duration::from_secs(1),
+            precommit: Duration::from_secs(1),
+            delta: Duration::from_millis(500),
+        }
+    }
+}
+
+impl Timeouts {
+    pub(crate) fn with_delta(base: Duration, delta: Duration, round: u32) -> Duration {
+        base.saturating_add(delta.saturating_mul(round))
+    }
+}
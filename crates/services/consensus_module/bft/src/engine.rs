@@ -0,0 +1,585 @@
+//! The Tendermint state machine of the single height.
+//!
+//! The engine doesn't perform any IO. It consumes proposals, votes and timeouts
+//! and returns the [`Action`]s that the caller should perform. Votes and proposals
+//! of the local validator are also expected to be fed back into the engine.
+
+use crate::{
+    config::Timeouts,
+    validators::ValidatorSet,
+};
+use fuel_core_types::{
+    blockchain::{
+        block::Block,
+        consensus::{
+            bft::{
+                BftConsensus,
+                Proposal,
+                SignedVote,
+                Vote,
+                VoteStep,
+            },
+            Consensus,
+        },
+        primitives::BlockId,
+        SealedBlock,
+    },
+    fuel_crypto::Signature,
+    fuel_types::{
+        Address,
+        BlockHeight,
+    },
+};
+use std::collections::{
+    BTreeMap,
+    BTreeSet,
+    HashMap,
+};
+use tokio::time::Duration;
+
+#[cfg(test)]
+mod tests;
+
+/// The number of rounds ahead of the current round for which messages are accepted.
+/// Messages for further rounds are dropped, so a byzantine validator can't
+/// fill the memory with messages for arbitrary rounds.
+pub const MAX_FUTURE_ROUNDS: u32 = 10;
+
+/// The step of the round.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Step {
+    /// Waiting for the proposal.
+    Propose,
+    /// Waiting for prevotes.
+    Prevote,
+    /// Waiting for precommits.
+    Precommit,
+    /// The height is decided.
+    Commit,
+}
+
+/// The timeout of the step scheduled by the engine.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Timeout {
+    pub height: BlockHeight,
+    pub round: u32,
+    pub step: Step,
+}
+
+/// The action requested by the engine.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Action {
+    /// Produce a new block and propose it in the `round`.
+    ProduceBlock { height: BlockHeight, round: u32 },
+    /// Sign and broadcast the proposal of the `block`.
+    Propose {
+        round: u32,
+        valid_round: Option<u32>,
+        block: Block,
+    },
+    /// Sign and broadcast the vote.
+    Vote(Vote),
+    /// Return the timeout to the engine after the delay.
+    ScheduleTimeout { timeout: Timeout, after: Duration },
+    /// Commit the decided block.
+    Commit(SealedBlock),
+}
+
+/// The reason why the proposal or the vote is not accepted.
+#[derive(Debug, thiserror::Error)]
+pub enum MessageError {
+    #[error(
+        "The message is for the height {actual}, but the current height is {expected}"
+    )]
+    WrongHeight {
+        expected: BlockHeight,
+        actual: BlockHeight,
+    },
+    #[error("The height {0} is already decided")]
+    AlreadyDecided(BlockHeight),
+    #[error("Can't recover the signer of the message: {0}")]
+    InvalidSignature(anyhow::Error),
+    #[error("{0} is not a validator")]
+    UnknownValidator(Address),
+    #[error("{signer} is not the proposer of the round {round}")]
+    WrongProposer { signer: Address, round: u32 },
+    #[error("The valid round {valid_round} is not lower than the round {round}")]
+    InvalidValidRound { round: u32, valid_round: u32 },
+    #[error("The message for the round {0} is already received")]
+    Duplicate(u32),
+    #[error("The round {round} is too far ahead of the current round {current}")]
+    RoundTooFar { round: u32, current: u32 },
+}
+
+impl MessageError {
+    /// Returns `true` if the honest validator can't create such a message.
+    pub fn is_malicious(&self) -> bool {
+        matches!(
+            self,
+            MessageError::InvalidSignature(_)
+                | MessageError::UnknownValidator(_)
+                | MessageError::WrongProposer { .. }
+                | MessageError::InvalidValidRound { .. }
+        )
+    }
+}
+
+struct ReceivedProposal {
+    proposal: Proposal,
+    block_id: BlockId,
+    /// The result of the execution of the block.
+    valid: bool,
+}
+
+/// Votes of one step of one round, ordered by validators.
+#[derive(Default)]
+struct StepVotes {
+    votes: BTreeMap<Address, (Option<BlockId>, Signature)>,
+}
+
+impl StepVotes {
+    fn total(&self) -> usize {
+        self.votes.len()
+    }
+
+    fn count(&self, block_id: Option<BlockId>) -> usize {
+        self.votes
+            .values()
+            .filter(|(voted, _)| *voted == block_id)
+            .count()
+    }
+
+    fn signatures(&self, block_id: BlockId) -> Vec<Signature> {
+        self.votes
+            .values()
+            .filter(|(voted, _)| *voted == Some(block_id))
+            .map(|(_, signature)| *signature)
+            .collect()
+    }
+}
+
+pub struct Engine {
+    validators: ValidatorSet,
+    timeouts: Timeouts,
+    local: Address,
+    height: BlockHeight,
+    round: u32,
+    step: Step,
+    /// The round and the block the local validator precommitted for.
+    locked: Option<(u32, BlockId)>,
+    /// The last round in which the proposal got the quorum of prevotes.
+    valid_round: Option<u32>,
+    proposals: HashMap<u32, ReceivedProposal>,
+    votes: HashMap<(u32, VoteStep), StepVotes>,
+    prevote_timeout_scheduled: bool,
+    precommit_timeout_scheduled: bool,
+    proposal_quorum_handled: bool,
+}
+
+impl Engine {
+    /// Creates the engine of the `local` validator. Nothing happens until
+    /// the [`Engine::start_height`] is called.
+    pub fn new(validators: ValidatorSet, timeouts: Timeouts, local: Address) -> Self {
+        Self {
+            validators,
+            timeouts,
+            local,
+            height: BlockHeight::from(0u32),
+            round: 0,
+            step: Step::Commit,
+            locked: None,
+            valid_round: None,
+            proposals: HashMap::new(),
+            votes: HashMap::new(),
+            prevote_timeout_scheduled: false,
+            precommit_timeout_scheduled: false,
+            proposal_quorum_handled: false,
+        }
+    }
+
+    pub fn validators(&self) -> &ValidatorSet {
+        &self.validators
+    }
+
+    pub fn height(&self) -> BlockHeight {
+        self.height
+    }
+
+    pub fn round(&self) -> u32 {
+        self.round
+    }
+
+    pub fn step(&self) -> Step {
+        self.step
+    }
+
+    /// The round and the block the local validator is locked on.
+    pub fn locked(&self) -> Option<(u32, BlockId)> {
+        self.locked
+    }
+
+    /// Forgets everything about the previous height and starts the round `0` of the `height`.
+    /// The `locked` is the lock of the local validator at the `height` before the restart.
+    pub fn start_height(
+        &mut self,
+        height: BlockHeight,
+        locked: Option<(u32, BlockId)>,
+    ) -> Vec<Action> {
+        self.height = height;
+        self.locked = locked;
+        self.valid_round = None;
+        self.proposals.clear();
+        self.votes.clear();
+
+        let mut actions = vec![];
+        self.start_round(0, &mut actions);
+        self.process(&mut actions);
+        actions
+    }
+
+    /// Checks that the proposal can be accepted before spending time on the validation of its block.
+    pub fn check_proposal(&self, proposal: &Proposal) -> Result<(), MessageError> {
+        self.check_height(proposal.height())?;
+        let round = proposal.round;
+        self.check_round(round)?;
+        if let Some(valid_round) = proposal.valid_round {
+            if valid_round >= round {
+                return Err(MessageError::InvalidValidRound { round, valid_round })
+            }
+        }
+
+        let signer = proposal.signer().map_err(MessageError::InvalidSignature)?;
+        if !self.validators.contains(&signer) {
+            return Err(MessageError::UnknownValidator(signer))
+        }
+        if self.validators.proposer(self.height, round) != Some(&signer) {
+            return Err(MessageError::WrongProposer { signer, round })
+        }
+        if self.proposals.contains_key(&round) {
+            return Err(MessageError::Duplicate(round))
+        }
+        Ok(())
+    }
+
+    /// Accepts the proposal, `valid` is the result of the execution of its block.
+    pub fn on_proposal(
+        &mut self,
+        proposal: Proposal,
+        valid: bool,
+    ) -> Result<Vec<Action>, MessageError> {
+        self.check_proposal(&proposal)?;
+        let block_id = proposal.block.id();
+        self.proposals.insert(
+            proposal.round,
+            ReceivedProposal {
+                proposal,
+                block_id,
+                valid,
+            },
+        );
+
+        let mut actions = vec![];
+        self.process(&mut actions);
+        Ok(actions)
+    }
+
+    pub fn on_vote(&mut self, vote: SignedVote) -> Result<Vec<Action>, MessageError> {
+        self.check_height(vote.vote.height)?;
+        self.check_round(vote.vote.round)?;
+        let signer = vote.signer().map_err(MessageError::InvalidSignature)?;
+        if !self.validators.contains(&signer) {
+            return Err(MessageError::UnknownValidator(signer))
+        }
+
+        let SignedVote { vote, signature } = vote;
+        let votes = self.votes.entry((vote.round, vote.step)).or_default();
+        if votes.votes.contains_key(&signer) {
+            return Err(MessageError::Duplicate(vote.round))
+        }
+        votes.votes.insert(signer, (vote.block_id, signature));
+
+        let mut actions = vec![];
+        self.process(&mut actions);
+        Ok(actions)
+    }
+
+    pub fn on_timeout(&mut self, timeout: Timeout) -> Vec<Action> {
+        let mut actions = vec![];
+        if timeout.height != self.height
+            || timeout.round != self.round
+            || self.step == Step::Commit
+        {
+            return actions
+        }
+
+        match timeout.step {
+            Step::Propose if self.step == Step::Propose => {
+                self.vote(VoteStep::Prevote, None, &mut actions);
+            }
+            Step::Prevote if self.step == Step::Prevote => {
+                self.vote(VoteStep::Precommit, None, &mut actions);
+            }
+            Step::Precommit => {
+                self.start_round(self.round.saturating_add(1), &mut actions);
+            }
+            _ => {}
+        }
+        self.process(&mut actions);
+        actions
+    }
+
+    fn check_height(&self, height: BlockHeight) -> Result<(), MessageError> {
+        if height != self.height {
+            return Err(MessageError::WrongHeight {
+                expected: self.height,
+                actual: height,
+            })
+        }
+        if self.step == Step::Commit {
+            return Err(MessageError::AlreadyDecided(height))
+        }
+        Ok(())
+    }
+
+    fn check_round(&self, round: u32) -> Result<(), MessageError> {
+        if round > self.round.saturating_add(MAX_FUTURE_ROUNDS) {
+            return Err(MessageError::RoundTooFar {
+                round,
+                current: self.round,
+            })
+        }
+        Ok(())
+    }
+
+    fn start_round(&mut self, round: u32, actions: &mut Vec<Action>) {
+        self.round = round;
+        self.step = Step::Propose;
+        self.prevote_timeout_scheduled = false;
+        self.precommit_timeout_scheduled = false;
+        self.proposal_quorum_handled = false;
+
+        if self.validators.proposer(self.height, round) == Some(&self.local) {
+            let valid = self
+                .valid_round
+                .and_then(|valid_round| self.proposals.get(&valid_round));
+            match valid {
+                Some(valid) => actions.push(Action::Propose {
+                    round,
+                    valid_round: self.valid_round,
+                    block: valid.proposal.block.clone(),
+                }),
+                None => actions.push(Action::ProduceBlock {
+                    height: self.height,
+                    round,
+                }),
+            }
+        }
+        // The proposer also waits for its own proposal, in case it fails to produce it.
+        self.schedule(Step::Propose, self.timeouts.propose, actions);
+    }
+
+    fn schedule(&self, step: Step, base: Duration, actions: &mut Vec<Action>) {
+        actions.push(Action::ScheduleTimeout {
+            timeout: Timeout {
+                height: self.height,
+                round: self.round,
+                step,
+            },
+            after: Timeouts::with_delta(base, self.timeouts.delta, self.round),
+        });
+    }
+
+    fn vote(
+        &mut self,
+        step: VoteStep,
+        block_id: Option<BlockId>,
+        actions: &mut Vec<Action>,
+    ) {
+        actions.push(Action::Vote(Vote {
+            height: self.height,
+            round: self.round,
+            step,
+            block_id,
+        }));
+        self.step = match step {
+            VoteStep::Prevote => Step::Prevote,
+            VoteStep::Precommit => Step::Precommit,
+        };
+    }
+
+    fn count(&self, round: u32, step: VoteStep, block_id: Option<BlockId>) -> usize {
+        self.votes
+            .get(&(round, step))
+            .map_or(0, |votes| votes.count(block_id))
+    }
+
+    fn total(&self, round: u32, step: VoteStep) -> usize {
+        self.votes
+            .get(&(round, step))
+            .map_or(0, |votes| votes.total())
+    }
+
+    /// Applies the rules of the algorithm until none of them changes the state.
+    fn process(&mut self, actions: &mut Vec<Action>) {
+        while self.step != Step::Commit {
+            let progressed = self.commit_decided_block(actions)
+                || self.skip_to_higher_round(actions)
+                || self.prevote_proposal(actions)
+                || self.schedule_prevote_timeout(actions)
+                || self.precommit_proposal_with_quorum(actions)
+                || self.precommit_nil(actions)
+                || self.schedule_precommit_timeout(actions);
+            if !progressed {
+                break
+            }
+        }
+    }
+
+    /// Prevotes for the proposal of the current round, unless the validator is
+    /// locked on another block.
+    fn prevote_proposal(&mut self, actions: &mut Vec<Action>) -> bool {
+        if self.step != Step::Propose {
+            return false
+        }
+        let Some(proposal) = self.proposals.get(&self.round) else {
+            return false
+        };
+        let block_id = proposal.block_id;
+
+        let acceptable = match proposal.proposal.valid_round {
+            None => self.locked.map_or(true, |(_, locked)| locked == block_id),
+            Some(valid_round) => {
+                let prevotes = self.count(valid_round, VoteStep::Prevote, Some(block_id));
+                if prevotes < self.validators.quorum() {
+                    return false
+                }
+                self.locked.map_or(true, |(locked_round, locked)| {
+                    locked_round <= valid_round || locked == block_id
+                })
+            }
+        };
+        let vote = (proposal.valid && acceptable).then_some(block_id);
+        self.vote(VoteStep::Prevote, vote, actions);
+        true
+    }
+
+    fn schedule_prevote_timeout(&mut self, actions: &mut Vec<Action>) -> bool {
+        if self.step != Step::Prevote
+            || self.prevote_timeout_scheduled
+            || self.total(self.round, VoteStep::Prevote) < self.validators.quorum()
+        {
+            return false
+        }
+        self.prevote_timeout_scheduled = true;
+        self.schedule(Step::Prevote, self.timeouts.prevote, actions);
+        true
+    }
+
+    /// Locks on the proposal that got the quorum of prevotes and precommits for it.
+    fn precommit_proposal_with_quorum(&mut self, actions: &mut Vec<Action>) -> bool {
+        if self.step == Step::Propose || self.proposal_quorum_handled {
+            return false
+        }
+        let Some(proposal) = self.proposals.get(&self.round) else {
+            return false
+        };
+        let block_id = proposal.block_id;
+        if !proposal.valid
+            || self.count(self.round, VoteStep::Prevote, Some(block_id))
+                < self.validators.quorum()
+        {
+            return false
+        }
+
+        self.proposal_quorum_handled = true;
+        if self.step == Step::Prevote {
+            self.locked = Some((self.round, block_id));
+            self.vote(VoteStep::Precommit, Some(block_id), actions);
+        }
+        self.valid_round = Some(self.round);
+        true
+    }
+
+    fn precommit_nil(&mut self, actions: &mut Vec<Action>) -> bool {
+        if self.step != Step::Prevote
+            || self.count(self.round, VoteStep::Prevote, None) < self.validators.quorum()
+        {
+            return false
+        }
+        self.vote(VoteStep::Precommit, None, actions);
+        true
+    }
+
+    fn schedule_precommit_timeout(&mut self, actions: &mut Vec<Action>) -> bool {
+        if self.precommit_timeout_scheduled
+            || self.total(self.round, VoteStep::Precommit) < self.validators.quorum()
+        {
+            return false
+        }
+        self.precommit_timeout_scheduled = true;
+        self.schedule(Step::Precommit, self.timeouts.precommit, actions);
+        true
+    }
+
+    /// Commits the proposal of any round that got the quorum of precommits.
+    fn commit_decided_block(&mut self, actions: &mut Vec<Action>) -> bool {
+        let quorum = self.validators.quorum();
+        let decided = self.proposals.values().find(|proposal| {
+            let precommits = self.count(
+                proposal.proposal.round,
+                VoteStep::Precommit,
+                Some(proposal.block_id),
+            );
+            proposal.valid && precommits >= quorum
+        });
+        let Some(decided) = decided else { return false };
+
+        let round = decided.proposal.round;
+        let signatures = self
+            .votes
+            .get(&(round, VoteStep::Precommit))
+            .map(|votes| votes.signatures(decided.block_id))
+            .unwrap_or_default();
+        let consensus =
+            BftConsensus::new(round, decided.proposal.block_signature, signatures);
+        actions.push(Action::Commit(SealedBlock {
+            entity: decided.proposal.block.clone(),
+            consensus: Consensus::Bft(consensus),
+        }));
+        self.step = Step::Commit;
+        true
+    }
+
+    /// Moves to the higher round if enough validators are already there,
+    /// so at least one of them is honest.
+    fn skip_to_higher_round(&mut self, actions: &mut Vec<Action>) -> bool {
+        let mut senders: BTreeMap<u32, BTreeSet<Address>> = BTreeMap::new();
+        for ((round, _), votes) in self.votes.iter() {
+            if *round > self.round {
+                senders
+                    .entry(*round)
+                    .or_default()
+                    .extend(votes.votes.keys().copied());
+            }
+        }
+        for round in self.proposals.keys() {
+            if *round > self.round {
+                if let Some(proposer) = self.validators.proposer(self.height, *round) {
+                    senders.entry(*round).or_default().insert(*proposer);
+                }
+            }
+        }
+
+        let threshold = self.validators.honest_threshold();
+        let higher_round = senders
+            .into_iter()
+            .rev()
+            .find(|(_, senders)| senders.len() >= threshold)
+            .map(|(round, _)| round);
+        let Some(round) = higher_round else {
+            return false
+        };
+        self.start_round(round, actions);
+        true
+    }
+}
@@ -0,0 +1,515 @@
+#![allow(non_snake_case)]
+
+use super::*;
+use crate::verifier::verify_seal;
+use fuel_core_types::{
+    fuel_crypto::SecretKey,
+    fuel_tx::Input,
+    tai64::Tai64,
+};
+use rand::{
+    rngs::StdRng,
+    SeedableRng,
+};
+
+const HEIGHT: u32 = 1;
+
+struct TestContext {
+    keys: Vec<SecretKey>,
+    validators: ValidatorSet,
+}
+
+impl TestContext {
+    fn new(n: usize) -> Self {
+        let mut rng = StdRng::seed_from_u64(2322);
+        let keys: Vec<_> = (0..n).map(|_| SecretKey::random(&mut rng)).collect();
+        let validators = ValidatorSet::new(keys.iter().map(address).collect::<Vec<_>>());
+        Self { keys, validators }
+    }
+
+    fn engine(&self, index: usize) -> Engine {
+        let mut engine = Engine::new(
+            self.validators.clone(),
+            Timeouts::default(),
+            address(&self.keys[index]),
+        );
+        engine.start_height(HEIGHT.into(), None);
+        engine
+    }
+
+    fn proposal(
+        &self,
+        index: usize,
+        round: u32,
+        valid_round: Option<u32>,
+        block: Block,
+    ) -> Proposal {
+        let block_signature =
+            Signature::sign(&self.keys[index], &block.id().into_message());
+        let mut proposal = Proposal {
+            round,
+            valid_round,
+            block,
+            block_signature,
+            signature: Default::default(),
+        };
+        proposal.signature =
+            Signature::sign(&self.keys[index], &proposal.signing_message());
+        proposal
+    }
+
+    fn vote(
+        &self,
+        index: usize,
+        round: u32,
+        step: VoteStep,
+        block_id: Option<BlockId>,
+    ) -> SignedVote {
+        let vote = Vote {
+            height: HEIGHT.into(),
+            round,
+            step,
+            block_id,
+        };
+        let signature = Signature::sign(&self.keys[index], &vote.signing_message());
+        SignedVote { vote, signature }
+    }
+
+    /// Feeds votes of validators with `indexes` and returns all requested actions.
+    fn votes(
+        &self,
+        engine: &mut Engine,
+        indexes: &[usize],
+        round: u32,
+        step: VoteStep,
+        block_id: Option<BlockId>,
+    ) -> Vec<Action> {
+        indexes
+            .iter()
+            .flat_map(|index| {
+                engine
+                    .on_vote(self.vote(*index, round, step, block_id))
+                    .unwrap()
+            })
+            .collect()
+    }
+}
+
+fn address(key: &SecretKey) -> Address {
+    Input::owner(&key.public_key())
+}
+
+fn block(time: u64) -> Block {
+    let mut block = Block::default();
+    block.header_mut().set_block_height(HEIGHT.into());
+    block.header_mut().set_time(Tai64(time));
+    block
+}
+
+fn vote_action(round: u32, step: VoteStep, block_id: Option<BlockId>) -> Action {
+    Action::Vote(Vote {
+        height: HEIGHT.into(),
+        round,
+        step,
+        block_id,
+    })
+}
+
+fn has_timeout(actions: &[Action], round: u32, step: Step) -> bool {
+    actions.iter().any(|action| {
+        matches!(
+            action,
+            Action::ScheduleTimeout { timeout, .. }
+                if timeout.round == round && timeout.step == step
+        )
+    })
+}
+
+#[test]
+fn start_height__proposer_produces_block() {
+    let ctx = TestContext::new(4);
+    let mut engine = Engine::new(
+        ctx.validators.clone(),
+        Timeouts::default(),
+        address(&ctx.keys[1]),
+    );
+
+    // When
+    let actions = engine.start_height(HEIGHT.into(), None);
+
+    // Then
+    assert!(actions.contains(&Action::ProduceBlock {
+        height: HEIGHT.into(),
+        round: 0
+    }));
+    assert!(has_timeout(&actions, 0, Step::Propose));
+}
+
+#[test]
+fn start_height__other_validators_wait_for_proposal() {
+    let ctx = TestContext::new(4);
+    let mut engine = Engine::new(
+        ctx.validators.clone(),
+        Timeouts::default(),
+        address(&ctx.keys[0]),
+    );
+
+    // When
+    let actions = engine.start_height(HEIGHT.into(), None);
+
+    // Then
+    assert_eq!(actions.len(), 1);
+    assert!(has_timeout(&actions, 0, Step::Propose));
+    assert_eq!(engine.step(), Step::Propose);
+}
+
+#[test]
+fn on_proposal__valid_proposal_is_committed_after_quorum_of_votes() {
+    let ctx = TestContext::new(4);
+    let mut engine = ctx.engine(0);
+    let block = block(1);
+    let block_id = block.id();
+
+    // When
+    let actions = engine
+        .on_proposal(ctx.proposal(1, 0, None, block), true)
+        .unwrap();
+
+    // Then
+    assert_eq!(
+        actions,
+        vec![vote_action(0, VoteStep::Prevote, Some(block_id))]
+    );
+
+    // When
+    let actions = ctx.votes(
+        &mut engine,
+        &[0, 1, 2],
+        0,
+        VoteStep::Prevote,
+        Some(block_id),
+    );
+
+    // Then
+    assert!(actions.contains(&vote_action(0, VoteStep::Precommit, Some(block_id))));
+
+    // When
+    let actions = ctx.votes(
+        &mut engine,
+        &[0, 2, 3],
+        0,
+        VoteStep::Precommit,
+        Some(block_id),
+    );
+
+    // Then
+    let Some(Action::Commit(sealed)) = actions.last() else {
+        panic!("Expected the commit, got {:?}", actions);
+    };
+    assert_eq!(sealed.entity.id(), block_id);
+    let Consensus::Bft(seal) = &sealed.consensus else {
+        panic!("Expected the BFT seal");
+    };
+    assert_eq!(seal.signatures.len(), 3);
+    verify_seal(&ctx.validators, sealed.entity.header(), seal).unwrap();
+    assert_eq!(engine.step(), Step::Commit);
+}
+
+#[test]
+fn on_proposal__invalid_block_is_prevoted_nil() {
+    let ctx = TestContext::new(4);
+    let mut engine = ctx.engine(0);
+
+    // When
+    let actions = engine
+        .on_proposal(ctx.proposal(1, 0, None, block(1)), false)
+        .unwrap();
+
+    // Then
+    assert_eq!(actions, vec![vote_action(0, VoteStep::Prevote, None)]);
+}
+
+#[test]
+fn on_proposal__rejects_proposal_of_wrong_proposer() {
+    let ctx = TestContext::new(4);
+    let mut engine = ctx.engine(0);
+
+    // When
+    let result = engine.on_proposal(ctx.proposal(2, 0, None, block(1)), true);
+
+    // Then
+    let err = result.unwrap_err();
+    assert!(matches!(err, MessageError::WrongProposer { round: 0, .. }));
+    assert!(err.is_malicious());
+}
+
+#[test]
+fn on_proposal__rejects_relayed_proposal_with_changed_rounds() {
+    let ctx = TestContext::new(4);
+    let mut engine = ctx.engine(0);
+    // The validator `2` proposes in the rounds `1` and `5`.
+    let honest = ctx.proposal(2, 1, Some(0), block(1));
+    let mut stripped_valid_round = honest.clone();
+    stripped_valid_round.valid_round = None;
+    let mut replayed = honest.clone();
+    replayed.round = 5;
+
+    // When
+    let stripped_valid_round = engine.on_proposal(stripped_valid_round, true);
+    let replayed = engine.on_proposal(replayed, true);
+    let honest = engine.on_proposal(honest, true);
+
+    // Then
+    let stripped_valid_round = stripped_valid_round.unwrap_err();
+    assert!(matches!(
+        stripped_valid_round,
+        MessageError::InvalidSignature(_)
+    ));
+    assert!(stripped_valid_round.is_malicious());
+    let replayed = replayed.unwrap_err();
+    assert!(matches!(replayed, MessageError::InvalidSignature(_)));
+    assert!(replayed.is_malicious());
+    assert!(honest.is_ok());
+}
+
+#[test]
+fn on_vote__rejects_votes_of_unknown_validators_and_duplicates() {
+    let ctx = TestContext::new(4);
+    let outsider = TestContext::new(5);
+    let mut engine = ctx.engine(0);
+
+    // When
+    let unknown = engine.on_vote(outsider.vote(4, 0, VoteStep::Prevote, None));
+    engine
+        .on_vote(ctx.vote(1, 0, VoteStep::Prevote, None))
+        .unwrap();
+    let duplicate = engine.on_vote(ctx.vote(1, 0, VoteStep::Prevote, None));
+
+    // Then
+    assert!(unknown.unwrap_err().is_malicious());
+    let duplicate = duplicate.unwrap_err();
+    assert!(matches!(duplicate, MessageError::Duplicate(0)));
+    assert!(!duplicate.is_malicious());
+}
+
+#[test]
+fn on_timeout__moves_to_next_round_without_proposal() {
+    let ctx = TestContext::new(4);
+    let mut engine = ctx.engine(0);
+    let timeout = |round, step| Timeout {
+        height: HEIGHT.into(),
+        round,
+        step,
+    };
+
+    // When
+    let actions = engine.on_timeout(timeout(0, Step::Propose));
+
+    // Then
+    assert_eq!(actions, vec![vote_action(0, VoteStep::Prevote, None)]);
+
+    // When
+    let actions = ctx.votes(&mut engine, &[0, 1, 3], 0, VoteStep::Prevote, None);
+
+    // Then
+    assert!(actions.contains(&vote_action(0, VoteStep::Precommit, None)));
+
+    // When
+    let actions = ctx.votes(&mut engine, &[0, 1, 3], 0, VoteStep::Precommit, None);
+
+    // Then
+    assert!(has_timeout(&actions, 0, Step::Precommit));
+
+    // When
+    let actions = engine.on_timeout(timeout(0, Step::Precommit));
+
+    // Then
+    assert_eq!(engine.round(), 1);
+    assert_eq!(engine.step(), Step::Propose);
+    assert!(has_timeout(&actions, 1, Step::Propose));
+}
+
+#[test]
+fn on_timeout__ignores_timeouts_of_other_rounds() {
+    let ctx = TestContext::new(4);
+    let mut engine = ctx.engine(0);
+
+    // When
+    let actions = engine.on_timeout(Timeout {
+        height: HEIGHT.into(),
+        round: 1,
+        step: Step::Propose,
+    });
+
+    // Then
+    assert!(actions.is_empty());
+    assert_eq!(engine.step(), Step::Propose);
+}
+
+#[test]
+fn on_proposal__locked_validator_prevotes_nil_for_another_block() {
+    let ctx = TestContext::new(4);
+    let mut engine = ctx.engine(0);
+    let locked_block = block(1);
+    let locked_id = locked_block.id();
+
+    // Given
+    engine
+        .on_proposal(ctx.proposal(1, 0, None, locked_block), true)
+        .unwrap();
+    let actions = ctx.votes(
+        &mut engine,
+        &[0, 1, 2],
+        0,
+        VoteStep::Prevote,
+        Some(locked_id),
+    );
+    assert!(actions.contains(&vote_action(0, VoteStep::Precommit, Some(locked_id))));
+    ctx.votes(&mut engine, &[1, 2, 3], 0, VoteStep::Precommit, None);
+    engine.on_timeout(Timeout {
+        height: HEIGHT.into(),
+        round: 0,
+        step: Step::Precommit,
+    });
+    assert_eq!(engine.round(), 1);
+
+    // When
+    let actions = engine
+        .on_proposal(ctx.proposal(2, 1, None, block(2)), true)
+        .unwrap();
+
+    // Then
+    assert_eq!(actions, vec![vote_action(1, VoteStep::Prevote, None)]);
+}
+
+#[test]
+fn start_round__proposer_reproposes_valid_block() {
+    let ctx = TestContext::new(4);
+    // The validator `2` proposes in the round `1`.
+    let mut engine = ctx.engine(2);
+    let valid_block = block(1);
+    let valid_id = valid_block.id();
+
+    // Given
+    engine
+        .on_proposal(ctx.proposal(1, 0, None, valid_block.clone()), true)
+        .unwrap();
+    ctx.votes(
+        &mut engine,
+        &[0, 1, 2],
+        0,
+        VoteStep::Prevote,
+        Some(valid_id),
+    );
+    ctx.votes(&mut engine, &[0, 1, 3], 0, VoteStep::Precommit, None);
+
+    // When
+    let actions = engine.on_timeout(Timeout {
+        height: HEIGHT.into(),
+        round: 0,
+        step: Step::Precommit,
+    });
+
+    // Then
+    assert!(actions.contains(&Action::Propose {
+        round: 1,
+        valid_round: Some(0),
+        block: valid_block,
+    }));
+}
+
+#[test]
+fn on_vote__skips_to_round_with_enough_validators() {
+    let ctx = TestContext::new(4);
+    let mut engine = ctx.engine(0);
+
+    // When
+    let actions = ctx.votes(&mut engine, &[1, 2], 3, VoteStep::Prevote, None);
+
+    // Then
+    assert_eq!(engine.round(), 3);
+    assert_eq!(engine.step(), Step::Propose);
+    assert!(has_timeout(&actions, 3, Step::Propose));
+}
+
+#[test]
+fn on_vote__rejects_votes_after_commit() {
+    let ctx = TestContext::new(1);
+    let mut engine = ctx.engine(0);
+    let block = block(1);
+    let block_id = block.id();
+
+    // Given
+    engine
+        .on_proposal(ctx.proposal(0, 0, None, block), true)
+        .unwrap();
+    ctx.votes(&mut engine, &[0], 0, VoteStep::Prevote, Some(block_id));
+    let actions = ctx.votes(&mut engine, &[0], 0, VoteStep::Precommit, Some(block_id));
+    assert!(matches!(actions.last(), Some(Action::Commit(_))));
+
+    // When
+    let result = engine.on_vote(ctx.vote(0, 1, VoteStep::Prevote, None));
+
+    // Then
+    assert!(matches!(result, Err(MessageError::AlreadyDecided(_))));
+}
+
+#[test]
+fn on_vote__rejects_votes_too_far_ahead_of_the_current_round() {
+    let ctx = TestContext::new(4);
+    let mut engine = ctx.engine(0);
+
+    // When
+    let within_window =
+        engine.on_vote(ctx.vote(1, MAX_FUTURE_ROUNDS, VoteStep::Prevote, None));
+    let too_far =
+        engine.on_vote(ctx.vote(1, MAX_FUTURE_ROUNDS + 1, VoteStep::Prevote, None));
+
+    // Then
+    assert!(within_window.is_ok());
+    let too_far = too_far.unwrap_err();
+    assert!(matches!(too_far, MessageError::RoundTooFar { .. }));
+    assert!(!too_far.is_malicious());
+}
+
+#[test]
+fn on_proposal__rejects_proposals_too_far_ahead_of_the_current_round() {
+    let ctx = TestContext::new(4);
+    let mut engine = ctx.engine(0);
+    let round = MAX_FUTURE_ROUNDS + 1;
+    let proposer = (0..4)
+        .find(|index| {
+            ctx.validators.proposer(HEIGHT.into(), round)
+                == Some(&address(&ctx.keys[*index]))
+        })
+        .unwrap();
+
+    // When
+    let result = engine.on_proposal(ctx.proposal(proposer, round, None, block(1)), true);
+
+    // Then
+    assert!(matches!(result, Err(MessageError::RoundTooFar { .. })));
+}
+
+#[test]
+fn start_height__restored_lock_prevents_prevote_for_another_block() {
+    let ctx = TestContext::new(4);
+    let mut engine = Engine::new(
+        ctx.validators.clone(),
+        Timeouts::default(),
+        address(&ctx.keys[0]),
+    );
+    let locked_id = block(1).id();
+
+    // Given
+    engine.start_height(HEIGHT.into(), Some((0, locked_id)));
+
+    // When
+    let actions = engine
+        .on_proposal(ctx.proposal(1, 0, None, block(2)), true)
+        .unwrap();
+
+    // Then
+    assert_eq!(engine.locked(), Some((0, locked_id)));
+    assert_eq!(actions, vec![vote_action(0, VoteStep::Prevote, None)]);
+}
@@ -0,0 +1,69 @@
+//! The messages of validators for heights that the engine hasn't reached yet.
+
+use fuel_core_types::{
+    blockchain::consensus::bft::{
+        BftMessage,
+        VoteStep,
+    },
+    fuel_types::{
+        Address,
+        BlockHeight,
+    },
+};
+use std::collections::BTreeMap;
+
+#[cfg(test)]
+mod tests;
+
+/// The maximum number of messages kept until their height starts.
+pub const MAX_FUTURE_MESSAGES: usize = 1024;
+
+/// The height, the round, the step (`None` for the proposal) and the signer of the message.
+type MessageKey = (BlockHeight, u32, Option<VoteStep>, Address);
+
+/// Keeps at most one message per signer, height, round and step.
+#[derive(Debug, Default)]
+pub struct FutureMessages {
+    messages: BTreeMap<MessageKey, BftMessage>,
+}
+
+impl FutureMessages {
+    pub fn len(&self) -> usize {
+        self.messages.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.messages.is_empty()
+    }
+
+    /// Keeps the message signed by the `signer`. Returns `false` if the message
+    /// of the same step is already kept or there is no space left.
+    pub fn insert(&mut self, signer: Address, message: BftMessage) -> bool {
+        let step = match &message {
+            BftMessage::Proposal(_) => None,
+            BftMessage::Vote(vote) => Some(vote.vote.step),
+        };
+        let key = (message.height(), message.round(), step, signer);
+        if self.messages.len() >= MAX_FUTURE_MESSAGES || self.messages.contains_key(&key)
+        {
+            return false
+        }
+        self.messages.insert(key, message);
+        true
+    }
+
+    /// Returns the messages of the `height` ordered by rounds and forgets
+    /// the messages of lower heights.
+    pub fn take(&mut self, height: BlockHeight) -> Vec<BftMessage> {
+        let mut later = self
+            .messages
+            .split_off(&(height, 0, None, Address::zeroed()));
+        let next_height = height.succ().map(|next| (next, 0, None, Address::zeroed()));
+        let higher = match next_height {
+            Some(next_height) => later.split_off(&next_height),
+            None => BTreeMap::new(),
+        };
+        self.messages = higher;
+        later.into_values().collect()
+    }
+}
@@ -0,0 +1,85 @@
+#![allow(non_snake_case)]
+
+use super::*;
+use fuel_core_types::{
+    blockchain::consensus::bft::{
+        SignedVote,
+        Vote,
+    },
+    fuel_crypto::Signature,
+};
+
+fn vote(height: u32, round: u32, step: VoteStep) -> BftMessage {
+    BftMessage::Vote(SignedVote {
+        vote: Vote {
+            height: height.into(),
+            round,
+            step,
+            block_id: None,
+        },
+        signature: Signature::default(),
+    })
+}
+
+fn signer(byte: u8) -> Address {
+    Address::new([byte; 32])
+}
+
+#[test]
+fn insert__keeps_one_message_per_signer_and_step() {
+    let mut messages = FutureMessages::default();
+
+    // When
+    let first = messages.insert(signer(1), vote(2, 0, VoteStep::Prevote));
+    let duplicate = messages.insert(signer(1), vote(2, 0, VoteStep::Prevote));
+    let another_step = messages.insert(signer(1), vote(2, 0, VoteStep::Precommit));
+    let another_signer = messages.insert(signer(2), vote(2, 0, VoteStep::Prevote));
+
+    // Then
+    assert!(first);
+    assert!(!duplicate);
+    assert!(another_step);
+    assert!(another_signer);
+    assert_eq!(messages.len(), 3);
+}
+
+#[test]
+fn insert__refuses_messages_above_the_limit() {
+    let mut messages = FutureMessages::default();
+    for index in 0..MAX_FUTURE_MESSAGES {
+        let round = u32::try_from(index).unwrap();
+        assert!(messages.insert(signer(1), vote(2, round, VoteStep::Prevote)));
+    }
+
+    // When
+    let inserted = messages.insert(signer(2), vote(2, 0, VoteStep::Prevote));
+
+    // Then
+    assert!(!inserted);
+}
+
+#[test]
+fn take__returns_messages_of_the_height_and_drops_lower_heights() {
+    let mut messages = FutureMessages::default();
+    messages.insert(signer(1), vote(1, 0, VoteStep::Prevote));
+    messages.insert(signer(1), vote(2, 1, VoteStep::Prevote));
+    messages.insert(signer(1), vote(2, 0, VoteStep::Precommit));
+    messages.insert(signer(1), vote(3, 0, VoteStep::Prevote));
+
+    // When
+    let taken = messages.take(2u32.into());
+
+    // Then
+    assert_eq!(
+        taken,
+        vec![
+            vote(2, 0, VoteStep::Precommit),
+            vote(2, 1, VoteStep::Prevote)
+        ]
+    );
+    assert_eq!(
+        messages.take(3u32.into()),
+        vec![vote(3, 0, VoteStep::Prevote)]
+    );
+    assert!(messages.is_empty());
+}
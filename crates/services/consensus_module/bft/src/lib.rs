@@ -1,4 +1,28 @@
 #![deny(clippy::arithmetic_side_effects)]
 #![deny(clippy::cast_possible_truncation)]
 #![deny(unused_crate_dependencies)]
+#![deny(unused_must_use)]
 #![deny(warnings)]
+#![allow(clippy::blocks_in_conditions)] // False positives with tracing macros
+
+#[cfg(test)]
+mod service_test;
+
+pub mod config;
+pub mod engine;
+pub mod future_messages;
+pub mod ports;
+pub mod service;
+pub mod validator_state;
+pub mod validators;
+pub mod verifier;
+
+pub use config::{
+    Config,
+    Timeouts,
+};
+pub use service::{
+    new_service,
+    Service,
+};
+pub use validators::ValidatorSet;
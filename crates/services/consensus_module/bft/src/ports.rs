@@ -0,0 +1,45 @@
+use fuel_core_services::stream::BoxStream;
+use fuel_core_types::{
+    blockchain::{
+        consensus::bft::BftMessage,
+        SealedBlock,
+    },
+    services::{
+        block_importer::BlockImportInfo,
+        p2p::{
+            ConsensusGossipData,
+            GossipsubMessageAcceptance,
+            GossipsubMessageInfo,
+        },
+    },
+};
+use std::sync::Arc;
+
+#[async_trait::async_trait]
+pub trait BlockImporter: Send + Sync {
+    /// Verifies and executes the proposed block without committing it.
+    async fn verify_block(&self, block: SealedBlock) -> anyhow::Result<()>;
+
+    /// Executes and commits the block decided by validators.
+    async fn execute_and_commit(&self, block: SealedBlock) -> anyhow::Result<()>;
+
+    fn block_stream(&self) -> BoxStream<BlockImportInfo>;
+}
+
+pub trait P2pPort: Send + Sync + 'static {
+    /// Broadcast the proposal or the vote to other validators.
+    fn broadcast_message(&self, message: Arc<BftMessage>) -> anyhow::Result<()>;
+
+    /// Broadcast the committed block to the network.
+    fn broadcast_block(&self, block: Arc<SealedBlock>) -> anyhow::Result<()>;
+
+    /// Subscribe to proposals and votes of other validators.
+    fn consensus_messages(&self) -> BoxStream<ConsensusGossipData>;
+
+    /// Report the validity of the received proposal or vote.
+    fn notify_message_validity(
+        &self,
+        message_info: GossipsubMessageInfo,
+        validity: GossipsubMessageAcceptance,
+    ) -> anyhow::Result<()>;
+}
@@ -0,0 +1,524 @@
+use crate::{
+    engine::{
+        Action,
+        Engine,
+        MessageError,
+        Timeout,
+        MAX_FUTURE_ROUNDS,
+    },
+    future_messages::FutureMessages,
+    ports::{
+        BlockImporter,
+        P2pPort,
+    },
+    validator_state::ValidatorStateStore,
+    Config,
+};
+use anyhow::anyhow;
+use fuel_core_poa::{
+    ports::{
+        BlockProducer,
+        GetTime,
+        TransactionsSource,
+    },
+    signer::SignMode,
+};
+use fuel_core_services::{
+    stream::{
+        BoxFuture,
+        BoxStream,
+    },
+    RunnableService,
+    RunnableTask,
+    ServiceRunner,
+    StateWatcher,
+};
+use fuel_core_types::{
+    blockchain::{
+        block::Block,
+        consensus::{
+            bft::{
+                BftConsensus,
+                BftMessage,
+                Proposal,
+                SignedVote,
+                Vote,
+            },
+            Consensus,
+        },
+        header::BlockHeader,
+        SealedBlock,
+    },
+    fuel_types::BlockHeight,
+    services::{
+        block_importer::BlockImportInfo,
+        p2p::{
+            ConsensusGossipData,
+            GossipsubMessageAcceptance,
+            GossipsubMessageInfo,
+        },
+    },
+    tai64::Tai64,
+};
+use std::{
+    collections::VecDeque,
+    sync::Arc,
+    time::Duration,
+};
+use tokio::time::{
+    sleep_until,
+    Instant,
+};
+use tokio_stream::StreamExt;
+
+pub type Service<B, I, P, C> = ServiceRunner<MainTask<B, I, P, C>>;
+
+pub struct MainTask<B, I, P, C> {
+    engine: Engine,
+    signer: SignMode,
+    /// The messages signed by the local validator, persisted before signing.
+    validator_state: ValidatorStateStore,
+    block_producer: B,
+    block_importer: I,
+    p2p_port: P,
+    clock: C,
+    block_time: Duration,
+    block_stream: BoxStream<BlockImportInfo>,
+    consensus_messages: BoxStream<ConsensusGossipData>,
+    /// The timeouts requested by the engine and their deadlines.
+    timeouts: Vec<(Instant, Timeout)>,
+    /// Messages for heights that the engine hasn't reached yet.
+    future_messages: FutureMessages,
+    last_height: BlockHeight,
+    last_timestamp: Tai64,
+    /// The time to start the height following the `last_height`.
+    next_height_start: Option<Instant>,
+}
+
+impl<B, I, P, C> MainTask<B, I, P, C>
+where
+    I: BlockImporter,
+    P: P2pPort,
+{
+    pub fn new(
+        last_block: &BlockHeader,
+        config: Config,
+        block_producer: B,
+        block_importer: I,
+        p2p_port: P,
+        clock: C,
+    ) -> anyhow::Result<Self> {
+        let Config {
+            signer,
+            validators,
+            block_time,
+            timeouts,
+            validator_state,
+        } = config;
+        let local = signer
+            .address()?
+            .ok_or_else(|| anyhow!("The BFT validator requires the signing key"))?;
+        if !signer.can_sign_messages() {
+            return Err(anyhow!(
+                "The BFT validator can't sign votes with the signer"
+            ))
+        }
+        if !validators.contains(&local) {
+            return Err(anyhow!("{} is not in the validator set", local))
+        }
+        let validator_state = ValidatorStateStore::load(validator_state)?;
+
+        let block_stream = block_importer.block_stream();
+        let consensus_messages = p2p_port.consensus_messages();
+
+        Ok(Self {
+            engine: Engine::new(validators, timeouts, local),
+            signer,
+            validator_state,
+            block_producer,
+            block_importer,
+            p2p_port,
+            clock,
+            block_time,
+            block_stream,
+            consensus_messages,
+            timeouts: vec![],
+            future_messages: FutureMessages::default(),
+            last_height: *last_block.height(),
+            last_timestamp: last_block.time(),
+            next_height_start: Some(Instant::now()),
+        })
+    }
+
+    fn next_height(&self) -> BlockHeight {
+        self.last_height
+            .succ()
+            .expect("It should be impossible to produce more blocks than u32::MAX")
+    }
+
+    fn on_block_imported(&mut self, block_header: &BlockHeader) {
+        let height = *block_header.height();
+        if height <= self.last_height {
+            return
+        }
+        self.last_height = height;
+        self.last_timestamp = block_header.time();
+        let now = Instant::now();
+        self.next_height_start = Some(now.checked_add(self.block_time).unwrap_or(now));
+    }
+
+    /// Returns `true` while the engine works on the height following the last block.
+    fn is_height_in_progress(&self) -> bool {
+        self.next_height_start.is_none() && self.engine.height() == self.next_height()
+    }
+}
+
+impl<B, I, P, C> MainTask<B, I, P, C>
+where
+    B: BlockProducer,
+    I: BlockImporter,
+    P: P2pPort,
+    C: GetTime,
+{
+    async fn start_next_height(&mut self) {
+        self.next_height_start = None;
+        let height = self.next_height();
+        self.timeouts.clear();
+        let locked = self.validator_state.locked(height);
+        let actions = self.engine.start_height(height, locked);
+        self.perform(actions).await;
+
+        for message in self.future_messages.take(height) {
+            let _ = self.on_message(message).await;
+        }
+    }
+
+    async fn on_gossip(&mut self, mut gossip: ConsensusGossipData) {
+        let Some(message) = gossip.data.take() else {
+            return
+        };
+        let acceptance = self.on_message(message).await;
+        let message_info = GossipsubMessageInfo {
+            message_id: gossip.message_id,
+            peer_id: gossip.peer_id,
+        };
+        if let Err(err) = self
+            .p2p_port
+            .notify_message_validity(message_info, acceptance)
+        {
+            tracing::warn!("Failed to report the consensus message validity: {:?}", err);
+        }
+    }
+
+    async fn on_message(&mut self, message: BftMessage) -> GossipsubMessageAcceptance {
+        let height = message.height();
+        if height <= self.last_height {
+            return GossipsubMessageAcceptance::Ignore
+        }
+        if height > self.next_height() || !self.is_height_in_progress() {
+            return self.on_future_message(message)
+        }
+
+        let result = match message {
+            BftMessage::Proposal(proposal) => self.on_proposal(proposal).await,
+            BftMessage::Vote(vote) => self.engine.on_vote(vote),
+        };
+        match result {
+            Ok(actions) => {
+                self.perform(actions).await;
+                GossipsubMessageAcceptance::Accept
+            }
+            Err(err) if err.is_malicious() => {
+                tracing::warn!("Rejected the consensus message: {}", err);
+                GossipsubMessageAcceptance::Reject
+            }
+            Err(err) => {
+                tracing::debug!("Ignored the consensus message: {}", err);
+                GossipsubMessageAcceptance::Ignore
+            }
+        }
+    }
+
+    /// Keeps the message of the validator until its height starts.
+    /// Only the next two heights and the rounds the engine accepts are kept.
+    fn on_future_message(&mut self, message: BftMessage) -> GossipsubMessageAcceptance {
+        let next_height = self.next_height();
+        let max_height = next_height.succ().unwrap_or(next_height);
+        if message.height() > max_height || message.round() > MAX_FUTURE_ROUNDS {
+            return GossipsubMessageAcceptance::Ignore
+        }
+        let signer = match message.signer() {
+            Ok(signer) => signer,
+            Err(err) => {
+                tracing::warn!("Rejected the consensus message: {:?}", err);
+                return GossipsubMessageAcceptance::Reject
+            }
+        };
+        if !self.engine.validators().contains(&signer) {
+            tracing::warn!(
+                "Rejected the consensus message: {} is not a validator",
+                signer
+            );
+            return GossipsubMessageAcceptance::Reject
+        }
+        self.future_messages.insert(signer, message);
+        GossipsubMessageAcceptance::Ignore
+    }
+
+    async fn on_proposal(
+        &mut self,
+        proposal: Proposal,
+    ) -> Result<Vec<Action>, MessageError> {
+        self.engine.check_proposal(&proposal)?;
+        let sealed_block = SealedBlock {
+            entity: proposal.block.clone(),
+            consensus: Consensus::Bft(BftConsensus::new(
+                proposal.round,
+                proposal.block_signature,
+                vec![],
+            )),
+        };
+        let valid = match self.block_importer.verify_block(sealed_block).await {
+            Ok(()) => true,
+            Err(err) => {
+                tracing::warn!("The proposed block is invalid: {:?}", err);
+                false
+            }
+        };
+        self.engine.on_proposal(proposal, valid)
+    }
+
+    /// Performs actions requested by the engine, including actions caused
+    /// by own proposals and votes.
+    async fn perform(&mut self, actions: Vec<Action>) {
+        let mut actions = VecDeque::from(actions);
+        while let Some(action) = actions.pop_front() {
+            let result = match action {
+                Action::ProduceBlock { height, round } => {
+                    self.produce_and_propose(height, round).await
+                }
+                Action::Propose {
+                    round,
+                    valid_round,
+                    block,
+                } => self.propose(round, valid_round, block).await,
+                Action::Vote(vote) => self.vote(vote).await,
+                Action::ScheduleTimeout { timeout, after } => {
+                    let now = Instant::now();
+                    let deadline = now.checked_add(after).unwrap_or(now);
+                    self.timeouts.push((deadline, timeout));
+                    Ok(vec![])
+                }
+                Action::Commit(block) => self.commit(block).await.map(|_| vec![]),
+            };
+            match result {
+                Ok(next_actions) => actions.extend(next_actions),
+                Err(err) => {
+                    tracing::error!("Failed to perform the BFT action: {:?}", err)
+                }
+            }
+        }
+    }
+
+    async fn produce_and_propose(
+        &mut self,
+        height: BlockHeight,
+        round: u32,
+    ) -> anyhow::Result<Vec<Action>> {
+        let now = self.clock.now();
+        let block_time = if now > self.last_timestamp {
+            now
+        } else {
+            self.last_timestamp
+        };
+        let block = self
+            .block_producer
+            .produce_and_execute_block(height, block_time, TransactionsSource::TxPool)
+            .await?
+            .into_result()
+            .block;
+        self.propose(round, None, block).await
+    }
+
+    async fn propose(
+        &mut self,
+        round: u32,
+        valid_round: Option<u32>,
+        block: Block,
+    ) -> anyhow::Result<Vec<Action>> {
+        self.validator_state
+            .record_proposal(*block.header().height(), round, block.id())
+            .await?;
+        let block_signature = self.signer.sign_message(block.id().into_message()).await?;
+        let mut proposal = Proposal {
+            round,
+            valid_round,
+            block,
+            block_signature,
+            signature: Default::default(),
+        };
+        proposal.signature = self.signer.sign_message(proposal.signing_message()).await?;
+        self.broadcast(BftMessage::Proposal(proposal.clone()));
+        Ok(self.engine.on_proposal(proposal, true)?)
+    }
+
+    async fn vote(&mut self, vote: Vote) -> anyhow::Result<Vec<Action>> {
+        // After the restart the validator repeats the vote signed before it.
+        let vote = self
+            .validator_state
+            .record_vote(vote, self.engine.locked())
+            .await?;
+        let signature = self.signer.sign_message(vote.signing_message()).await?;
+        let vote = SignedVote { vote, signature };
+        self.broadcast(BftMessage::Vote(vote.clone()));
+        Ok(self.engine.on_vote(vote)?)
+    }
+
+    fn broadcast(&self, message: BftMessage) {
+        if let Err(err) = self.p2p_port.broadcast_message(Arc::new(message)) {
+            tracing::warn!("Failed to broadcast the consensus message: {:?}", err);
+        }
+    }
+
+    async fn commit(&mut self, block: SealedBlock) -> anyhow::Result<()> {
+        self.block_importer
+            .execute_and_commit(block.clone())
+            .await?;
+        self.on_block_imported(block.entity.header());
+        if let Err(err) = self.p2p_port.broadcast_block(Arc::new(block)) {
+            tracing::warn!("Failed to broadcast the committed block: {:?}", err);
+        }
+        Ok(())
+    }
+
+    async fn on_timeout(&mut self, timeout: Timeout) {
+        if !self.is_height_in_progress() {
+            return
+        }
+        let actions = self.engine.on_timeout(timeout);
+        self.perform(actions).await;
+    }
+
+    /// Removes and returns the earliest timeout, if its deadline has passed.
+    fn take_expired_timeout(&mut self) -> Option<Timeout> {
+        let now = Instant::now();
+        let (index, _) = self
+            .timeouts
+            .iter()
+            .enumerate()
+            .filter(|(_, (deadline, _))| *deadline <= now)
+            .min_by_key(|(_, (deadline, _))| *deadline)?;
+        Some(self.timeouts.swap_remove(index).1)
+    }
+}
+
+#[async_trait::async_trait]
+impl<B, I, P, C> RunnableService for MainTask<B, I, P, C>
+where
+    Self: RunnableTask,
+{
+    const NAME: &'static str = "BFT";
+
+    type SharedData = ();
+    type Task = MainTask<B, I, P, C>;
+    type TaskParams = ();
+
+    fn shared_data(&self) -> Self::SharedData {}
+
+    async fn into_task(
+        self,
+        _: &StateWatcher,
+        _: Self::TaskParams,
+    ) -> anyhow::Result<Self::Task> {
+        Ok(self)
+    }
+}
+
+#[async_trait::async_trait]
+impl<B, I, P, C> RunnableTask for MainTask<B, I, P, C>
+where
+    B: BlockProducer,
+    I: BlockImporter,
+    P: P2pPort,
+    C: GetTime,
+{
+    async fn run(&mut self, watcher: &mut StateWatcher) -> anyhow::Result<bool> {
+        let should_continue;
+
+        let next_height_start: BoxFuture<()> = match self.next_height_start {
+            Some(deadline) => Box::pin(sleep_until(deadline)),
+            None => Box::pin(core::future::pending()),
+        };
+        let next_timeout: BoxFuture<()> =
+            match self.timeouts.iter().map(|(deadline, _)| *deadline).min() {
+                Some(deadline) => Box::pin(sleep_until(deadline)),
+                None => Box::pin(core::future::pending()),
+            };
+
+        tokio::select! {
+            biased;
+            _ = watcher.while_started() => {
+                should_continue = false;
+            }
+            block = self.block_stream.next() => {
+                if let Some(block) = block {
+                    self.on_block_imported(&block.block_header);
+                    should_continue = true;
+                } else {
+                    tracing::error!("The block importer stream is closed");
+                    should_continue = false;
+                }
+            }
+            _ = next_height_start => {
+                self.start_next_height().await;
+                should_continue = true;
+            }
+            _ = next_timeout => {
+                if let Some(timeout) = self.take_expired_timeout() {
+                    self.on_timeout(timeout).await;
+                }
+                should_continue = true;
+            }
+            message = self.consensus_messages.next() => {
+                if let Some(message) = message {
+                    self.on_gossip(message).await;
+                    should_continue = true;
+                } else {
+                    tracing::error!("The consensus messages stream is closed");
+                    should_continue = false;
+                }
+            }
+        }
+
+        Ok(should_continue)
+    }
+
+    async fn shutdown(self) -> anyhow::Result<()> {
+        tracing::info!("BFT MainTask shutting down");
+        Ok(())
+    }
+}
+
+pub fn new_service<B, I, P, C>(
+    last_block: &BlockHeader,
+    config: Config,
+    block_producer: B,
+    block_importer: I,
+    p2p_port: P,
+    clock: C,
+) -> anyhow::Result<Service<B, I, P, C>>
+where
+    B: BlockProducer + 'static,
+    I: BlockImporter + 'static,
+    P: P2pPort,
+    C: GetTime + 'static,
+{
+    Ok(Service::new(MainTask::new(
+        last_block,
+        config,
+        block_producer,
+        block_importer,
+        p2p_port,
+        clock,
+    )?))
+}
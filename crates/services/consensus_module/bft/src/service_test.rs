@@ -0,0 +1,394 @@
+#![allow(non_snake_case)]
+
+//! The in-process network of validators connected by channels.
+
+use crate::{
+    new_service,
+    ports::{
+        BlockImporter,
+        P2pPort,
+    },
+    verifier::verify_seal,
+    Config,
+    Service,
+    Timeouts,
+    ValidatorSet,
+};
+use fuel_core_poa::{
+    ports::{
+        BlockProducer,
+        GetTime,
+        TransactionsSource,
+    },
+    signer::SignMode,
+};
+use fuel_core_services::{
+    stream::{
+        BoxStream,
+        IntoBoxStream,
+    },
+    Service as _,
+};
+use fuel_core_storage::transactional::Changes;
+use fuel_core_types::{
+    blockchain::{
+        block::Block,
+        consensus::{
+            bft::BftMessage,
+            Consensus,
+        },
+        header::BlockHeader,
+        SealedBlock,
+    },
+    fuel_crypto::SecretKey,
+    fuel_tx::Input,
+    fuel_types::BlockHeight,
+    secrecy::Secret,
+    services::{
+        block_importer::BlockImportInfo,
+        executor::{
+            ExecutionResult,
+            UncommittedResult,
+        },
+        p2p::{
+            ConsensusGossipData,
+            GossipData,
+            GossipsubMessageAcceptance,
+            GossipsubMessageInfo,
+        },
+    },
+    tai64::Tai64,
+};
+use rand::{
+    rngs::StdRng,
+    SeedableRng,
+};
+use std::{
+    sync::{
+        Arc,
+        Mutex,
+    },
+    time::Duration,
+};
+use tokio::sync::{
+    broadcast,
+    mpsc,
+};
+use tokio_stream::{
+    wrappers::{
+        BroadcastStream,
+        UnboundedReceiverStream,
+    },
+    StreamExt,
+};
+
+type Chain = Arc<Mutex<Vec<SealedBlock>>>;
+
+type TestService = Service<FakeProducer, FakeImporter, NetworkPort, Clock>;
+
+/// Delivers messages of each validator to all other online validators.
+#[derive(Default)]
+struct Network {
+    inboxes: Mutex<Vec<Option<mpsc::UnboundedSender<ConsensusGossipData>>>>,
+}
+
+struct NetworkPort {
+    index: usize,
+    network: Arc<Network>,
+    inbox: Mutex<Option<mpsc::UnboundedReceiver<ConsensusGossipData>>>,
+}
+
+impl P2pPort for NetworkPort {
+    fn broadcast_message(&self, message: Arc<BftMessage>) -> anyhow::Result<()> {
+        let inboxes = self.network.inboxes.lock().unwrap();
+        for (index, inbox) in inboxes.iter().enumerate() {
+            if let (true, Some(inbox)) = (index != self.index, inbox) {
+                let peer_id = vec![u8::try_from(self.index)?];
+                let gossip = GossipData::new((*message).clone(), peer_id, vec![]);
+                let _ = inbox.send(gossip);
+            }
+        }
+        Ok(())
+    }
+
+    fn broadcast_block(&self, _: Arc<SealedBlock>) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn consensus_messages(&self) -> BoxStream<ConsensusGossipData> {
+        let inbox = self
+            .inbox
+            .lock()
+            .unwrap()
+            .take()
+            .expect("Subscribed only once");
+        UnboundedReceiverStream::new(inbox).into_boxed()
+    }
+
+    fn notify_message_validity(
+        &self,
+        _: GossipsubMessageInfo,
+        _: GossipsubMessageAcceptance,
+    ) -> anyhow::Result<()> {
+        Ok(())
+    }
+}
+
+struct FakeProducer;
+
+#[async_trait::async_trait]
+impl BlockProducer for FakeProducer {
+    async fn produce_and_execute_block(
+        &self,
+        height: BlockHeight,
+        block_time: Tai64,
+        _: TransactionsSource,
+    ) -> anyhow::Result<UncommittedResult<Changes>> {
+        let mut block = Block::default();
+        block.header_mut().set_block_height(height);
+        block.header_mut().set_time(block_time);
+        Ok(UncommittedResult::new(
+            ExecutionResult {
+                block,
+                skipped_transactions: vec![],
+                tx_status: vec![],
+                events: vec![],
+            },
+            Default::default(),
+        ))
+    }
+
+    async fn produce_predefined_block(
+        &self,
+        _: &Block,
+    ) -> anyhow::Result<UncommittedResult<Changes>> {
+        unimplemented!("BFT doesn't produce predefined blocks")
+    }
+}
+
+struct FakeImporter {
+    chain: Chain,
+    imported: broadcast::Sender<BlockImportInfo>,
+}
+
+impl FakeImporter {
+    fn ensure_next_height(&self, block: &SealedBlock) -> anyhow::Result<()> {
+        let expected = u32::try_from(self.chain.lock().unwrap().len())?.saturating_add(1);
+        let actual = *block.entity.header().height();
+        anyhow::ensure!(
+            actual == expected.into(),
+            "Expected {expected}, got {actual}"
+        );
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl BlockImporter for FakeImporter {
+    async fn verify_block(&self, block: SealedBlock) -> anyhow::Result<()> {
+        self.ensure_next_height(&block)
+    }
+
+    async fn execute_and_commit(&self, block: SealedBlock) -> anyhow::Result<()> {
+        self.ensure_next_height(&block)?;
+        let header = block.entity.header().clone();
+        self.chain.lock().unwrap().push(block);
+        let _ = self.imported.send(BlockImportInfo::from(header));
+        Ok(())
+    }
+
+    fn block_stream(&self) -> BoxStream<BlockImportInfo> {
+        BroadcastStream::new(self.imported.subscribe())
+            .filter_map(|result| result.ok())
+            .into_boxed()
+    }
+}
+
+struct Clock;
+
+impl GetTime for Clock {
+    fn now(&self) -> Tai64 {
+        Tai64::now()
+    }
+}
+
+struct TestValidators {
+    validators: ValidatorSet,
+    chains: Vec<Chain>,
+    services: Vec<TestService>,
+}
+
+impl TestValidators {
+    /// Starts only the first `online` validators out of `total`.
+    async fn start(total: usize, online: usize) -> Self {
+        let mut rng = StdRng::seed_from_u64(2322);
+        let keys: Vec<_> = (0..total).map(|_| SecretKey::random(&mut rng)).collect();
+        let validators = ValidatorSet::new(
+            keys.iter()
+                .map(|key| Input::owner(&key.public_key()))
+                .collect(),
+        );
+        let network = Arc::new(Network::default());
+
+        let mut receivers = vec![];
+        {
+            let mut inboxes = network.inboxes.lock().unwrap();
+            for index in 0..total {
+                if index < online {
+                    let (sender, receiver) = mpsc::unbounded_channel();
+                    inboxes.push(Some(sender));
+                    receivers.push(receiver);
+                } else {
+                    inboxes.push(None);
+                }
+            }
+        }
+
+        let mut chains = vec![];
+        let mut services = vec![];
+        for (index, receiver) in receivers.into_iter().enumerate() {
+            let chain = Chain::default();
+            let config = Config {
+                signer: SignMode::Key(Secret::new(keys[index].into())),
+                validators: validators.clone(),
+                block_time: Duration::ZERO,
+                timeouts: Timeouts {
+                    propose: Duration::from_millis(300),
+                    prevote: Duration::from_millis(100),
+                    precommit: Duration::from_millis(100),
+                    delta: Duration::from_millis(50),
+                },
+                validator_state: None,
+            };
+            let service = new_service(
+                &BlockHeader::default(),
+                config,
+                FakeProducer,
+                FakeImporter {
+                    chain: chain.clone(),
+                    imported: broadcast::channel(1024).0,
+                },
+                NetworkPort {
+                    index,
+                    network: network.clone(),
+                    inbox: Mutex::new(Some(receiver)),
+                },
+                Clock,
+            )
+            .unwrap();
+            service.start_and_await().await.unwrap();
+            chains.push(chain);
+            services.push(service);
+        }
+
+        Self {
+            validators,
+            chains,
+            services,
+        }
+    }
+
+    /// Waits until each online validator commits `height` blocks.
+    async fn wait_for_height(&self, height: usize, timeout: Duration) -> bool {
+        let reached = async {
+            while self
+                .chains
+                .iter()
+                .any(|chain| chain.lock().unwrap().len() < height)
+            {
+                tokio::time::sleep(Duration::from_millis(10)).await;
+            }
+        };
+        tokio::time::timeout(timeout, reached).await.is_ok()
+    }
+
+    fn committed(&self, index: usize) -> Vec<SealedBlock> {
+        self.chains[index].lock().unwrap().clone()
+    }
+
+    async fn stop(self) {
+        for service in self.services {
+            service.stop_and_await().await.unwrap();
+        }
+    }
+}
+
+fn assert_sealed_by_validators(validators: &ValidatorSet, blocks: &[SealedBlock]) {
+    for block in blocks {
+        let Consensus::Bft(seal) = &block.consensus else {
+            panic!("Expected the BFT seal, got {:?}", block.consensus);
+        };
+        verify_seal(validators, block.entity.header(), seal).unwrap();
+    }
+}
+
+#[tokio::test]
+async fn all_validators__commit_the_same_blocks() {
+    let validators = TestValidators::start(4, 4).await;
+
+    // When
+    let reached = validators.wait_for_height(3, Duration::from_secs(10)).await;
+
+    // Then
+    assert!(reached);
+    let expected: Vec<_> = validators.committed(0)[..3]
+        .iter()
+        .map(|block| block.entity.id())
+        .collect();
+    for index in 0..4 {
+        let committed = validators.committed(index);
+        let actual: Vec<_> = committed[..3]
+            .iter()
+            .map(|block| block.entity.id())
+            .collect();
+        assert_eq!(actual, expected);
+        assert_sealed_by_validators(&validators.validators, &committed);
+    }
+    validators.stop().await;
+}
+
+#[tokio::test]
+async fn one_offline_validator__does_not_stop_the_chain() {
+    let validators = TestValidators::start(4, 3).await;
+
+    // When
+    // The offline validator is the proposer of one of each four heights,
+    // so these heights are decided in the next round.
+    let reached = validators.wait_for_height(5, Duration::from_secs(20)).await;
+
+    // Then
+    assert!(reached);
+    let committed = validators.committed(0);
+    assert_sealed_by_validators(&validators.validators, &committed);
+    assert!(committed.iter().any(|block| {
+        matches!(&block.consensus, Consensus::Bft(seal) if seal.round > 0)
+    }));
+    validators.stop().await;
+}
+
+#[tokio::test]
+async fn two_offline_validators__stop_the_chain() {
+    let validators = TestValidators::start(4, 2).await;
+
+    // When
+    let reached = validators.wait_for_height(1, Duration::from_secs(2)).await;
+
+    // Then
+    assert!(!reached);
+    assert!(validators.committed(0).is_empty());
+    assert!(validators.committed(1).is_empty());
+    validators.stop().await;
+}
+
+#[tokio::test]
+async fn single_validator__commits_blocks_alone() {
+    let validators = TestValidators::start(1, 1).await;
+
+    // When
+    let reached = validators.wait_for_height(3, Duration::from_secs(5)).await;
+
+    // Then
+    assert!(reached);
+    assert_sealed_by_validators(&validators.validators, &validators.committed(0));
+    validators.stop().await;
+}
@@ -0,0 +1,144 @@
+//! The messages signed by the local validator at the current height.
+//!
+//! The state is persisted before each signature. After the restart the validator
+//! signs the same messages again instead of conflicting ones, so it never
+//! equivocates in the same height, round and step.
+
+use anyhow::{
+    anyhow,
+    Context,
+};
+use fuel_core_types::{
+    blockchain::{
+        consensus::bft::Vote,
+        primitives::BlockId,
+    },
+    fuel_types::BlockHeight,
+};
+use std::{
+    io::Write,
+    path::PathBuf,
+};
+
+#[cfg(test)]
+mod tests;
+
+/// The proposals, the votes and the lock of the local validator at the height.
+#[derive(Debug, Default, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+struct ValidatorState {
+    height: BlockHeight,
+    /// The rounds and the blocks proposed by the validator.
+    proposals: Vec<(u32, BlockId)>,
+    /// The votes signed by the validator, at most one per round and step.
+    votes: Vec<Vote>,
+    /// The round and the block the validator is locked on.
+    locked: Option<(u32, BlockId)>,
+}
+
+impl ValidatorState {
+    /// Returns the state of the `height`, the state of other heights is forgotten.
+    fn at(&self, height: BlockHeight) -> Self {
+        if self.height == height {
+            self.clone()
+        } else {
+            Self {
+                height,
+                ..Default::default()
+            }
+        }
+    }
+}
+
+/// Keeps the state of the local validator in the file.
+/// Without the file the state is kept only in memory.
+#[derive(Debug)]
+pub struct ValidatorStateStore {
+    path: Option<PathBuf>,
+    state: ValidatorState,
+}
+
+impl ValidatorStateStore {
+    pub fn load(path: Option<PathBuf>) -> anyhow::Result<Self> {
+        let state = match &path {
+            Some(path) if path.exists() => {
+                let content = std::fs::read(path)?;
+                serde_json::from_slice(&content)
+                    .context("The BFT validator state file is corrupted")?
+            }
+            _ => ValidatorState::default(),
+        };
+        Ok(Self { path, state })
+    }
+
+    /// The lock of the local validator at the `height` before the restart.
+    pub fn locked(&self, height: BlockHeight) -> Option<(u32, BlockId)> {
+        self.state.at(height).locked
+    }
+
+    /// Records the proposal of the block before signing it.
+    /// Fails if another block was already proposed in the same round.
+    pub async fn record_proposal(
+        &mut self,
+        height: BlockHeight,
+        round: u32,
+        block_id: BlockId,
+    ) -> anyhow::Result<()> {
+        let mut state = self.state.at(height);
+        match state
+            .proposals
+            .iter()
+            .find(|(proposed, _)| *proposed == round)
+        {
+            Some((_, proposed)) if *proposed == block_id => return Ok(()),
+            Some((_, proposed)) => {
+                return Err(anyhow!(
+                    "Refusing to propose the block {block_id} at height {height} \
+                    in round {round}, the block {proposed} was already proposed"
+                ))
+            }
+            None => state.proposals.push((round, block_id)),
+        }
+        self.persist(state).await
+    }
+
+    /// Records the vote and the current lock of the validator before signing the vote.
+    /// If the vote for the same height, round and step was already signed,
+    /// returns that vote instead, so the validator signs it again.
+    pub async fn record_vote(
+        &mut self,
+        vote: Vote,
+        locked: Option<(u32, BlockId)>,
+    ) -> anyhow::Result<Vote> {
+        let mut state = self.state.at(vote.height);
+        if let Some(signed) = state
+            .votes
+            .iter()
+            .find(|signed| signed.round == vote.round && signed.step == vote.step)
+        {
+            return Ok(*signed)
+        }
+        state.votes.push(vote);
+        state.locked = locked;
+        self.persist(state).await?;
+        Ok(vote)
+    }
+
+    /// Writes the `state` to the temporary file and replaces the old file with it,
+    /// so the file is never left partially written.
+    async fn persist(&mut self, state: ValidatorState) -> anyhow::Result<()> {
+        if let Some(path) = self.path.clone() {
+            let bytes = serde_json::to_vec(&state)?;
+            tokio::task::spawn_blocking(move || {
+                let tmp = path.with_extension("tmp");
+                let mut file = std::fs::File::create(&tmp)?;
+                file.write_all(&bytes)?;
+                file.sync_all()?;
+                std::fs::rename(&tmp, &path)
+            })
+            .await?
+            .context("Failed to persist the BFT validator state")?;
+        }
+        self.state = state;
+        Ok(())
+    }
+}
@@ -0,0 +1,110 @@
+#![allow(non_snake_case)]
+
+use super::*;
+use fuel_core_types::blockchain::consensus::bft::VoteStep;
+
+fn vote(round: u32, step: VoteStep, block: u8) -> Vote {
+    Vote {
+        height: 1u32.into(),
+        round,
+        step,
+        block_id: Some([block; 32].into()),
+    }
+}
+
+#[tokio::test]
+async fn record_vote__returns_the_vote_signed_before_the_restart() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("state.json");
+    let signed = vote(0, VoteStep::Prevote, 1);
+    let locked = Some((0, [1; 32].into()));
+    let mut store = ValidatorStateStore::load(Some(path.clone())).unwrap();
+    store.record_vote(signed, locked).await.unwrap();
+
+    // When
+    let mut store = ValidatorStateStore::load(Some(path)).unwrap();
+    let result = store
+        .record_vote(vote(0, VoteStep::Prevote, 2), None)
+        .await
+        .unwrap();
+
+    // Then
+    assert_eq!(result, signed);
+    assert_eq!(store.locked(1u32.into()), locked);
+}
+
+#[tokio::test]
+async fn record_vote__records_votes_of_other_steps_and_rounds() {
+    let mut store = ValidatorStateStore::load(None).unwrap();
+    store
+        .record_vote(vote(0, VoteStep::Prevote, 1), None)
+        .await
+        .unwrap();
+
+    // When
+    let precommit = store
+        .record_vote(vote(0, VoteStep::Precommit, 2), None)
+        .await
+        .unwrap();
+    let next_round = store
+        .record_vote(vote(1, VoteStep::Prevote, 2), None)
+        .await
+        .unwrap();
+
+    // Then
+    assert_eq!(precommit, vote(0, VoteStep::Precommit, 2));
+    assert_eq!(next_round, vote(1, VoteStep::Prevote, 2));
+}
+
+#[tokio::test]
+async fn record_vote__forgets_the_state_of_previous_heights() {
+    let mut store = ValidatorStateStore::load(None).unwrap();
+    store
+        .record_vote(vote(0, VoteStep::Prevote, 1), Some((0, [1; 32].into())))
+        .await
+        .unwrap();
+    let next_height = Vote {
+        height: 2u32.into(),
+        ..vote(0, VoteStep::Prevote, 2)
+    };
+
+    // When
+    let result = store.record_vote(next_height, None).await.unwrap();
+
+    // Then
+    assert_eq!(result, next_height);
+    assert_eq!(store.locked(2u32.into()), None);
+}
+
+#[tokio::test]
+async fn record_proposal__fails_for_another_block_in_the_same_round() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("state.json");
+    let mut store = ValidatorStateStore::load(Some(path.clone())).unwrap();
+    store
+        .record_proposal(1u32.into(), 0, [1; 32].into())
+        .await
+        .unwrap();
+
+    // When
+    let mut store = ValidatorStateStore::load(Some(path)).unwrap();
+    let same = store.record_proposal(1u32.into(), 0, [1; 32].into()).await;
+    let another = store.record_proposal(1u32.into(), 0, [2; 32].into()).await;
+
+    // Then
+    assert!(same.is_ok());
+    assert!(another.is_err());
+}
+
+#[test]
+fn load__fails_for_the_corrupted_file() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("state.json");
+    std::fs::write(&path, b"{\"height\":").unwrap();
+
+    // When
+    let result = ValidatorStateStore::load(Some(path));
+
+    // Then
+    assert!(result.is_err());
+}
@@ -0,0 +1,123 @@
+use fuel_core_chain_config::ConsensusConfig;
+use fuel_core_types::fuel_types::{
+    Address,
+    BlockHeight,
+};
+
+/// The set of validators that decides on blocks.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidatorSet {
+    validators: Vec<Address>,
+}
+
+impl ValidatorSet {
+    /// Creates the validator set, ignoring duplicated validators.
+    pub fn new(validators: Vec<Address>) -> Self {
+        let mut unique = Vec::with_capacity(validators.len());
+        for validator in validators {
+            if !unique.contains(&validator) {
+                unique.push(validator);
+            }
+        }
+        Self { validators: unique }
+    }
+
+    /// Returns the validator set of the BFT consensus, if the chain uses it.
+    pub fn from_consensus_config(config: &ConsensusConfig) -> Option<Self> {
+        match config {
+            ConsensusConfig::Bft { validators } => Some(Self::new(validators.clone())),
+            _ => None,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.validators.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.validators.is_empty()
+    }
+
+    pub fn contains(&self, address: &Address) -> bool {
+        self.validators.contains(address)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Address> {
+        self.validators.iter()
+    }
+
+    /// The number of faulty validators the set tolerates.
+    pub fn max_faulty(&self) -> usize {
+        self.len().saturating_sub(1) / 3
+    }
+
+    /// The number of votes that guarantees that more than two thirds
+    /// of validators agreed.
+    pub fn quorum(&self) -> usize {
+        self.len().saturating_sub(self.max_faulty())
+    }
+
+    /// The number of votes that guarantees that at least one honest validator
+    /// is among the voters.
+    pub fn honest_threshold(&self) -> usize {
+        self.max_faulty().saturating_add(1)
+    }
+
+    /// The validator that proposes the block at the `height` in the `round`.
+    /// The proposer rotates with each height and each round.
+    pub fn proposer(&self, height: BlockHeight, round: u32) -> Option<&Address> {
+        let len = u64::try_from(self.len()).ok()?;
+        let index = u64::from(u32::from(height))
+            .wrapping_add(u64::from(round))
+            .checked_rem(len)?;
+        self.validators.get(usize::try_from(index).ok()?)
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    fn validators(n: u8) -> ValidatorSet {
+        ValidatorSet::new((0..n).map(|i| Address::from([i; 32])).collect())
+    }
+
+    #[test_case(1, 1, 0)]
+    #[test_case(2, 2, 0)]
+    #[test_case(3, 3, 0)]
+    #[test_case(4, 3, 1)]
+    #[test_case(5, 4, 1)]
+    #[test_case(7, 5, 2)]
+    #[test_case(10, 7, 3)]
+    fn quorum__is_more_than_two_thirds(n: u8, quorum: usize, max_faulty: usize) {
+        let set = validators(n);
+        assert_eq!(set.quorum(), quorum);
+        assert_eq!(set.max_faulty(), max_faulty);
+        assert!(set.quorum().saturating_mul(3) > set.len().saturating_mul(2));
+    }
+
+    #[test]
+    fn new__ignores_duplicates() {
+        let address = Address::from([1; 32]);
+        let set = ValidatorSet::new(vec![address, address, Address::from([2; 32])]);
+        assert_eq!(set.len(), 2);
+    }
+
+    #[test]
+    fn proposer__rotates_by_height_and_round() {
+        let set = validators(4);
+        let proposer = |height: u32, round| *set.proposer(height.into(), round).unwrap();
+
+        assert_eq!(proposer(1, 0), Address::from([1; 32]));
+        assert_eq!(proposer(2, 0), Address::from([2; 32]));
+        assert_eq!(proposer(1, 1), Address::from([2; 32]));
+        assert_eq!(proposer(3, 2), Address::from([1; 32]));
+    }
+
+    #[test]
+    fn proposer__is_none_for_empty_set() {
+        assert_eq!(validators(0).proposer(1u32.into(), 0), None);
+    }
+}
@@ -0,0 +1,81 @@
+use crate::validators::ValidatorSet;
+use anyhow::{
+    anyhow,
+    ensure,
+};
+use fuel_core_chain_config::ConsensusConfig;
+use fuel_core_types::{
+    blockchain::{
+        consensus::bft::{
+            BftConsensus,
+            Vote,
+            VoteStep,
+        },
+        header::BlockHeader,
+    },
+    fuel_tx::Input,
+};
+use std::collections::HashSet;
+
+#[cfg(test)]
+mod tests;
+
+pub fn verify_consensus(
+    consensus_config: &ConsensusConfig,
+    header: &BlockHeader,
+    consensus: &BftConsensus,
+) -> bool {
+    match ValidatorSet::from_consensus_config(consensus_config) {
+        Some(validators) => verify_seal(&validators, header, consensus).is_ok(),
+        None => false,
+    }
+}
+
+/// Verifies that the block was proposed by the proposer of the round
+/// and precommitted by the quorum of validators.
+pub fn verify_seal(
+    validators: &ValidatorSet,
+    header: &BlockHeader,
+    consensus: &BftConsensus,
+) -> anyhow::Result<()> {
+    let height = *header.height();
+    let block_id = header.id();
+
+    let proposer = validators
+        .proposer(height, consensus.round)
+        .ok_or_else(|| anyhow!("The validator set is empty"))?;
+    let producer = consensus
+        .producer_signature
+        .recover(block_id.as_message())
+        .map(|public_key| Input::owner(&public_key))
+        .map_err(|e| anyhow!("Can't recover the producer: {:?}", e))?;
+    ensure!(
+        &producer == proposer,
+        "The block is not signed by the proposer of the round {}",
+        consensus.round
+    );
+
+    let vote = Vote {
+        height,
+        round: consensus.round,
+        step: VoteStep::Precommit,
+        block_id: Some(block_id),
+    };
+    let mut signers = HashSet::with_capacity(consensus.signatures.len());
+    for signature in consensus.signatures.iter() {
+        let signer = vote.signer(signature)?;
+        ensure!(
+            validators.contains(&signer),
+            "{} is not a validator",
+            signer
+        );
+        ensure!(signers.insert(signer), "{} signed the block twice", signer);
+    }
+    ensure!(
+        signers.len() >= validators.quorum(),
+        "The block is signed by {} validators, but {} are required",
+        signers.len(),
+        validators.quorum()
+    );
+    Ok(())
+}
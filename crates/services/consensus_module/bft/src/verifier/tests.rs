@@ -0,0 +1,121 @@
+#![allow(non_snake_case)]
+
+use super::*;
+use fuel_core_types::{
+    fuel_crypto::{
+        SecretKey,
+        Signature,
+    },
+    fuel_types::Address,
+};
+use rand::{
+    rngs::StdRng,
+    SeedableRng,
+};
+use test_case::test_case;
+
+struct Input {
+    keys: Vec<SecretKey>,
+    header: BlockHeader,
+    consensus: BftConsensus,
+}
+
+fn address(key: &SecretKey) -> Address {
+    fuel_core_types::fuel_tx::Input::owner(&key.public_key())
+}
+
+fn precommit(key: &SecretKey, header: &BlockHeader, round: u32) -> Signature {
+    let vote = Vote {
+        height: *header.height(),
+        round,
+        step: VoteStep::Precommit,
+        block_id: Some(header.id()),
+    };
+    Signature::sign(key, &vote.signing_message())
+}
+
+/// The block at the height `1`, committed in the round `2` by validators `0`, `1` and `2`.
+/// The proposer of the round is the validator `3`.
+fn correct() -> Input {
+    let mut rng = StdRng::seed_from_u64(2322);
+    let keys: Vec<_> = (0..4).map(|_| SecretKey::random(&mut rng)).collect();
+    let mut header = BlockHeader::default();
+    header.set_block_height(1u32.into());
+    let round = 2;
+
+    let producer_signature = Signature::sign(&keys[3], &header.id().into_message());
+    let signatures = keys[..3]
+        .iter()
+        .map(|key| precommit(key, &header, round))
+        .collect();
+    Input {
+        keys,
+        header,
+        consensus: BftConsensus::new(round, producer_signature, signatures),
+    }
+}
+
+#[test_case(correct() => matches Ok(_) ; "Correct seal")]
+#[test_case(
+    {
+        let mut i = correct();
+        i.consensus.signatures.pop();
+        i
+    } => matches Err(_) ; "Not enough signatures"
+)]
+#[test_case(
+    {
+        let mut i = correct();
+        let duplicate = i.consensus.signatures[0];
+        i.consensus.signatures[1] = duplicate;
+        i
+    } => matches Err(_) ; "Duplicated signature"
+)]
+#[test_case(
+    {
+        let mut i = correct();
+        i.consensus.producer_signature =
+            Signature::sign(&i.keys[0], &i.header.id().into_message());
+        i
+    } => matches Err(_) ; "Wrong proposer"
+)]
+#[test_case(
+    {
+        let mut i = correct();
+        i.consensus.round = 1;
+        i
+    } => matches Err(_) ; "Wrong round"
+)]
+#[test_case(
+    {
+        let mut i = correct();
+        let outsider = SecretKey::random(&mut StdRng::seed_from_u64(1));
+        i.consensus.signatures[0] = precommit(&outsider, &i.header, 2);
+        i
+    } => matches Err(_) ; "Signature of non validator"
+)]
+#[test_case(
+    {
+        let mut i = correct();
+        i.consensus.signatures[0] = precommit(&i.keys[0], &i.header, 1);
+        i
+    } => matches Err(_) ; "Precommit for another round"
+)]
+fn verify_seal__checks_proposer_and_quorum(input: Input) -> anyhow::Result<()> {
+    let validators = ValidatorSet::new(input.keys.iter().map(address).collect());
+    verify_seal(&validators, &input.header, &input.consensus)
+}
+
+#[test]
+fn verify_consensus__requires_bft_config() {
+    let input = correct();
+    let validators: Vec<_> = input.keys.iter().map(address).collect();
+
+    let bft = ConsensusConfig::Bft { validators };
+    let poa = ConsensusConfig::PoA {
+        signing_key: address(&input.keys[3]),
+    };
+
+    assert!(verify_consensus(&bft, &input.header, &input.consensus));
+    assert!(!verify_consensus(&poa, &input.header, &input.consensus));
+}
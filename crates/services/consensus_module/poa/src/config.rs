@@ -1,3 +1,4 @@
+use fuel_core_chain_config::PoAV3;
 use fuel_core_types::fuel_types::ChainId;
 use tokio::time::Duration;
 
@@ -11,6 +12,9 @@ pub struct Config {
     pub min_connected_reserved_peers: usize,
     pub time_until_synced: Duration,
     pub chain_id: ChainId,
+    /// The schedule of producers of the `PoAV3` network.
+    /// If set, the node produces blocks only in its own slots.
+    pub round_robin: Option<PoAV3>,
 }
 
 #[cfg(feature = "test-helpers")]
@@ -23,6 +27,7 @@ impl Default for Config {
             min_connected_reserved_peers: 0,
             time_until_synced: Duration::ZERO,
             chain_id: ChainId::default(),
+            round_robin: None,
         }
     }
 }
@@ -38,4 +43,33 @@ pub enum Trigger {
     Never,
     /// A new block is produced periodically. Used to simulate consensus block delay.
     Interval { block_time: Duration },
+    /// A new block is produced when the consumable gas of the pending transactions
+    /// reaches the `gas_threshold`, or when the `max_block_time` elapses, whichever
+    /// comes first. Blocks are never produced faster than the `min_block_time`.
+    Hybrid {
+        gas_threshold: u64,
+        min_block_time: Duration,
+        max_block_time: Duration,
+    },
+}
+
+impl Trigger {
+    /// Creates the `Hybrid` trigger. Fails if the `min_block_time` exceeds
+    /// the `max_block_time`, because then reaching the gas threshold would delay blocks.
+    pub fn hybrid(
+        gas_threshold: u64,
+        min_block_time: Duration,
+        max_block_time: Duration,
+    ) -> anyhow::Result<Self> {
+        anyhow::ensure!(
+            min_block_time <= max_block_time,
+            "The min block time {min_block_time:?} of the hybrid trigger exceeds \
+            the max block time {max_block_time:?}"
+        );
+        Ok(Trigger::Hybrid {
+            gas_threshold,
+            min_block_time,
+            max_block_time,
+        })
+    }
 }
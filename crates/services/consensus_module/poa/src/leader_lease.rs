@@ -0,0 +1,245 @@
+//! The leader lease stored in a file shared by the producers.
+
+use crate::ports::LeaderLease;
+use anyhow::anyhow;
+use fs2::FileExt;
+use fuel_core_types::{
+    blockchain::{
+        primitives::BlockId,
+        SealedBlock,
+    },
+    fuel_types::BlockHeight,
+};
+use serde::{
+    Deserialize,
+    Serialize,
+};
+use std::{
+    fs::{
+        File,
+        OpenOptions,
+    },
+    io::{
+        Read,
+        Seek,
+        SeekFrom,
+        Write,
+    },
+    path::PathBuf,
+    time::{
+        Duration,
+        SystemTime,
+        UNIX_EPOCH,
+    },
+};
+
+#[cfg(test)]
+mod tests;
+
+/// The shortest duration of the lease. The lease is renewed three times per duration,
+/// and each renewal syncs the lease file to the disk.
+pub const MIN_LEASE_DURATION: Duration = Duration::from_secs(1);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LeaderLeaseConfig {
+    /// The path to the lease file. All producers should use the same file.
+    pub path: PathBuf,
+    /// The unique name of the producer.
+    pub holder: String,
+    /// How long the lease is valid after the last renewal.
+    pub duration: Duration,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct LeaseRecord {
+    holder: String,
+    /// The expiration time in milliseconds since the UNIX epoch.
+    expires_at: u64,
+    /// The highest height reserved by holders of the lease.
+    height: u32,
+    /// The block reserved at the `height`.
+    block_id: Option<BlockId>,
+    /// The signed block at the `height`, stored before it is published.
+    sealed_block: Option<SealedBlock>,
+}
+
+/// The lease that is stored in the file and guarded by the exclusive file lock.
+/// The expiration time is the wall-clock time, so producers should have synchronized clocks.
+///
+/// The holder stores the signed block in the lease before publishing it. If the holder
+/// fails after that, the standby imports the stored block and takes over. If the holder
+/// fails before that, the block was never published, so the standby drops the reservation.
+pub struct FileLeaderLease {
+    config: LeaderLeaseConfig,
+}
+
+impl FileLeaderLease {
+    pub fn new(config: LeaderLeaseConfig) -> Self {
+        Self { config }
+    }
+
+    /// Applies the `f` to the record under the file lock. The record is written
+    /// back only if the `f` changes it.
+    async fn update<F, T>(&self, f: F) -> anyhow::Result<T>
+    where
+        F: FnOnce(&mut LeaseRecord, u64) -> T + Send + 'static,
+        T: Send + 'static,
+    {
+        let path = self.config.path.clone();
+        tokio::task::spawn_blocking(move || {
+            let mut file = OpenOptions::new()
+                .read(true)
+                .write(true)
+                .create(true)
+                .truncate(false)
+                .open(path)?;
+            file.lock_exclusive()?;
+            let result = Self::update_locked(&mut file, f);
+            file.unlock()?;
+            result
+        })
+        .await?
+    }
+
+    fn update_locked<F, T>(file: &mut File, f: F) -> anyhow::Result<T>
+    where
+        F: FnOnce(&mut LeaseRecord, u64) -> T,
+    {
+        let mut content = String::new();
+        file.read_to_string(&mut content)?;
+        let old_record = if content.trim().is_empty() {
+            LeaseRecord::default()
+        } else {
+            serde_json::from_str(&content)?
+        };
+
+        let mut record = old_record.clone();
+        let result = f(&mut record, now_millis()?);
+        if record != old_record {
+            file.set_len(0)?;
+            file.seek(SeekFrom::Start(0))?;
+            file.write_all(&serde_json::to_vec(&record)?)?;
+            file.sync_all()?;
+        }
+        Ok(result)
+    }
+
+    fn expires_at(&self, now: u64) -> anyhow::Result<u64> {
+        let duration = u64::try_from(self.config.duration.as_millis())?;
+        Ok(now.saturating_add(duration))
+    }
+}
+
+#[async_trait::async_trait]
+impl LeaderLease for FileLeaderLease {
+    fn renewal_interval(&self) -> Option<Duration> {
+        self.config.duration.checked_div(3)
+    }
+
+    async fn try_hold(&self, last_height: BlockHeight) -> anyhow::Result<bool> {
+        let holder = self.config.holder.clone();
+        let expires_at = self.expires_at(now_millis()?)?;
+        self.update(move |record, now| {
+            let last_height = u32::from(last_height);
+            let is_holder = record.holder == holder;
+            let is_expired = record.expires_at <= now;
+            let is_synced = last_height >= record.height;
+            // The block that wasn't stored was never published, so it is safe
+            // to reserve the height again.
+            let is_unpublished = record.sealed_block.is_none()
+                && last_height.checked_add(1) == Some(record.height);
+            if is_holder || (is_expired && (is_synced || is_unpublished)) {
+                if is_unpublished {
+                    record.height = last_height;
+                    record.block_id = None;
+                }
+                record.holder = holder;
+                record.expires_at = expires_at;
+                true
+            } else {
+                false
+            }
+        })
+        .await
+    }
+
+    async fn stored_block(
+        &self,
+        last_height: BlockHeight,
+    ) -> anyhow::Result<Option<SealedBlock>> {
+        self.update(move |record, _| {
+            let next_height = last_height.succ()?;
+            record
+                .sealed_block
+                .clone()
+                .filter(|block| *block.entity.header().height() == next_height)
+        })
+        .await
+    }
+
+    async fn reserve(
+        &self,
+        height: BlockHeight,
+        block_id: BlockId,
+    ) -> anyhow::Result<bool> {
+        let holder = self.config.holder.clone();
+        let expires_at = self.expires_at(now_millis()?)?;
+        self.update(move |record, now| {
+            let height = u32::from(height);
+            let is_holder = record.holder == holder;
+            let is_expired = record.expires_at <= now;
+            let is_new_height = height > record.height;
+            let is_same_block =
+                height == record.height && record.block_id == Some(block_id);
+            if is_holder && !is_expired && (is_new_height || is_same_block) {
+                if is_new_height {
+                    record.height = height;
+                    record.block_id = Some(block_id);
+                    record.sealed_block = None;
+                }
+                record.expires_at = expires_at;
+                true
+            } else {
+                false
+            }
+        })
+        .await
+    }
+
+    async fn store_block(&self, block: &SealedBlock) -> anyhow::Result<bool> {
+        let holder = self.config.holder.clone();
+        let block = block.clone();
+        let height = u32::from(*block.entity.header().height());
+        let block_id = block.entity.id();
+        self.update(move |record, now| {
+            let is_holder = record.holder == holder;
+            let is_expired = record.expires_at <= now;
+            let is_reserved =
+                record.height == height && record.block_id == Some(block_id);
+            if is_holder && !is_expired && is_reserved {
+                record.sealed_block = Some(block);
+                true
+            } else {
+                false
+            }
+        })
+        .await
+    }
+
+    async fn release(&self) -> anyhow::Result<()> {
+        let holder = self.config.holder.clone();
+        self.update(move |record, _| {
+            if record.holder == holder {
+                record.expires_at = 0;
+            }
+        })
+        .await
+    }
+}
+
+fn now_millis() -> anyhow::Result<u64> {
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_err(|e| anyhow!("The system time is before the UNIX epoch: {e}"))?;
+    Ok(u64::try_from(now.as_millis())?)
+}
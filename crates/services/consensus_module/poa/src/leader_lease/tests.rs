@@ -0,0 +1,199 @@
+#![allow(non_snake_case)]
+
+use super::*;
+use fuel_core_types::blockchain::block::Block;
+use tempfile::TempDir;
+
+const DURATION: Duration = Duration::from_millis(200);
+
+fn lease(dir: &TempDir, holder: &str) -> FileLeaderLease {
+    FileLeaderLease::new(LeaderLeaseConfig {
+        path: dir.path().join("lease.json"),
+        holder: holder.to_string(),
+        duration: DURATION,
+    })
+}
+
+fn block(height: u32, time: u64) -> SealedBlock {
+    let mut block = Block::default();
+    block.header_mut().set_block_height(height.into());
+    block
+        .header_mut()
+        .set_time(fuel_core_types::tai64::Tai64(time));
+    block.header_mut().recalculate_metadata();
+    SealedBlock {
+        entity: block,
+        consensus: Default::default(),
+    }
+}
+
+/// Reserves the height of the `block` and stores it, as the leader does before publishing.
+async fn reserve_and_store(lease: &FileLeaderLease, block: &SealedBlock) {
+    let height = *block.entity.header().height();
+    assert!(lease.reserve(height, block.entity.id()).await.unwrap());
+    assert!(lease.store_block(block).await.unwrap());
+}
+
+#[tokio::test]
+async fn try_hold__first_producer_acquires_free_lease() {
+    let dir = TempDir::new().unwrap();
+    let leader = lease(&dir, "leader");
+    let standby = lease(&dir, "standby");
+
+    // When
+    let leader_holds = leader.try_hold(0u32.into()).await.unwrap();
+    let standby_holds = standby.try_hold(0u32.into()).await.unwrap();
+
+    // Then
+    assert!(leader_holds);
+    assert!(!standby_holds);
+}
+
+#[tokio::test]
+async fn try_hold__standby_takes_over_expired_lease() {
+    let dir = TempDir::new().unwrap();
+    let leader = lease(&dir, "leader");
+    let standby = lease(&dir, "standby");
+    assert!(leader.try_hold(0u32.into()).await.unwrap());
+    reserve_and_store(&leader, &block(5, 0)).await;
+
+    // When
+    tokio::time::sleep(DURATION).await;
+    let standby_holds = standby.try_hold(5u32.into()).await.unwrap();
+
+    // Then
+    assert!(standby_holds);
+    let next = block(6, 0);
+    assert!(!leader.reserve(6u32.into(), next.entity.id()).await.unwrap());
+    assert!(!leader.try_hold(5u32.into()).await.unwrap());
+}
+
+#[tokio::test]
+async fn try_hold__standby_waits_for_stored_block() {
+    let dir = TempDir::new().unwrap();
+    let leader = lease(&dir, "leader");
+    let standby = lease(&dir, "standby");
+    assert!(leader.try_hold(0u32.into()).await.unwrap());
+    let stored = block(5, 0);
+    reserve_and_store(&leader, &stored).await;
+    tokio::time::sleep(DURATION).await;
+
+    // When
+    let behind = standby.try_hold(4u32.into()).await.unwrap();
+    let stored_block = standby.stored_block(4u32.into()).await.unwrap();
+    let synced = standby.try_hold(5u32.into()).await.unwrap();
+
+    // Then
+    assert!(!behind);
+    assert_eq!(stored_block, Some(stored));
+    assert!(synced);
+}
+
+#[tokio::test]
+async fn try_hold__standby_drops_reservation_of_unpublished_block() {
+    let dir = TempDir::new().unwrap();
+    let leader = lease(&dir, "leader");
+    let standby = lease(&dir, "standby");
+    assert!(leader.try_hold(0u32.into()).await.unwrap());
+    let unpublished = block(5, 0);
+    assert!(leader
+        .reserve(5u32.into(), unpublished.entity.id())
+        .await
+        .unwrap());
+    tokio::time::sleep(DURATION).await;
+
+    // When
+    let far_behind = standby.try_hold(3u32.into()).await.unwrap();
+    let behind = standby.try_hold(4u32.into()).await.unwrap();
+
+    // Then
+    assert!(!far_behind);
+    assert!(behind);
+    let conflicting = block(5, 1);
+    assert!(standby
+        .reserve(5u32.into(), conflicting.entity.id())
+        .await
+        .unwrap());
+    assert!(!leader.store_block(&unpublished).await.unwrap());
+}
+
+#[tokio::test]
+async fn reserve__fails_without_lease() {
+    let dir = TempDir::new().unwrap();
+    let leader = lease(&dir, "leader");
+    let standby = lease(&dir, "standby");
+    assert!(leader.try_hold(0u32.into()).await.unwrap());
+
+    // When
+    let reserved = standby
+        .reserve(1u32.into(), block(1, 0).entity.id())
+        .await
+        .unwrap();
+
+    // Then
+    assert!(!reserved);
+}
+
+#[tokio::test]
+async fn reserve__refuses_another_block_at_reserved_height() {
+    let dir = TempDir::new().unwrap();
+    let leader = lease(&dir, "leader");
+    assert!(leader.try_hold(0u32.into()).await.unwrap());
+    let reserved = block(5, 0);
+    assert!(leader
+        .reserve(5u32.into(), reserved.entity.id())
+        .await
+        .unwrap());
+
+    // When
+    let same_block = leader
+        .reserve(5u32.into(), reserved.entity.id())
+        .await
+        .unwrap();
+    let another_block = leader
+        .reserve(5u32.into(), block(5, 1).entity.id())
+        .await
+        .unwrap();
+    let lower_height = leader
+        .reserve(4u32.into(), block(4, 0).entity.id())
+        .await
+        .unwrap();
+
+    // Then
+    assert!(same_block);
+    assert!(!another_block);
+    assert!(!lower_height);
+}
+
+#[tokio::test]
+async fn store_block__fails_for_not_reserved_block() {
+    let dir = TempDir::new().unwrap();
+    let leader = lease(&dir, "leader");
+    assert!(leader.try_hold(0u32.into()).await.unwrap());
+    assert!(leader
+        .reserve(5u32.into(), block(5, 0).entity.id())
+        .await
+        .unwrap());
+
+    // When
+    let stored = leader.store_block(&block(5, 1)).await.unwrap();
+
+    // Then
+    assert!(!stored);
+    assert_eq!(leader.stored_block(4u32.into()).await.unwrap(), None);
+}
+
+#[tokio::test]
+async fn release__standby_takes_over_without_waiting() {
+    let dir = TempDir::new().unwrap();
+    let leader = lease(&dir, "leader");
+    let standby = lease(&dir, "standby");
+    assert!(leader.try_hold(0u32.into()).await.unwrap());
+    reserve_and_store(&leader, &block(1, 0)).await;
+
+    // When
+    leader.release().await.unwrap();
+
+    // Then
+    assert!(standby.try_hold(1u32.into()).await.unwrap());
+}
@@ -11,6 +11,7 @@ mod sync;
 mod service_test;
 
 pub mod config;
+pub mod leader_lease;
 pub mod ports;
 pub mod service;
 pub mod signer;
@@ -8,7 +8,11 @@ use fuel_core_types::{
         block::Block,
         consensus::Consensus,
         header::BlockHeader,
-        primitives::DaBlockHeight,
+        primitives::{
+            BlockId,
+            DaBlockHeight,
+        },
+        SealedBlock,
     },
     fuel_tx::{
         Transaction,
@@ -31,7 +35,11 @@ use fuel_core_types::{
     },
     tai64::Tai64,
 };
-use std::collections::HashMap;
+use std::{
+    collections::HashMap,
+    sync::Arc,
+    time::Duration,
+};
 
 #[cfg_attr(test, mockall::automock)]
 pub trait TransactionPool: Send + Sync {
@@ -77,6 +85,9 @@ pub trait BlockImporter: Send + Sync {
         result: UncommittedImportResult<Changes>,
     ) -> anyhow::Result<()>;
 
+    /// Verifies, executes and commits the block signed by another producer.
+    async fn execute_and_commit(&self, block: SealedBlock) -> anyhow::Result<()>;
+
     fn block_stream(&self) -> BoxStream<BlockImportInfo>;
 }
 
@@ -114,6 +125,9 @@ pub trait RelayerPort {
 pub trait P2pPort: Send + Sync + 'static {
     /// Subscribe to reserved peers connection updates.
     fn reserved_peers_count(&self) -> BoxStream<usize>;
+
+    /// Broadcast the produced block to the network.
+    fn broadcast_block(&self, block: Arc<SealedBlock>) -> anyhow::Result<()>;
 }
 
 #[async_trait::async_trait]
@@ -149,6 +163,129 @@ impl PredefinedBlocks for InMemoryPredefinedBlocks {
     }
 }
 
+/// The lease that allows only one of the producers sharing the signing key
+/// to sign blocks at a time.
+#[cfg_attr(test, mockall::automock)]
+#[async_trait::async_trait]
+pub trait LeaderLease: Send + Sync {
+    /// How often the lease should be renewed. `None` if the lease never expires.
+    fn renewal_interval(&self) -> Option<Duration>;
+
+    /// Acquires or renews the lease. The lease of another producer is taken over
+    /// only after it expires, and only if the node already has the block at
+    /// the height reserved by that producer, so it never signs a conflicting block.
+    /// The reservation of the block that was never stored is dropped on the takeover.
+    ///
+    /// Returns `true` if the node holds the lease.
+    async fn try_hold(&self, last_height: BlockHeight) -> anyhow::Result<bool>;
+
+    /// Returns the block stored by the holder of the lease
+    /// at the height following the `last_height`.
+    async fn stored_block(
+        &self,
+        last_height: BlockHeight,
+    ) -> anyhow::Result<Option<SealedBlock>>;
+
+    /// Reserves the `height` for the block with the `block_id` right before signing it.
+    /// The reserved height can't be reserved again for another block.
+    ///
+    /// Returns `false` if the node doesn't hold the lease anymore,
+    /// or the height is already reserved.
+    async fn reserve(
+        &self,
+        height: BlockHeight,
+        block_id: BlockId,
+    ) -> anyhow::Result<bool>;
+
+    /// Stores the signed block at the reserved height right before publishing it,
+    /// so the standby can import it if the node fails.
+    ///
+    /// Returns `false` if the node doesn't hold the lease anymore.
+    async fn store_block(&self, block: &SealedBlock) -> anyhow::Result<bool>;
+
+    /// Releases the lease, so the standby can take over without waiting for the expiration.
+    async fn release(&self) -> anyhow::Result<()>;
+}
+
+/// The node without the lease is always the leader.
+pub struct NoLeaderLease;
+
+#[async_trait::async_trait]
+impl LeaderLease for NoLeaderLease {
+    fn renewal_interval(&self) -> Option<Duration> {
+        None
+    }
+
+    async fn try_hold(&self, _: BlockHeight) -> anyhow::Result<bool> {
+        Ok(true)
+    }
+
+    async fn stored_block(&self, _: BlockHeight) -> anyhow::Result<Option<SealedBlock>> {
+        Ok(None)
+    }
+
+    async fn reserve(&self, _: BlockHeight, _: BlockId) -> anyhow::Result<bool> {
+        Ok(true)
+    }
+
+    async fn store_block(&self, _: &SealedBlock) -> anyhow::Result<bool> {
+        Ok(true)
+    }
+
+    async fn release(&self) -> anyhow::Result<()> {
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl<L: LeaderLease> LeaderLease for Option<L> {
+    fn renewal_interval(&self) -> Option<Duration> {
+        self.as_ref().and_then(LeaderLease::renewal_interval)
+    }
+
+    async fn try_hold(&self, last_height: BlockHeight) -> anyhow::Result<bool> {
+        match self {
+            Some(lease) => lease.try_hold(last_height).await,
+            None => NoLeaderLease.try_hold(last_height).await,
+        }
+    }
+
+    async fn stored_block(
+        &self,
+        last_height: BlockHeight,
+    ) -> anyhow::Result<Option<SealedBlock>> {
+        match self {
+            Some(lease) => lease.stored_block(last_height).await,
+            None => NoLeaderLease.stored_block(last_height).await,
+        }
+    }
+
+    async fn reserve(
+        &self,
+        height: BlockHeight,
+        block_id: BlockId,
+    ) -> anyhow::Result<bool> {
+        match self {
+            Some(lease) => lease.reserve(height, block_id).await,
+            None => NoLeaderLease.reserve(height, block_id).await,
+        }
+    }
+
+    async fn store_block(&self, block: &SealedBlock) -> anyhow::Result<bool> {
+        match self {
+            Some(lease) => lease.store_block(block).await,
+            None => NoLeaderLease.store_block(block).await,
+        }
+    }
+
+    async fn release(&self) -> anyhow::Result<()> {
+        match self {
+            Some(lease) => lease.release().await,
+            None => NoLeaderLease.release().await,
+        }
+    }
+}
+
 pub trait GetTime: Send + Sync {
     fn now(&self) -> Tai64;
 }
@@ -24,6 +24,7 @@ use crate::{
         BlockProducer,
         BlockSigner,
         GetTime,
+        LeaderLease,
         P2pPort,
         PredefinedBlocks,
         TransactionPool,
@@ -36,6 +37,7 @@ use crate::{
     Config,
     Trigger,
 };
+use fuel_core_chain_config::PoAV3;
 use fuel_core_services::{
     stream::{
         BoxFuture,
@@ -58,7 +60,10 @@ use fuel_core_types::{
         Transaction,
         TxId,
     },
-    fuel_types::BlockHeight,
+    fuel_types::{
+        Address,
+        BlockHeight,
+    },
     services::{
         block_importer::ImportResult,
         executor::{
@@ -72,7 +77,8 @@ use fuel_core_types::{
 };
 use serde::Serialize;
 
-pub type Service<T, B, I, S, PB, C> = ServiceRunner<MainTask<T, B, I, S, PB, C>>;
+pub type Service<T, B, I, P, S, PB, L, C> =
+    ServiceRunner<MainTask<T, B, I, P, S, PB, L, C>>;
 
 #[derive(Clone)]
 pub struct SharedState {
@@ -127,10 +133,11 @@ pub(crate) enum RequestType {
     Trigger,
 }
 
-pub struct MainTask<T, B, I, S, PB, C> {
+pub struct MainTask<T, B, I, P, S, PB, L, C> {
     signer: S,
     block_producer: B,
     block_importer: I,
+    p2p_port: P,
     txpool: T,
     tx_status_update_stream: BoxStream<TxId>,
     request_receiver: mpsc::Receiver<Request>,
@@ -139,21 +146,29 @@ pub struct MainTask<T, B, I, S, PB, C> {
     last_timestamp: Tai64,
     last_block_created: Instant,
     predefined_blocks: PB,
+    leader_lease: L,
+    /// `true` if the node holds the leader lease.
+    is_leader: bool,
+    next_lease_renewal: Instant,
     trigger: Trigger,
+    round_robin: Option<PoAV3>,
+    local_producer: Option<Address>,
     clock: C,
     /// Deadline clock, used by the triggers
     sync_task_handle: ServiceRunner<SyncTask>,
 }
 
-impl<T, B, I, S, PB, C> MainTask<T, B, I, S, PB, C>
+impl<T, B, I, P, S, PB, L, C> MainTask<T, B, I, P, S, PB, L, C>
 where
     T: TransactionPool,
     I: BlockImporter,
+    P: P2pPort,
     PB: PredefinedBlocks,
+    L: LeaderLease,
     C: GetTime,
 {
     #[allow(clippy::too_many_arguments)]
-    pub fn new<P: P2pPort>(
+    pub fn new(
         last_block: &BlockHeader,
         config: Config,
         txpool: T,
@@ -162,6 +177,7 @@ where
         p2p_port: P,
         signer: S,
         predefined_blocks: PB,
+        leader_lease: L,
         clock: C,
     ) -> Self {
         let tx_status_update_stream = txpool.transaction_status_events();
@@ -172,10 +188,12 @@ where
         let block_stream = block_importer.block_stream();
         let peer_connections_stream = p2p_port.reserved_peers_count();
 
+        let local_producer = config.signer.address().ok().flatten();
         let Config {
             min_connected_reserved_peers,
             time_until_synced,
             trigger,
+            round_robin,
             ..
         } = config;
 
@@ -194,6 +212,7 @@ where
             txpool,
             block_producer,
             block_importer,
+            p2p_port,
             tx_status_update_stream,
             request_receiver,
             shared_state: SharedState { request_sender },
@@ -201,7 +220,12 @@ where
             last_timestamp,
             last_block_created,
             predefined_blocks,
+            is_leader: leader_lease.renewal_interval().is_none(),
+            leader_lease,
+            next_lease_renewal: Instant::now(),
             trigger,
+            round_robin,
+            local_producer,
             sync_task_handle,
             clock,
         }
@@ -227,6 +251,109 @@ where
             .expect("It should be impossible to produce more blocks than u32::MAX")
     }
 
+    /// Returns `true` if the next block with the `block_time` belongs to the slot
+    /// of the local producer. Always `true` without the round-robin schedule.
+    fn is_own_slot(&self, block_time: Tai64) -> bool {
+        match &self.round_robin {
+            None => true,
+            Some(poa) => self.local_producer.is_some_and(|local| {
+                poa.producer_at(self.next_height(), self.last_timestamp, block_time)
+                    == Some(local)
+            }),
+        }
+    }
+
+    /// Renews the leader lease, or takes it over if the previous leader is gone.
+    pub(crate) async fn renew_lease(&mut self) {
+        match self.leader_lease.stored_block(self.last_height).await {
+            Ok(Some(block)) => self.import_stored_block(block).await,
+            Ok(None) => {}
+            Err(err) => {
+                tracing::error!("Failed to read the block stored in the lease: {:?}", err)
+            }
+        }
+
+        let was_leader = self.is_leader;
+        self.is_leader = match self.leader_lease.try_hold(self.last_height).await {
+            Ok(is_leader) => is_leader,
+            Err(err) => {
+                tracing::error!("Failed to renew the leader lease: {:?}", err);
+                false
+            }
+        };
+        match (was_leader, self.is_leader) {
+            (false, true) => tracing::info!("Acquired the leader lease"),
+            (true, false) => tracing::warn!("Lost the leader lease"),
+            _ => {}
+        }
+
+        let interval = self.leader_lease.renewal_interval().unwrap_or_default();
+        self.next_lease_renewal = Instant::now()
+            .checked_add(interval)
+            .unwrap_or(self.next_lease_renewal);
+    }
+
+    /// Imports and publishes the block that the previous leader signed and stored
+    /// in the lease, but may have failed to publish.
+    async fn import_stored_block(&mut self, block: SealedBlock) {
+        tracing::info!("Importing the block stored in the leader lease");
+        if let Err(err) = self.block_importer.execute_and_commit(block.clone()).await {
+            tracing::warn!("Failed to import the block stored in the lease: {:?}", err);
+            return
+        }
+        let header = block.entity.header();
+        self.last_height = *header.height();
+        self.last_timestamp = header.time();
+        self.last_block_created = Instant::now();
+        self.broadcast_block(block);
+    }
+
+    /// Reserves the height of the `block` in the leader lease right before signing it,
+    /// so the standby never signs a conflicting block at the same height.
+    async fn reserve_height(&mut self, block: &Block) -> anyhow::Result<()> {
+        let height = *block.header().height();
+        if !self.leader_lease.reserve(height, block.id()).await? {
+            self.is_leader = false;
+            return Err(anyhow!(
+                "The node doesn't hold the leader lease or the height {height} is reserved"
+            ))
+        }
+        Ok(())
+    }
+
+    /// Stores the signed `block` in the leader lease right before publishing it,
+    /// so the standby can publish it if the node fails.
+    async fn store_block(&mut self, block: &SealedBlock) -> anyhow::Result<()> {
+        if !self.leader_lease.store_block(block).await? {
+            self.is_leader = false;
+            return Err(anyhow!("The node doesn't hold the leader lease"))
+        }
+        Ok(())
+    }
+
+    /// Gossip the produced block to peers, so they can import it without
+    /// waiting for the sync task to pull it.
+    fn broadcast_block(&self, block: SealedBlock) {
+        if let Err(err) = self.p2p_port.broadcast_block(Arc::new(block)) {
+            tracing::warn!("Failed to broadcast the produced block: {:?}", err);
+        }
+    }
+
+    /// Postpones the `deadline` until the next slot of the local producer.
+    /// Returns `None` if the local producer doesn't have slots.
+    fn next_own_slot(&self, deadline: Instant) -> Option<Instant> {
+        let Some(poa) = &self.round_robin else {
+            return Some(deadline)
+        };
+        let local = self.local_producer?;
+        let until_deadline = deadline.saturating_duration_since(Instant::now());
+        let time = increase_time(self.clock.now(), until_deadline).ok()?;
+        let slot_start =
+            poa.next_slot_of(&local, self.next_height(), self.last_timestamp, time)?;
+        let delay = Duration::from_secs(slot_start.0.saturating_sub(time.0));
+        deadline.checked_add(delay)
+    }
+
     fn next_time(&self, request_type: RequestType) -> anyhow::Result<Tai64> {
         match request_type {
             RequestType::Manual => match self.trigger {
@@ -237,6 +364,10 @@ where
                 Trigger::Interval { block_time } => {
                     increase_time(self.last_timestamp, block_time)
                 }
+                Trigger::Hybrid { min_block_time, .. } => {
+                    let duration = self.last_block_created.elapsed().max(min_block_time);
+                    increase_time(self.last_timestamp, duration)
+                }
             },
             RequestType::Trigger => {
                 let now = self.clock.now();
@@ -250,13 +381,15 @@ where
     }
 }
 
-impl<T, B, I, S, PB, C> MainTask<T, B, I, S, PB, C>
+impl<T, B, I, P, S, PB, L, C> MainTask<T, B, I, P, S, PB, L, C>
 where
     T: TransactionPool,
     B: BlockProducer,
     I: BlockImporter,
+    P: P2pPort,
     S: BlockSigner,
     PB: PredefinedBlocks,
+    L: LeaderLease,
     C: GetTime,
 {
     // Request the block producer to make a new block, and return it when ready
@@ -272,12 +405,20 @@ where
     }
 
     pub(crate) async fn produce_next_block(&mut self) -> anyhow::Result<()> {
-        self.produce_block(
-            self.next_height(),
-            self.next_time(RequestType::Trigger)?,
-            TransactionsSource::TxPool,
-        )
-        .await
+        let block_time = self.next_time(RequestType::Trigger)?;
+        if !self.is_leader {
+            tracing::debug!("Skipping the block production on the standby");
+            self.last_block_created = Instant::now();
+            return Ok(())
+        }
+        if !self.is_own_slot(block_time) {
+            tracing::debug!("Skipping the block production outside of the own slot");
+            // Wait for the next trigger instead of retrying right away.
+            self.last_block_created = Instant::now();
+            return Ok(())
+        }
+        self.produce_block(self.next_height(), block_time, TransactionsSource::TxPool)
+            .await
     }
 
     async fn produce_manual_blocks(
@@ -329,6 +470,13 @@ where
             return Err(anyhow!("The block timestamp should monotonically increase"))
         }
 
+        if !self.is_own_slot(block_time) {
+            return Err(anyhow!(
+                "The block timestamp {} is outside of the slots of the producer",
+                block_time.0
+            ))
+        }
+
         // Ask the block producer to create the block
         let (
             ExecutionResult {
@@ -355,21 +503,22 @@ where
         self.txpool.remove_txs(tx_ids_to_remove);
 
         // Sign the block and seal it
+        self.reserve_height(&block).await?;
         let seal = self.signer.seal_block(&block).await?;
         let block = SealedBlock {
             entity: block,
             consensus: seal,
         };
-
-        block.entity.header().time();
+        self.store_block(&block).await?;
 
         // Import the sealed block
         self.block_importer
             .commit_result(Uncommitted::new(
-                ImportResult::new_from_local(block, tx_status, events),
+                ImportResult::new_from_local(block.clone(), tx_status, events),
                 changes,
             ))
             .await?;
+        self.broadcast_block(block);
 
         // Update last block time
         self.last_height = height;
@@ -417,11 +566,13 @@ where
         }
 
         // Sign the block and seal it
+        self.reserve_height(&block).await?;
         let seal = self.signer.seal_block(&block).await?;
         let sealed_block = SealedBlock {
             entity: block,
             consensus: seal,
         };
+        self.store_block(&sealed_block).await?;
         // Import the sealed block
         self.block_importer
             .commit_result(Uncommitted::new(
@@ -434,10 +585,18 @@ where
         self.last_height = *sealed_block.entity.header().height();
         self.last_timestamp = sealed_block.entity.header().time();
         self.last_block_created = last_block_created;
+        self.broadcast_block(sealed_block);
 
         Ok(())
     }
 
+    /// Returns `true` if the pending transactions consume enough gas
+    /// to produce the block before the `max_block_time` in the `Hybrid` mode.
+    fn gas_threshold_reached(&self, gas_threshold: u64) -> bool {
+        self.txpool.pending_number() > 0
+            && self.txpool.total_consumable_gas() >= gas_threshold
+    }
+
     pub(crate) async fn on_txpool_event(&mut self) -> anyhow::Result<()> {
         match self.trigger {
             Trigger::Instant => {
@@ -448,6 +607,19 @@ where
                 }
                 Ok(())
             }
+            Trigger::Hybrid {
+                gas_threshold,
+                min_block_time,
+                ..
+            } => {
+                // Before the `min_block_time` elapses, the timer produces the block.
+                if self.gas_threshold_reached(gas_threshold)
+                    && self.last_block_created.elapsed() >= min_block_time
+                {
+                    self.produce_next_block().await?;
+                }
+                Ok(())
+            }
             Trigger::Never | Trigger::Interval { .. } => Ok(()),
         }
     }
@@ -457,8 +629,8 @@ where
             Trigger::Instant | Trigger::Never => {
                 unreachable!("Timer is never set in this mode");
             }
-            // In the Interval mode the timer expires only when a new block should be created.
-            Trigger::Interval { .. } => {
+            // In the Interval and Hybrid modes the timer expires only when a new block should be created.
+            Trigger::Interval { .. } | Trigger::Hybrid { .. } => {
                 self.produce_next_block().await?;
                 Ok(())
             }
@@ -482,14 +654,14 @@ struct PredefinedBlockWithSkippedTransactions {
 }
 
 #[async_trait::async_trait]
-impl<T, B, I, S, PB, C> RunnableService for MainTask<T, B, I, S, PB, C>
+impl<T, B, I, P, S, PB, L, C> RunnableService for MainTask<T, B, I, P, S, PB, L, C>
 where
     Self: RunnableTask,
 {
     const NAME: &'static str = "PoA";
 
     type SharedData = SharedState;
-    type Task = MainTask<T, B, I, S, PB, C>;
+    type Task = MainTask<T, B, I, P, S, PB, L, C>;
     type TaskParams = ();
 
     fn shared_data(&self) -> Self::SharedData {
@@ -505,7 +677,7 @@ where
 
         match self.trigger {
             Trigger::Never | Trigger::Instant => {}
-            Trigger::Interval { .. } => {
+            Trigger::Interval { .. } | Trigger::Hybrid { .. } => {
                 return Ok(Self {
                     last_block_created: Instant::now(),
                     ..self
@@ -518,13 +690,15 @@ where
 }
 
 #[async_trait::async_trait]
-impl<T, B, I, S, PB, C> RunnableTask for MainTask<T, B, I, S, PB, C>
+impl<T, B, I, P, S, PB, L, C> RunnableTask for MainTask<T, B, I, P, S, PB, L, C>
 where
     T: TransactionPool,
     B: BlockProducer,
     I: BlockImporter,
+    P: P2pPort,
     S: BlockSigner,
     PB: PredefinedBlocks,
+    L: LeaderLease,
     C: GetTime,
 {
     async fn run(&mut self, watcher: &mut StateWatcher) -> anyhow::Result<bool> {
@@ -552,20 +726,49 @@ where
         }
 
         let next_height = self.next_height();
-        let maybe_block = self.predefined_blocks.get_block(&next_height)?;
+        let maybe_block = if self.is_leader {
+            self.predefined_blocks.get_block(&next_height)?
+        } else {
+            None
+        };
         if let Some(block) = maybe_block {
             self.produce_predefined_block(&block).await?;
             should_continue = true;
             return Ok(should_continue)
         }
 
-        let next_block_production: BoxFuture<()> = match self.trigger {
-            Trigger::Never | Trigger::Instant => Box::pin(core::future::pending()),
-            Trigger::Interval { block_time } => Box::pin(sleep_until(
-                self.last_block_created
+        let block_time = match self.trigger {
+            Trigger::Never | Trigger::Instant => None,
+            Trigger::Interval { block_time } => Some(block_time),
+            Trigger::Hybrid {
+                gas_threshold,
+                min_block_time,
+                max_block_time,
+            } => {
+                if self.gas_threshold_reached(gas_threshold) {
+                    Some(min_block_time)
+                } else {
+                    Some(max_block_time)
+                }
+            }
+        };
+        let next_block_production: BoxFuture<()> = match block_time {
+            None => Box::pin(core::future::pending()),
+            Some(block_time) => {
+                let deadline = self
+                    .last_block_created
                     .checked_add(block_time)
-                    .ok_or(anyhow!("Time exceeds system limits"))?,
-            )),
+                    .ok_or(anyhow!("Time exceeds system limits"))?;
+                match self.next_own_slot(deadline) {
+                    Some(deadline) => Box::pin(sleep_until(deadline)),
+                    None => Box::pin(core::future::pending()),
+                }
+            }
+        };
+
+        let lease_renewal: BoxFuture<()> = match self.leader_lease.renewal_interval() {
+            None => Box::pin(core::future::pending()),
+            Some(_) => Box::pin(sleep_until(self.next_lease_renewal)),
         };
 
         tokio::select! {
@@ -573,6 +776,10 @@ where
             _ = watcher.while_started() => {
                 should_continue = false;
             }
+            _ = lease_renewal => {
+                self.renew_lease().await;
+                should_continue = true;
+            }
             request = self.request_receiver.recv() => {
                 if let Some(request) = request {
                     match request {
@@ -618,13 +825,18 @@ where
 
     async fn shutdown(self) -> anyhow::Result<()> {
         tracing::info!("PoA MainTask shutting down");
+        if self.is_leader {
+            if let Err(err) = self.leader_lease.release().await {
+                tracing::warn!("Failed to release the leader lease: {:?}", err);
+            }
+        }
         self.sync_task_handle.stop_and_await().await?;
         Ok(())
     }
 }
 
 #[allow(clippy::too_many_arguments)]
-pub fn new_service<T, B, I, P, S, PB, C>(
+pub fn new_service<T, B, I, P, S, PB, L, C>(
     last_block: &BlockHeader,
     config: Config,
     txpool: T,
@@ -633,14 +845,16 @@ pub fn new_service<T, B, I, P, S, PB, C>(
     p2p_port: P,
     block_signer: S,
     predefined_blocks: PB,
+    leader_lease: L,
     clock: C,
-) -> Service<T, B, I, S, PB, C>
+) -> Service<T, B, I, P, S, PB, L, C>
 where
     T: TransactionPool + 'static,
     B: BlockProducer + 'static,
     I: BlockImporter + 'static,
     S: BlockSigner + 'static,
     PB: PredefinedBlocks + 'static,
+    L: LeaderLease + 'static,
     P: P2pPort,
     C: GetTime,
 {
@@ -653,6 +867,7 @@ where
         p2p_port,
         block_signer,
         predefined_blocks,
+        leader_lease,
         clock,
     ))
 }
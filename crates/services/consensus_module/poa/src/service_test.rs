@@ -10,8 +10,10 @@ use crate::{
         InMemoryPredefinedBlocks,
         MockBlockImporter,
         MockBlockProducer,
+        MockLeaderLease,
         MockP2pPort,
         MockTransactionPool,
+        NoLeaderLease,
         TransactionsSource,
     },
     service::MainTask,
@@ -105,6 +107,7 @@ fn generate_p2p_port() -> MockP2pPort {
     p2p_port
         .expect_reserved_peers_count()
         .returning(move || Box::pin(tokio_stream::pending()));
+    p2p_port.expect_broadcast_block().returning(|_| Ok(()));
 
     p2p_port
 }
@@ -190,6 +193,7 @@ impl TestContextBuilder {
             p2p_port,
             FakeBlockSigner { succeeds: true },
             predefined_blocks,
+            NoLeaderLease,
             watch,
         );
         service.start().unwrap();
@@ -223,8 +227,10 @@ struct TestContext {
         MockTransactionPool,
         MockBlockProducer,
         MockBlockImporter,
+        MockP2pPort,
         FakeBlockSigner,
         InMemoryPredefinedBlocks,
+        NoLeaderLease,
         test_time::Watch,
     >,
     time: TestTime,
@@ -412,12 +418,277 @@ async fn remove_skipped_transactions() {
         p2p_port,
         FakeBlockSigner { succeeds: true },
         predefined_blocks,
+        NoLeaderLease,
         time.watch(),
     );
 
     assert!(task.produce_next_block().await.is_ok());
 }
 
+#[tokio::test]
+async fn produced_block_is_broadcast_after_commit() {
+    let mut block_producer = MockBlockProducer::default();
+    block_producer
+        .expect_produce_and_execute_block()
+        .times(1)
+        .returning(|_, _, _| {
+            Ok(UncommittedResult::new(
+                ExecutionResult {
+                    block: Default::default(),
+                    skipped_transactions: Default::default(),
+                    tx_status: Default::default(),
+                    events: Default::default(),
+                },
+                Default::default(),
+            ))
+        });
+
+    let committed = Arc::new(StdMutex::new(false));
+    let mut block_importer = MockBlockImporter::default();
+    block_importer.expect_commit_result().times(1).returning({
+        let committed = committed.clone();
+        move |_| {
+            *committed.lock().unwrap() = true;
+            Ok(())
+        }
+    });
+    block_importer
+        .expect_block_stream()
+        .returning(|| Box::pin(tokio_stream::pending()));
+
+    let mut txpool = MockTransactionPool::no_tx_updates();
+    txpool.expect_remove_txs().returning(|_| vec![]);
+
+    let mut p2p_port = MockP2pPort::default();
+    p2p_port
+        .expect_reserved_peers_count()
+        .returning(move || Box::pin(tokio_stream::pending()));
+    p2p_port
+        .expect_broadcast_block()
+        .times(1)
+        .returning(move |_| {
+            assert!(
+                *committed.lock().unwrap(),
+                "The block should be broadcast only after the commit"
+            );
+            Ok(())
+        });
+
+    let config = Config {
+        trigger: Trigger::Instant,
+        signer: SignMode::Key(test_signing_key()),
+        metrics: false,
+        ..Default::default()
+    };
+
+    let mut task = MainTask::new(
+        &BlockHeader::new_block(BlockHeight::from(1u32), Tai64::now()),
+        config,
+        txpool,
+        block_producer,
+        block_importer,
+        p2p_port,
+        FakeBlockSigner { succeeds: true },
+        InMemoryPredefinedBlocks::from(HashMap::new()),
+        NoLeaderLease,
+        TestTime::at_unix_epoch().watch(),
+    );
+
+    assert!(task.produce_next_block().await.is_ok());
+}
+
+fn leader_lease_task(
+    block_producer: MockBlockProducer,
+    block_importer: MockBlockImporter,
+    leader_lease: MockLeaderLease,
+) -> MainTask<
+    MockTransactionPool,
+    MockBlockProducer,
+    MockBlockImporter,
+    MockP2pPort,
+    FakeBlockSigner,
+    InMemoryPredefinedBlocks,
+    MockLeaderLease,
+    test_time::Watch,
+> {
+    let config = Config {
+        trigger: Trigger::Instant,
+        signer: SignMode::Key(Secret::new(default_consensus_dev_key().into())),
+        metrics: false,
+        ..Default::default()
+    };
+    let mut txpool = MockTransactionPool::no_tx_updates();
+    txpool.expect_remove_txs().returning(|_| vec![]);
+
+    MainTask::new(
+        &BlockHeader::new_block(BlockHeight::from(1u32), Tai64::now()),
+        config,
+        txpool,
+        block_producer,
+        block_importer,
+        generate_p2p_port(),
+        FakeBlockSigner { succeeds: true },
+        InMemoryPredefinedBlocks::from(HashMap::new()),
+        leader_lease,
+        TestTime::at_unix_epoch().watch(),
+    )
+}
+
+#[tokio::test]
+async fn standby_does_not_produce_blocks_until_it_holds_the_lease() {
+    let mut block_producer = MockBlockProducer::default();
+    block_producer.expect_produce_and_execute_block().times(0);
+    let mut block_importer = MockBlockImporter::default();
+    block_importer
+        .expect_block_stream()
+        .returning(|| Box::pin(tokio_stream::pending()));
+    let mut leader_lease = MockLeaderLease::default();
+    leader_lease
+        .expect_renewal_interval()
+        .returning(|| Some(Duration::from_secs(1)));
+
+    let mut task = leader_lease_task(block_producer, block_importer, leader_lease);
+
+    assert!(task.produce_next_block().await.is_ok());
+}
+
+#[tokio::test]
+async fn block_is_not_committed_if_the_height_is_not_reserved() {
+    let mut block_producer = MockBlockProducer::default();
+    block_producer
+        .expect_produce_and_execute_block()
+        .times(1)
+        .returning(|_, _, _| {
+            Ok(UncommittedResult::new(
+                ExecutionResult {
+                    block: Default::default(),
+                    skipped_transactions: Default::default(),
+                    tx_status: Default::default(),
+                    events: Default::default(),
+                },
+                Default::default(),
+            ))
+        });
+    let mut block_importer = MockBlockImporter::default();
+    block_importer.expect_commit_result().times(0);
+    block_importer
+        .expect_block_stream()
+        .returning(|| Box::pin(tokio_stream::pending()));
+    let mut leader_lease = MockLeaderLease::default();
+    leader_lease.expect_renewal_interval().returning(|| None);
+    leader_lease
+        .expect_reserve()
+        .times(1)
+        .returning(|_, _| Ok(false));
+
+    let mut task = leader_lease_task(block_producer, block_importer, leader_lease);
+
+    assert!(task.produce_next_block().await.is_err());
+    // The node lost the lease and doesn't try to produce the next block.
+    assert!(task.produce_next_block().await.is_ok());
+}
+
+#[tokio::test]
+async fn block_is_not_committed_if_it_is_not_stored_in_the_lease() {
+    let mut block_producer = MockBlockProducer::default();
+    block_producer
+        .expect_produce_and_execute_block()
+        .times(1)
+        .returning(|_, _, _| {
+            Ok(UncommittedResult::new(
+                ExecutionResult {
+                    block: Default::default(),
+                    skipped_transactions: Default::default(),
+                    tx_status: Default::default(),
+                    events: Default::default(),
+                },
+                Default::default(),
+            ))
+        });
+    let mut block_importer = MockBlockImporter::default();
+    block_importer.expect_commit_result().times(0);
+    block_importer
+        .expect_block_stream()
+        .returning(|| Box::pin(tokio_stream::pending()));
+    let mut leader_lease = MockLeaderLease::default();
+    leader_lease.expect_renewal_interval().returning(|| None);
+    leader_lease.expect_reserve().returning(|_, _| Ok(true));
+    leader_lease
+        .expect_store_block()
+        .times(1)
+        .returning(|_| Ok(false));
+
+    let mut task = leader_lease_task(block_producer, block_importer, leader_lease);
+
+    assert!(task.produce_next_block().await.is_err());
+    // The node lost the lease and doesn't try to produce the next block.
+    assert!(task.produce_next_block().await.is_ok());
+}
+
+#[tokio::test]
+async fn lease_renewal_imports_the_block_stored_by_the_previous_leader() {
+    let mut stored_block = SealedBlock::default();
+    stored_block
+        .entity
+        .header_mut()
+        .set_block_height(2u32.into());
+    let mut block_importer = MockBlockImporter::default();
+    block_importer
+        .expect_block_stream()
+        .returning(|| Box::pin(tokio_stream::pending()));
+    let expected_block = stored_block.clone();
+    block_importer
+        .expect_execute_and_commit()
+        .times(1)
+        .withf(move |block| block == &expected_block)
+        .returning(|_| Ok(()));
+    let mut leader_lease = MockLeaderLease::default();
+    leader_lease
+        .expect_renewal_interval()
+        .returning(|| Some(Duration::from_secs(1)));
+    leader_lease
+        .expect_stored_block()
+        .times(1)
+        .returning(move |_| Ok(Some(stored_block.clone())));
+    leader_lease
+        .expect_try_hold()
+        .times(1)
+        .withf(|last_height| *last_height == 2u32.into())
+        .returning(|_| Ok(true));
+
+    block_importer
+        .expect_commit_result()
+        .times(1)
+        .returning(|_| Ok(()));
+    leader_lease.expect_reserve().returning(|_, _| Ok(true));
+    leader_lease.expect_store_block().returning(|_| Ok(true));
+    // The node continues on top of the stored block.
+    let mut block_producer = MockBlockProducer::default();
+    block_producer
+        .expect_produce_and_execute_block()
+        .times(1)
+        .withf(|height, _, _| *height == 3u32.into())
+        .returning(|_, _, _| {
+            Ok(UncommittedResult::new(
+                ExecutionResult {
+                    block: Default::default(),
+                    skipped_transactions: Default::default(),
+                    tx_status: Default::default(),
+                    events: Default::default(),
+                },
+                Default::default(),
+            ))
+        });
+
+    let mut task = leader_lease_task(block_producer, block_importer, leader_lease);
+
+    // When
+    task.renew_lease().await;
+
+    // Then
+    assert!(task.produce_next_block().await.is_ok());
+}
+
 #[tokio::test]
 async fn does_not_produce_when_txpool_empty_in_instant_mode() {
     // verify the PoA service doesn't trigger empty blocks to be produced when there are
@@ -468,6 +739,7 @@ async fn does_not_produce_when_txpool_empty_in_instant_mode() {
         p2p_port,
         FakeBlockSigner { succeeds: true },
         predefined_blocks,
+        NoLeaderLease,
         time.watch(),
     );
 
@@ -587,6 +859,7 @@ async fn consensus_service__run__will_include_sequential_predefined_blocks_befor
         generate_p2p_port(),
         FakeBlockSigner { succeeds: true },
         InMemoryPredefinedBlocks::new(blocks_map),
+        NoLeaderLease,
         time.watch(),
     );
 
@@ -651,6 +924,7 @@ async fn consensus_service__run__will_insert_predefined_blocks_in_correct_order(
         generate_p2p_port(),
         FakeBlockSigner { succeeds: true },
         InMemoryPredefinedBlocks::new(predefined_blocks_map),
+        NoLeaderLease,
         time.watch(),
     );
 
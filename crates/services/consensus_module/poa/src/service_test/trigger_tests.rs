@@ -1,3 +1,4 @@
+use fuel_core_chain_config::PoAV3;
 use mockall::Sequence;
 use tokio::{
     sync::Notify,
@@ -14,6 +15,11 @@ async fn clean_startup_shutdown_each_trigger() -> anyhow::Result<()> {
         Trigger::Interval {
             block_time: Duration::new(1, 0),
         },
+        Trigger::Hybrid {
+            gas_threshold: 1,
+            min_block_time: Duration::new(1, 0),
+            max_block_time: Duration::new(2, 0),
+        },
     ] {
         let mut ctx_builder = TestContextBuilder::new();
         ctx_builder.with_config(Config {
@@ -334,6 +340,118 @@ async fn interval_trigger_doesnt_react_to_full_txpool() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn hybrid_trigger_waits_for_min_block_time_when_gas_threshold_is_reached(
+) -> anyhow::Result<()> {
+    let mut ctx = DefaultContext::new(Config {
+        trigger: Trigger::Hybrid {
+            gas_threshold: 1,
+            min_block_time: Duration::new(2, 0),
+            max_block_time: Duration::new(10, 0),
+        },
+        signer: SignMode::Key(test_signing_key()),
+        metrics: false,
+        ..Default::default()
+    });
+    ctx.status_sender.send_replace(Some(TxId::zeroed()));
+
+    // Make sure blocks are not produced before the min block time
+    time::sleep(Duration::new(1, 0)).await;
+    assert!(matches!(
+        ctx.block_import.try_recv(),
+        Err(broadcast::error::TryRecvError::Empty)
+    ));
+
+    // The txpool is still full, so blocks are produced every min block time
+    for _ in 0..3 {
+        time::sleep(Duration::new(2, 0)).await;
+        assert!(ctx.block_import.try_recv().is_ok());
+        assert!(matches!(
+            ctx.block_import.try_recv(),
+            Err(broadcast::error::TryRecvError::Empty)
+        ));
+    }
+
+    // Stop
+    ctx.test_ctx.service.stop_and_await().await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn hybrid_trigger_produces_empty_blocks_every_max_block_time() -> anyhow::Result<()>
+{
+    let mut ctx = DefaultContext::new(Config {
+        trigger: Trigger::Hybrid {
+            gas_threshold: u64::MAX,
+            min_block_time: Duration::new(1, 0),
+            max_block_time: Duration::new(4, 0),
+        },
+        signer: SignMode::Key(test_signing_key()),
+        metrics: false,
+        ..Default::default()
+    });
+    ctx.status_sender.send_replace(Some(TxId::zeroed()));
+
+    // The gas threshold is not reached, so the block waits for the max block time
+    time::sleep(Duration::new(3, 0)).await;
+    assert!(matches!(
+        ctx.block_import.try_recv(),
+        Err(broadcast::error::TryRecvError::Empty)
+    ));
+
+    time::sleep(Duration::new(2, 0)).await;
+    assert!(ctx.block_import.try_recv().is_ok());
+    assert!(matches!(
+        ctx.block_import.try_recv(),
+        Err(broadcast::error::TryRecvError::Empty)
+    ));
+
+    // Stop
+    ctx.test_ctx.service.stop_and_await().await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn hybrid_trigger_produces_block_as_soon_as_gas_threshold_is_reached(
+) -> anyhow::Result<()> {
+    let mut ctx = DefaultContext::new(Config {
+        trigger: Trigger::Hybrid {
+            gas_threshold: 1,
+            min_block_time: Duration::new(1, 0),
+            max_block_time: Duration::new(10, 0),
+        },
+        signer: SignMode::Key(test_signing_key()),
+        metrics: false,
+        ..Default::default()
+    });
+    // Start with the empty txpool
+    ctx.txs.lock().unwrap().clear();
+
+    time::sleep(Duration::new(2, 0)).await;
+    assert!(matches!(
+        ctx.block_import.try_recv(),
+        Err(broadcast::error::TryRecvError::Empty)
+    ));
+
+    // When
+    {
+        let tx = make_tx(&mut ctx.rng);
+        ctx.txs.lock().unwrap().push(tx);
+        ctx.status_sender.send_replace(Some(TxId::zeroed()));
+    }
+    time::sleep(Duration::from_millis(1)).await;
+
+    // Then
+    assert!(ctx.block_import.try_recv().is_ok());
+
+    // Stop
+    ctx.test_ctx.service.stop_and_await().await?;
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn interval_trigger_produces_blocks_in_the_future_when_time_is_lagging() {
     // Given
@@ -474,3 +592,54 @@ async fn interval_trigger_produces_blocks_in_the_future_when_time_rewinds() {
     // similarly to how it works when time is lagging.
     assert_eq!(second_block_time, start_time + block_time.as_secs() * 2);
 }
+
+#[test]
+fn hybrid_trigger_requires_min_block_time_not_above_max_block_time() {
+    let second = Duration::from_secs(1);
+
+    assert!(Trigger::hybrid(1, second, second).is_ok());
+    assert!(Trigger::hybrid(1, second * 2, second).is_err());
+}
+
+#[tokio::test]
+async fn interval_trigger_produces_blocks_only_in_own_slots() {
+    let local = SignMode::Key(test_signing_key())
+        .address()
+        .unwrap()
+        .unwrap();
+    let other = Address::from([1; 32]);
+    let round_robin = PoAV3::new(vec![other, local], 3).unwrap();
+    let mut ctx = DefaultContext::new(Config {
+        trigger: Trigger::Interval {
+            block_time: Duration::new(1, 0),
+        },
+        signer: SignMode::Key(test_signing_key()),
+        metrics: false,
+        round_robin: Some(round_robin.clone()),
+        ..Default::default()
+    });
+    let genesis_time = ctx.now();
+
+    // When
+    let mut blocks = vec![];
+    for _ in 0..24 {
+        time::sleep(Duration::from_millis(500)).await;
+        ctx.advance_time_with_tokio();
+        while let Ok(block) = ctx.block_import.try_recv() {
+            blocks.push(block);
+        }
+    }
+
+    // Then
+    assert!(blocks.len() >= 2);
+    let mut prev_time = genesis_time;
+    for (height, block) in (2u32..).zip(blocks) {
+        let time = block.entity.header().time();
+        assert_eq!(
+            round_robin.producer_at(height.into(), prev_time, time),
+            Some(local)
+        );
+        prev_time = time;
+    }
+    assert_eq!(ctx.test_ctx.stop().await, State::Stopped);
+}
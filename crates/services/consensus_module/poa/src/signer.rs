@@ -7,8 +7,6 @@ use aws_sdk_kms::{
         SigningAlgorithmSpec,
     },
 };
-#[cfg(feature = "aws-kms")]
-use fuel_core_types::fuel_crypto::Message;
 use fuel_core_types::{
     blockchain::{
         block::Block,
@@ -18,7 +16,10 @@ use fuel_core_types::{
         },
         primitives::SecretKeyWrapper,
     },
-    fuel_crypto::PublicKey,
+    fuel_crypto::{
+        Message,
+        PublicKey,
+    },
     fuel_tx::{
         Address,
         Input,
@@ -31,6 +32,9 @@ use fuel_core_types::{
 };
 use std::ops::Deref;
 
+#[cfg(feature = "remote-signer")]
+pub mod remote;
+
 /// How the block is signed
 #[derive(Clone, Debug)]
 pub enum SignMode {
@@ -45,6 +49,9 @@ pub enum SignMode {
         client: aws_sdk_kms::Client,
         cached_public_key_bytes: Vec<u8>,
     },
+    /// Sign using the remote signer
+    #[cfg(feature = "remote-signer")]
+    Remote(remote::RemoteSigner),
 }
 
 impl SignMode {
@@ -57,8 +64,17 @@ impl SignMode {
     pub async fn seal_block(&self, block: &Block) -> anyhow::Result<Consensus> {
         let block_hash = block.id();
         let message = block_hash.into_message();
-
         let poa_signature = match self {
+            #[cfg(feature = "remote-signer")]
+            SignMode::Remote(signer) => signer.sign_block(block).await?,
+            _ => self.sign_message(message).await?,
+        };
+        Ok(Consensus::PoA(PoAConsensus::new(poa_signature)))
+    }
+
+    /// Sign an arbitrary message
+    pub async fn sign_message(&self, message: Message) -> anyhow::Result<Signature> {
+        let signature = match self {
             SignMode::Unavailable => return Err(anyhow!("no PoA signing key configured")),
             SignMode::Key(key) => {
                 let signing_key = key.expose_secret().deref();
@@ -70,8 +86,27 @@ impl SignMode {
                 client,
                 cached_public_key_bytes,
             } => sign_with_kms(client, key_id, cached_public_key_bytes, message).await?,
+            #[cfg(feature = "remote-signer")]
+            SignMode::Remote(_) => {
+                return Err(anyhow!(
+                    "The remote signer signs only blocks, \
+                    because arbitrary messages bypass the slashing protection"
+                ))
+            }
         };
-        Ok(Consensus::PoA(PoAConsensus::new(poa_signature)))
+        Ok(signature)
+    }
+
+    /// Returns `true` if the signer can sign arbitrary messages, like consensus votes.
+    pub fn can_sign_messages(&self) -> bool {
+        match self {
+            SignMode::Unavailable => false,
+            SignMode::Key(_) => true,
+            #[cfg(feature = "aws-kms")]
+            SignMode::Kms { .. } => true,
+            #[cfg(feature = "remote-signer")]
+            SignMode::Remote(_) => false,
+        }
     }
 
     /// Returns the public key of the block producer, if any
@@ -93,6 +128,9 @@ impl SignMode {
                     k256::PublicKey::from_public_key_der(cached_public_key_bytes)?;
                 Ok(Some(PublicKey::from(k256_public_key)))
             }
+
+            #[cfg(feature = "remote-signer")]
+            SignMode::Remote(signer) => Ok(Some(signer.public_key())),
         }
     }
 
@@ -0,0 +1,335 @@
+//! The client of the remote signer with the web3signer-style HTTP API:
+//! - `GET {url}/api/v1/fuel/publicKeys` returns the list of the available public keys.
+//! - `POST {url}/api/v1/fuel/sign/{public_key}` signs the `data` from the JSON body
+//!   and returns the signature as the hex string.
+
+use anyhow::{
+    anyhow,
+    Context,
+};
+use fuel_core_chain_config::PoAV2;
+use fuel_core_types::{
+    blockchain::block::Block,
+    fuel_crypto::{
+        Message,
+        PublicKey,
+    },
+    fuel_tx::{
+        Address,
+        Input,
+    },
+    fuel_types::{
+        BlockHeight,
+        Bytes32,
+        Bytes64,
+    },
+    fuel_vm::Signature,
+};
+use serde::{
+    Deserialize,
+    Serialize,
+};
+use std::{
+    fs::File,
+    io::Write,
+    path::{
+        Path,
+        PathBuf,
+    },
+    str::FromStr,
+    sync::Arc,
+    time::Duration,
+};
+use tokio::sync::Mutex;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RemoteSignerConfig {
+    /// The base URL of the remote signer.
+    pub url: String,
+    /// The public key used to sign blocks.
+    pub public_key: PublicKey,
+    /// The timeout of each request to the remote signer.
+    pub timeout: Duration,
+    /// The PEM encoded CA certificate used to verify the remote signer.
+    pub ca_certificate: Option<PathBuf>,
+    /// The PEM encoded client certificate and private key used for mTLS.
+    pub client_identity: Option<PathBuf>,
+    /// The file with the last signed block. Without it, the slashing protection
+    /// doesn't survive restarts of the node.
+    pub slashing_protection: Option<PathBuf>,
+}
+
+#[derive(Debug, Serialize)]
+struct SignRequest {
+    /// The hex encoded message.
+    data: String,
+    /// The height of the block.
+    height: u32,
+}
+
+/// The last block signed by the local producer.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct SignedBlock {
+    height: u32,
+    /// The hex encoded id of the block.
+    block_id: String,
+    /// `true` until the valid signature of the block is received. Without the signature
+    /// the block can't be published, so another block may replace it at the same height.
+    #[serde(default)]
+    pending: bool,
+}
+
+/// Refuses to sign two different blocks at the same height,
+/// or blocks below the last signed one.
+#[derive(Debug)]
+struct SlashingProtection {
+    path: Option<PathBuf>,
+    last_signed: Mutex<Option<SignedBlock>>,
+}
+
+impl SlashingProtection {
+    fn load(path: Option<PathBuf>) -> anyhow::Result<Self> {
+        let last_signed = match &path {
+            Some(path) if path.exists() => {
+                let content = std::fs::read_to_string(path)?;
+                serde_json::from_str(&content)
+                    .context("The slashing protection file is corrupted")?
+            }
+            _ => None,
+        };
+        Ok(Self {
+            path,
+            last_signed: Mutex::new(last_signed),
+        })
+    }
+
+    /// Records the block as pending before signing it. The record is persisted before
+    /// the request to the signer, so the node doesn't sign another block
+    /// at the same height after the restart.
+    async fn record(&self, height: BlockHeight, block_id: Bytes32) -> anyhow::Result<()> {
+        let block = SignedBlock {
+            height: height.into(),
+            block_id: format!("{block_id:#x}"),
+            pending: true,
+        };
+        let mut last_signed = self.last_signed.lock().await;
+        if let Some(last) = last_signed.as_ref() {
+            if last.height == block.height && last.block_id == block.block_id {
+                return Ok(())
+            }
+            let superseded = last.pending && last.height == block.height;
+            if block.height <= last.height && !superseded {
+                return Err(anyhow!(
+                    "Refusing to sign the block {} at height {}, \
+                    the block {} at height {} was already signed",
+                    block.block_id,
+                    block.height,
+                    last.block_id,
+                    last.height,
+                ))
+            }
+        }
+        self.persist(&block).await?;
+        *last_signed = Some(block);
+        Ok(())
+    }
+
+    /// Marks the recorded block as signed after receiving its signature.
+    /// Fails if another block replaced the record in the meantime.
+    async fn confirm(
+        &self,
+        height: BlockHeight,
+        block_id: Bytes32,
+    ) -> anyhow::Result<()> {
+        let mut last_signed = self.last_signed.lock().await;
+        let Some(last) = last_signed.as_ref() else {
+            return Err(anyhow!("The block at height {height} is not recorded"))
+        };
+        if last.height != u32::from(height) || last.block_id != format!("{block_id:#x}") {
+            return Err(anyhow!(
+                "The block {block_id:#x} at height {height} was replaced by the block {} at height {}",
+                last.block_id,
+                last.height,
+            ))
+        }
+        if last.pending {
+            let block = SignedBlock {
+                pending: false,
+                ..last.clone()
+            };
+            self.persist(&block).await?;
+            *last_signed = Some(block);
+        }
+        Ok(())
+    }
+
+    async fn persist(&self, block: &SignedBlock) -> anyhow::Result<()> {
+        if let Some(path) = self.path.clone() {
+            let bytes = serde_json::to_vec(block)?;
+            tokio::task::spawn_blocking(move || write_atomically(&path, &bytes))
+                .await?
+                .context("Failed to persist the slashing protection record")?;
+        }
+        Ok(())
+    }
+}
+
+/// Writes the `bytes` to the temporary file and replaces the file at the `path` with it,
+/// so a crash never leaves the file partially written.
+fn write_atomically(path: &Path, bytes: &[u8]) -> std::io::Result<()> {
+    let tmp = path.with_extension("tmp");
+    let mut file = File::create(&tmp)?;
+    file.write_all(bytes)?;
+    file.sync_all()?;
+    std::fs::rename(&tmp, path)
+}
+
+/// The signer that delegates signing to the remote service.
+#[derive(Debug, Clone)]
+pub struct RemoteSigner {
+    client: reqwest::Client,
+    url: String,
+    public_key: PublicKey,
+    /// The signing keys of the chain, used to refuse signing blocks
+    /// at heights where the key isn't the producer.
+    signing_keys: Option<PoAV2>,
+    slashing_protection: Arc<SlashingProtection>,
+}
+
+impl RemoteSigner {
+    /// Connects to the remote signer and checks that it has the configured key.
+    pub async fn connect(
+        config: RemoteSignerConfig,
+        signing_keys: Option<PoAV2>,
+    ) -> anyhow::Result<Self> {
+        let mut builder = reqwest::Client::builder()
+            .use_rustls_tls()
+            .timeout(config.timeout)
+            .connect_timeout(config.timeout);
+        if let Some(path) = &config.ca_certificate {
+            let pem = std::fs::read(path)?;
+            builder = builder.add_root_certificate(reqwest::Certificate::from_pem(&pem)?);
+        }
+        if let Some(path) = &config.client_identity {
+            let pem = std::fs::read(path)?;
+            builder = builder.identity(reqwest::Identity::from_pem(&pem)?);
+        }
+
+        let signer = Self {
+            client: builder.build()?,
+            url: config.url.trim_end_matches('/').to_string(),
+            public_key: config.public_key,
+            signing_keys,
+            slashing_protection: Arc::new(SlashingProtection::load(
+                config.slashing_protection,
+            )?),
+        };
+        let public_keys = signer.public_keys().await?;
+        if !public_keys.contains(&signer.public_key) {
+            return Err(anyhow!(
+                "The remote signer doesn't have the key {}",
+                signer.public_key
+            ))
+        }
+        Ok(signer)
+    }
+
+    /// Returns the public key cached during the connection.
+    pub fn public_key(&self) -> PublicKey {
+        self.public_key
+    }
+
+    pub fn address(&self) -> Address {
+        Input::owner(&self.public_key)
+    }
+
+    async fn public_keys(&self) -> anyhow::Result<Vec<PublicKey>> {
+        let response = self
+            .client
+            .get(format!("{}/api/v1/fuel/publicKeys", self.url))
+            .send()
+            .await?
+            .error_for_status()?;
+        let keys: Vec<String> = serde_json::from_slice(&response.bytes().await?)?;
+        keys.iter()
+            .map(|key| {
+                parse_public_key(key)
+                    .with_context(|| format!("Invalid public key {key} from the signer"))
+            })
+            .collect()
+    }
+
+    /// Signs the block id after the slashing protection checks.
+    pub async fn sign_block(&self, block: &Block) -> anyhow::Result<Signature> {
+        let block_id = block.id();
+        let height = *block.header().height();
+        if let Some(signing_keys) = &self.signing_keys {
+            let expected = signing_keys.signing_key_at(height);
+            if expected != self.address() {
+                return Err(anyhow!(
+                    "The key {} is not the signing key at height {height}, expected {expected}",
+                    self.address(),
+                ))
+            }
+        }
+        let block_id = Bytes32::new(block_id.into());
+        self.slashing_protection.record(height, block_id).await?;
+        let signature = self.sign(Message::from_bytes(*block_id), height).await?;
+        self.slashing_protection.confirm(height, block_id).await?;
+        Ok(signature)
+    }
+
+    async fn sign(
+        &self,
+        message: Message,
+        height: BlockHeight,
+    ) -> anyhow::Result<Signature> {
+        let request = SignRequest {
+            data: format!("{:#x}", Bytes32::new(*message)),
+            height: height.into(),
+        };
+        let response = self
+            .client
+            .post(format!(
+                "{}/api/v1/fuel/sign/{:#x}",
+                self.url, self.public_key
+            ))
+            .header(reqwest::header::CONTENT_TYPE, "application/json")
+            .body(serde_json::to_vec(&request)?)
+            .send()
+            .await
+            .inspect_err(|err| {
+                tracing::error!("Failed to reach the remote signer: {err:?}")
+            })?
+            .error_for_status()?;
+        let text = response.text().await?;
+        let signature = Signature::from_str(text.trim())
+            .map_err(|e| anyhow!("Invalid signature from the remote signer: {e}"))?;
+
+        // The signer is not trusted to sign with the expected key.
+        let recovered = signature.recover(&message)?;
+        if recovered != self.public_key {
+            return Err(anyhow!(
+                "The remote signer signed with the key {recovered}, expected {}",
+                self.public_key
+            ))
+        }
+        Ok(signature)
+    }
+}
+
+/// Parses the hex encoded uncompressed public key without the SEC1 tag.
+pub fn parse_public_key(s: &str) -> anyhow::Result<PublicKey> {
+    let bytes = Bytes64::from_str(s).map_err(|e| anyhow!(e))?;
+    // `PublicKey::from_str` expects the SEC1 tag, so the key is decoded by `k256`.
+    let mut sec1 = [0u8; 65];
+    sec1[0] = 0x04;
+    sec1[1..].copy_from_slice(bytes.as_ref());
+    let key = k256::PublicKey::from_sec1_bytes(&sec1)?;
+    Ok(PublicKey::from(key))
+}
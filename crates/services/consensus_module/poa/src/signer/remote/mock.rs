@@ -0,0 +1,149 @@
+//! The local remote signer for tests.
+
+use axum::{
+    extract::{
+        Extension,
+        Path,
+    },
+    http::StatusCode,
+    routing::{
+        get,
+        post,
+    },
+    Json,
+    Router,
+};
+use fuel_core_types::{
+    fuel_crypto::{
+        Message,
+        SecretKey,
+    },
+    fuel_types::Bytes32,
+    fuel_vm::Signature,
+};
+use serde::Deserialize;
+use std::{
+    collections::HashMap,
+    net::{
+        SocketAddr,
+        TcpListener,
+    },
+    str::FromStr,
+    sync::{
+        Arc,
+        Mutex,
+    },
+    time::Duration,
+};
+
+#[derive(Deserialize)]
+struct SignRequest {
+    data: String,
+    height: Option<u32>,
+}
+
+struct State {
+    keys: Vec<SecretKey>,
+    /// The key used to sign instead of the requested one.
+    wrong_key: Option<SecretKey>,
+    delay: Mutex<Duration>,
+    /// The messages signed at each height.
+    signed: Mutex<HashMap<u32, String>>,
+}
+
+/// The signer serving the `keys`. Like web3signer, it refuses to sign
+/// different blocks at the same height with `412 Precondition Failed`.
+pub struct MockRemoteSigner {
+    addr: SocketAddr,
+    state: Arc<State>,
+    handle: tokio::task::JoinHandle<()>,
+}
+
+impl MockRemoteSigner {
+    pub fn start(keys: Vec<SecretKey>) -> Self {
+        Self::start_with(keys, None, Duration::ZERO)
+    }
+
+    pub fn start_with(
+        keys: Vec<SecretKey>,
+        wrong_key: Option<SecretKey>,
+        delay: Duration,
+    ) -> Self {
+        let state = Arc::new(State {
+            keys,
+            wrong_key,
+            delay: Mutex::new(delay),
+            signed: Default::default(),
+        });
+        let app = Router::new()
+            .route("/api/v1/fuel/publicKeys", get(public_keys))
+            .route("/api/v1/fuel/sign/:identifier", post(sign))
+            .layer(Extension(state.clone()));
+
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        let server = axum::Server::from_tcp(listener)
+            .unwrap()
+            .serve(app.into_make_service());
+        let handle = tokio::spawn(async move {
+            let _ = server.await;
+        });
+        Self {
+            addr,
+            state,
+            handle,
+        }
+    }
+
+    pub fn url(&self) -> String {
+        format!("http://{}", self.addr)
+    }
+
+    /// Changes the delay of the following signing requests.
+    pub fn set_delay(&self, delay: Duration) {
+        *self.state.delay.lock().unwrap() = delay;
+    }
+}
+
+impl Drop for MockRemoteSigner {
+    fn drop(&mut self) {
+        self.handle.abort();
+    }
+}
+
+async fn public_keys(Extension(state): Extension<Arc<State>>) -> Json<Vec<String>> {
+    Json(
+        state
+            .keys
+            .iter()
+            .map(|key| format!("{:#x}", key.public_key()))
+            .collect(),
+    )
+}
+
+async fn sign(
+    Path(identifier): Path<String>,
+    Json(request): Json<SignRequest>,
+    Extension(state): Extension<Arc<State>>,
+) -> Result<String, StatusCode> {
+    let delay = *state.delay.lock().unwrap();
+    tokio::time::sleep(delay).await;
+    let key = state
+        .keys
+        .iter()
+        .find(|key| format!("{:#x}", key.public_key()) == identifier)
+        .ok_or(StatusCode::NOT_FOUND)?;
+    let data = Bytes32::from_str(&request.data).map_err(|_| StatusCode::BAD_REQUEST)?;
+
+    if let Some(height) = request.height {
+        let mut signed = state.signed.lock().unwrap();
+        let previous = signed.entry(height).or_insert_with(|| request.data.clone());
+        if previous != &request.data {
+            return Err(StatusCode::PRECONDITION_FAILED)
+        }
+    }
+
+    let key = state.wrong_key.as_ref().unwrap_or(key);
+    let signature = Signature::sign(key, &Message::from_bytes(*data));
+    Ok(format!("{signature:#x}"))
+}
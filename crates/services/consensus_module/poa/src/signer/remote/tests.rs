@@ -0,0 +1,267 @@
+#![allow(non_snake_case)]
+
+use super::{
+    mock::MockRemoteSigner,
+    parse_public_key,
+    RemoteSigner,
+    RemoteSignerConfig,
+};
+use crate::signer::SignMode;
+use fuel_core_chain_config::PoAV2;
+use fuel_core_types::{
+    blockchain::{
+        block::Block,
+        consensus::Consensus,
+    },
+    fuel_crypto::SecretKey,
+    fuel_tx::Input,
+    fuel_types::BlockHeight,
+};
+use rand::{
+    rngs::StdRng,
+    SeedableRng,
+};
+use std::{
+    collections::BTreeMap,
+    path::PathBuf,
+    time::Duration,
+};
+
+fn keys() -> (SecretKey, SecretKey) {
+    let mut rng = StdRng::seed_from_u64(2322);
+    (SecretKey::random(&mut rng), SecretKey::random(&mut rng))
+}
+
+fn config(signer: &MockRemoteSigner, key: &SecretKey) -> RemoteSignerConfig {
+    RemoteSignerConfig {
+        url: signer.url(),
+        public_key: key.public_key(),
+        timeout: Duration::from_secs(1),
+        ca_certificate: None,
+        client_identity: None,
+        slashing_protection: None,
+    }
+}
+
+fn block(height: u32, time: u64) -> Block {
+    let mut block = Block::default();
+    block.header_mut().set_block_height(height.into());
+    block
+        .header_mut()
+        .set_time(fuel_core_types::tai64::Tai64(time));
+    block
+}
+
+async fn seal(signer: &SignMode, block: &Block) -> anyhow::Result<()> {
+    let Consensus::PoA(seal) = signer.seal_block(block).await? else {
+        panic!("Expected the PoA seal");
+    };
+    let public_key = seal.signature.recover(&block.id().into_message())?;
+    assert_eq!(Some(public_key), signer.public_key()?);
+    Ok(())
+}
+
+#[test]
+fn parse_public_key__decodes_the_hex_key() {
+    let (key, _) = keys();
+    let public_key = key.public_key();
+
+    // When
+    let with_prefix = parse_public_key(&format!("{public_key:#x}")).unwrap();
+    let without_prefix = parse_public_key(&format!("{public_key:x}")).unwrap();
+
+    // Then
+    assert_eq!(with_prefix, public_key);
+    assert_eq!(without_prefix, public_key);
+    assert!(parse_public_key("0x1234").is_err());
+}
+
+#[tokio::test]
+async fn seal_block__signs_with_the_remote_key() {
+    let (key, _) = keys();
+    let mock = MockRemoteSigner::start(vec![key]);
+    let signer = RemoteSigner::connect(config(&mock, &key), None)
+        .await
+        .unwrap();
+
+    // When
+    let result = seal(&SignMode::Remote(signer), &block(1, 1)).await;
+
+    // Then
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn connect__fails_if_the_signer_does_not_have_the_key() {
+    let (key, other_key) = keys();
+    let mock = MockRemoteSigner::start(vec![other_key]);
+
+    // When
+    let result = RemoteSigner::connect(config(&mock, &key), None).await;
+
+    // Then
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn seal_block__fails_if_signed_with_another_key() {
+    let (key, other_key) = keys();
+    let mock = MockRemoteSigner::start_with(vec![key], Some(other_key), Duration::ZERO);
+    let signer = RemoteSigner::connect(config(&mock, &key), None)
+        .await
+        .unwrap();
+
+    // When
+    let result = SignMode::Remote(signer).seal_block(&block(1, 1)).await;
+
+    // Then
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn seal_block__fails_after_the_timeout() {
+    let (key, _) = keys();
+    let mock = MockRemoteSigner::start_with(vec![key], None, Duration::from_secs(5));
+    let mut config = config(&mock, &key);
+    config.timeout = Duration::from_millis(100);
+
+    // When
+    let result = RemoteSigner::connect(config, None).await;
+
+    // Then
+    // The list of keys is returned right away.
+    let signer = result.unwrap();
+    let result = SignMode::Remote(signer).seal_block(&block(1, 1)).await;
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn seal_block__refuses_to_sign_another_block_at_the_same_height() {
+    let (key, _) = keys();
+    let mock = MockRemoteSigner::start(vec![key]);
+    let signer = SignMode::Remote(
+        RemoteSigner::connect(config(&mock, &key), None)
+            .await
+            .unwrap(),
+    );
+    seal(&signer, &block(1, 1)).await.unwrap();
+
+    // When
+    let same_block = seal(&signer, &block(1, 1)).await;
+    let conflicting_block = seal(&signer, &block(1, 2)).await;
+    let lower_block = seal(&signer, &block(0, 3)).await;
+    let next_block = seal(&signer, &block(2, 4)).await;
+
+    // Then
+    assert!(same_block.is_ok());
+    assert!(conflicting_block.is_err());
+    assert!(lower_block.is_err());
+    assert!(next_block.is_ok());
+}
+
+#[tokio::test]
+async fn seal_block__slashing_protection_survives_restart() {
+    let (key, _) = keys();
+    let mock = MockRemoteSigner::start(vec![key]);
+    let dir = tempfile::tempdir().unwrap();
+    let path: PathBuf = dir.path().join("slashing_protection.json");
+    let mut config = config(&mock, &key);
+    config.slashing_protection = Some(path);
+    let signer = RemoteSigner::connect(config.clone(), None).await.unwrap();
+    seal(&SignMode::Remote(signer), &block(1, 1)).await.unwrap();
+
+    // When
+    let restarted = RemoteSigner::connect(config, None).await.unwrap();
+    let result = SignMode::Remote(restarted).seal_block(&block(1, 2)).await;
+
+    // Then
+    let err = result.unwrap_err();
+    assert!(err.to_string().contains("Refusing to sign"), "{err}");
+}
+
+#[tokio::test]
+async fn seal_block__signs_another_block_at_the_height_after_the_timeout() {
+    let (key, _) = keys();
+    let mock = MockRemoteSigner::start_with(vec![key], None, Duration::from_secs(5));
+    let dir = tempfile::tempdir().unwrap();
+    let mut config = config(&mock, &key);
+    config.timeout = Duration::from_millis(100);
+    config.slashing_protection = Some(dir.path().join("slashing_protection.json"));
+    let signer =
+        SignMode::Remote(RemoteSigner::connect(config.clone(), None).await.unwrap());
+    assert!(signer.seal_block(&block(1, 1)).await.is_err());
+    mock.set_delay(Duration::ZERO);
+
+    // When
+    let restarted = SignMode::Remote(RemoteSigner::connect(config, None).await.unwrap());
+    let another_block = seal(&restarted, &block(1, 2)).await;
+    let conflicting_block = seal(&restarted, &block(1, 3)).await;
+
+    // Then
+    assert!(another_block.is_ok());
+    assert!(conflicting_block.is_err());
+}
+
+#[tokio::test]
+async fn seal_block__refuses_heights_of_other_signing_keys() {
+    let (key, other_key) = keys();
+    let mock = MockRemoteSigner::start(vec![key]);
+    let signing_keys = PoAV2::new(
+        Input::owner(&key.public_key()),
+        BTreeMap::from([(
+            BlockHeight::from(10u32),
+            Input::owner(&other_key.public_key()),
+        )]),
+    );
+    let signer = SignMode::Remote(
+        RemoteSigner::connect(config(&mock, &key), Some(signing_keys))
+            .await
+            .unwrap(),
+    );
+
+    // When
+    let own_height = seal(&signer, &block(9, 1)).await;
+    let other_height = seal(&signer, &block(10, 2)).await;
+
+    // Then
+    assert!(own_height.is_ok());
+    assert!(other_height.is_err());
+}
+
+#[tokio::test]
+async fn sign_message__is_refused_by_the_remote_signer() {
+    let (key, _) = keys();
+    let mock = MockRemoteSigner::start(vec![key]);
+    let signer = SignMode::Remote(
+        RemoteSigner::connect(config(&mock, &key), None)
+            .await
+            .unwrap(),
+    );
+
+    // When
+    let result = signer.sign_message(block(1, 1).id().into_message()).await;
+
+    // Then
+    assert!(result.is_err());
+    assert!(!signer.can_sign_messages());
+}
+
+#[tokio::test]
+async fn seal_block__replaces_the_slashing_protection_file() {
+    let (key, _) = keys();
+    let mock = MockRemoteSigner::start(vec![key]);
+    let dir = tempfile::tempdir().unwrap();
+    let path: PathBuf = dir.path().join("slashing_protection.json");
+    let mut config = config(&mock, &key);
+    config.slashing_protection = Some(path.clone());
+    let signer = SignMode::Remote(RemoteSigner::connect(config, None).await.unwrap());
+    seal(&signer, &block(1, 1)).await.unwrap();
+
+    // When
+    seal(&signer, &block(2, 2)).await.unwrap();
+
+    // Then
+    let content = std::fs::read_to_string(&path).unwrap();
+    assert!(content.contains("\"height\":2"), "{content}");
+    assert!(!path.with_extension("tmp").exists());
+}
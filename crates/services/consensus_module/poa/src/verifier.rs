@@ -1,6 +1,9 @@
 use crate::ports::Database;
 use anyhow::ensure;
-use fuel_core_chain_config::ConsensusConfig;
+use fuel_core_chain_config::{
+    ConsensusConfig,
+    PoAV3,
+};
 use fuel_core_types::{
     blockchain::{
         block::Block,
@@ -8,11 +11,16 @@ use fuel_core_types::{
         header::BlockHeader,
     },
     fuel_tx::Input,
+    tai64::Tai64,
 };
 
 #[cfg(test)]
 mod tests;
 
+/// How far the time of the `PoAV3` block can be ahead of the local time,
+/// to tolerate the clock drift between producers.
+pub const MAX_BLOCK_TIME_DRIFT_SECS: u64 = 1;
+
 // TODO: Make this function `async` and await the synchronization with the relayer.
 pub fn verify_consensus(
     consensus_config: &ConsensusConfig,
@@ -37,6 +45,18 @@ pub fn verify_consensus(
                 .recover(m)
                 .map_or(false, |k| Input::owner(&k) == signing_key)
         }
+        // The producer of the slot depends on the previous block,
+        // it is checked by the `verify_round_robin_slot`.
+        ConsensusConfig::PoAV3(poa) => {
+            let id = header.id();
+            let m = id.as_message();
+            consensus
+                .signature
+                .recover(m)
+                .map_or(false, |k| poa.producers().contains(&Input::owner(&k)))
+        }
+        // The network that uses BFT doesn't accept blocks sealed by a single producer.
+        ConsensusConfig::Bft { .. } => false,
     }
 }
 
@@ -82,3 +102,35 @@ pub fn verify_block_fields<D: Database>(
 
     Ok(())
 }
+
+/// Verifies that the block is signed by the producer of its slot. The slot is derived
+/// from the height and the time since the previous block, so producers can't choose it.
+pub fn verify_round_robin_slot<D: Database>(
+    database: &D,
+    poa: &PoAV3,
+    header: &BlockHeader,
+    consensus: &PoAConsensus,
+    now: Tai64,
+) -> anyhow::Result<()> {
+    ensure!(
+        header.time().0 <= now.0.saturating_add(MAX_BLOCK_TIME_DRIFT_SECS),
+        "The `time` of the block is ahead of the local time"
+    );
+
+    let height = *header.height();
+    let prev_height = height
+        .pred()
+        .ok_or_else(|| anyhow::anyhow!("The PoA block can't have the zero height"))?;
+    let prev_header = database.block_header(&prev_height)?;
+    let producer = poa
+        .producer_at(height, prev_header.time(), header.time())
+        .ok_or_else(|| {
+            anyhow::anyhow!("The slot of the block doesn't have a producer")
+        })?;
+    let signer = consensus.signature.recover(header.id().as_message())?;
+    ensure!(
+        Input::owner(&signer) == producer,
+        "The block is not signed by the producer of its slot"
+    );
+    Ok(())
+}
@@ -1,5 +1,6 @@
 use super::*;
 use crate as fuel_core_poa;
+use fuel_core_chain_config::PoAV3;
 use fuel_core_poa::ports::MockDatabase;
 use fuel_core_types::{
     blockchain::header::{
@@ -9,9 +10,17 @@ use fuel_core_types::{
         GeneratedConsensusFields,
         PartialBlockHeader,
     },
+    fuel_crypto::{
+        SecretKey,
+        Signature,
+    },
     fuel_tx::Transaction,
     tai64::Tai64,
 };
+use rand::{
+    rngs::StdRng,
+    SeedableRng,
+};
 use test_case::test_case;
 
 struct Input {
@@ -118,3 +127,52 @@ fn test_verify_genesis_block_fields(input: Input) -> anyhow::Result<()> {
     *b.transactions_mut() = txs;
     verify_block_fields(&d, &b)
 }
+
+fn poa_v3_keys() -> (Vec<SecretKey>, PoAV3) {
+    let mut rng = StdRng::seed_from_u64(2322);
+    let keys: Vec<_> = (0..4).map(|_| SecretKey::random(&mut rng)).collect();
+    let producers = keys[..3]
+        .iter()
+        .map(|key| fuel_core_types::fuel_tx::Input::owner(&key.public_key()))
+        .collect();
+    (keys, PoAV3::new(producers, 4).unwrap())
+}
+
+#[test_case(0 => true ; "producer signs the block")]
+#[test_case(3 => false ; "non producer signs the block")]
+fn test_verify_poa_v3_consensus(signer: usize) -> bool {
+    let (keys, poa) = poa_v3_keys();
+    let config = ConsensusConfig::PoAV3(poa);
+
+    let mut header = BlockHeader::default();
+    header.set_block_height(1u32.into());
+    let signature = Signature::sign(&keys[signer], &header.id().into_message());
+
+    verify_consensus(&config, &header, &PoAConsensus::new(signature))
+}
+
+#[test_case(100, 1 => true ; "primary producer signs its slot")]
+#[test_case(104, 2 => true ; "backup producer takes over after the slot")]
+#[test_case(112, 1 => true ; "producers start the next cycle")]
+#[test_case(104, 1 => false ; "primary producer signs the slot of the backup")]
+#[test_case(103, 2 => false ; "backup producer signs the slot of the primary")]
+#[test_case(100, 3 => false ; "non producer signs the block")]
+#[test_case(124, 1 => false ; "producer signs the slot ahead of the local time")]
+fn test_verify_round_robin_slot(time: u64, signer: usize) -> bool {
+    let (keys, poa) = poa_v3_keys();
+    let prev_time = Tai64(100);
+    let now = Tai64(120);
+    let mut d = MockDatabase::default();
+    d.expect_block_header().returning(move |_| {
+        let mut h = BlockHeader::default();
+        h.set_time(prev_time);
+        Ok(h)
+    });
+
+    let mut header = BlockHeader::default();
+    header.set_block_height(1u32.into());
+    header.set_time(Tai64(time));
+    let signature = Signature::sign(&keys[signer], &header.id().into_message());
+
+    verify_round_robin_slot(&d, &poa, &header, &PoAConsensus::new(signature), now).is_ok()
+}
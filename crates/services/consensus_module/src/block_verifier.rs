@@ -3,6 +3,7 @@
 
 use crate::block_verifier::config::Config;
 use anyhow::ensure;
+use fuel_core_chain_config::ConsensusConfig;
 use fuel_core_poa::ports::Database as PoAVerifierDatabase;
 use fuel_core_storage::transactional::AtomicView;
 use fuel_core_types::{
@@ -64,9 +65,22 @@ where
                     block.header(),
                 )
             }
-            Consensus::PoA(_) => {
+            // The BFT uses the same rules for the fields of the block as the PoA.
+            Consensus::PoA(_) | Consensus::Bft(_) => {
                 let view = self.view_provider.latest_view()?;
-                fuel_core_poa::verifier::verify_block_fields(&view, block)
+                fuel_core_poa::verifier::verify_block_fields(&view, block)?;
+                if let (Consensus::PoA(consensus), ConsensusConfig::PoAV3(poa)) =
+                    (consensus, &self.config.consensus)
+                {
+                    fuel_core_poa::verifier::verify_round_robin_slot(
+                        &view,
+                        poa,
+                        block.header(),
+                        consensus,
+                        Tai64::now(),
+                    )?;
+                }
+                Ok(())
             }
             _ => Err(anyhow::anyhow!("Unsupported consensus: {:?}", consensus)),
         }
@@ -85,6 +99,11 @@ where
                 header,
                 consensus,
             ),
+            Consensus::Bft(consensus) => fuel_core_bft::verifier::verify_consensus(
+                &self.config.consensus,
+                header,
+                consensus,
+            ),
             _ => false,
         }
     }
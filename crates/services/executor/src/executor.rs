@@ -5,6 +5,7 @@ use crate::{
         TransactionsSource,
     },
     refs::ContractRef,
+    tracer::trace_transaction,
 };
 use fuel_core_storage::{
     column::Column,
@@ -133,6 +134,7 @@ use fuel_core_types::{
             Result as ExecutorResult,
             TransactionExecutionResult,
             TransactionExecutionStatus,
+            TransactionTrace,
             TransactionValidityError,
             UncommittedResult,
             UncommittedValidationResult,
@@ -204,6 +206,8 @@ pub struct ExecutionData {
     changes: Changes,
     pub skipped_transactions: Vec<(TxId, ExecutorError)>,
     event_inbox_root: Bytes32,
+    /// The traces of the executed transactions, if the tracing is enabled.
+    traces: Option<TransactionTraces>,
 }
 
 impl ExecutionData {
@@ -219,10 +223,14 @@ impl ExecutionData {
             changes: Default::default(),
             skipped_transactions: Vec::new(),
             event_inbox_root: Default::default(),
+            traces: None,
         }
     }
 }
 
+/// The traces of the executed transactions by their ids.
+pub type TransactionTraces = Vec<(TxId, TransactionTrace)>;
+
 /// Per-block execution options
 #[derive(serde::Serialize, serde::Deserialize, Clone, Default, Debug)]
 pub struct ExecutionOptions {
@@ -261,6 +269,32 @@ where
         components: Components<TxSource>,
         dry_run: bool,
     ) -> ExecutorResult<UncommittedResult<Changes>>
+    where
+        TxSource: TransactionsSource,
+    {
+        let (result, _) = self.produce_inner(components, dry_run, false)?;
+        Ok(result)
+    }
+
+    /// Executes the block in the dry run mode with the debugger of the VM enabled
+    /// and returns the trace of each executed transaction along with the result.
+    #[tracing::instrument(skip_all)]
+    pub fn dry_run_with_traces<TxSource>(
+        self,
+        components: Components<TxSource>,
+    ) -> ExecutorResult<(UncommittedResult<Changes>, TransactionTraces)>
+    where
+        TxSource: TransactionsSource,
+    {
+        self.produce_inner(components, true, true)
+    }
+
+    fn produce_inner<TxSource>(
+        self,
+        components: Components<TxSource>,
+        dry_run: bool,
+        trace: bool,
+    ) -> ExecutorResult<(UncommittedResult<Changes>, TransactionTraces)>
     where
         TxSource: TransactionsSource,
     {
@@ -269,7 +303,7 @@ where
             self.into_executor(consensus_params_version)?;
 
         let (partial_block, execution_data) = if dry_run {
-            block_executor.dry_run_block(components, storage_tx)?
+            block_executor.dry_run_block(components, storage_tx, trace)?
         } else {
             block_executor.produce_block(components, storage_tx)?
         };
@@ -283,6 +317,7 @@ where
             skipped_transactions,
             coinbase,
             used_gas,
+            traces,
             ..
         } = execution_data;
 
@@ -304,7 +339,10 @@ where
             events,
         };
 
-        Ok(UncommittedResult::new(result, changes))
+        Ok((
+            UncommittedResult::new(result, changes),
+            traces.unwrap_or_default(),
+        ))
     }
 
     pub fn validate_without_commit(
@@ -484,11 +522,13 @@ where
         Ok(())
     }
 
-    /// Execute dry-run of block with specified components
+    /// Execute dry-run of block with specified components.
+    /// If the `trace` is `true`, the trace of each transaction is collected.
     fn dry_run_block<TxSource, D>(
         mut self,
         components: Components<TxSource>,
         mut block_storage_tx: BlockStorageTransaction<D>,
+        trace: bool,
     ) -> ExecutorResult<(PartialFuelBlock, ExecutionData)>
     where
         TxSource: TransactionsSource,
@@ -497,6 +537,7 @@ where
         let mut partial_block =
             PartialFuelBlock::new(components.header_to_produce, vec![]);
         let mut data = ExecutionData::new();
+        data.traces = trace.then(Vec::new);
 
         self.process_l2_txs(
             &mut partial_block,
@@ -1158,7 +1199,7 @@ where
     ) -> ExecutorResult<Transaction>
     where
         Tx: ExecutableTransaction + Cacheable + Send + Sync + 'static,
-        <Tx as IntoChecked>::Metadata: CheckedMetadataTrait + Send + Sync,
+        <Tx as IntoChecked>::Metadata: CheckedMetadataTrait + Clone + Send + Sync,
         T: KeyValueInspect<Column = Column>,
     {
         let tx_id = checked_tx.id();
@@ -1167,6 +1208,22 @@ where
             checked_tx = self.extra_tx_checks(checked_tx, header, storage_tx, memory)?;
         }
 
+        // The trace is collected on top of the same state as the execution below.
+        let trace = execution_data
+            .traces
+            .is_some()
+            .then(|| {
+                trace_transaction(
+                    checked_tx.clone(),
+                    header,
+                    coinbase_contract_id,
+                    gas_price,
+                    &self.consensus_params,
+                    storage_tx,
+                )
+            })
+            .transpose()?;
+
         let (reverted, state, tx, receipts) = self.attempt_tx_execution_with_vm(
             checked_tx,
             header,
@@ -1201,6 +1258,10 @@ where
             tx_id,
         )?;
 
+        if let (Some(traces), Some(trace)) = (execution_data.traces.as_mut(), trace) {
+            traces.push((tx_id, trace));
+        }
+
         Ok(tx.into())
     }
 
@@ -0,0 +1,326 @@
+//! The tracer executes transactions with the debugger of the VM enabled
+//! and records every executed instruction along with the storage accesses.
+
+use fuel_core_storage::{
+    column::Column,
+    kv_store::KeyValueInspect,
+    tables::ContractsState,
+    transactional::{
+        ConflictPolicy,
+        ReadTransaction,
+        StorageTransaction,
+    },
+    vm_storage::VmStorage,
+    ContractsStateKey,
+    Error as StorageError,
+    StorageInspect,
+};
+use fuel_core_types::{
+    blockchain::header::PartialBlockHeader,
+    fuel_asm::{
+        Instruction,
+        RegId,
+        Word,
+    },
+    fuel_tx::{
+        Bytes32,
+        Cacheable,
+        ConsensusParameters,
+        ContractId,
+    },
+    fuel_vm::{
+        checked_transaction::{
+            Checked,
+            IntoChecked,
+        },
+        interpreter::{
+            CheckedMetadata as CheckedMetadataTrait,
+            ExecutableTransaction,
+            InterpreterParams,
+            MemoryInstance,
+        },
+        state::DebugEval,
+        Breakpoint,
+        Interpreter,
+        ProgramState,
+    },
+    services::executor::{
+        Error as ExecutorError,
+        Result as ExecutorResult,
+        StorageAccess,
+        StorageAccessKind,
+        TraceStep,
+        TransactionTrace,
+    },
+};
+
+#[cfg(feature = "alloc")]
+use alloc::{
+    string::ToString,
+    vec,
+    vec::Vec,
+};
+
+/// The maximum number of instructions recorded for one transaction.
+/// The execution continues without recording after reaching the limit.
+pub const MAX_TRACE_STEPS: usize = 100_000;
+
+/// The maximum number of storage slots recorded for one instruction.
+const MAX_TRACED_SLOTS_PER_INSTRUCTION: Word = 256;
+
+/// Executes the `checked_tx` with the debugger of the VM enabled on top of
+/// the `storage` and returns its trace. The changes of the debugged execution
+/// are discarded.
+///
+/// The debugger of the VM finalizes the transaction at the first breakpoint,
+/// so the executor calls it right before the regular execution of the same
+/// transaction on top of the same state, and uses the receipts and
+/// the outputs of the regular execution.
+pub(crate) fn trace_transaction<Tx, T>(
+    checked_tx: Checked<Tx>,
+    header: &PartialBlockHeader,
+    coinbase_contract_id: ContractId,
+    gas_price: Word,
+    consensus_params: &ConsensusParameters,
+    storage: &StorageTransaction<T>,
+) -> ExecutorResult<TransactionTrace>
+where
+    Tx: ExecutableTransaction + Cacheable,
+    <Tx as IntoChecked>::Metadata: CheckedMetadataTrait + Send + Sync,
+    T: KeyValueInspect<Column = Column>,
+{
+    let tx_id = checked_tx.id();
+    let ready_tx = checked_tx.into_ready(
+        gas_price,
+        consensus_params.gas_costs(),
+        consensus_params.fee_params(),
+    )?;
+
+    let mut tx_storage = storage
+        .read_transaction()
+        .with_policy(ConflictPolicy::Overwrite);
+    let vm_db = VmStorage::new(
+        &mut tx_storage,
+        &header.consensus,
+        &header.application,
+        coinbase_contract_id,
+    );
+    let mut vm = Interpreter::with_storage(
+        MemoryInstance::new(),
+        vm_db,
+        InterpreterParams::new(gas_price, consensus_params),
+    );
+    vm.set_single_stepping(true);
+
+    let vm_error = |error: fuel_core_types::fuel_vm::InterpreterError<_>| {
+        ExecutorError::VmExecution {
+            error: error.to_string(),
+            transaction_id: tx_id,
+        }
+    };
+
+    let mut recorder = TraceRecorder::default();
+    let mut state = *vm.transact(ready_tx).map_err(vm_error)?.state();
+    while let ProgramState::RunProgram(DebugEval::Breakpoint(breakpoint)) = state {
+        recorder.record_step(&vm, &breakpoint)?;
+        if recorder.trace.steps.len() >= MAX_TRACE_STEPS {
+            recorder.trace.truncated = true;
+            vm.set_single_stepping(false);
+        }
+        state = vm.resume().map_err(vm_error)?;
+    }
+    recorder.finish_step(&vm)?;
+
+    Ok(recorder.trace)
+}
+
+#[derive(Default)]
+struct TraceRecorder {
+    trace: TransactionTrace,
+    /// The registers before the execution of the last recorded step.
+    registers: Vec<Word>,
+    /// The storage slots accessed by the last recorded step.
+    pending_accesses: Vec<(StorageAccessKind, ContractId, Bytes32)>,
+}
+
+impl TraceRecorder {
+    /// Records the instruction that the VM is about to execute.
+    fn record_step<S, Tx>(
+        &mut self,
+        vm: &Interpreter<MemoryInstance, S, Tx>,
+        breakpoint: &Breakpoint,
+    ) -> ExecutorResult<()>
+    where
+        S: StorageInspect<ContractsState, Error = StorageError>,
+        Tx: ExecutableTransaction,
+    {
+        self.finish_step(vm)?;
+
+        let registers = vm.registers();
+        let instruction = vm
+            .memory()
+            .read(registers[RegId::PC], Instruction::SIZE)
+            .ok()
+            .and_then(|bytes| <[u8; 4]>::try_from(bytes).ok())
+            .map(u32::from_be_bytes)
+            .unwrap_or_default();
+        let contract_id = Some(*breakpoint.contract())
+            .filter(|contract_id| *contract_id != ContractId::zeroed());
+
+        if let (Some(contract_id), Ok(instruction)) =
+            (contract_id, Instruction::try_from(instruction))
+        {
+            self.pending_accesses =
+                storage_accesses(vm, registers, contract_id, instruction);
+        }
+
+        self.trace.steps.push(TraceStep {
+            pc: breakpoint.pc(),
+            instruction,
+            gas_remaining: registers[RegId::CGAS],
+            contract_id,
+            changed_registers: vec![],
+        });
+        self.registers = registers.to_vec();
+
+        Ok(())
+    }
+
+    /// Fills the effects of the last recorded step after its execution.
+    fn finish_step<S, Tx>(
+        &mut self,
+        vm: &Interpreter<MemoryInstance, S, Tx>,
+    ) -> ExecutorResult<()>
+    where
+        S: StorageInspect<ContractsState, Error = StorageError>,
+        Tx: ExecutableTransaction,
+    {
+        let Some(step_index) = self.trace.steps.len().checked_sub(1) else {
+            return Ok(())
+        };
+        let step_index = u32::try_from(step_index).unwrap_or(u32::MAX);
+        let Some(step) = self.trace.steps.last_mut() else {
+            return Ok(())
+        };
+
+        step.changed_registers = vm
+            .registers()
+            .iter()
+            .zip(self.registers.iter())
+            .enumerate()
+            .filter(|(_, (after, before))| after != before)
+            .filter_map(|(index, (after, _))| {
+                u8::try_from(index).ok().map(|index| (index, *after))
+            })
+            .collect();
+
+        for (kind, contract_id, key) in core::mem::take(&mut self.pending_accesses) {
+            let slot_key = ContractsStateKey::new(&contract_id, &key);
+            let value = StorageInspect::<ContractsState>::get(vm.as_ref(), &slot_key)?
+                .map(|value| value.into_owned().0);
+            self.trace.storage_accesses.push(StorageAccess {
+                step: step_index,
+                kind,
+                contract_id,
+                key,
+                value,
+            });
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns the storage slots accessed by the `instruction`.
+fn storage_accesses<S, Tx>(
+    vm: &Interpreter<MemoryInstance, S, Tx>,
+    registers: &[Word],
+    contract_id: ContractId,
+    instruction: Instruction,
+) -> Vec<(StorageAccessKind, ContractId, Bytes32)>
+where
+    Tx: ExecutableTransaction,
+{
+    let (kind, key_pointer, slots) = match instruction {
+        Instruction::SRW(op) => {
+            let (_, _, key) = op.unpack();
+            (StorageAccessKind::Read, registers[key], 1)
+        }
+        Instruction::SRWQ(op) => {
+            let (_, _, key, slots) = op.unpack();
+            (StorageAccessKind::Read, registers[key], registers[slots])
+        }
+        Instruction::SWW(op) => {
+            let (key, _, _) = op.unpack();
+            (StorageAccessKind::Write, registers[key], 1)
+        }
+        Instruction::SWWQ(op) => {
+            let (key, _, _, slots) = op.unpack();
+            (StorageAccessKind::Write, registers[key], registers[slots])
+        }
+        Instruction::SCWQ(op) => {
+            let (key, _, slots) = op.unpack();
+            (StorageAccessKind::Clear, registers[key], registers[slots])
+        }
+        _ => return vec![],
+    };
+
+    let Some(first_key) = vm
+        .memory()
+        .read(key_pointer, Bytes32::LEN)
+        .ok()
+        .and_then(|bytes| <[u8; 32]>::try_from(bytes).ok())
+        .map(Bytes32::from)
+    else {
+        return vec![]
+    };
+
+    (0..slots.min(MAX_TRACED_SLOTS_PER_INSTRUCTION))
+        .map_while(|offset| key_with_offset(&first_key, offset))
+        .map(|key| (kind, contract_id, key))
+        .collect()
+}
+
+/// Multi-slot storage instructions access sequential keys,
+/// where the key is a big-endian 256-bit number.
+fn key_with_offset(key: &Bytes32, offset: Word) -> Option<Bytes32> {
+    let mut key = **key;
+    let mut carry = u128::from(offset);
+    for byte in key.iter_mut().rev() {
+        if carry == 0 {
+            break
+        }
+        let sum = u128::from(*byte).checked_add(carry)?;
+        *byte = sum.to_le_bytes()[0];
+        carry = sum.checked_shr(8)?;
+    }
+
+    (carry == 0).then_some(key.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn key_with_offset_increments_the_key_as_a_big_endian_number() {
+        let mut key = [0u8; 32];
+        key[31] = 0xff;
+        let key = Bytes32::from(key);
+
+        let result = key_with_offset(&key, 1).unwrap();
+
+        let mut expected = [0u8; 32];
+        expected[30] = 1;
+        assert_eq!(result, Bytes32::from(expected));
+    }
+
+    #[test]
+    fn key_with_offset_returns_none_on_overflow() {
+        let key = Bytes32::from([0xff; 32]);
+
+        let result = key_with_offset(&key, 1);
+
+        assert_eq!(result, None);
+    }
+}
@@ -292,7 +292,7 @@ where
                 }
                 actual_next_height
             }
-            Consensus::PoA(_) => {
+            Consensus::PoA(_) | Consensus::Bft(_) => {
                 if actual_next_height == BlockHeight::from(0u32) {
                     return Err(Error::ZeroNonGenericHeight)
                 }
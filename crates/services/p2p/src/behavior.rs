@@ -3,13 +3,17 @@ use crate::{
         postcard::PostcardCodec,
         NetworkCodec,
     },
-    config::Config,
+    config::{
+        connection_tracker::ConnectionTracker,
+        Config,
+    },
     discovery,
     gossipsub::{
         config::build_gossipsub_behaviour,
         topics::GossipTopic,
     },
     heartbeat,
+    peer_manager::ConnectionState,
     peer_report,
     request_response::messages::{
         RequestMessage,
@@ -36,6 +40,10 @@ use libp2p::{
     Multiaddr,
     PeerId,
 };
+use std::sync::{
+    Arc,
+    RwLock,
+};
 
 /// Handles all p2p protocols needed for Fuel.
 #[derive(NetworkBehaviour)]
@@ -65,7 +73,11 @@ pub struct FuelBehaviour {
 }
 
 impl FuelBehaviour {
-    pub(crate) fn new(p2p_config: &Config, codec: PostcardCodec) -> Self {
+    pub(crate) fn new(
+        p2p_config: &Config,
+        codec: PostcardCodec,
+        connection_state: Arc<RwLock<ConnectionState>>,
+    ) -> Self {
         let local_public_key = p2p_config.keypair.public();
         let local_peer_id = PeerId::from_public_key(&local_public_key);
 
@@ -93,7 +105,10 @@ impl FuelBehaviour {
 
         let gossipsub = build_gossipsub_behaviour(p2p_config);
 
-        let peer_report = peer_report::Behaviour::new(p2p_config);
+        let peer_report = peer_report::Behaviour::new(
+            p2p_config,
+            ConnectionTracker::new(connection_state, p2p_config),
+        );
 
         let identify = {
             let identify_config = identify::Config::new(
@@ -112,8 +127,10 @@ impl FuelBehaviour {
             BlockHeight::default(),
         );
 
-        let req_res_protocol =
-            core::iter::once((codec.get_req_res_protocol(), ProtocolSupport::Full));
+        let req_res_protocol = codec
+            .get_req_res_protocols()
+            .map(|protocol| (protocol, ProtocolSupport::Full))
+            .collect::<Vec<_>>();
 
         let req_res_config = request_response::Config::default()
             .with_request_timeout(p2p_config.set_request_timeout)
@@ -212,4 +229,23 @@ impl FuelBehaviour {
     pub fn block_peer(&mut self, peer_id: PeerId) {
         self.blocked_peer.block_peer(peer_id)
     }
+
+    pub fn unblock_peer(&mut self, peer_id: PeerId) {
+        self.blocked_peer.unblock_peer(peer_id)
+    }
+
+    pub fn add_reserved_node(
+        &mut self,
+        peer_id: PeerId,
+        multiaddr: Multiaddr,
+        connected: bool,
+    ) {
+        self.discovery.add_address(&peer_id, multiaddr.clone());
+        self.peer_report
+            .add_reserved_node(peer_id, multiaddr, connected);
+    }
+
+    pub fn remove_reserved_node(&mut self, peer_id: &PeerId) {
+        self.peer_report.remove_reserved_node(peer_id);
+    }
 }
@@ -39,7 +39,9 @@ pub trait NetworkCodec:
     + Send
     + 'static
 {
-    /// Returns RequestResponse's Protocol
+    /// Returns RequestResponse's Protocols, starting from the preferred one
     /// Needed for initialization of RequestResponse Behaviour
-    fn get_req_res_protocol(&self) -> <Self as request_response::Codec>::Protocol;
+    fn get_req_res_protocols(
+        &self,
+    ) -> impl Iterator<Item = <Self as request_response::Codec>::Protocol>;
 }
@@ -10,8 +10,8 @@ use crate::{
     },
     request_response::messages::{
         RequestMessage,
+        RequestResponseProtocol,
         ResponseMessage,
-        REQUEST_RESPONSE_PROTOCOL_ID,
     },
 };
 use async_trait::async_trait;
@@ -62,6 +62,8 @@ impl PostcardCodec {
 
 /// Since Postcard does not support async reads or writes out of the box
 /// We prefix Request & Response Messages with the length of the data in bytes
+/// Requests are written only under the protocol version that supports them,
+/// so peers running an older version never receive unknown requests.
 /// We expect the substream to be properly closed when response channel is dropped.
 /// Since the request protocol used here expects a response, the sender considers this
 /// early close as a protocol violation which results in the connection being closed.
@@ -69,13 +71,13 @@ impl PostcardCodec {
 /// run into a timeout waiting for the response.
 #[async_trait]
 impl request_response::Codec for PostcardCodec {
-    type Protocol = MessageExchangePostcardProtocol;
+    type Protocol = RequestResponseProtocol;
     type Request = RequestMessage;
     type Response = ResponseMessage;
 
     async fn read_request<T>(
         &mut self,
-        _: &Self::Protocol,
+        protocol: &Self::Protocol,
         socket: &mut T,
     ) -> io::Result<Self::Request>
     where
@@ -86,7 +88,9 @@ impl request_response::Codec for PostcardCodec {
             .take(self.max_response_size as u64)
             .read_to_end(&mut response)
             .await?;
-        deserialize(&response)
+        let request: RequestMessage = deserialize(&response)?;
+        ensure_supported(protocol, &request)?;
+        Ok(request)
     }
 
     async fn read_response<T>(
@@ -108,13 +112,14 @@ impl request_response::Codec for PostcardCodec {
 
     async fn write_request<T>(
         &mut self,
-        _protocol: &Self::Protocol,
+        protocol: &Self::Protocol,
         socket: &mut T,
         req: Self::Request,
     ) -> io::Result<()>
     where
         T: futures::AsyncWrite + Unpin + Send,
     {
+        ensure_supported(protocol, &req)?;
         let encoded_data = serialize(&req)?;
         socket.write_all(&encoded_data).await?;
         Ok(())
@@ -142,6 +147,10 @@ impl GossipsubCodec for PostcardCodec {
     fn encode(&self, data: Self::RequestMessage) -> Result<Vec<u8>, io::Error> {
         let encoded_data = match data {
             GossipsubBroadcastRequest::NewTx(tx) => postcard::to_stdvec(&*tx),
+            GossipsubBroadcastRequest::NewBlock(block) => postcard::to_stdvec(&*block),
+            GossipsubBroadcastRequest::Consensus(message) => {
+                postcard::to_stdvec(&*message)
+            }
         };
 
         encoded_data.map_err(|e| io::Error::new(io::ErrorKind::Other, e.to_string()))
@@ -154,29 +163,46 @@ impl GossipsubCodec for PostcardCodec {
     ) -> Result<Self::ResponseMessage, io::Error> {
         let decoded_response = match gossipsub_tag {
             GossipTopicTag::NewTx => GossipsubMessage::NewTx(deserialize(encoded_data)?),
+            GossipTopicTag::NewBlock => {
+                GossipsubMessage::NewBlock(deserialize(encoded_data)?)
+            }
+            GossipTopicTag::Consensus => {
+                GossipsubMessage::Consensus(deserialize(encoded_data)?)
+            }
         };
 
         Ok(decoded_response)
     }
 }
 
-impl NetworkCodec for PostcardCodec {
-    fn get_req_res_protocol(&self) -> <Self as request_response::Codec>::Protocol {
-        MessageExchangePostcardProtocol {}
+fn ensure_supported(
+    protocol: &RequestResponseProtocol,
+    request: &RequestMessage,
+) -> io::Result<()> {
+    if request.min_protocol() > *protocol {
+        return Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            format!(
+                "Request {:?} is not supported by the protocol {}",
+                request,
+                protocol.as_ref()
+            ),
+        ))
     }
+    Ok(())
 }
 
-#[derive(Default, Debug, Clone)]
-pub struct MessageExchangePostcardProtocol;
-
-impl AsRef<str> for MessageExchangePostcardProtocol {
-    fn as_ref(&self) -> &str {
-        REQUEST_RESPONSE_PROTOCOL_ID
+impl NetworkCodec for PostcardCodec {
+    fn get_req_res_protocols(
+        &self,
+    ) -> impl Iterator<Item = <Self as request_response::Codec>::Protocol> {
+        RequestResponseProtocol::ALL.into_iter()
     }
 }
 
 #[cfg(test)]
 mod tests {
+    #![allow(non_snake_case)]
     use super::*;
     use crate::request_response::messages::MAX_REQUEST_SIZE;
 
@@ -186,4 +212,64 @@ mod tests {
         let m = RequestMessage::Transactions(arbitrary_range);
         assert!(postcard::to_stdvec(&m).unwrap().len() <= MAX_REQUEST_SIZE);
     }
+
+    #[test]
+    fn v1_requests_keep_their_encoding() {
+        // The encoding of the requests from the first version of the protocol
+        // shouldn't change, otherwise older peers can't decode them.
+        let headers = RequestMessage::SealedHeaders(2..6);
+        let transactions = RequestMessage::Transactions(2..6);
+
+        assert_eq!(postcard::to_stdvec(&headers).unwrap(), vec![0, 2, 6]);
+        assert_eq!(postcard::to_stdvec(&transactions).unwrap(), vec![1, 2, 6]);
+    }
+
+    #[tokio::test]
+    async fn write_request__v2_request_is_rejected_under_v1_protocol() {
+        let mut codec = PostcardCodec::new(1024);
+        let mut buf = Vec::new();
+
+        let result = request_response::Codec::write_request(
+            &mut codec,
+            &RequestResponseProtocol::V1,
+            &mut futures::io::Cursor::new(&mut buf),
+            RequestMessage::SealedBlocks(2..6),
+        )
+        .await;
+
+        assert_eq!(result.unwrap_err().kind(), io::ErrorKind::Unsupported);
+        assert!(buf.is_empty());
+    }
+
+    #[tokio::test]
+    async fn read_request__v2_request_is_rejected_under_v1_protocol() {
+        let mut codec = PostcardCodec::new(1024);
+        let encoded =
+            postcard::to_stdvec(&RequestMessage::TxPoolAllTransactionsIds).unwrap();
+
+        let result = request_response::Codec::read_request(
+            &mut codec,
+            &RequestResponseProtocol::V1,
+            &mut futures::io::Cursor::new(encoded),
+        )
+        .await;
+
+        assert_eq!(result.unwrap_err().kind(), io::ErrorKind::Unsupported);
+    }
+
+    #[tokio::test]
+    async fn read_request__v1_request_is_accepted_under_v2_protocol() {
+        let mut codec = PostcardCodec::new(1024);
+        let request = RequestMessage::SealedHeaders(2..6);
+        let encoded = postcard::to_stdvec(&request).unwrap();
+
+        let result = request_response::Codec::read_request(
+            &mut codec,
+            &RequestResponseProtocol::V2,
+            &mut futures::io::Cursor::new(encoded),
+        )
+        .await;
+
+        assert_eq!(result.unwrap(), request);
+    }
 }
@@ -2,22 +2,34 @@ use crate::{
     gossipsub::config::default_gossipsub_config,
     heartbeat,
     peer_manager::ConnectionState,
-    TryPeerId,
 };
 use fuel_core_types::blockchain::consensus::Genesis;
+use ip_network::IpNetwork;
 
 use libp2p::{
+    core::{
+        muxing::StreamMuxerBox,
+        transport::{
+            dummy::DummyTransport,
+            upgrade::Version,
+            Boxed,
+        },
+    },
     gossipsub,
     identity::{
         secp256k1,
         Keypair,
     },
+    multiaddr::Protocol,
     noise,
+    quic,
+    tcp,
+    websocket,
     Multiaddr,
     PeerId,
+    Transport,
 };
 use std::{
-    collections::HashSet,
     net::{
         IpAddr,
         Ipv4Addr,
@@ -31,10 +43,13 @@ use std::{
 
 use self::{
     connection_tracker::ConnectionTracker,
-    fuel_authenticated::FuelAuthenticated,
+    fuel_authenticated::{
+        authenticate_muxed_connection,
+        FuelAuthenticated,
+    },
     fuel_upgrade::Checksum,
 };
-mod connection_tracker;
+pub(crate) mod connection_tracker;
 mod fuel_authenticated;
 pub(crate) mod fuel_upgrade;
 
@@ -69,6 +84,14 @@ pub struct Config<State = Initialized> {
     /// The TCP port that Swarm listens on
     pub tcp_port: u16,
 
+    /// The UDP port that Swarm listens on for QUIC connections.
+    /// QUIC is disabled if it is not set.
+    pub quic_port: Option<u16>,
+
+    /// The TCP port that Swarm listens on for WebSocket connections.
+    /// WebSocket is disabled if it is not set.
+    pub websocket_port: Option<u16>,
+
     /// Max Size of a Block in bytes
     pub max_block_size: usize,
     pub max_headers_per_request: usize,
@@ -86,6 +109,14 @@ pub struct Config<State = Initialized> {
     /// Should the node only accept connection requests from the Reserved Nodes
     pub reserved_nodes_only_mode: bool,
 
+    // IP filtering
+    /// If not empty, only peers with IP addresses within these networks are allowed
+    pub allowed_ip_networks: Vec<IpNetwork>,
+    /// Peers with IP addresses within these networks are never allowed
+    pub denied_ip_networks: Vec<IpNetwork>,
+    /// Max number of inbound connections from a single IP address
+    pub max_connections_per_ip: Option<u32>,
+
     // `PeerManager` fields
     /// Max number of unique peers connected
     /// This number should be at least number of `mesh_n` from `Gossipsub` configuration.
@@ -142,6 +173,14 @@ impl Config<NotInitialized> {
     pub fn init(self, genesis: Genesis) -> anyhow::Result<Config<Initialized>> {
         use fuel_core_chain_config::GenesisCommitment;
 
+        for address in self
+            .bootstrap_nodes
+            .iter()
+            .chain(self.reserved_nodes.iter())
+        {
+            self.ensure_transport_enabled(address)?;
+        }
+
         Ok(Config {
             keypair: self.keypair,
             network_name: self.network_name,
@@ -149,6 +188,8 @@ impl Config<NotInitialized> {
             address: self.address,
             public_address: self.public_address,
             tcp_port: self.tcp_port,
+            quic_port: self.quic_port,
+            websocket_port: self.websocket_port,
             max_block_size: self.max_block_size,
             max_headers_per_request: self.max_headers_per_request,
             bootstrap_nodes: self.bootstrap_nodes,
@@ -160,6 +201,9 @@ impl Config<NotInitialized> {
             connection_idle_timeout: self.connection_idle_timeout,
             reserved_nodes: self.reserved_nodes,
             reserved_nodes_only_mode: self.reserved_nodes_only_mode,
+            allowed_ip_networks: self.allowed_ip_networks,
+            denied_ip_networks: self.denied_ip_networks,
+            max_connections_per_ip: self.max_connections_per_ip,
             identify_interval: self.identify_interval,
             info_interval: self.info_interval,
             gossipsub_config: self.gossipsub_config,
@@ -176,6 +220,62 @@ impl Config<NotInitialized> {
     }
 }
 
+impl<State> Config<State> {
+    /// Returns an error if the address requires a transport that is disabled.
+    pub fn ensure_transport_enabled(&self, address: &Multiaddr) -> anyhow::Result<()> {
+        match TransportKind::of(address) {
+            Some(TransportKind::Quic) if self.quic_port.is_none() => {
+                Err(anyhow::anyhow!(
+                    "The address `{address}` requires QUIC, but it is disabled"
+                ))
+            }
+            Some(TransportKind::WebSocket) if self.websocket_port.is_none() => {
+                Err(anyhow::anyhow!(
+                    "The address `{address}` requires WebSocket, but it is disabled"
+                ))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// The addresses that Swarm listens on, one per enabled transport.
+    pub fn listen_addresses(&self) -> Vec<Multiaddr> {
+        let ip = Multiaddr::from(self.address);
+        let mut addresses = vec![ip.clone().with(Protocol::Tcp(self.tcp_port))];
+        if let Some(port) = self.quic_port {
+            addresses.push(ip.clone().with(Protocol::Udp(port)).with(Protocol::QuicV1));
+        }
+        if let Some(port) = self.websocket_port {
+            addresses.push(ip.with(Protocol::Tcp(port)).with(Protocol::Ws("/".into())));
+        }
+        addresses
+    }
+}
+
+/// The transport required to reach the address.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TransportKind {
+    Tcp,
+    Quic,
+    WebSocket,
+}
+
+impl TransportKind {
+    /// Returns `None` if the address doesn't specify a supported transport.
+    pub fn of(address: &Multiaddr) -> Option<Self> {
+        let mut kind = None;
+        for protocol in address.iter() {
+            match protocol {
+                Protocol::Tcp(_) => kind = Some(Self::Tcp),
+                Protocol::QuicV1 => return Some(Self::Quic),
+                Protocol::Ws(_) | Protocol::Wss(_) => return Some(Self::WebSocket),
+                _ => {}
+            }
+        }
+        kind
+    }
+}
+
 /// Takes secret key bytes generated outside of libp2p.
 /// And converts it into libp2p's `Keypair::Secp256k1`.
 pub fn convert_to_libp2p_keypair(
@@ -198,6 +298,8 @@ impl Config<NotInitialized> {
             address: IpAddr::V4(Ipv4Addr::from([0, 0, 0, 0])),
             public_address: None,
             tcp_port: 0,
+            quic_port: None,
+            websocket_port: None,
             max_block_size: MAX_RESPONSE_SIZE,
             max_headers_per_request: MAX_HEADERS_PER_REQUEST,
             bootstrap_nodes: vec![],
@@ -209,6 +311,9 @@ impl Config<NotInitialized> {
             connection_idle_timeout: Some(Duration::from_secs(120)),
             reserved_nodes: vec![],
             reserved_nodes_only_mode: false,
+            allowed_ip_networks: vec![],
+            denied_ip_networks: vec![],
+            max_connections_per_ip: None,
             gossipsub_config: default_gossipsub_config(),
             heartbeat_config: heartbeat::Config::default(),
             set_request_timeout: REQ_RES_TIMEOUT,
@@ -250,14 +355,7 @@ pub(crate) fn build_transport_function(
         let noise_authenticated =
             noise::Config::new(keypair).expect("Noise key generation failed");
 
-        let connection_state = if p2p_config.reserved_nodes_only_mode {
-            None
-        } else {
-            Some(connection_state)
-        };
-
-        let connection_tracker =
-            ConnectionTracker::new(&p2p_config.reserved_nodes, connection_state);
+        let connection_tracker = ConnectionTracker::new(connection_state, p2p_config);
 
         Ok(FuelAuthenticated::new(
             noise_authenticated,
@@ -269,11 +367,133 @@ pub(crate) fn build_transport_function(
     (transport_function, kept_connection_state)
 }
 
-fn peer_ids_set_from(multiaddr: &[Multiaddr]) -> HashSet<PeerId> {
-    multiaddr
-        .iter()
-        // Safety: as is the case with `bootstrap_nodes` it is assumed that `reserved_nodes` [`Multiadr`]
-        // come with PeerId included, in case they are not the `unwrap()` will only panic when the node is started.
-        .map(|address| address.try_to_peer_id().unwrap())
-        .collect()
+/// Transports enabled in addition to TCP:
+/// QUIC, secured by its built-in TLS, with the `FuelAuthenticated` checks applied
+/// to the established connection.
+/// WebSocket, secured and multiplexed the same way as TCP.
+pub(crate) fn build_other_transports_function(
+    p2p_config: &Config,
+    connection_state: Arc<RwLock<ConnectionState>>,
+) -> impl FnOnce(&Keypair) -> Boxed<(PeerId, StreamMuxerBox)> + '_ {
+    move |keypair: &Keypair| {
+        let connection_tracker = ConnectionTracker::new(connection_state, p2p_config);
+        let mut transport = DummyTransport::<(PeerId, StreamMuxerBox)>::new().boxed();
+
+        if p2p_config.quic_port.is_some() {
+            let connection_tracker = connection_tracker.clone();
+            let checksum = p2p_config.checksum;
+            let quic = quic::tokio::Transport::new(quic::Config::new(keypair))
+                .and_then(move |connection, endpoint| {
+                    authenticate_muxed_connection(
+                        connection_tracker,
+                        checksum,
+                        connection,
+                        endpoint,
+                    )
+                })
+                .map(|(peer_id, connection), _| {
+                    (peer_id, StreamMuxerBox::new(connection))
+                });
+            transport = transport
+                .or_transport(quic)
+                .map(|either, _| either.into_inner())
+                .boxed();
+        }
+
+        if p2p_config.websocket_port.is_some() {
+            let noise_authenticated =
+                noise::Config::new(keypair).expect("Noise key generation failed");
+            let tcp_config = tcp::Config::new().port_reuse(true);
+            let websocket =
+                websocket::WsConfig::new(tcp::tokio::Transport::new(tcp_config))
+                    .upgrade(Version::V1Lazy)
+                    .authenticate(FuelAuthenticated::new(
+                        noise_authenticated,
+                        connection_tracker,
+                        p2p_config.checksum,
+                    ))
+                    .multiplex(libp2p::yamux::Config::default())
+                    .map(|(peer_id, connection), _| {
+                        (peer_id, StreamMuxerBox::new(connection))
+                    });
+            transport = transport
+                .or_transport(websocket)
+                .map(|either, _| either.into_inner())
+                .boxed();
+        }
+
+        transport
+    }
+}
+
+/// Secures and multiplexes the connections of the `transport` the same way as TCP.
+#[cfg(any(test, feature = "network-simulation"))]
+pub(crate) fn build_secured_transport<T>(
+    p2p_config: &Config,
+    transport: T,
+) -> (
+    Boxed<(PeerId, StreamMuxerBox)>,
+    Arc<RwLock<ConnectionState>>,
+)
+where
+    T: Transport + Send + Unpin + 'static,
+    T::Output: futures::AsyncRead + futures::AsyncWrite + Send + Unpin + 'static,
+    T::Error: Send + Sync,
+    T::Dial: Send,
+    T::ListenerUpgrade: Send,
+{
+    let (transport_function, connection_state) = build_transport_function(p2p_config);
+    let authenticated = transport_function(&p2p_config.keypair)
+        .expect("The authentication of the transport is always configured");
+    let transport = transport
+        .upgrade(Version::V1Lazy)
+        .authenticate(authenticated)
+        .multiplex(libp2p::yamux::Config::default())
+        .map(|(peer_id, connection), _| (peer_id, StreamMuxerBox::new(connection)))
+        .boxed();
+
+    (transport, connection_state)
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use super::*;
+
+    fn quic_address() -> Multiaddr {
+        format!("/ip4/127.0.0.1/udp/4001/quic-v1/p2p/{}", PeerId::random())
+            .parse()
+            .unwrap()
+    }
+
+    #[test]
+    fn transport_kind__is_detected_from_address() {
+        let tcp: Multiaddr = "/ip4/127.0.0.1/tcp/4001".parse().unwrap();
+        let websocket: Multiaddr = "/dns4/example.com/tcp/443/wss".parse().unwrap();
+
+        assert_eq!(TransportKind::of(&tcp), Some(TransportKind::Tcp));
+        assert_eq!(
+            TransportKind::of(&quic_address()),
+            Some(TransportKind::Quic)
+        );
+        assert_eq!(
+            TransportKind::of(&websocket),
+            Some(TransportKind::WebSocket)
+        );
+    }
+
+    #[test]
+    fn init__fails_if_reserved_node_requires_disabled_transport() {
+        let mut p2p_config = Config::<NotInitialized>::default("init");
+        p2p_config.reserved_nodes = vec![quic_address()];
+
+        // When
+        let result = p2p_config.clone().init(Default::default());
+        p2p_config.quic_port = Some(4001);
+        let result_with_quic = p2p_config.init(Default::default());
+
+        // Then
+        assert!(result.is_err());
+        assert!(result_with_quic.is_ok());
+    }
 }
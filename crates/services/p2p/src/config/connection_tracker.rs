@@ -1,14 +1,16 @@
 use super::{
     fuel_authenticated::Approver,
-    peer_ids_set_from,
+    Config,
 };
 use crate::peer_manager::ConnectionState;
+use ip_network::IpNetwork;
 use libp2p::{
+    multiaddr::Protocol,
     Multiaddr,
     PeerId,
 };
 use std::{
-    collections::HashSet,
+    net::IpAddr,
     sync::{
         Arc,
         RwLock,
@@ -17,32 +19,112 @@ use std::{
 
 /// A `ConnectionTracker` allows either Reserved Peers or other peers if there is an available slot.
 /// It is synced with `PeerManager` which keeps track of the `ConnectionState`.
+/// It also filters connections by IP address, which is known only to the `Swarm`,
+/// so these checks are done by the connection handlers of `PeerReportBehavior`.
 #[derive(Debug, Clone)]
 pub(crate) struct ConnectionTracker {
-    reserved_nodes: HashSet<PeerId>,
-    connection_state: Option<Arc<RwLock<ConnectionState>>>,
+    connection_state: Arc<RwLock<ConnectionState>>,
+    reserved_nodes_only_mode: bool,
+    allowed_ip_networks: Vec<IpNetwork>,
+    denied_ip_networks: Vec<IpNetwork>,
+    max_connections_per_ip: Option<u32>,
+}
+
+/// The reason why the connection is refused by the `ConnectionTracker`.
+#[derive(Debug, Clone, thiserror::Error)]
+pub(crate) enum ConnectionRejected {
+    #[error("The IP address of `{0}` is not allowed")]
+    IpNotAllowed(Multiaddr),
+    #[error("Too many connections from the IP address of `{0}`")]
+    TooManyConnectionsFromIp(Multiaddr),
 }
 
 impl ConnectionTracker {
     pub(crate) fn new(
-        reserved_nodes: &[Multiaddr],
-        connection_state: Option<Arc<RwLock<ConnectionState>>>,
+        connection_state: Arc<RwLock<ConnectionState>>,
+        p2p_config: &Config,
     ) -> Self {
         Self {
-            reserved_nodes: peer_ids_set_from(reserved_nodes),
             connection_state,
+            reserved_nodes_only_mode: p2p_config.reserved_nodes_only_mode,
+            allowed_ip_networks: p2p_config.allowed_ip_networks.clone(),
+            denied_ip_networks: p2p_config.denied_ip_networks.clone(),
+            max_connections_per_ip: p2p_config.max_connections_per_ip,
+        }
+    }
+
+    /// Checks the address against the allow and deny lists.
+    /// Addresses without IP, like DNS names that are not resolved yet, are allowed.
+    pub(crate) fn check_address(
+        &self,
+        address: &Multiaddr,
+    ) -> Result<(), ConnectionRejected> {
+        let Some(ip) = ip_of(address) else {
+            return Ok(())
+        };
+        let allowed = self.allowed_ip_networks.is_empty()
+            || self
+                .allowed_ip_networks
+                .iter()
+                .any(|network| network.contains(ip));
+        let denied = self
+            .denied_ip_networks
+            .iter()
+            .any(|network| network.contains(ip));
+
+        if allowed && !denied {
+            Ok(())
+        } else {
+            Err(ConnectionRejected::IpNotAllowed(address.clone()))
+        }
+    }
+
+    /// Checks the address of the remote peer trying to connect to us,
+    /// including the limit of connections from the same IP.
+    pub(crate) fn check_inbound_connection(
+        &self,
+        remote_addr: &Multiaddr,
+    ) -> Result<(), ConnectionRejected> {
+        self.check_address(remote_addr)?;
+
+        if let (Some(limit), Some(ip)) = (self.max_connections_per_ip, ip_of(remote_addr))
+        {
+            if let Ok(connection_state) = self.connection_state.read() {
+                if connection_state.inbound_connections(&ip) >= limit {
+                    return Err(ConnectionRejected::TooManyConnectionsFromIp(
+                        remote_addr.clone(),
+                    ))
+                }
+            }
+        }
+        Ok(())
+    }
+
+    pub(crate) fn inbound_connection_established(&self, remote_addr: &Multiaddr) {
+        if let Some(ip) = ip_of(remote_addr) {
+            if let Ok(mut connection_state) = self.connection_state.write() {
+                connection_state.add_inbound_connection(ip);
+            }
+        }
+    }
+
+    pub(crate) fn inbound_connection_closed(&self, remote_addr: &Multiaddr) {
+        if let Some(ip) = ip_of(remote_addr) {
+            if let Ok(mut connection_state) = self.connection_state.write() {
+                connection_state.remove_inbound_connection(&ip);
+            }
         }
     }
 }
 
 impl Approver for ConnectionTracker {
     fn allow_peer(&self, peer_id: &PeerId) -> bool {
-        if self.reserved_nodes.contains(peer_id) {
-            return true
-        }
+        if let Ok(connection_state) = self.connection_state.read() {
+            if connection_state.is_reserved(peer_id) {
+                return true
+            }
 
-        if let Some(connection_state) = &self.connection_state {
-            if let Ok(connection_state) = connection_state.read() {
+            if !self.reserved_nodes_only_mode {
                 return connection_state.available_slot()
             }
         }
@@ -50,3 +132,64 @@ impl Approver for ConnectionTracker {
         false
     }
 }
+
+fn ip_of(address: &Multiaddr) -> Option<IpAddr> {
+    address.iter().find_map(|protocol| match protocol {
+        Protocol::Ip4(ip) => Some(IpAddr::V4(ip)),
+        Protocol::Ip6(ip) => Some(IpAddr::V6(ip)),
+        _ => None,
+    })
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use super::*;
+    use crate::config::NotInitialized;
+
+    fn tracker(p2p_config: &Config<NotInitialized>) -> ConnectionTracker {
+        let p2p_config = p2p_config
+            .clone()
+            .init(Default::default())
+            .expect("Valid config");
+        ConnectionTracker::new(ConnectionState::new(), &p2p_config)
+    }
+
+    fn address(ip: &str) -> Multiaddr {
+        format!("/ip4/{ip}/tcp/4001").parse().unwrap()
+    }
+
+    #[test]
+    fn check_address__respects_allow_and_deny_lists() {
+        let mut p2p_config = Config::<NotInitialized>::default("test");
+        p2p_config.allowed_ip_networks = vec!["10.0.0.0/8".parse().unwrap()];
+        p2p_config.denied_ip_networks = vec!["10.1.0.0/16".parse().unwrap()];
+        let tracker = tracker(&p2p_config);
+
+        assert!(tracker.check_address(&address("10.2.0.1")).is_ok());
+        assert!(tracker.check_address(&address("10.1.0.1")).is_err());
+        assert!(tracker.check_address(&address("192.168.0.1")).is_err());
+        let dns: Multiaddr = "/dns4/example.com/tcp/4001".parse().unwrap();
+        assert!(tracker.check_address(&dns).is_ok());
+    }
+
+    #[test]
+    fn check_inbound_connection__limits_connections_from_the_same_ip() {
+        let mut p2p_config = Config::<NotInitialized>::default("test");
+        p2p_config.max_connections_per_ip = Some(2);
+        let tracker = tracker(&p2p_config);
+        let remote_addr = address("10.0.0.1");
+
+        for _ in 0..2 {
+            assert!(tracker.check_inbound_connection(&remote_addr).is_ok());
+            tracker.inbound_connection_established(&remote_addr);
+        }
+        assert!(tracker.check_inbound_connection(&remote_addr).is_err());
+        assert!(tracker
+            .check_inbound_connection(&address("10.0.0.2"))
+            .is_ok());
+
+        tracker.inbound_connection_closed(&remote_addr);
+        assert!(tracker.check_inbound_connection(&remote_addr).is_ok());
+    }
+}
@@ -1,22 +1,38 @@
 use crate::config::fuel_upgrade::Checksum;
 use futures::{
+    future,
     AsyncRead,
+    AsyncReadExt,
     AsyncWrite,
+    AsyncWriteExt,
     Future,
 };
 use libp2p::{
     self,
     core::{
+        muxing::{
+            StreamMuxer,
+            StreamMuxerExt,
+        },
         upgrade::{
             InboundConnectionUpgrade,
             OutboundConnectionUpgrade,
         },
+        ConnectedPoint,
         UpgradeInfo,
     },
     noise,
     PeerId,
 };
-use std::pin::Pin;
+use std::{
+    io,
+    pin::Pin,
+    time::Duration,
+};
+
+/// The time the remote peer has to confirm the checksum
+/// of the connection that is already secured by the transport
+const CHECKSUM_EXCHANGE_TIMEOUT: Duration = Duration::from_secs(10);
 
 pub(crate) trait Approver {
     /// Allows Peer connection based on it's PeerId and the Approver's knowledge of the Connection State
@@ -106,3 +122,60 @@ where
         })
     }
 }
+
+/// Performs the checks of the `FuelAuthenticated` on the connection that is secured
+/// by the transport itself, like QUIC with its built-in TLS, where the noise
+/// handshake can't be used. The approver decides on the authenticated peer, and
+/// both sides exchange checksums over the first substream of the connection.
+pub(crate) async fn authenticate_muxed_connection<A, M>(
+    approver: A,
+    checksum: Checksum,
+    (remote_peer_id, mut muxer): (PeerId, M),
+    endpoint: ConnectedPoint,
+) -> io::Result<(PeerId, M)>
+where
+    A: Approver,
+    M: StreamMuxer + Unpin,
+    M::Substream: Unpin,
+    M::Error: std::error::Error + Send + Sync + 'static,
+{
+    if !approver.allow_peer(&remote_peer_id) {
+        return Err(io::Error::new(
+            io::ErrorKind::PermissionDenied,
+            "The peer is not allowed to connect",
+        ))
+    }
+
+    let exchange = async {
+        let mut remote_checksum = [0u8; 32];
+        if endpoint.is_dialer() {
+            let mut substream = future::poll_fn(|cx| muxer.poll_outbound_unpin(cx))
+                .await
+                .map_err(io::Error::other)?;
+            substream.write_all(checksum.as_ref()).await?;
+            substream.flush().await?;
+            substream.read_exact(&mut remote_checksum).await?;
+            substream.close().await?;
+        } else {
+            let mut substream = future::poll_fn(|cx| muxer.poll_inbound_unpin(cx))
+                .await
+                .map_err(io::Error::other)?;
+            substream.read_exact(&mut remote_checksum).await?;
+            substream.write_all(checksum.as_ref()).await?;
+            substream.close().await?;
+        }
+        Ok::<_, io::Error>(remote_checksum)
+    };
+    let remote_checksum = tokio::time::timeout(CHECKSUM_EXCHANGE_TIMEOUT, exchange)
+        .await
+        .map_err(|_| io::Error::from(io::ErrorKind::TimedOut))??;
+
+    if remote_checksum.as_slice() != checksum.as_ref() {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "The peer is from another network",
+        ))
+    }
+
+    Ok((remote_peer_id, muxer))
+}
@@ -27,6 +27,8 @@ use std::{
 
 use super::topics::{
     GossipTopic,
+    CONSENSUS_GOSSIP_TOPIC,
+    NEW_BLOCK_GOSSIP_TOPIC,
     NEW_TX_GOSSIP_TOPIC,
 };
 
@@ -51,6 +53,12 @@ const MESH_SIZE: usize = 8;
 // The weight applied to the score for delivering new transactions.
 const NEW_TX_GOSSIP_WEIGHT: f64 = 0.05;
 
+// The weight applied to the score for delivering new blocks.
+const NEW_BLOCK_GOSSIP_WEIGHT: f64 = 0.05;
+
+// The weight applied to the score for delivering consensus messages.
+const CONSENSUS_GOSSIP_WEIGHT: f64 = 0.05;
+
 // The threshold for a peer's score to be considered for greylisting.
 // If a peer's score falls below this value, they will be greylisted.
 // Greylisting is a lighter form of banning, where the peer's messages might be ignored or given lower priority,
@@ -222,7 +230,11 @@ fn initialize_gossipsub(gossipsub: &mut gossipsub::Behaviour, p2p_config: &Confi
         .with_peer_score(peer_score_params, peer_score_thresholds)
         .expect("gossipsub initialized with peer score");
 
-    let topics = vec![(NEW_TX_GOSSIP_TOPIC, NEW_TX_GOSSIP_WEIGHT)];
+    let topics = vec![
+        (NEW_TX_GOSSIP_TOPIC, NEW_TX_GOSSIP_WEIGHT),
+        (NEW_BLOCK_GOSSIP_TOPIC, NEW_BLOCK_GOSSIP_WEIGHT),
+        (CONSENSUS_GOSSIP_TOPIC, CONSENSUS_GOSSIP_WEIGHT),
+    ];
 
     // subscribe to gossipsub topics with the network name suffix
     for (topic, weight) in topics {
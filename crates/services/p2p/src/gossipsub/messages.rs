@@ -1,6 +1,12 @@
 use std::sync::Arc;
 
-use fuel_core_types::fuel_tx::Transaction;
+use fuel_core_types::{
+    blockchain::{
+        consensus::bft::BftMessage,
+        SealedBlock,
+    },
+    fuel_tx::Transaction,
+};
 
 use serde::{
     Deserialize,
@@ -12,6 +18,8 @@ use serde::{
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
 pub enum GossipTopicTag {
     NewTx,
+    NewBlock,
+    Consensus,
 }
 
 /// Takes `Arc<T>` and wraps it in a matching GossipsubBroadcastRequest
@@ -20,9 +28,13 @@ pub enum GossipTopicTag {
 #[derive(Debug, Clone)]
 pub enum GossipsubBroadcastRequest {
     NewTx(Arc<Transaction>),
+    NewBlock(Arc<SealedBlock>),
+    Consensus(Arc<BftMessage>),
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub enum GossipsubMessage {
     NewTx(Transaction),
+    NewBlock(SealedBlock),
+    Consensus(BftMessage),
 }
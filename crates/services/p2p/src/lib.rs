@@ -7,16 +7,19 @@ pub mod config;
 pub mod discovery;
 pub mod gossipsub;
 pub mod heartbeat;
-pub mod heavy_task_processor;
+#[cfg(any(test, feature = "network-simulation"))]
+pub mod network_simulation;
 pub mod p2p_service;
 pub mod peer_manager;
 pub mod peer_report;
 pub mod ports;
 pub mod request_response;
 pub mod service;
+pub mod storage;
 
 pub use gossipsub::config as gossipsub_config;
 pub use heartbeat::Config;
+pub use ip_network::IpNetwork;
 
 pub use libp2p::{
     multiaddr::Protocol,
@@ -0,0 +1,692 @@
+//! The in-process network of `FuelP2PService`s connected by the memory transport.
+//! It allows testing the gossip propagation, the synchronization of peers,
+//! and the reputation rules on different topologies without the real network.
+//!
+//! The conditions of links, like the latency, the packet loss, and partitions,
+//! can be changed at any time. The packet loss is derived from the seed of the network,
+//! so the same seed produces the same delays.
+
+use crate::{
+    codecs::postcard::PostcardCodec,
+    config::{
+        build_secured_transport,
+        Config,
+    },
+    p2p_service::{
+        FuelP2PEvent,
+        FuelP2PService,
+        PeerManagementRequest,
+    },
+};
+use conditions::NetworkState;
+use futures::FutureExt;
+use libp2p::{
+    multiaddr::Protocol,
+    Multiaddr,
+    PeerId,
+};
+use std::{
+    sync::{
+        atomic::{
+            AtomicU64,
+            Ordering,
+        },
+        Arc,
+        Mutex,
+    },
+    time::Duration,
+};
+use tokio::sync::broadcast;
+use transport::{
+    lock,
+    SharedNetworkState,
+    SimulatedTransport,
+};
+
+mod conditions;
+mod transport;
+
+pub use conditions::{
+    LinkConditions,
+    NodeId,
+};
+
+/// The number of memory ports reserved by each `SimulatedNetwork`.
+const PORTS_PER_NETWORK: u64 = 1 << 16;
+
+/// The first memory port used by simulated networks. Ports of networks never overlap,
+/// so tests running in parallel don't affect each other.
+static NEXT_PORT_BASE: AtomicU64 = AtomicU64::new(1 << 48);
+
+/// The links created between nodes spawned together.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Topology {
+    /// Each node is connected to each other node.
+    FullMesh,
+    /// Each node is connected to the previous and the next nodes.
+    Line,
+    /// The `Line` where the last node is connected to the first node.
+    Ring,
+    /// Each node is connected only to the first node.
+    Star,
+}
+
+impl Topology {
+    fn links(&self, nodes: &[NodeId]) -> Vec<(NodeId, NodeId)> {
+        let neighbours = nodes.iter().copied().zip(nodes.iter().copied().skip(1));
+        match self {
+            Topology::FullMesh => nodes
+                .iter()
+                .enumerate()
+                .flat_map(|(i, a)| {
+                    nodes.iter().skip(i.saturating_add(1)).map(|b| (*a, *b))
+                })
+                .collect(),
+            Topology::Line => neighbours.collect(),
+            Topology::Ring => {
+                let mut links: Vec<_> = neighbours.collect();
+                if let (Some(first), Some(last)) = (nodes.first(), nodes.last()) {
+                    if nodes.len() > 2 {
+                        links.push((*last, *first));
+                    }
+                }
+                links
+            }
+            Topology::Star => nodes
+                .split_first()
+                .map(|(center, others)| {
+                    others.iter().map(|node| (*center, *node)).collect()
+                })
+                .unwrap_or_default(),
+        }
+    }
+}
+
+/// The node of the simulated network.
+pub struct SimulatedNode {
+    pub id: NodeId,
+    /// The address of the node, including its peer id.
+    pub address: Multiaddr,
+    pub service: FuelP2PService,
+}
+
+impl SimulatedNode {
+    pub fn peer_id(&self) -> PeerId {
+        self.service.local_peer_id
+    }
+}
+
+pub struct SimulatedNetwork {
+    state: SharedNetworkState,
+    nodes: Vec<SimulatedNode>,
+}
+
+impl SimulatedNetwork {
+    /// Creates the network without nodes. The `seed` defines the packet loss of links.
+    pub fn new(seed: u64) -> Self {
+        let port_base = NEXT_PORT_BASE.fetch_add(PORTS_PER_NETWORK, Ordering::Relaxed);
+        Self {
+            state: Arc::new(Mutex::new(NetworkState::new(seed, port_base))),
+            nodes: vec![],
+        }
+    }
+
+    /// Starts the node with a new keypair. The node is not connected to other nodes.
+    pub async fn spawn_node(&mut self, mut config: Config) -> anyhow::Result<NodeId> {
+        let id = NodeId(u64::try_from(self.nodes.len())?);
+        if id.0 >= PORTS_PER_NETWORK {
+            return Err(anyhow::anyhow!("Too many nodes in the simulated network"))
+        }
+        let port = lock(&self.state).port_of(id);
+
+        config.keypair = libp2p::identity::Keypair::generate_secp256k1();
+        let peer_id = config.keypair.public().to_peer_id();
+        let max_block_size = config.max_block_size;
+        let (reserved_peers_updates, _) =
+            broadcast::channel(config.reserved_nodes.len().saturating_add(1));
+        let (transport, connection_state) = build_secured_transport(
+            &config,
+            SimulatedTransport::new(id, self.state.clone()),
+        );
+        let mut service = FuelP2PService::with_transport(
+            reserved_peers_updates,
+            config,
+            PostcardCodec::new(max_block_size),
+            transport,
+            connection_state,
+            vec![Protocol::Memory(port).into()],
+        );
+        service.start().await?;
+
+        let address = Multiaddr::empty()
+            .with(Protocol::Memory(port))
+            .with(Protocol::P2p(peer_id));
+        self.nodes.push(SimulatedNode {
+            id,
+            address,
+            service,
+        });
+        Ok(id)
+    }
+
+    /// Starts `count` nodes and connects them according to the `topology`.
+    pub async fn spawn_nodes(
+        &mut self,
+        config: &Config,
+        count: usize,
+        topology: Topology,
+    ) -> anyhow::Result<Vec<NodeId>> {
+        let mut nodes = Vec::with_capacity(count);
+        for _ in 0..count {
+            nodes.push(self.spawn_node(config.clone()).await?);
+        }
+        for (a, b) in topology.links(&nodes) {
+            self.connect(a, b)?;
+        }
+        Ok(nodes)
+    }
+
+    /// Adds the link between nodes and dials `b` from `a`.
+    /// Once any link is added, nodes can communicate only via added links.
+    pub fn connect(&mut self, a: NodeId, b: NodeId) -> anyhow::Result<()> {
+        lock(&self.state).allow_link(a, b);
+        self.dial(a, b)
+    }
+
+    /// Dials `b` from `a` via the existing link.
+    pub fn dial(&mut self, a: NodeId, b: NodeId) -> anyhow::Result<()> {
+        let address = self.node(b).address.clone();
+        self.node_mut(a)
+            .service
+            .manage_peers(PeerManagementRequest::Dial(address))
+    }
+
+    pub fn node(&self, id: NodeId) -> &SimulatedNode {
+        &self.nodes[Self::index(id)]
+    }
+
+    pub fn node_mut(&mut self, id: NodeId) -> &mut SimulatedNode {
+        &mut self.nodes[Self::index(id)]
+    }
+
+    pub fn nodes(&self) -> &[SimulatedNode] {
+        &self.nodes
+    }
+
+    /// Returns the node with the `peer_id`.
+    pub fn node_id_of(&self, peer_id: &PeerId) -> Option<NodeId> {
+        self.nodes
+            .iter()
+            .find(|node| &node.peer_id() == peer_id)
+            .map(|node| node.id)
+    }
+
+    fn index(id: NodeId) -> usize {
+        usize::try_from(id.0).expect("The node is spawned by this network")
+    }
+
+    /// Sets the conditions of links without their own conditions.
+    pub fn set_default_link_conditions(&self, conditions: LinkConditions) {
+        lock(&self.state).set_default_link(conditions);
+    }
+
+    /// Sets the conditions of the link between `a` and `b`.
+    pub fn set_link_conditions(&self, a: NodeId, b: NodeId, conditions: LinkConditions) {
+        lock(&self.state).set_link(a, b, conditions);
+    }
+
+    /// Splits the network into `groups`. Nodes of different groups can't communicate,
+    /// and the connections between them fail on the next read or write.
+    /// Nodes not mentioned in any group form one more group.
+    pub fn partition(&self, groups: &[&[NodeId]]) {
+        lock(&self.state).partition(groups);
+    }
+
+    /// Removes all partitions. The closed connections are not restored,
+    /// nodes reconnect on their own or via `dial`.
+    pub fn heal(&self) {
+        lock(&self.state).heal();
+    }
+
+    /// Drives all nodes until one of them returns the event.
+    pub async fn next_event(&mut self) -> (NodeId, FuelP2PEvent) {
+        if self.nodes.is_empty() {
+            return std::future::pending().await
+        }
+        loop {
+            let events = self.nodes.iter_mut().map(|node| {
+                let id = node.id;
+                async move { (id, node.service.next_event().await) }.boxed()
+            });
+            if let ((id, Some(event)), _, _) = futures::future::select_all(events).await {
+                return (id, event)
+            }
+        }
+    }
+
+    /// Drives all nodes until the `condition` returns `true` for the received event.
+    pub async fn run_until<F>(
+        &mut self,
+        timeout: Duration,
+        mut condition: F,
+    ) -> anyhow::Result<()>
+    where
+        F: FnMut(&mut Self, NodeId, FuelP2PEvent) -> bool,
+    {
+        tokio::time::timeout(timeout, async {
+            loop {
+                let (id, event) = self.next_event().await;
+                if condition(self, id, event) {
+                    break
+                }
+            }
+        })
+        .await
+        .map_err(|_| anyhow::anyhow!("The condition is not met within {timeout:?}"))
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use super::*;
+    use crate::{
+        gossipsub::messages::{
+            GossipTopicTag,
+            GossipsubBroadcastRequest,
+            GossipsubMessage,
+        },
+        peer_manager::PeerInfo,
+        request_response::messages::{
+            RequestMessage,
+            ResponseMessage,
+            ResponseSender,
+        },
+    };
+    use fuel_core_types::{
+        blockchain::{
+            block::Block,
+            consensus::{
+                poa::PoAConsensus,
+                Consensus,
+            },
+            header::BlockHeader,
+            primitives::BlockId,
+            SealedBlock,
+        },
+        fuel_tx::{
+            Finalizable,
+            Transaction,
+            TransactionBuilder,
+        },
+        fuel_types::BlockHeight,
+    };
+    use libp2p::gossipsub::MessageAcceptance;
+    use std::{
+        collections::{
+            HashMap,
+            HashSet,
+        },
+        ops::Range,
+    };
+    use tokio::{
+        sync::oneshot,
+        time::Instant,
+    };
+
+    const TIMEOUT: Duration = Duration::from_secs(20);
+
+    fn config(name: &str) -> Config {
+        Config::default_initialized(name)
+    }
+
+    fn tx(script: Vec<u8>) -> Transaction {
+        TransactionBuilder::script(script, vec![])
+            .max_fee_limit(0)
+            .add_random_fee_input()
+            .finalize()
+            .into()
+    }
+
+    fn blocks(range: Range<u32>) -> Vec<SealedBlock> {
+        range
+            .map(|height| {
+                let mut header = BlockHeader::default();
+                header.set_block_height(height.into());
+                header.recalculate_metadata();
+                let mut block = Block::default();
+                *block.header_mut() = header;
+                SealedBlock {
+                    entity: block,
+                    consensus: Consensus::PoA(PoAConsensus::new(Default::default())),
+                }
+            })
+            .collect()
+    }
+
+    fn heights_and_ids(blocks: &[SealedBlock]) -> Vec<(BlockHeight, BlockId)> {
+        blocks
+            .iter()
+            .map(|block| {
+                let header = block.entity.header();
+                (*header.height(), header.id())
+            })
+            .collect()
+    }
+
+    /// Requests the `range` of blocks by `to` from `from`, which serves them from
+    /// its imported `chains`. The received blocks are imported by `to`,
+    /// each of them must continue its chain.
+    async fn sync_blocks(
+        network: &mut SimulatedNetwork,
+        chains: &mut HashMap<NodeId, Vec<SealedBlock>>,
+        from: NodeId,
+        to: NodeId,
+        range: Range<u32>,
+    ) {
+        let (sender, mut receiver) = oneshot::channel();
+        let peer_id = network.node(from).peer_id();
+        network
+            .node_mut(to)
+            .service
+            .send_request_msg(
+                Some(peer_id),
+                RequestMessage::SealedBlocks(range),
+                ResponseSender::SealedBlocks(sender),
+            )
+            .unwrap();
+
+        let response = tokio::time::timeout(TIMEOUT, async {
+            loop {
+                tokio::select! {
+                    response = &mut receiver => break response,
+                    (id, event) = network.next_event() => {
+                        if let FuelP2PEvent::InboundRequestMessage {
+                            request_id,
+                            request_message: RequestMessage::SealedBlocks(range),
+                        } = event
+                        {
+                            let chain = chains.get(&id).cloned().unwrap_or_default();
+                            let blocks = chain
+                                .into_iter()
+                                .filter(|block| {
+                                    range.contains(&**block.entity.header().height())
+                                })
+                                .collect();
+                            let _ = network.node_mut(id).service.send_response_msg(
+                                request_id,
+                                ResponseMessage::SealedBlocks(Some(blocks)),
+                            );
+                        }
+                    }
+                }
+            }
+        })
+        .await
+        .expect("The blocks should be received in time");
+        let (_, blocks) = response.expect("The request should not be dropped");
+        let blocks = blocks
+            .expect("The response should be valid")
+            .expect("The peer should have the blocks");
+
+        let chain = chains.entry(to).or_default();
+        for block in blocks {
+            let expected_height = chain
+                .last()
+                .and_then(|last| last.entity.header().height().succ())
+                .unwrap_or_default();
+            assert_eq!(*block.entity.header().height(), expected_height);
+            chain.push(block);
+        }
+    }
+
+    /// Waits until each linked node knows that its neighbours subscribed to new transactions.
+    async fn wait_for_subscriptions(
+        network: &mut SimulatedNetwork,
+        links: &[(NodeId, NodeId)],
+    ) {
+        let mut pending: HashSet<(NodeId, NodeId)> = links
+            .iter()
+            .flat_map(|(a, b)| [(*a, *b), (*b, *a)])
+            .collect();
+        network
+            .run_until(TIMEOUT, |network, id, event| {
+                if let FuelP2PEvent::NewSubscription {
+                    peer_id,
+                    tag: GossipTopicTag::NewTx,
+                } = event
+                {
+                    if let Some(peer) = network.node_id_of(&peer_id) {
+                        pending.remove(&(id, peer));
+                    }
+                }
+                pending.is_empty()
+            })
+            .await
+            .expect("Nodes should subscribe to each other");
+    }
+
+    /// Drives the network and returns nodes that received the gossiped transaction,
+    /// all received transactions are accepted for further propagation.
+    async fn propagate(
+        network: &mut SimulatedNetwork,
+        expected_receivers: usize,
+        timeout: Duration,
+    ) -> HashSet<NodeId> {
+        let mut receivers = HashSet::new();
+        let _ = network
+            .run_until(timeout, |network, id, event| {
+                if let FuelP2PEvent::GossipsubMessage {
+                    peer_id,
+                    message_id,
+                    message: GossipsubMessage::NewTx(_),
+                    ..
+                } = event
+                {
+                    network
+                        .node_mut(id)
+                        .service
+                        .report_message_validation_result(
+                            &message_id,
+                            peer_id,
+                            MessageAcceptance::Accept,
+                        );
+                    receivers.insert(id);
+                }
+                receivers.len() >= expected_receivers
+            })
+            .await;
+        receivers
+    }
+
+    #[tokio::test(flavor = "current_thread", start_paused = true)]
+    async fn gossip__propagates_along_the_line_with_the_latency_of_each_link() {
+        let latency = Duration::from_millis(50);
+        let mut network = SimulatedNetwork::new(0);
+        let nodes = network
+            .spawn_nodes(&config("gossip_line"), 4, Topology::Line)
+            .await
+            .unwrap();
+        wait_for_subscriptions(&mut network, &Topology::Line.links(&nodes)).await;
+        network.set_default_link_conditions(LinkConditions {
+            latency,
+            ..Default::default()
+        });
+
+        // When
+        let published_at = Instant::now();
+        network
+            .node_mut(nodes[0])
+            .service
+            .publish_message(GossipsubBroadcastRequest::NewTx(Arc::new(tx(vec![]))))
+            .unwrap();
+        let receivers = propagate(&mut network, 3, TIMEOUT).await;
+
+        // Then
+        assert_eq!(receivers, nodes[1..].iter().copied().collect());
+        assert!(published_at.elapsed() >= latency * 3);
+    }
+
+    #[tokio::test(flavor = "current_thread", start_paused = true)]
+    async fn gossip__does_not_cross_the_partition_until_it_is_healed() {
+        let mut network = SimulatedNetwork::new(1);
+        let nodes = network
+            .spawn_nodes(&config("gossip_partition"), 3, Topology::Line)
+            .await
+            .unwrap();
+        let links = Topology::Line.links(&nodes);
+        wait_for_subscriptions(&mut network, &links).await;
+
+        // Given
+        network.partition(&[&nodes[..2], &nodes[2..]]);
+
+        // When
+        network
+            .node_mut(nodes[0])
+            .service
+            .publish_message(GossipsubBroadcastRequest::NewTx(Arc::new(tx(vec![]))))
+            .unwrap();
+        let receivers = propagate(&mut network, 2, Duration::from_secs(2)).await;
+
+        // Then
+        assert_eq!(receivers, [nodes[1]].into());
+
+        // Given
+        network.heal();
+        network.dial(nodes[2], nodes[1]).unwrap();
+        wait_for_subscriptions(&mut network, &links[1..]).await;
+
+        // When
+        network
+            .node_mut(nodes[0])
+            .service
+            .publish_message(GossipsubBroadcastRequest::NewTx(Arc::new(tx(vec![1]))))
+            .unwrap();
+        let receivers = propagate(&mut network, 2, TIMEOUT).await;
+
+        // Then
+        assert_eq!(receivers, nodes[1..].iter().copied().collect());
+    }
+
+    #[tokio::test(flavor = "current_thread", start_paused = true)]
+    async fn heartbeat__block_heights_converge_over_lossy_links() {
+        let mut network = SimulatedNetwork::new(2);
+        network.set_default_link_conditions(LinkConditions {
+            latency: Duration::from_millis(10),
+            packet_loss: 0.2,
+            retransmission_timeout: Duration::from_millis(100),
+        });
+        let nodes = network
+            .spawn_nodes(&config("heartbeat_ring"), 4, Topology::Ring)
+            .await
+            .unwrap();
+        let height_of = |id: NodeId| BlockHeight::from(u32::try_from(id.0).unwrap() + 10);
+
+        // When
+        for id in &nodes {
+            network
+                .node_mut(*id)
+                .service
+                .update_block_height(height_of(*id));
+        }
+
+        // Then
+        let links = Topology::Ring.links(&nodes);
+        let mut pending: HashSet<(NodeId, NodeId)> = links
+            .iter()
+            .flat_map(|(a, b)| [(*a, *b), (*b, *a)])
+            .collect();
+        network
+            .run_until(TIMEOUT, |network, id, event| {
+                if let FuelP2PEvent::PeerInfoUpdated {
+                    peer_id,
+                    block_height,
+                } = event
+                {
+                    if let Some(peer) = network.node_id_of(&peer_id) {
+                        if block_height == height_of(peer) {
+                            pending.remove(&(id, peer));
+                        }
+                    }
+                }
+                pending.is_empty()
+            })
+            .await
+            .expect("Each node should learn the block height of its neighbours");
+        for (a, b) in links {
+            let info = network
+                .node(a)
+                .service
+                .peer_manager()
+                .get_peer_info(&network.node(b).peer_id());
+            assert!(matches!(
+                info,
+                Some(PeerInfo { heartbeat_data, .. })
+                    if heartbeat_data.block_height == Some(height_of(b))
+            ));
+        }
+    }
+
+    #[tokio::test(flavor = "current_thread", start_paused = true)]
+    async fn sync__blocks_are_imported_along_the_line_over_lossy_links() {
+        let mut network = SimulatedNetwork::new(4);
+        network.set_default_link_conditions(LinkConditions {
+            latency: Duration::from_millis(10),
+            packet_loss: 0.2,
+            retransmission_timeout: Duration::from_millis(100),
+        });
+        let nodes = network
+            .spawn_nodes(&config("sync_line"), 3, Topology::Line)
+            .await
+            .unwrap();
+        wait_for_subscriptions(&mut network, &Topology::Line.links(&nodes)).await;
+
+        // Given
+        let produced = blocks(0..10);
+        let mut chains = HashMap::from([(nodes[0], produced.clone())]);
+
+        // When
+        for (from, to) in Topology::Line.links(&nodes) {
+            sync_blocks(&mut network, &mut chains, from, to, 0..5).await;
+            sync_blocks(&mut network, &mut chains, from, to, 5..10).await;
+        }
+
+        // Then
+        let expected = heights_and_ids(&produced);
+        for id in &nodes[1..] {
+            assert_eq!(heights_and_ids(&chains[id]), expected);
+        }
+    }
+
+    #[tokio::test(flavor = "current_thread", start_paused = true)]
+    async fn reputation__peer_with_low_score_is_banned_and_disconnected() {
+        let mut network = SimulatedNetwork::new(3);
+        let nodes = network
+            .spawn_nodes(&config("reputation_star"), 3, Topology::Star)
+            .await
+            .unwrap();
+        wait_for_subscriptions(&mut network, &Topology::Star.links(&nodes)).await;
+        let center = nodes[0];
+        let bad_peer = network.node(nodes[1]).peer_id();
+        let good_peer = network.node(nodes[2]).peer_id();
+
+        // When
+        network
+            .node_mut(center)
+            .service
+            .report_peer(bad_peer, -100.0, "test");
+
+        // Then
+        network
+            .run_until(TIMEOUT, |_, id, event| {
+                id == center
+                    && matches!(event, FuelP2PEvent::PeerDisconnected(peer) if peer == bad_peer)
+            })
+            .await
+            .expect("The bad peer should be disconnected");
+        let peer_manager = network.node(center).service.peer_manager();
+        assert!(peer_manager.is_banned(&bad_peer));
+        assert!(!peer_manager.is_banned(&good_peer));
+        assert!(peer_manager.get_peer_info(&good_peer).is_some());
+    }
+}
@@ -0,0 +1,207 @@
+use rand::{
+    rngs::StdRng,
+    Rng,
+    SeedableRng,
+};
+use std::{
+    collections::{
+        HashMap,
+        HashSet,
+    },
+    time::Duration,
+};
+
+/// The maximum number of times the same chunk of data can be lost in a row.
+/// It keeps the delay finite even if the `packet_loss` is close to `1.0`.
+const MAX_RETRANSMISSIONS: usize = 8;
+
+/// The identifier of the node in the simulated network.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct NodeId(pub u64);
+
+/// The quality of the link between two nodes, the same in both directions.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LinkConditions {
+    /// The time it takes the data to reach the other node.
+    pub latency: Duration,
+    /// The probability from `0.0` to `1.0` that the chunk of data is lost.
+    /// The lost data is delivered again after the `retransmission_timeout`,
+    /// like it happens with TCP.
+    pub packet_loss: f64,
+    /// The additional delay of the lost chunk of data.
+    pub retransmission_timeout: Duration,
+}
+
+impl Default for LinkConditions {
+    fn default() -> Self {
+        Self {
+            latency: Duration::ZERO,
+            packet_loss: 0.0,
+            retransmission_timeout: Duration::from_millis(200),
+        }
+    }
+}
+
+/// The undirected link between two nodes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct Link(NodeId, NodeId);
+
+impl Link {
+    fn new(a: NodeId, b: NodeId) -> Self {
+        if a <= b {
+            Self(a, b)
+        } else {
+            Self(b, a)
+        }
+    }
+}
+
+/// The state of the simulated network shared by transports of all nodes.
+pub(crate) struct NetworkState {
+    seed: u64,
+    /// The memory port of the node with `NodeId(0)`, other nodes use the following ports.
+    port_base: u64,
+    default_link: LinkConditions,
+    links: HashMap<Link, LinkConditions>,
+    /// If set, only these links can be used.
+    allowed_links: Option<HashSet<Link>>,
+    /// The index of the partition of each node, nodes without it are in the partition `0`.
+    partitions: HashMap<NodeId, usize>,
+    /// The source of randomness of each direction of each link.
+    rngs: HashMap<(NodeId, NodeId), StdRng>,
+}
+
+impl NetworkState {
+    pub fn new(seed: u64, port_base: u64) -> Self {
+        Self {
+            seed,
+            port_base,
+            default_link: Default::default(),
+            links: Default::default(),
+            allowed_links: None,
+            partitions: Default::default(),
+            rngs: Default::default(),
+        }
+    }
+
+    pub fn port_of(&self, node: NodeId) -> u64 {
+        self.port_base.saturating_add(node.0)
+    }
+
+    pub fn node_of(&self, port: u64) -> Option<NodeId> {
+        port.checked_sub(self.port_base).map(NodeId)
+    }
+
+    pub fn set_default_link(&mut self, conditions: LinkConditions) {
+        self.default_link = conditions;
+    }
+
+    pub fn set_link(&mut self, a: NodeId, b: NodeId, conditions: LinkConditions) {
+        self.links.insert(Link::new(a, b), conditions);
+    }
+
+    /// Allows the link. Once any link is allowed, nodes can communicate only via allowed links.
+    pub fn allow_link(&mut self, a: NodeId, b: NodeId) {
+        self.allowed_links
+            .get_or_insert_with(HashSet::new)
+            .insert(Link::new(a, b));
+    }
+
+    pub fn partition(&mut self, groups: &[&[NodeId]]) {
+        self.partitions.clear();
+        for (index, group) in groups.iter().enumerate() {
+            for node in group.iter() {
+                self.partitions.insert(*node, index.saturating_add(1));
+            }
+        }
+    }
+
+    pub fn heal(&mut self) {
+        self.partitions.clear();
+    }
+
+    /// Returns `true` if the nodes are in the same partition
+    /// and the link between them is allowed.
+    pub fn can_communicate(&self, a: NodeId, b: NodeId) -> bool {
+        let partition_of = |node| self.partitions.get(&node).copied().unwrap_or(0);
+        let allowed = self
+            .allowed_links
+            .as_ref()
+            .map_or(true, |links| links.contains(&Link::new(a, b)));
+        allowed && partition_of(a) == partition_of(b)
+    }
+
+    /// The delay of the next chunk of data sent from `from` to `to`.
+    pub fn next_delay(&mut self, from: NodeId, to: NodeId) -> Duration {
+        let conditions = self
+            .links
+            .get(&Link::new(from, to))
+            .copied()
+            .unwrap_or(self.default_link);
+        let seed = self.seed ^ from.0.rotate_left(32) ^ to.0;
+        let rng = self
+            .rngs
+            .entry((from, to))
+            .or_insert_with(|| StdRng::seed_from_u64(seed));
+        let packet_loss = conditions.packet_loss.clamp(0.0, 1.0);
+
+        let mut delay = conditions.latency;
+        for _ in 0..MAX_RETRANSMISSIONS {
+            if !rng.gen_bool(packet_loss) {
+                break;
+            }
+            delay = delay.saturating_add(conditions.retransmission_timeout);
+        }
+        delay
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_communicate__respects_partitions_and_allowed_links() {
+        let [a, b, c] = [NodeId(0), NodeId(1), NodeId(2)];
+        let mut state = NetworkState::new(0, 1);
+        assert!(state.can_communicate(a, c));
+
+        state.allow_link(a, b);
+        state.allow_link(c, b);
+        assert!(state.can_communicate(b, a));
+        assert!(!state.can_communicate(a, c));
+
+        state.partition(&[&[a], &[b, c]]);
+        assert!(!state.can_communicate(a, b));
+        assert!(state.can_communicate(b, c));
+
+        state.heal();
+        assert!(state.can_communicate(a, b));
+    }
+
+    #[test]
+    fn next_delay__is_the_same_for_the_same_seed() {
+        let conditions = LinkConditions {
+            latency: Duration::from_millis(10),
+            packet_loss: 0.5,
+            retransmission_timeout: Duration::from_millis(100),
+        };
+        let delays = |seed| {
+            let mut state = NetworkState::new(seed, 1);
+            state.set_default_link(conditions);
+            (0..100)
+                .map(|_| state.next_delay(NodeId(0), NodeId(1)))
+                .collect::<Vec<_>>()
+        };
+
+        let delays_of_first_run = delays(42);
+        assert_eq!(delays_of_first_run, delays(42));
+        assert!(delays_of_first_run
+            .iter()
+            .all(|delay| *delay >= conditions.latency));
+        assert!(delays_of_first_run
+            .iter()
+            .any(|delay| *delay > conditions.latency));
+    }
+}
@@ -0,0 +1,313 @@
+use super::conditions::{
+    NetworkState,
+    NodeId,
+};
+use futures::{
+    future::BoxFuture,
+    AsyncRead,
+    AsyncReadExt,
+    AsyncWrite,
+    AsyncWriteExt,
+    FutureExt,
+};
+use libp2p::{
+    core::{
+        transport::{
+            memory::{
+                Channel,
+                MemoryTransportError,
+            },
+            ListenerId,
+            MemoryTransport,
+            TransportError,
+            TransportEvent,
+        },
+        Transport,
+    },
+    multiaddr::Protocol,
+    Multiaddr,
+};
+use std::{
+    collections::VecDeque,
+    future::Future,
+    io,
+    pin::Pin,
+    sync::{
+        Arc,
+        Mutex,
+        MutexGuard,
+    },
+    task::{
+        Context,
+        Poll,
+    },
+};
+use tokio::time::{
+    Instant,
+    Sleep,
+};
+
+/// The size of the buffer used to read the data from the memory channel.
+const READ_BUFFER_SIZE: usize = 16 * 1024;
+
+#[derive(Debug, thiserror::Error)]
+pub(crate) enum SimulatedTransportError {
+    #[error(transparent)]
+    Memory(#[from] MemoryTransportError),
+    #[error("The node {0:?} is unreachable from the node {1:?}")]
+    Unreachable(NodeId, NodeId),
+    #[error(transparent)]
+    Io(#[from] io::Error),
+}
+
+pub(crate) type SharedNetworkState = Arc<Mutex<NetworkState>>;
+
+pub(crate) fn lock(state: &SharedNetworkState) -> MutexGuard<'_, NetworkState> {
+    state.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+/// The `MemoryTransport` that applies the conditions of the simulated network
+/// to the connections between nodes.
+///
+/// The dialer sends its `NodeId` right after the connection is opened,
+/// so both sides know the link used by the connection.
+pub(crate) struct SimulatedTransport {
+    inner: MemoryTransport,
+    local: NodeId,
+    state: SharedNetworkState,
+}
+
+impl SimulatedTransport {
+    pub fn new(local: NodeId, state: SharedNetworkState) -> Self {
+        Self {
+            inner: MemoryTransport::new(),
+            local,
+            state,
+        }
+    }
+}
+
+impl Transport for SimulatedTransport {
+    type Output = SimulatedConnection;
+    type Error = SimulatedTransportError;
+    type ListenerUpgrade = BoxFuture<'static, Result<Self::Output, Self::Error>>;
+    type Dial = BoxFuture<'static, Result<Self::Output, Self::Error>>;
+
+    fn listen_on(
+        &mut self,
+        id: ListenerId,
+        addr: Multiaddr,
+    ) -> Result<(), TransportError<Self::Error>> {
+        self.inner
+            .listen_on(id, addr)
+            .map_err(|e| e.map(Into::into))
+    }
+
+    fn remove_listener(&mut self, id: ListenerId) -> bool {
+        self.inner.remove_listener(id)
+    }
+
+    fn dial(
+        &mut self,
+        addr: Multiaddr,
+    ) -> Result<Self::Dial, TransportError<Self::Error>> {
+        let remote = addr
+            .iter()
+            .find_map(|protocol| match protocol {
+                Protocol::Memory(port) => lock(&self.state).node_of(port),
+                _ => None,
+            })
+            .ok_or_else(|| TransportError::MultiaddrNotSupported(addr.clone()))?;
+        let local = self.local;
+
+        if !lock(&self.state).can_communicate(local, remote) {
+            return Err(TransportError::Other(SimulatedTransportError::Unreachable(
+                remote, local,
+            )))
+        }
+
+        let dial = self.inner.dial(addr).map_err(|e| e.map(Into::into))?;
+        let state = self.state.clone();
+        Ok(async move {
+            let mut channel = dial.await?;
+            channel.write_all(&local.0.to_be_bytes()).await?;
+            channel.flush().await?;
+            Ok(SimulatedConnection::new(channel, local, remote, state))
+        }
+        .boxed())
+    }
+
+    fn dial_as_listener(
+        &mut self,
+        addr: Multiaddr,
+    ) -> Result<Self::Dial, TransportError<Self::Error>> {
+        self.dial(addr)
+    }
+
+    fn poll(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<TransportEvent<Self::ListenerUpgrade, Self::Error>> {
+        let local = self.local;
+        let state = self.state.clone();
+        Pin::new(&mut self.inner).poll(cx).map(|event| {
+            event
+                .map_upgrade(move |upgrade| {
+                    async move {
+                        let mut channel = upgrade.await?;
+                        let mut remote = [0u8; 8];
+                        channel.read_exact(&mut remote).await?;
+                        let remote = NodeId(u64::from_be_bytes(remote));
+
+                        if !lock(&state).can_communicate(local, remote) {
+                            return Err(SimulatedTransportError::Unreachable(
+                                local, remote,
+                            ))
+                        }
+                        Ok(SimulatedConnection::new(channel, local, remote, state))
+                    }
+                    .boxed()
+                })
+                .map_err(Into::into)
+        })
+    }
+
+    fn address_translation(
+        &self,
+        _listen: &Multiaddr,
+        _observed: &Multiaddr,
+    ) -> Option<Multiaddr> {
+        None
+    }
+}
+
+/// The connection between two nodes of the simulated network.
+///
+/// The received data becomes readable only after the delay of the link.
+/// The connection fails as soon as the nodes can't communicate.
+pub(crate) struct SimulatedConnection {
+    inner: Channel<Vec<u8>>,
+    local: NodeId,
+    remote: NodeId,
+    state: SharedNetworkState,
+    /// The received data and the time when it can be read, in the order of receiving.
+    received: VecDeque<(Instant, Vec<u8>)>,
+    /// The time when the last received data can be read. The data is never reordered.
+    last_delivery: Instant,
+    /// `true` if the other side closed the connection.
+    inner_closed: bool,
+    timer: Option<Pin<Box<Sleep>>>,
+}
+
+impl SimulatedConnection {
+    fn new(
+        inner: Channel<Vec<u8>>,
+        local: NodeId,
+        remote: NodeId,
+        state: SharedNetworkState,
+    ) -> Self {
+        Self {
+            inner,
+            local,
+            remote,
+            state,
+            received: VecDeque::new(),
+            last_delivery: Instant::now(),
+            inner_closed: false,
+            timer: None,
+        }
+    }
+
+    fn ensure_link(&self) -> io::Result<()> {
+        if lock(&self.state).can_communicate(self.local, self.remote) {
+            Ok(())
+        } else {
+            Err(io::ErrorKind::ConnectionReset.into())
+        }
+    }
+
+    /// Moves all the data available in the memory channel into the `received` queue.
+    fn receive(&mut self, cx: &mut Context<'_>) -> io::Result<()> {
+        let mut buf = [0u8; READ_BUFFER_SIZE];
+        while !self.inner_closed {
+            match Pin::new(&mut self.inner).poll_read(cx, &mut buf) {
+                Poll::Ready(Ok(0)) => self.inner_closed = true,
+                Poll::Ready(Ok(read)) => {
+                    let now = Instant::now();
+                    let delay = lock(&self.state).next_delay(self.remote, self.local);
+                    let deliver_at = now.checked_add(delay).unwrap_or(now);
+                    self.last_delivery = self.last_delivery.max(deliver_at);
+                    self.received
+                        .push_back((self.last_delivery, buf[..read].to_vec()));
+                }
+                Poll::Ready(Err(e)) => return Err(e),
+                Poll::Pending => break,
+            }
+        }
+        Ok(())
+    }
+}
+
+impl AsyncRead for SimulatedConnection {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<io::Result<usize>> {
+        let this = self.get_mut();
+        this.ensure_link()?;
+        this.receive(cx)?;
+
+        loop {
+            let Some((deliver_at, data)) = this.received.front_mut() else {
+                return if this.inner_closed {
+                    Poll::Ready(Ok(0))
+                } else {
+                    Poll::Pending
+                };
+            };
+
+            if *deliver_at <= Instant::now() {
+                this.timer = None;
+                let read = buf.len().min(data.len());
+                buf[..read].copy_from_slice(&data[..read]);
+                data.drain(..read);
+                if data.is_empty() {
+                    this.received.pop_front();
+                }
+                return Poll::Ready(Ok(read))
+            }
+
+            let deadline = *deliver_at;
+            let timer = this
+                .timer
+                .get_or_insert_with(|| Box::pin(tokio::time::sleep_until(deadline)));
+            timer.as_mut().reset(deadline);
+            if timer.as_mut().poll(cx).is_pending() {
+                return Poll::Pending
+            }
+        }
+    }
+}
+
+impl AsyncWrite for SimulatedConnection {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        let this = self.get_mut();
+        this.ensure_link()?;
+        Pin::new(&mut this.inner).poll_write(cx, buf)
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        let this = self.get_mut();
+        this.ensure_link()?;
+        Pin::new(&mut this.inner).poll_flush(cx)
+    }
+
+    fn poll_close(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.get_mut().inner).poll_close(cx)
+    }
+}
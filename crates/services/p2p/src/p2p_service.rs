@@ -8,6 +8,7 @@ use crate::{
         GossipsubCodec,
     },
     config::{
+        build_other_transports_function,
         build_transport_function,
         Config,
     },
@@ -21,13 +22,16 @@ use crate::{
     },
     heartbeat,
     peer_manager::{
+        ConnectionState,
         PeerManager,
         Punisher,
     },
     peer_report::PeerReportEvent,
+    ports::PeersReputation,
     request_response::messages::{
         RequestError,
         RequestMessage,
+        RequestResponseProtocol,
         ResponseError,
         ResponseMessage,
         ResponseSendError,
@@ -42,6 +46,7 @@ use fuel_core_types::{
 };
 use futures::prelude::*;
 use libp2p::{
+    core::transport::ListenerId,
     gossipsub::{
         self,
         MessageAcceptance,
@@ -50,7 +55,6 @@ use libp2p::{
         TopicHash,
     },
     identify,
-    multiaddr::Protocol,
     request_response::{
         self,
         InboundRequestId,
@@ -66,10 +70,20 @@ use libp2p::{
 };
 use rand::seq::IteratorRandom;
 use std::{
-    collections::HashMap,
+    collections::{
+        HashMap,
+        HashSet,
+    },
+    sync::{
+        Arc,
+        RwLock,
+    },
     time::Duration,
 };
-use tokio::sync::broadcast;
+use tokio::{
+    sync::broadcast,
+    time::Instant,
+};
 use tracing::{
     debug,
     warn,
@@ -82,6 +96,19 @@ impl Punisher for Swarm<FuelBehaviour> {
     fn ban_peer(&mut self, peer_id: PeerId) {
         self.behaviour_mut().block_peer(peer_id)
     }
+
+    fn unban_peer(&mut self, peer_id: PeerId) {
+        self.behaviour_mut().unblock_peer(peer_id)
+    }
+}
+
+/// The request sent to the peer and waiting for a response
+struct OutboundRequest {
+    on_response: ResponseSender,
+    sent_at: Instant,
+    /// The number of items expected in the response, if it is known.
+    /// The shorter response is a failure of the peer.
+    expected_items: Option<usize>,
 }
 
 /// Listens to the events on the p2p network
@@ -93,8 +120,8 @@ pub struct FuelP2PService {
     /// IP address for Swarm to listen on
     local_address: std::net::IpAddr,
 
-    /// The TCP port that Swarm listens on
-    tcp_port: u16,
+    /// The addresses that Swarm listens on, one per enabled transport
+    listen_addresses: Vec<Multiaddr>,
 
     /// Swarm handler for FuelBehaviour
     swarm: Swarm<FuelBehaviour>,
@@ -105,7 +132,7 @@ pub struct FuelP2PService {
     /// Whenever a response (or an error) is received from the p2p network,
     /// the request is removed from this table, and the channel is used to
     /// send the result to the caller.
-    outbound_requests_table: HashMap<OutboundRequestId, ResponseSender>,
+    outbound_requests_table: HashMap<OutboundRequestId, OutboundRequest>,
 
     /// Holds active inbound requests and associated oneshot channels.
     /// Whenever we're done processing the request, it's removed from this table,
@@ -166,6 +193,36 @@ pub enum FuelP2PEvent {
         peer_id: PeerId,
         block_height: BlockHeight,
     },
+    /// The peer is identified for the first time since it connected.
+    PeerIdentified {
+        peer_id: PeerId,
+        req_res_protocol: Option<RequestResponseProtocol>,
+    },
+}
+
+/// The operator's request to act on the peers of the node.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PeerManagementRequest {
+    /// Closes all connections to the peer. Reserved peers are reconnected later.
+    DisconnectPeer(PeerId),
+    /// Bans the peer and closes all connections to it.
+    BanPeer(PeerId),
+    /// Lifts the ban of the peer.
+    UnbanPeer(PeerId),
+    /// Adds the reserved node. The multiaddr must contain the peer id.
+    AddReservedNode(Multiaddr),
+    /// Makes the reserved node a regular peer.
+    RemoveReservedNode(PeerId),
+    /// Dials the peer at the multiaddr.
+    Dial(Multiaddr),
+}
+
+fn swarm_config(config: &Config, cfg: libp2p::swarm::Config) -> libp2p::swarm::Config {
+    if let Some(timeout) = config.connection_idle_timeout {
+        cfg.with_idle_connection_timeout(timeout)
+    } else {
+        cfg
+    }
 }
 
 impl FuelP2PService {
@@ -174,16 +231,15 @@ impl FuelP2PService {
         config: Config,
         codec: PostcardCodec,
     ) -> Self {
-        let gossipsub_data =
-            GossipsubData::with_topics(GossipsubTopics::new(&config.network_name));
-        let network_metadata = NetworkMetadata { gossipsub_data };
-
         // configure and build P2P Service
         let (transport_function, connection_state) = build_transport_function(&config);
+        let other_transports_function =
+            build_other_transports_function(&config, connection_state.clone());
         let tcp_config = tcp::Config::new().port_reuse(true);
-        let behaviour = FuelBehaviour::new(&config, codec.clone());
+        let behaviour =
+            FuelBehaviour::new(&config, codec.clone(), connection_state.clone());
 
-        let mut swarm = SwarmBuilder::with_existing_identity(config.keypair.clone())
+        let swarm = SwarmBuilder::with_existing_identity(config.keypair.clone())
             .with_tokio()
             .with_tcp(
                 tcp_config,
@@ -191,19 +247,74 @@ impl FuelP2PService {
                 libp2p::yamux::Config::default,
             )
             .unwrap()
+            .with_other_transport(other_transports_function)
+            .unwrap()
             .with_dns()
             .unwrap()
             .with_behaviour(|_| behaviour)
             .unwrap()
-            .with_swarm_config(|cfg| {
-                if let Some(timeout) = config.connection_idle_timeout {
-                    cfg.with_idle_connection_timeout(timeout)
-                } else {
-                    cfg
-                }
-            })
+            .with_swarm_config(|cfg| swarm_config(&config, cfg))
+            .build();
+
+        let listen_addresses = config.listen_addresses();
+        Self::from_swarm(
+            reserved_peers_updates,
+            config,
+            codec,
+            swarm,
+            connection_state,
+            listen_addresses,
+        )
+    }
+
+    /// Creates the service that uses only the given `transport`
+    /// and listens on the `listen_addresses`.
+    #[cfg(any(test, feature = "network-simulation"))]
+    pub(crate) fn with_transport(
+        reserved_peers_updates: broadcast::Sender<usize>,
+        config: Config,
+        codec: PostcardCodec,
+        transport: libp2p::core::transport::Boxed<(
+            PeerId,
+            libp2p::core::muxing::StreamMuxerBox,
+        )>,
+        connection_state: Arc<RwLock<ConnectionState>>,
+        listen_addresses: Vec<Multiaddr>,
+    ) -> Self {
+        let behaviour =
+            FuelBehaviour::new(&config, codec.clone(), connection_state.clone());
+
+        let swarm = SwarmBuilder::with_existing_identity(config.keypair.clone())
+            .with_tokio()
+            .with_other_transport(|_| transport)
+            .unwrap()
+            .with_behaviour(|_| behaviour)
+            .unwrap()
+            .with_swarm_config(|cfg| swarm_config(&config, cfg))
             .build();
 
+        Self::from_swarm(
+            reserved_peers_updates,
+            config,
+            codec,
+            swarm,
+            connection_state,
+            listen_addresses,
+        )
+    }
+
+    fn from_swarm(
+        reserved_peers_updates: broadcast::Sender<usize>,
+        config: Config,
+        codec: PostcardCodec,
+        mut swarm: Swarm<FuelBehaviour>,
+        connection_state: Arc<RwLock<ConnectionState>>,
+        listen_addresses: Vec<Multiaddr>,
+    ) -> Self {
+        let gossipsub_data =
+            GossipsubData::with_topics(GossipsubTopics::new(&config.network_name));
+        let network_metadata = NetworkMetadata { gossipsub_data };
+
         let local_peer_id = swarm.local_peer_id().to_owned();
 
         let metrics = config.metrics;
@@ -221,7 +332,7 @@ impl FuelP2PService {
         Self {
             local_peer_id,
             local_address: config.address,
-            tcp_port: config.tcp_port,
+            listen_addresses,
             swarm,
             network_codec: codec,
             outbound_requests_table: HashMap::default(),
@@ -238,35 +349,35 @@ impl FuelP2PService {
     }
 
     pub async fn start(&mut self) -> anyhow::Result<()> {
-        // set up node's address to listen on
-        let listen_multiaddr = {
-            let mut m = Multiaddr::from(self.local_address);
-            m.push(Protocol::Tcp(self.tcp_port));
-            m
-        };
         let peer_id = self.local_peer_id;
 
-        tracing::info!(
-            "The p2p service starts on the `{listen_multiaddr}` with `{peer_id}`"
-        );
-
-        // start listening at the given address
-        self.swarm.listen_on(listen_multiaddr)?;
+        // start listening at the given addresses
+        let mut listeners = HashSet::new();
+        for listen_multiaddr in self.listen_addresses.clone() {
+            tracing::info!(
+                "The p2p service starts on the `{listen_multiaddr}` with `{peer_id}`"
+            );
+            listeners.insert(self.swarm.listen_on(listen_multiaddr)?);
+        }
 
         // Wait for listener addresses.
-        tokio::time::timeout(Duration::from_secs(5), self.await_listeners_address())
-            .await
-            .map_err(|_| {
-                anyhow::anyhow!("P2PService should get a new address within 5 seconds")
-            })?;
+        tokio::time::timeout(
+            Duration::from_secs(5),
+            self.await_listeners_address(listeners),
+        )
+        .await
+        .map_err(|_| {
+            anyhow::anyhow!("P2PService should get a new address within 5 seconds")
+        })?;
         Ok(())
     }
 
-    async fn await_listeners_address(&mut self) {
-        loop {
-            if let SwarmEvent::NewListenAddr { .. } = self.swarm.select_next_some().await
+    async fn await_listeners_address(&mut self, mut listeners: HashSet<ListenerId>) {
+        while !listeners.is_empty() {
+            if let SwarmEvent::NewListenAddr { listener_id, .. } =
+                self.swarm.select_next_some().await
             {
-                break;
+                listeners.remove(&listener_id);
             }
         }
     }
@@ -328,24 +439,41 @@ impl FuelP2PService {
         let peer_id = match peer_id {
             Some(peer_id) => peer_id,
             _ => {
-                let peers = self.get_peers_ids_iter();
                 let peers_count = self.peer_manager.total_peers_connected();
 
                 if peers_count == 0 {
                     return Err(RequestError::NoPeersConnected);
                 }
 
+                let protocol = message_request.min_protocol();
+                let peers = self
+                    .peer_manager
+                    .get_all_peers()
+                    .filter(|(_, peer_info)| peer_info.supports(protocol))
+                    .map(|(peer_id, _)| peer_id);
+
                 let mut range = rand::thread_rng();
-                *peers.choose(&mut range).unwrap()
+                *peers
+                    .choose(&mut range)
+                    .ok_or(RequestError::NoPeersSupportingRequest)?
             }
         };
 
+        let expected_items = message_request.expected_items();
         let request_id = self
             .swarm
             .behaviour_mut()
             .send_request_msg(message_request, &peer_id);
 
-        self.outbound_requests_table.insert(request_id, on_response);
+        self.peer_manager.handle_request_sent(&peer_id);
+        self.outbound_requests_table.insert(
+            request_id,
+            OutboundRequest {
+                on_response,
+                sent_at: Instant::now(),
+                expected_items,
+            },
+        );
 
         Ok(request_id)
     }
@@ -412,6 +540,67 @@ impl FuelP2PService {
         self.swarm.behaviour().get_peer_score(peer_id)
     }
 
+    /// Applies the peer management request from the operator.
+    pub fn manage_peers(&mut self, request: PeerManagementRequest) -> anyhow::Result<()> {
+        tracing::info!(target: "fuel-p2p", "Handling the peer management request: {request:?}");
+        match request {
+            PeerManagementRequest::DisconnectPeer(peer_id) => {
+                self.swarm.disconnect_peer_id(peer_id).map_err(|_| {
+                    anyhow::anyhow!("The peer {peer_id} is not connected")
+                })?;
+            }
+            PeerManagementRequest::BanPeer(peer_id) => {
+                if self.peer_manager.is_reserved(&peer_id) {
+                    return Err(anyhow::anyhow!(
+                        "The peer {peer_id} is reserved, remove it from reserved nodes first"
+                    ))
+                }
+                self.peer_manager.ban_peer(peer_id, &mut self.swarm);
+            }
+            PeerManagementRequest::UnbanPeer(peer_id) => {
+                if !self.peer_manager.unban_peer(&peer_id, &mut self.swarm) {
+                    return Err(anyhow::anyhow!("The peer {peer_id} is not banned"))
+                }
+            }
+            PeerManagementRequest::AddReservedNode(multiaddr) => {
+                let peer_id = multiaddr.try_to_peer_id().ok_or_else(|| {
+                    anyhow::anyhow!(
+                        "The multiaddr {multiaddr} doesn't contain the peer id"
+                    )
+                })?;
+                if self.peer_manager.is_banned(&peer_id) {
+                    return Err(anyhow::anyhow!(
+                        "The peer {peer_id} is banned, unban it first"
+                    ))
+                }
+                let connected = self.swarm.is_connected(&peer_id);
+                self.peer_manager.add_reserved_peer(peer_id);
+                self.swarm.behaviour_mut().add_reserved_node(
+                    peer_id,
+                    multiaddr.clone(),
+                    connected,
+                );
+                if !connected {
+                    // Don't wait for the next health check to connect to the node
+                    self.swarm.dial(multiaddr)?;
+                }
+            }
+            PeerManagementRequest::RemoveReservedNode(peer_id) => {
+                if !self.peer_manager.is_reserved(&peer_id) {
+                    return Err(anyhow::anyhow!("The peer {peer_id} is not reserved"))
+                }
+                self.swarm.behaviour_mut().remove_reserved_node(&peer_id);
+                if self.peer_manager.remove_reserved_peer(&peer_id) {
+                    let _ = self.swarm.disconnect_peer_id(peer_id);
+                }
+            }
+            PeerManagementRequest::Dial(multiaddr) => {
+                self.swarm.dial(multiaddr)?;
+            }
+        }
+        Ok(())
+    }
+
     /// Report application score
     /// If application peer score is below allowed threshold
     /// the peer is banned
@@ -468,6 +657,24 @@ impl FuelP2PService {
         &self.peer_manager
     }
 
+    /// Restores the reputation of peers stored before the restart
+    /// and seeds the discovery with the known peers that behaved well.
+    pub fn restore_peers_reputation(&mut self, reputation: PeersReputation) {
+        self.peer_manager
+            .restore_reputation(reputation, &mut self.swarm);
+
+        let known_good_peers = self
+            .peer_manager
+            .known_good_peers()
+            .map(|(peer_id, known_peer)| (*peer_id, known_peer.addresses.clone()))
+            .collect::<Vec<_>>();
+        for (peer_id, addresses) in known_good_peers {
+            self.swarm
+                .behaviour_mut()
+                .add_addresses_to_discovery(&peer_id, addresses);
+        }
+    }
+
     fn get_topic_tag(&self, topic_hash: &TopicHash) -> Option<GossipTopicTag> {
         let topic = self
             .network_metadata
@@ -540,7 +747,8 @@ impl FuelP2PService {
     ) -> Option<FuelP2PEvent> {
         match event {
             PeerReportEvent::PerformDecay => {
-                self.peer_manager.batch_update_score_with_decay()
+                self.peer_manager.batch_update_score_with_decay();
+                self.peer_manager.lift_expired_bans(&mut self.swarm);
             }
             PeerReportEvent::PeerConnected { peer_id } => {
                 if self.peer_manager.handle_peer_connected(&peer_id) {
@@ -579,38 +787,26 @@ impl FuelP2PService {
                     request_id,
                     response,
                 } => {
-                    let Some(channel) = self.outbound_requests_table.remove(&request_id)
+                    let Some(request) = self.outbound_requests_table.remove(&request_id)
                     else {
                         debug!("Send channel not found for {:?}", request_id);
                         return None;
                     };
 
-                    let send_ok = match channel {
-                        ResponseSender::SealedHeaders(c) => match response {
-                            ResponseMessage::SealedHeaders(v) => {
-                                c.send((peer, Ok(v))).is_ok()
-                            }
-                            _ => {
-                                warn!(
-                                    "Invalid response type received for request {:?}",
-                                    request_id
-                                );
-                                c.send((peer, Err(ResponseError::TypeMismatch))).is_ok()
-                            }
-                        },
-                        ResponseSender::Transactions(c) => match response {
-                            ResponseMessage::Transactions(v) => {
-                                c.send((peer, Ok(v))).is_ok()
-                            }
-                            _ => {
-                                warn!(
-                                    "Invalid response type received for request {:?}",
-                                    request_id
-                                );
-                                c.send((peer, Err(ResponseError::TypeMismatch))).is_ok()
-                            }
-                        },
-                    };
+                    let incomplete = request
+                        .expected_items
+                        .is_some_and(|expected| response.items() < expected);
+                    if incomplete {
+                        self.peer_manager.handle_request_failed(
+                            peer,
+                            false,
+                            &mut self.swarm,
+                        );
+                    } else {
+                        self.peer_manager
+                            .handle_request_succeeded(&peer, request.sent_at.elapsed());
+                    }
+                    let send_ok = request.on_response.send_response(peer, response);
 
                     if !send_ok {
                         warn!("Failed to send through the channel for {:?}", request_id);
@@ -634,15 +830,17 @@ impl FuelP2PService {
             } => {
                 tracing::error!("RequestResponse outbound error for peer: {:?} with id: {:?} and error: {:?}", peer, request_id, error);
 
-                if let Some(channel) = self.outbound_requests_table.remove(&request_id) {
-                    match channel {
-                        ResponseSender::SealedHeaders(c) => {
-                            let _ = c.send((peer, Err(ResponseError::P2P(error))));
-                        }
-                        ResponseSender::Transactions(c) => {
-                            let _ = c.send((peer, Err(ResponseError::P2P(error))));
-                        }
-                    };
+                if let Some(request) = self.outbound_requests_table.remove(&request_id) {
+                    let stalled =
+                        matches!(error, request_response::OutboundFailure::Timeout);
+                    self.peer_manager.handle_request_failed(
+                        peer,
+                        stalled,
+                        &mut self.swarm,
+                    );
+                    request
+                        .on_response
+                        .send_error(peer, ResponseError::P2P(error));
                 }
             }
             _ => {}
@@ -668,15 +866,31 @@ impl FuelP2PService {
                     addresses.truncate(MAX_IDENTIFY_ADDRESSES);
                 }
 
-                self.peer_manager.handle_peer_identified(
+                let req_res_protocol = info
+                    .protocols
+                    .iter()
+                    .filter_map(|protocol| {
+                        RequestResponseProtocol::from_protocol_id(protocol.as_ref())
+                    })
+                    .max();
+
+                let first_identification = self.peer_manager.handle_peer_identified(
                     &peer_id,
                     addresses.clone(),
                     agent_version,
+                    req_res_protocol,
                 );
 
                 self.swarm
                     .behaviour_mut()
                     .add_addresses_to_discovery(&peer_id, addresses);
+
+                if first_identification {
+                    return Some(FuelP2PEvent::PeerIdentified {
+                        peer_id,
+                        req_res_protocol,
+                    });
+                }
             }
             identify::Event::Sent { .. } => {}
             identify::Event::Pushed { .. } => {}
@@ -707,21 +921,29 @@ impl FuelP2PService {
 
 #[allow(clippy::cast_possible_truncation)]
 #[cfg(test)]
+#[allow(non_snake_case)]
 mod tests {
     use super::{
         FuelP2PService,
+        PeerManagementRequest,
         PublishError,
     };
     use crate::{
         codecs::postcard::PostcardCodec,
-        config::Config,
+        config::{
+            Config,
+            TransportKind,
+        },
         gossipsub::{
             messages::{
+                GossipTopicTag,
                 GossipsubBroadcastRequest,
                 GossipsubMessage,
             },
             topics::{
                 GossipTopic,
+                CONSENSUS_GOSSIP_TOPIC,
+                NEW_BLOCK_GOSSIP_TOPIC,
                 NEW_TX_GOSSIP_TOPIC,
             },
         },
@@ -729,6 +951,7 @@ mod tests {
         peer_manager::PeerInfo,
         request_response::messages::{
             RequestMessage,
+            RequestResponseProtocol,
             ResponseError,
             ResponseMessage,
             ResponseSender,
@@ -737,16 +960,25 @@ mod tests {
     };
     use fuel_core_types::{
         blockchain::{
+            block::Block,
             consensus::{
+                bft::{
+                    BftMessage,
+                    SignedVote,
+                    Vote,
+                    VoteStep,
+                },
                 poa::PoAConsensus,
                 Consensus,
             },
             header::BlockHeader,
+            SealedBlock,
             SealedBlockHeader,
         },
         fuel_tx::{
             Transaction,
             TransactionBuilder,
+            TxId,
         },
         services::p2p::{
             GossipsubMessageAcceptance,
@@ -1119,6 +1351,40 @@ mod tests {
     // Simulates 2 p2p nodes that are on the same network but their Fuel Upgrade checksum is different
     // (different chain id or chain config)
     // So they are not able to connect
+    #[tokio::test]
+    #[instrument]
+    async fn nodes_cannot_connect_from_denied_ip_network() {
+        // Node A
+        let mut p2p_config =
+            Config::default_initialized("nodes_cannot_connect_from_denied_ip_network");
+        let mut node_a = {
+            let mut p2p_config = p2p_config.clone();
+            p2p_config.denied_ip_networks = vec!["127.0.0.0/8".parse().unwrap()];
+            build_service_from_config(p2p_config).await
+        };
+
+        p2p_config.bootstrap_nodes = node_a.multiaddrs();
+        // Node B
+        let mut node_b = build_service_from_config(p2p_config).await;
+
+        loop {
+            tokio::select! {
+                node_a_event = node_a.swarm.select_next_some() => {
+                    tracing::info!("Node A Event: {:?}", node_a_event);
+                    if let SwarmEvent::IncomingConnectionError { error: ListenError::Denied { .. }, .. } = node_a_event {
+                        break
+                    }
+                },
+                node_b_event = node_b.next_event() => {
+                    if let Some(FuelP2PEvent::PeerConnected(_)) = node_b_event {
+                        panic!("Node B should not connect to Node A!")
+                    }
+                    tracing::info!("Node B Event: {:?}", node_b_event);
+                },
+            };
+        }
+    }
+
     #[tokio::test]
     #[instrument]
     async fn nodes_cannot_connect_due_to_different_checksum() {
@@ -1153,6 +1419,100 @@ mod tests {
         }
     }
 
+    fn config_with_all_transports(network_name: &str) -> Config {
+        let mut p2p_config = Config::default_initialized(network_name);
+        p2p_config.quic_port = Some(0);
+        p2p_config.websocket_port = Some(0);
+        p2p_config
+    }
+
+    fn multiaddrs_of_transport(
+        node: &P2PService,
+        transport: TransportKind,
+    ) -> Vec<Multiaddr> {
+        node.multiaddrs()
+            .into_iter()
+            .filter(|address| TransportKind::of(address) == Some(transport))
+            .collect()
+    }
+
+    // Simulates 2 p2p nodes, Node B is bootstrapped with Node A
+    // only via the addresses of the given transport
+    async fn nodes_connect_via_transport(transport: TransportKind) {
+        // Node A
+        let mut p2p_config = config_with_all_transports("nodes_connect_via_transport");
+        let mut node_a = build_service_from_config(p2p_config.clone()).await;
+
+        // Node B
+        p2p_config.bootstrap_nodes = multiaddrs_of_transport(&node_a, transport);
+        assert!(!p2p_config.bootstrap_nodes.is_empty());
+        let mut node_b = build_service_from_config(p2p_config).await;
+
+        loop {
+            tokio::select! {
+                node_a_event = node_a.next_event() => {
+                    tracing::info!("Node A Event: {:?}", node_a_event);
+                },
+                node_b_event = node_b.next_event() => {
+                    if let Some(FuelP2PEvent::PeerConnected(peer_id)) = node_b_event {
+                        assert_eq!(peer_id, node_a.local_peer_id);
+                        break
+                    }
+                    tracing::info!("Node B Event: {:?}", node_b_event);
+                },
+            };
+        }
+    }
+
+    #[tokio::test]
+    #[instrument]
+    async fn nodes_connected_via_quic() {
+        nodes_connect_via_transport(TransportKind::Quic).await
+    }
+
+    #[tokio::test]
+    #[instrument]
+    async fn nodes_connected_via_websocket() {
+        nodes_connect_via_transport(TransportKind::WebSocket).await
+    }
+
+    // The same as `nodes_cannot_connect_due_to_different_checksum`, but via QUIC,
+    // where the checksum is checked after the connection is secured by TLS
+    #[tokio::test]
+    #[instrument]
+    async fn nodes_cannot_connect_via_quic_due_to_different_checksum() {
+        use libp2p::TransportError;
+        // Node A
+        let mut p2p_config = config_with_all_transports(
+            "nodes_cannot_connect_via_quic_due_to_different_checksum",
+        );
+        let mut node_a = build_service_from_config(p2p_config.clone()).await;
+
+        // different checksum
+        p2p_config.checksum = [1u8; 32].into();
+        p2p_config.bootstrap_nodes =
+            multiaddrs_of_transport(&node_a, TransportKind::Quic);
+        // Node B
+        let mut node_b = build_service_from_config(p2p_config).await;
+
+        loop {
+            tokio::select! {
+                node_a_event = node_a.swarm.select_next_some() => {
+                    tracing::info!("Node A Event: {:?}", node_a_event);
+                    if let SwarmEvent::IncomingConnectionError { error: ListenError::Transport(TransportError::Other(_)), .. } = node_a_event {
+                        break
+                    }
+                },
+                node_b_event = node_b.next_event() => {
+                    if let Some(FuelP2PEvent::PeerConnected(_)) = node_b_event {
+                        panic!("Node B should not connect to Node A!")
+                    }
+                    tracing::info!("Node B Event: {:?}", node_b_event);
+                },
+            };
+        }
+    }
+
     // Simulates 3 p2p nodes, Node B & Node C are bootstrapped with Node A
     // Using Identify Protocol Node C should be able to identify and connect to Node B
     #[tokio::test]
@@ -1240,6 +1600,132 @@ mod tests {
         }
     }
 
+    // Simulates 2 p2p nodes that connect to each other and exchange the identify info.
+    // Node A is notified about the identification of node B only once.
+    #[tokio::test]
+    #[instrument]
+    async fn peer_identified_event_is_emitted_once_per_connection() {
+        let mut p2p_config =
+            Config::default_initialized("peer_identified_event_is_emitted_once");
+        p2p_config.identify_interval = Some(Duration::from_millis(100));
+
+        // Node A
+        let mut node_a = build_service_from_config(p2p_config.clone()).await;
+
+        // Node B
+        p2p_config.bootstrap_nodes = node_a.multiaddrs();
+        let mut node_b = build_service_from_config(p2p_config).await;
+        let node_b_peer_id = *node_b.swarm.local_peer_id();
+
+        let mut identified_events = 0u32;
+        let deadline = tokio::time::sleep(Duration::from_secs(5));
+        tokio::pin!(deadline);
+        loop {
+            tokio::select! {
+                _ = &mut deadline => break,
+                node_a_event = node_a.next_event() => {
+                    if let Some(FuelP2PEvent::PeerIdentified { peer_id, req_res_protocol }) = node_a_event {
+                        assert_eq!(peer_id, node_b_peer_id);
+                        assert_eq!(req_res_protocol, Some(RequestResponseProtocol::V2));
+                        identified_events = identified_events.saturating_add(1);
+                    }
+                },
+                _ = node_b.next_event() => {}
+            }
+        }
+
+        assert_eq!(identified_events, 1);
+    }
+
+    // Node A dials node B, bans it, lifts the ban and dials it again.
+    #[tokio::test]
+    #[instrument]
+    async fn manage_peers__ban_disconnects_peer_until_unban() {
+        let p2p_config = Config::default_initialized("manage_peers_ban");
+        let mut node_a = build_service_from_config(p2p_config.clone()).await;
+        let mut node_b = build_service_from_config(p2p_config).await;
+        let node_b_peer_id = node_b.local_peer_id;
+        let node_b_address = node_b.multiaddrs().remove(0);
+
+        node_a
+            .manage_peers(PeerManagementRequest::Dial(node_b_address.clone()))
+            .unwrap();
+
+        let mut connections = 0u32;
+        loop {
+            tokio::select! {
+                node_a_event = node_a.next_event() => {
+                    match node_a_event {
+                        Some(FuelP2PEvent::PeerConnected(peer_id)) => {
+                            assert_eq!(peer_id, node_b_peer_id);
+                            connections = connections.saturating_add(1);
+                            if connections == 2 {
+                                break
+                            }
+                            node_a
+                                .manage_peers(PeerManagementRequest::BanPeer(peer_id))
+                                .unwrap();
+                        }
+                        Some(FuelP2PEvent::PeerDisconnected(peer_id)) => {
+                            assert_eq!(peer_id, node_b_peer_id);
+                            assert!(node_a.peer_manager.is_banned(&peer_id));
+                            node_a
+                                .manage_peers(PeerManagementRequest::UnbanPeer(peer_id))
+                                .unwrap();
+                            node_a
+                                .manage_peers(PeerManagementRequest::Dial(
+                                    node_b_address.clone(),
+                                ))
+                                .unwrap();
+                        }
+                        _ => {}
+                    }
+                },
+                _ = node_b.next_event() => {}
+            }
+        }
+
+        assert!(!node_a.peer_manager.is_banned(&node_b_peer_id));
+    }
+
+    #[tokio::test]
+    #[instrument]
+    async fn manage_peers__added_reserved_node_is_connected() {
+        let p2p_config = Config::default_initialized("manage_peers_reserved");
+        let mut node_a = build_service_from_config(p2p_config.clone()).await;
+        let mut node_b = build_service_from_config(p2p_config).await;
+        let node_b_peer_id = node_b.local_peer_id;
+
+        node_a
+            .manage_peers(PeerManagementRequest::AddReservedNode(
+                node_b.multiaddrs().remove(0),
+            ))
+            .unwrap();
+
+        loop {
+            tokio::select! {
+                node_a_event = node_a.next_event() => {
+                    if let Some(FuelP2PEvent::PeerConnected(peer_id)) = node_a_event {
+                        assert_eq!(peer_id, node_b_peer_id);
+                        break
+                    }
+                },
+                _ = node_b.next_event() => {}
+            }
+        }
+
+        assert!(node_a.peer_manager.is_reserved(&node_b_peer_id));
+        // Reserved peers can't be banned
+        assert!(node_a
+            .manage_peers(PeerManagementRequest::BanPeer(node_b_peer_id))
+            .is_err());
+        node_a
+            .manage_peers(PeerManagementRequest::RemoveReservedNode(node_b_peer_id))
+            .unwrap();
+        assert!(!node_a.peer_manager.is_reserved(&node_b_peer_id));
+        assert_eq!(node_a.peer_manager.total_peers_connected(), 1);
+    }
+
     #[tokio::test]
     #[instrument]
     async fn gossipsub_broadcast_tx_with_accept() {
@@ -1276,6 +1762,86 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    #[instrument]
+    async fn gossipsub_broadcast_block_with_accept() {
+        for _ in 0..100 {
+            tokio::time::timeout(
+                Duration::from_secs(5),
+                gossipsub_broadcast(
+                    GossipsubBroadcastRequest::NewBlock(Arc::new(SealedBlock::default())),
+                    GossipsubMessageAcceptance::Accept,
+                ),
+            )
+            .await
+            .unwrap();
+        }
+    }
+
+    #[tokio::test]
+    #[instrument]
+    async fn gossipsub_broadcast_block_with_reject() {
+        for _ in 0..100 {
+            tokio::time::timeout(
+                Duration::from_secs(5),
+                gossipsub_broadcast(
+                    GossipsubBroadcastRequest::NewBlock(Arc::new(SealedBlock::default())),
+                    GossipsubMessageAcceptance::Reject,
+                ),
+            )
+            .await
+            .unwrap();
+        }
+    }
+
+    fn test_consensus_message() -> BftMessage {
+        BftMessage::Vote(SignedVote {
+            vote: Vote {
+                height: 1u32.into(),
+                round: 0,
+                step: VoteStep::Precommit,
+                block_id: None,
+            },
+            signature: Default::default(),
+        })
+    }
+
+    #[tokio::test]
+    #[instrument]
+    async fn gossipsub_broadcast_consensus_message_with_accept() {
+        for _ in 0..100 {
+            tokio::time::timeout(
+                Duration::from_secs(5),
+                gossipsub_broadcast(
+                    GossipsubBroadcastRequest::Consensus(Arc::new(
+                        test_consensus_message(),
+                    )),
+                    GossipsubMessageAcceptance::Accept,
+                ),
+            )
+            .await
+            .unwrap();
+        }
+    }
+
+    #[tokio::test]
+    #[instrument]
+    async fn gossipsub_broadcast_consensus_message_with_reject() {
+        for _ in 0..100 {
+            tokio::time::timeout(
+                Duration::from_secs(5),
+                gossipsub_broadcast(
+                    GossipsubBroadcastRequest::Consensus(Arc::new(
+                        test_consensus_message(),
+                    )),
+                    GossipsubMessageAcceptance::Reject,
+                ),
+            )
+            .await
+            .unwrap();
+        }
+    }
+
     #[tokio::test]
     #[instrument]
     #[ignore]
@@ -1403,12 +1969,23 @@ mod tests {
     ) {
         let mut p2p_config = Config::default_initialized("gossipsub_exchanges_messages");
 
-        let selected_topic: GossipTopic = {
-            let topic = match broadcast_request {
-                GossipsubBroadcastRequest::NewTx(_) => NEW_TX_GOSSIP_TOPIC,
+        let (selected_tag, selected_topic): (GossipTopicTag, GossipTopic) = {
+            let (tag, topic) = match broadcast_request {
+                GossipsubBroadcastRequest::NewTx(_) => {
+                    (GossipTopicTag::NewTx, NEW_TX_GOSSIP_TOPIC)
+                }
+                GossipsubBroadcastRequest::NewBlock(_) => {
+                    (GossipTopicTag::NewBlock, NEW_BLOCK_GOSSIP_TOPIC)
+                }
+                GossipsubBroadcastRequest::Consensus(_) => {
+                    (GossipTopicTag::Consensus, CONSENSUS_GOSSIP_TOPIC)
+                }
             };
 
-            Topic::new(format!("{}/{}", topic, p2p_config.network_name))
+            (
+                tag,
+                Topic::new(format!("{}/{}", topic, p2p_config.network_name)),
+            )
         };
 
         let mut message_sent = false;
@@ -1443,16 +2020,16 @@ mod tests {
 
             tokio::select! {
                 node_a_event = node_a.next_event() => {
-                    if let Some(FuelP2PEvent::NewSubscription { peer_id, .. }) = &node_a_event {
-                        if peer_id == &node_b.local_peer_id {
+                    if let Some(FuelP2PEvent::NewSubscription { peer_id, tag }) = &node_a_event {
+                        if peer_id == &node_b.local_peer_id && tag == &selected_tag {
                             a_connected_to_b = true;
                         }
                     }
                     tracing::info!("Node A Event: {:?}", node_a_event);
                 },
                 node_b_event = node_b.next_event() => {
-                    if let Some(FuelP2PEvent::NewSubscription { peer_id, .. }) = &node_b_event {
-                        if peer_id == &node_c.local_peer_id {
+                    if let Some(FuelP2PEvent::NewSubscription { peer_id, tag }) = &node_b_event {
+                        if peer_id == &node_c.local_peer_id && tag == &selected_tag {
                             b_connected_to_c = true;
                         }
                     }
@@ -1476,6 +2053,18 @@ mod tests {
                                     panic!("Wrong GossipsubMessage")
                                 }
                             }
+                            GossipsubMessage::NewBlock(block) => {
+                                if block != &SealedBlock::default() {
+                                    tracing::error!("Wrong p2p message {:?}", message);
+                                    panic!("Wrong GossipsubMessage")
+                                }
+                            }
+                            GossipsubMessage::Consensus(consensus_message) => {
+                                if consensus_message != &test_consensus_message() {
+                                    tracing::error!("Wrong p2p message {:?}", message);
+                                    panic!("Wrong GossipsubMessage")
+                                }
+                            }
                         }
 
                         // Node B received the correct message
@@ -1530,6 +2119,20 @@ mod tests {
         blocks
     }
 
+    fn arbitrary_blocks_for_range(range: Range<u32>) -> Vec<SealedBlock> {
+        arbitrary_headers_for_range(range)
+            .into_iter()
+            .map(|header| {
+                let mut block = Block::default();
+                *block.header_mut() = header.entity;
+                SealedBlock {
+                    entity: block,
+                    consensus: header.consensus,
+                }
+            })
+            .collect()
+    }
+
     // Metadata gets skipped during serialization, so this is the fuzzy way to compare blocks
     fn eq_except_metadata(a: &SealedBlockHeader, b: &SealedBlockHeader) -> bool {
         a.entity.application() == b.entity.application()
@@ -1559,7 +2162,8 @@ mod tests {
                 }
                 node_a_event = node_a.next_event() => {
                     if let Some(FuelP2PEvent::PeerInfoUpdated { peer_id, block_height: _ }) = node_a_event {
-                        if node_a.peer_manager.get_peer_info(&peer_id).is_some() {
+                        let protocol = request_msg.min_protocol();
+                        if node_a.peer_manager.get_peer_info(&peer_id).is_some_and(|info| info.supports(protocol)) {
                             // 0. verifies that we've got at least a single peer address to request message from
                             if !request_sent {
                                 request_sent = true;
@@ -1601,6 +2205,58 @@ mod tests {
                                             }
                                         });
                                     }
+                                    RequestMessage::SealedBlocks(range) => {
+                                        let (tx_orchestrator, rx_orchestrator) = oneshot::channel();
+                                        assert!(node_a.send_request_msg(None, request_msg.clone(), ResponseSender::SealedBlocks(tx_orchestrator)).is_ok());
+                                        let tx_test_end = tx_test_end.clone();
+
+                                        tokio::spawn(async move {
+                                            let response_message = rx_orchestrator.await;
+
+                                            if let Ok((_, Ok(Some(blocks)))) = response_message {
+                                                let heights = blocks.iter().map(|block| **block.entity.header().height()).collect::<Vec<_>>();
+                                                let check = heights == range.collect::<Vec<_>>();
+                                                let _ = tx_test_end.send(check).await;
+                                            } else {
+                                                tracing::error!("Orchestrator failed to receive a message: {:?}", response_message);
+                                                let _ = tx_test_end.send(false).await;
+                                            }
+                                        });
+                                    }
+                                    RequestMessage::TxPoolAllTransactionsIds => {
+                                        let (tx_orchestrator, rx_orchestrator) = oneshot::channel();
+                                        assert!(node_a.send_request_msg(None, request_msg.clone(), ResponseSender::TxPoolAllTransactionsIds(tx_orchestrator)).is_ok());
+                                        let tx_test_end = tx_test_end.clone();
+
+                                        tokio::spawn(async move {
+                                            let response_message = rx_orchestrator.await;
+
+                                            if let Ok((_, Ok(Some(tx_ids)))) = response_message {
+                                                let check = tx_ids.len() == 5;
+                                                let _ = tx_test_end.send(check).await;
+                                            } else {
+                                                tracing::error!("Orchestrator failed to receive a message: {:?}", response_message);
+                                                let _ = tx_test_end.send(false).await;
+                                            }
+                                        });
+                                    }
+                                    RequestMessage::TxPoolFullTransactions(tx_ids) => {
+                                        let (tx_orchestrator, rx_orchestrator) = oneshot::channel();
+                                        assert!(node_a.send_request_msg(None, request_msg.clone(), ResponseSender::TxPoolFullTransactions(tx_orchestrator)).is_ok());
+                                        let tx_test_end = tx_test_end.clone();
+
+                                        tokio::spawn(async move {
+                                            let response_message = rx_orchestrator.await;
+
+                                            if let Ok((_, Ok(Some(transactions)))) = response_message {
+                                                let check = transactions.len() == tx_ids.len() && transactions.iter().all(Option::is_some);
+                                                let _ = tx_test_end.send(check).await;
+                                            } else {
+                                                tracing::error!("Orchestrator failed to receive a message: {:?}", response_message);
+                                                let _ = tx_test_end.send(false).await;
+                                            }
+                                        });
+                                    }
                                 }
                             }
                         }
@@ -1622,6 +2278,19 @@ mod tests {
                                 let transactions = vec![Transactions(txs)];
                                 let _ = node_b.send_response_msg(*request_id, ResponseMessage::Transactions(Some(transactions)));
                             }
+                            RequestMessage::SealedBlocks(range) => {
+                                let sealed_blocks = arbitrary_blocks_for_range(range.clone());
+
+                                let _ = node_b.send_response_msg(*request_id, ResponseMessage::SealedBlocks(Some(sealed_blocks)));
+                            }
+                            RequestMessage::TxPoolAllTransactionsIds => {
+                                let tx_ids = (0..5u8).map(|i| TxId::from([i; 32])).collect();
+                                let _ = node_b.send_response_msg(*request_id, ResponseMessage::TxPoolAllTransactionsIds(Some(tx_ids)));
+                            }
+                            RequestMessage::TxPoolFullTransactions(tx_ids) => {
+                                let txs = tx_ids.iter().map(|_| Some(Transaction::default_test_tx())).collect();
+                                let _ = node_b.send_response_msg(*request_id, ResponseMessage::TxPoolFullTransactions(Some(txs)));
+                            }
                         }
                     }
 
@@ -1645,6 +2314,26 @@ mod tests {
         request_response_works_with(RequestMessage::SealedHeaders(arbitrary_range)).await
     }
 
+    #[tokio::test]
+    #[instrument]
+    async fn request_response_works_with_sealed_blocks() {
+        let arbitrary_range = 2..6;
+        request_response_works_with(RequestMessage::SealedBlocks(arbitrary_range)).await
+    }
+
+    #[tokio::test]
+    #[instrument]
+    async fn request_response_works_with_txpool_all_transactions_ids() {
+        request_response_works_with(RequestMessage::TxPoolAllTransactionsIds).await
+    }
+
+    #[tokio::test]
+    #[instrument]
+    async fn request_response_works_with_txpool_full_transactions() {
+        let tx_ids = (0..3u8).map(|i| TxId::from([i; 32])).collect();
+        request_response_works_with(RequestMessage::TxPoolFullTransactions(tx_ids)).await
+    }
+
     /// We send a request for transactions, but it's responded by only headers
     #[tokio::test]
     #[instrument]
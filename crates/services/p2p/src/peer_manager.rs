@@ -7,21 +7,24 @@ use fuel_core_types::{
         MAX_APP_SCORE,
         MIN_APP_SCORE,
     },
+    tai64::Tai64,
 };
 use libp2p::{
     Multiaddr,
     PeerId,
 };
-use rand::seq::IteratorRandom;
+use rand::seq::SliceRandom;
 use std::{
     collections::{
         HashMap,
         HashSet,
     },
+    net::IpAddr,
     sync::{
         Arc,
         RwLock,
     },
+    time::Duration,
 };
 use tracing::{
     debug,
@@ -30,14 +33,40 @@ use tracing::{
 
 use crate::{
     gossipsub_config::GRAYLIST_THRESHOLD,
-    peer_manager::heartbeat_data::HeartbeatData,
+    peer_manager::{
+        heartbeat_data::HeartbeatData,
+        request_stats::RequestStats,
+    },
+    ports::PeersReputation,
+    request_response::messages::RequestResponseProtocol,
+    storage::{
+        KnownPeer,
+        PeerBan,
+    },
 };
 
 pub mod heartbeat_data;
+pub mod request_stats;
 
 /// At this point we better just ban the peer
 const MIN_GOSSIPSUB_SCORE_BEFORE_BAN: AppScore = GRAYLIST_THRESHOLD;
 
+/// How long the peer stays banned after its reputation dropped too low
+const PEER_BAN_DURATION: Duration = Duration::from_secs(60 * 60);
+
+/// The known peer is forgotten if it wasn't seen for this long
+const KNOWN_PEER_EXPIRATION: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// Maximum amount of known peers kept between restarts
+const MAX_KNOWN_PEERS: usize = 1000;
+
+/// Number of requests in a row the peer may leave without a response
+/// before its reputation is decreased
+const MAX_CONSECUTIVE_STALLS: u32 = 3;
+
+/// Penalty for every stalled request after `MAX_CONSECUTIVE_STALLS` is reached
+const STALLED_REQUEST_PENALTY: AppScore = -5.0;
+
 // Info about a single Peer that we're connected to
 #[derive(Debug, Clone)]
 pub struct PeerInfo {
@@ -45,6 +74,9 @@ pub struct PeerInfo {
     pub client_version: Option<String>,
     pub heartbeat_data: HeartbeatData,
     pub score: AppScore,
+    /// The latest version of the request/response protocol reported by the peer
+    pub req_res_protocol: Option<RequestResponseProtocol>,
+    pub request_stats: RequestStats,
 }
 
 impl PeerInfo {
@@ -54,8 +86,16 @@ impl PeerInfo {
             client_version: None,
             heartbeat_data: HeartbeatData::new(heartbeat_avg_window),
             score: DEFAULT_APP_SCORE,
+            req_res_protocol: None,
+            request_stats: RequestStats::default(),
         }
     }
+
+    /// All peers support the first version of the protocol, so it is assumed
+    /// even before the peer is identified.
+    pub fn supports(&self, protocol: RequestResponseProtocol) -> bool {
+        protocol == RequestResponseProtocol::V1 || self.req_res_protocol >= Some(protocol)
+    }
 }
 
 /// Manages Peers and their events
@@ -68,6 +108,9 @@ pub struct PeerManager {
     connection_state: Arc<RwLock<ConnectionState>>,
     max_non_reserved_peers: usize,
     reserved_peers_updates: tokio::sync::broadcast::Sender<usize>,
+    banned_peers: HashMap<PeerId, PeerBan>,
+    /// Peers seen before, whose reputation is restored when they reconnect
+    known_peers: HashMap<PeerId, KnownPeer>,
 }
 
 impl PeerManager {
@@ -77,6 +120,10 @@ impl PeerManager {
         connection_state: Arc<RwLock<ConnectionState>>,
         max_non_reserved_peers: usize,
     ) -> Self {
+        if let Ok(mut connection_state) = connection_state.write() {
+            connection_state.reserved_peers.clone_from(&reserved_peers);
+        }
+
         Self {
             score_config: ScoreConfig::default(),
             non_reserved_connected_peers: HashMap::with_capacity(max_non_reserved_peers),
@@ -85,6 +132,8 @@ impl PeerManager {
             connection_state,
             max_non_reserved_peers,
             reserved_peers_updates,
+            banned_peers: HashMap::new(),
+            known_peers: HashMap::new(),
         }
     }
 
@@ -96,8 +145,88 @@ impl PeerManager {
         self.reserved_peers.contains(peer_id)
     }
 
+    pub fn is_banned(&self, peer_id: &PeerId) -> bool {
+        self.banned_peers.contains_key(peer_id)
+    }
+
+    /// Makes the peer reserved. If the peer is already connected,
+    /// it releases the non-reserved slot taken by the peer.
+    pub fn add_reserved_peer(&mut self, peer_id: PeerId) {
+        if !self.reserved_peers.insert(peer_id) {
+            return
+        }
+        if let Ok(mut connection_state) = self.connection_state.write() {
+            connection_state.reserved_peers.insert(peer_id);
+        }
+
+        let all_slots_taken =
+            self.non_reserved_connected_peers.len() >= self.max_non_reserved_peers;
+        if let Some(peer_info) = self.non_reserved_connected_peers.remove(&peer_id) {
+            self.reserved_connected_peers.insert(peer_id, peer_info);
+            if all_slots_taken {
+                if let Ok(mut connection_state) = self.connection_state.write() {
+                    connection_state.allow_new_peers();
+                }
+            }
+            self.send_reserved_peers_update();
+        }
+    }
+
+    /// Makes the reserved peer a regular one. If the peer is connected,
+    /// it takes a non-reserved slot.
+    /// Returns `true` signaling that the peer should be disconnected
+    /// because all non-reserved slots are taken.
+    pub fn remove_reserved_peer(&mut self, peer_id: &PeerId) -> bool {
+        if !self.reserved_peers.remove(peer_id) {
+            return false
+        }
+        if let Ok(mut connection_state) = self.connection_state.write() {
+            connection_state.reserved_peers.remove(peer_id);
+        }
+
+        let Some(peer_info) = self.reserved_connected_peers.remove(peer_id) else {
+            return false
+        };
+        self.send_reserved_peers_update();
+
+        let non_reserved_peers_connected = self.non_reserved_connected_peers.len();
+        if non_reserved_peers_connected >= self.max_non_reserved_peers {
+            return true
+        }
+        if non_reserved_peers_connected.saturating_add(1) == self.max_non_reserved_peers {
+            if let Ok(mut connection_state) = self.connection_state.write() {
+                connection_state.deny_new_peers();
+            }
+        }
+        self.non_reserved_connected_peers
+            .insert(*peer_id, peer_info);
+        false
+    }
+
+    /// Bans the peer until the ban expires.
+    pub fn ban_peer<T: Punisher>(&mut self, peer_id: PeerId, punisher: &mut T) {
+        let expires_at =
+            Tai64(Tai64::now().0.saturating_add(PEER_BAN_DURATION.as_secs()));
+        self.banned_peers.insert(peer_id, PeerBan { expires_at });
+        punisher.ban_peer(peer_id);
+    }
+
+    /// Lifts the ban of the peer before it expires.
+    /// Returns `false` if the peer isn't banned.
+    pub fn unban_peer<T: Punisher>(
+        &mut self,
+        peer_id: &PeerId,
+        punisher: &mut T,
+    ) -> bool {
+        let banned = self.banned_peers.remove(peer_id).is_some();
+        if banned {
+            punisher.unban_peer(*peer_id);
+        }
+        banned
+    }
+
     pub fn handle_gossip_score_update<T: Punisher>(
-        &self,
+        &mut self,
         peer_id: PeerId,
         gossip_score: f64,
         punisher: &mut T,
@@ -105,7 +234,82 @@ impl PeerManager {
         if gossip_score < self.score_config.min_gossip_score_allowed
             && !self.reserved_peers.contains(&peer_id)
         {
-            punisher.ban_peer(peer_id);
+            self.ban_peer(peer_id, punisher);
+        }
+    }
+
+    /// Restores the reputation of peers stored before the restart.
+    /// Bans that are still active are applied to the `punisher`.
+    pub fn restore_reputation<T: Punisher>(
+        &mut self,
+        reputation: PeersReputation,
+        punisher: &mut T,
+    ) {
+        let now = Tai64::now();
+        let PeersReputation {
+            banned_peers,
+            known_peers,
+        } = reputation;
+
+        for (peer_id, ban) in banned_peers {
+            if ban.expires_at > now && !self.reserved_peers.contains(&peer_id) {
+                punisher.ban_peer(peer_id);
+                self.banned_peers.insert(peer_id, ban);
+            }
+        }
+
+        self.known_peers = known_peers
+            .into_iter()
+            .filter(|(_, known_peer)| !is_expired(known_peer, now))
+            .collect();
+    }
+
+    /// Returns the known peers with a good reputation that aren't banned.
+    pub fn known_good_peers(&self) -> impl Iterator<Item = (&PeerId, &KnownPeer)> {
+        self.known_peers.iter().filter(|(peer_id, known_peer)| {
+            known_peer.score >= DEFAULT_APP_SCORE
+                && !self.banned_peers.contains_key(peer_id)
+        })
+    }
+
+    /// Lifts the bans that have expired.
+    pub fn lift_expired_bans<T: Punisher>(&mut self, punisher: &mut T) {
+        let now = Tai64::now();
+        self.banned_peers.retain(|peer_id, ban| {
+            let expired = ban.expires_at <= now;
+            if expired {
+                debug!(target: "fuel-p2p", "The ban of {peer_id} has expired");
+                punisher.unban_peer(*peer_id);
+            }
+            !expired
+        });
+    }
+
+    /// Returns the reputation of peers that should survive the restart.
+    pub fn peers_reputation(&self) -> PeersReputation {
+        let now = Tai64::now();
+        let connected_peers = self
+            .non_reserved_connected_peers
+            .iter()
+            .map(|(peer_id, peer_info)| (*peer_id, known_peer_from_info(peer_info, now)));
+
+        let mut known_peers = self
+            .known_peers
+            .iter()
+            .filter(|(_, known_peer)| !is_expired(known_peer, now))
+            .map(|(peer_id, known_peer)| (*peer_id, known_peer.clone()))
+            .chain(connected_peers)
+            .collect::<HashMap<_, _>>()
+            .into_iter()
+            .collect::<Vec<_>>();
+
+        // Keep only the most recently seen peers
+        known_peers.sort_by(|(_, a), (_, b)| b.last_seen.cmp(&a.last_seen));
+        known_peers.truncate(MAX_KNOWN_PEERS);
+
+        PeersReputation {
+            banned_peers: self.banned_peers.clone(),
+            known_peers: known_peers.into_iter().collect(),
         }
     }
 
@@ -130,15 +334,60 @@ impl PeerManager {
         self.handle_initial_connection(peer_id)
     }
 
+    /// Returns `true` if the peer is identified for the first time since it connected
     pub fn handle_peer_identified(
         &mut self,
         peer_id: &PeerId,
         addresses: Vec<Multiaddr>,
         agent_version: String,
-    ) {
+        req_res_protocol: Option<RequestResponseProtocol>,
+    ) -> bool {
         let peers = self.get_assigned_peer_table_mut(peer_id);
+        let first_identification = peers
+            .get(peer_id)
+            .map_or(false, |peer| peer.client_version.is_none());
         insert_client_version(peers, peer_id, agent_version);
         insert_peer_addresses(peers, peer_id, addresses);
+        if let Some(peer) = peers.get_mut(peer_id) {
+            peer.req_res_protocol = req_res_protocol;
+        }
+        first_identification
+    }
+
+    pub fn handle_request_sent(&mut self, peer_id: &PeerId) {
+        if let Some(peer) = self.get_assigned_peer_table_mut(peer_id).get_mut(peer_id) {
+            peer.request_stats.request_sent();
+        }
+    }
+
+    pub fn handle_request_succeeded(&mut self, peer_id: &PeerId, latency: Duration) {
+        if let Some(peer) = self.get_assigned_peer_table_mut(peer_id).get_mut(peer_id) {
+            peer.request_stats.request_succeeded(latency);
+        }
+    }
+
+    /// Records the failed request. The peer's reputation is decreased
+    /// if it keeps stalling requests.
+    pub fn handle_request_failed<T: Punisher>(
+        &mut self,
+        peer_id: PeerId,
+        stalled: bool,
+        punisher: &mut T,
+    ) {
+        let Some(peer) = self.get_assigned_peer_table_mut(&peer_id).get_mut(&peer_id)
+        else {
+            return
+        };
+        peer.request_stats.request_failed();
+
+        if stalled && peer.request_stats.consecutive_failures >= MAX_CONSECUTIVE_STALLS {
+            self.update_app_score(
+                peer_id,
+                STALLED_REQUEST_PENALTY,
+                "p2p_request_response",
+                punisher,
+            );
+        }
     }
 
     pub fn batch_update_score_with_decay(&mut self) {
@@ -162,7 +411,7 @@ impl PeerManager {
             info!(target: "fuel-p2p", "{reporting_service} updated {peer_id} with new score {score}");
 
             if new_score < self.score_config.min_app_score_allowed {
-                punisher.ban_peer(peer_id);
+                self.ban_peer(peer_id, punisher);
             }
         } else {
             log_missing_peer(&peer_id);
@@ -205,9 +454,13 @@ impl PeerManager {
             let all_slots_taken = self.max_non_reserved_peers
                 == self.non_reserved_connected_peers.len().saturating_add(1);
 
-            if self.non_reserved_connected_peers.remove(&peer_id).is_some()
-                && all_slots_taken
-            {
+            let removed_peer = self.non_reserved_connected_peers.remove(&peer_id);
+            if let Some(peer_info) = &removed_peer {
+                self.known_peers
+                    .insert(peer_id, known_peer_from_info(peer_info, Tai64::now()));
+            }
+
+            if removed_peer.is_some() && all_slots_taken {
                 // since all the slots were full prior to this disconnect
                 // let's allow new peer non-reserved peers connections
                 if let Ok(mut connection_state) = self.connection_state.write() {
@@ -225,19 +478,39 @@ impl PeerManager {
     }
 
     /// Find a peer that is holding the given block height.
-    pub fn get_peer_id_with_height(&self, height: &BlockHeight) -> Option<PeerId> {
-        let mut range = rand::thread_rng();
-        // TODO: Optimize the selection of the peer.
-        //  We can store pair `(peer id, height)` for all nodes(reserved and not) in the
-        //  https://docs.rs/sorted-vec/latest/sorted_vec/struct.SortedVec.html
-        self.non_reserved_connected_peers
+    /// Peers that failed fewer requests in a row are preferred, so a failed
+    /// request is retried on another peer. Then the peer expected to
+    /// respond the fastest is chosen, considering its latency, failure rate and
+    /// requests already sent to it, which spreads parallel requests across peers.
+    /// The `excluded_peers` are never chosen.
+    pub fn get_peer_id_with_height(
+        &self,
+        height: &BlockHeight,
+        protocol: RequestResponseProtocol,
+        excluded_peers: &HashSet<PeerId>,
+    ) -> Option<PeerId> {
+        let mut candidates = self
+            .non_reserved_connected_peers
             .iter()
             .chain(self.reserved_connected_peers.iter())
-            .filter(|(_, peer_info)| {
+            .filter(|(peer_id, peer_info)| {
                 peer_info.heartbeat_data.block_height >= Some(*height)
+                    && peer_info.supports(protocol)
+                    && !excluded_peers.contains(peer_id)
+            })
+            .collect::<Vec<_>>();
+        // Shuffle to choose randomly between equally good peers.
+        candidates.shuffle(&mut rand::thread_rng());
+        candidates
+            .into_iter()
+            .min_by(|(_, a), (_, b)| {
+                let a = &a.request_stats;
+                let b = &b.request_stats;
+                a.consecutive_failures
+                    .cmp(&b.consecutive_failures)
+                    .then(a.expected_latency().total_cmp(&b.expected_latency()))
             })
             .map(|(peer_id, _)| *peer_id)
-            .choose(&mut range)
     }
 
     /// Handles the first connection established with a Peer
@@ -263,8 +536,12 @@ impl PeerManager {
                 }
             }
 
+            let mut peer_info = PeerInfo::new(HEARTBEAT_AVG_WINDOW);
+            if let Some(known_peer) = self.known_peers.get(peer_id) {
+                peer_info.score = known_peer.score;
+            }
             self.non_reserved_connected_peers
-                .insert(*peer_id, PeerInfo::new(HEARTBEAT_AVG_WINDOW));
+                .insert(*peer_id, peer_info);
         } else if is_reserved && !self.reserved_connected_peers.contains_key(peer_id) {
             self.reserved_connected_peers
                 .insert(*peer_id, PeerInfo::new(HEARTBEAT_AVG_WINDOW));
@@ -307,22 +584,53 @@ fn insert_peer_addresses(
     }
 }
 
-#[derive(Debug, Default, Clone, Copy)]
+/// The state shared with the transport to decide whether to accept new connections.
+/// Reserved peers are kept in sync with the `PeerManager`, as they can change at runtime.
+#[derive(Debug, Default, Clone)]
 pub struct ConnectionState {
     peers_allowed: bool,
+    reserved_peers: HashSet<PeerId>,
+    inbound_connections_per_ip: HashMap<IpAddr, u32>,
 }
 
 impl ConnectionState {
     pub fn new() -> Arc<RwLock<Self>> {
         Arc::new(RwLock::new(Self {
             peers_allowed: true,
+            reserved_peers: HashSet::new(),
+            inbound_connections_per_ip: HashMap::new(),
         }))
     }
 
+    pub fn inbound_connections(&self, ip: &IpAddr) -> u32 {
+        self.inbound_connections_per_ip
+            .get(ip)
+            .copied()
+            .unwrap_or_default()
+    }
+
+    pub(crate) fn add_inbound_connection(&mut self, ip: IpAddr) {
+        let connections = self.inbound_connections_per_ip.entry(ip).or_default();
+        *connections = connections.saturating_add(1);
+    }
+
+    pub(crate) fn remove_inbound_connection(&mut self, ip: &IpAddr) {
+        if let Some(connections) = self.inbound_connections_per_ip.get_mut(ip) {
+            *connections = connections.saturating_sub(1);
+            if *connections == 0 {
+                self.inbound_connections_per_ip.remove(ip);
+            }
+        }
+    }
+
     pub fn available_slot(&self) -> bool {
         self.peers_allowed
     }
 
+    pub fn is_reserved(&self, peer_id: &PeerId) -> bool {
+        self.reserved_peers.contains(peer_id)
+    }
+
     fn allow_new_peers(&mut self) {
         self.peers_allowed = true;
     }
@@ -356,6 +664,22 @@ fn insert_client_version(
     }
 }
 
+fn known_peer_from_info(peer_info: &PeerInfo, now: Tai64) -> KnownPeer {
+    KnownPeer {
+        addresses: peer_info.peer_addresses.iter().cloned().collect(),
+        score: peer_info.score,
+        last_seen: now,
+    }
+}
+
+fn is_expired(known_peer: &KnownPeer, now: Tai64) -> bool {
+    known_peer
+        .last_seen
+        .0
+        .saturating_add(KNOWN_PEER_EXPIRATION.as_secs())
+        < now.0
+}
+
 fn log_missing_peer(peer_id: &PeerId) {
     debug!(target: "fuel-p2p", "Peer with PeerId: {:?} is not among the connected peers", peer_id)
 }
@@ -385,9 +709,12 @@ impl ScoreConfig {
 
 pub trait Punisher {
     fn ban_peer(&mut self, peer_id: PeerId);
+
+    fn unban_peer(&mut self, peer_id: PeerId);
 }
 
 #[cfg(test)]
+#[allow(non_snake_case)]
 mod tests {
     use super::*;
 
@@ -486,4 +813,380 @@ mod tests {
             reserved_peers.len() + max_non_reserved_peers
         );
     }
+
+    #[derive(Default)]
+    struct FakePunisher {
+        banned_peers: HashSet<PeerId>,
+    }
+
+    impl Punisher for FakePunisher {
+        fn ban_peer(&mut self, peer_id: PeerId) {
+            self.banned_peers.insert(peer_id);
+        }
+
+        fn unban_peer(&mut self, peer_id: PeerId) {
+            self.banned_peers.remove(&peer_id);
+        }
+    }
+
+    fn seconds_from_now(seconds: i64) -> Tai64 {
+        Tai64(Tai64::now().0.saturating_add_signed(seconds))
+    }
+
+    fn known_peer(score: AppScore, last_seen: Tai64) -> KnownPeer {
+        KnownPeer {
+            addresses: vec!["/ip4/127.0.0.1/tcp/4001".parse().unwrap()],
+            score,
+            last_seen,
+        }
+    }
+
+    #[test]
+    fn peer_with_low_app_score_is_banned_temporarily() {
+        let mut peer_manager = initialize_peer_manager(vec![], 5);
+        let mut punisher = FakePunisher::default();
+        let peer_id = PeerId::random();
+        peer_manager.handle_initial_connection(&peer_id);
+
+        // When
+        peer_manager.update_app_score(peer_id, -100.0, "test", &mut punisher);
+
+        // Then
+        assert!(punisher.banned_peers.contains(&peer_id));
+        let ban = peer_manager.peers_reputation().banned_peers[&peer_id];
+        assert!(ban.expires_at > Tai64::now());
+    }
+
+    #[test]
+    fn restore_reputation__applies_only_active_bans() {
+        let mut peer_manager = initialize_peer_manager(vec![], 5);
+        let mut punisher = FakePunisher::default();
+        let active = PeerId::random();
+        let expired = PeerId::random();
+        let reputation = PeersReputation {
+            banned_peers: [
+                (
+                    active,
+                    PeerBan {
+                        expires_at: seconds_from_now(60),
+                    },
+                ),
+                (
+                    expired,
+                    PeerBan {
+                        expires_at: seconds_from_now(-60),
+                    },
+                ),
+            ]
+            .into(),
+            known_peers: Default::default(),
+        };
+
+        // When
+        peer_manager.restore_reputation(reputation, &mut punisher);
+
+        // Then
+        assert_eq!(punisher.banned_peers, [active].into());
+        assert_eq!(
+            peer_manager
+                .peers_reputation()
+                .banned_peers
+                .keys()
+                .collect::<Vec<_>>(),
+            vec![&active]
+        );
+    }
+
+    #[test]
+    fn lift_expired_bans__unbans_peers_after_ban_expiration() {
+        let mut peer_manager = initialize_peer_manager(vec![], 5);
+        let mut punisher = FakePunisher::default();
+        let peer_id = PeerId::random();
+        peer_manager.handle_initial_connection(&peer_id);
+        peer_manager.update_app_score(peer_id, -100.0, "test", &mut punisher);
+        assert!(punisher.banned_peers.contains(&peer_id));
+        peer_manager.banned_peers.insert(
+            peer_id,
+            PeerBan {
+                expires_at: seconds_from_now(-1),
+            },
+        );
+
+        // When
+        peer_manager.lift_expired_bans(&mut punisher);
+
+        // Then
+        assert!(punisher.banned_peers.is_empty());
+        assert!(peer_manager.peers_reputation().banned_peers.is_empty());
+    }
+
+    #[test]
+    fn score_of_known_peer_is_restored_on_reconnect() {
+        let mut peer_manager = initialize_peer_manager(vec![], 5);
+        let peer_id = PeerId::random();
+        let reputation = PeersReputation {
+            banned_peers: Default::default(),
+            known_peers: [(peer_id, known_peer(42.0, Tai64::now()))].into(),
+        };
+        peer_manager.restore_reputation(reputation, &mut FakePunisher::default());
+
+        // When
+        peer_manager.handle_initial_connection(&peer_id);
+
+        // Then
+        assert_eq!(peer_manager.get_peer_info(&peer_id).unwrap().score, 42.0);
+    }
+
+    #[test]
+    fn disconnected_peer_is_remembered_with_its_score() {
+        let mut peer_manager = initialize_peer_manager(vec![], 5);
+        let peer_id = PeerId::random();
+        peer_manager.handle_initial_connection(&peer_id);
+        peer_manager.update_app_score(
+            peer_id,
+            -10.0,
+            "test",
+            &mut FakePunisher::default(),
+        );
+
+        // When
+        peer_manager.handle_peer_disconnect(peer_id);
+
+        // Then
+        let known_peers = peer_manager.peers_reputation().known_peers;
+        assert_eq!(known_peers[&peer_id].score, DEFAULT_APP_SCORE - 10.0);
+    }
+
+    #[test]
+    fn known_good_peers__skips_banned_expired_and_badly_behaving_peers() {
+        let mut peer_manager = initialize_peer_manager(vec![], 5);
+        let good = PeerId::random();
+        let bad_score = PeerId::random();
+        let banned = PeerId::random();
+        let expired = PeerId::random();
+        let long_ago = seconds_from_now(-(KNOWN_PEER_EXPIRATION.as_secs() as i64) - 1);
+        let reputation = PeersReputation {
+            banned_peers: [(
+                banned,
+                PeerBan {
+                    expires_at: seconds_from_now(60),
+                },
+            )]
+            .into(),
+            known_peers: [
+                (good, known_peer(DEFAULT_APP_SCORE, Tai64::now())),
+                (bad_score, known_peer(DEFAULT_APP_SCORE - 1.0, Tai64::now())),
+                (banned, known_peer(DEFAULT_APP_SCORE, Tai64::now())),
+                (expired, known_peer(DEFAULT_APP_SCORE, long_ago)),
+            ]
+            .into(),
+        };
+
+        // When
+        peer_manager.restore_reputation(reputation, &mut FakePunisher::default());
+
+        // Then
+        let known_good_peers = peer_manager
+            .known_good_peers()
+            .map(|(peer_id, _)| *peer_id)
+            .collect::<Vec<_>>();
+        assert_eq!(known_good_peers, vec![good]);
+    }
+
+    #[test]
+    fn add_reserved_peer__releases_non_reserved_slot_of_connected_peer() {
+        let max_non_reserved_peers = 2;
+        let mut peer_manager = initialize_peer_manager(vec![], max_non_reserved_peers);
+        let peers = get_random_peers(max_non_reserved_peers);
+        for peer_id in &peers {
+            peer_manager.handle_initial_connection(peer_id);
+        }
+        assert!(!peer_manager
+            .connection_state
+            .read()
+            .unwrap()
+            .available_slot());
+
+        // When
+        peer_manager.add_reserved_peer(peers[0]);
+
+        // Then
+        assert!(peer_manager.is_reserved(&peers[0]));
+        assert!(peer_manager
+            .reserved_connected_peers
+            .contains_key(&peers[0]));
+        let connection_state = peer_manager.connection_state.read().unwrap();
+        assert!(connection_state.available_slot());
+        assert!(connection_state.is_reserved(&peers[0]));
+    }
+
+    #[test]
+    fn remove_reserved_peer__disconnects_peer_if_all_slots_are_taken() {
+        let max_non_reserved_peers = 1;
+        let reserved_peers = get_random_peers(2);
+        let mut peer_manager =
+            initialize_peer_manager(reserved_peers.clone(), max_non_reserved_peers);
+        for peer_id in &reserved_peers {
+            peer_manager.handle_initial_connection(peer_id);
+        }
+
+        // When
+        let first_disconnected = peer_manager.remove_reserved_peer(&reserved_peers[0]);
+        let second_disconnected = peer_manager.remove_reserved_peer(&reserved_peers[1]);
+
+        // Then
+        assert!(!first_disconnected);
+        assert!(second_disconnected);
+        assert!(!peer_manager.is_reserved(&reserved_peers[0]));
+        assert!(peer_manager
+            .non_reserved_connected_peers
+            .contains_key(&reserved_peers[0]));
+        let connection_state = peer_manager.connection_state.read().unwrap();
+        assert!(!connection_state.available_slot());
+        assert!(!connection_state.is_reserved(&reserved_peers[1]));
+    }
+
+    fn connect_peers_at_height(
+        peer_manager: &mut PeerManager,
+        count: usize,
+        height: BlockHeight,
+    ) -> Vec<PeerId> {
+        let peers = get_random_peers(count);
+        for peer_id in &peers {
+            peer_manager.handle_initial_connection(peer_id);
+            peer_manager.handle_peer_info_updated(peer_id, height);
+        }
+        peers
+    }
+
+    #[test]
+    fn get_peer_id_with_height__prefers_the_fastest_peer() {
+        let height = BlockHeight::from(10);
+        let mut peer_manager = initialize_peer_manager(vec![], 5);
+        let peers = connect_peers_at_height(&mut peer_manager, 3, height);
+        for (peer_id, latency) in peers.iter().zip([300, 100, 200]) {
+            peer_manager.handle_request_sent(peer_id);
+            peer_manager
+                .handle_request_succeeded(peer_id, Duration::from_millis(latency));
+        }
+
+        // When
+        let peer = peer_manager.get_peer_id_with_height(
+            &height,
+            RequestResponseProtocol::V1,
+            &HashSet::new(),
+        );
+
+        // Then
+        assert_eq!(peer, Some(peers[1]));
+    }
+
+    #[test]
+    fn get_peer_id_with_height__spreads_requests_across_peers() {
+        let height = BlockHeight::from(10);
+        let mut peer_manager = initialize_peer_manager(vec![], 5);
+        let peers = connect_peers_at_height(&mut peer_manager, 3, height);
+
+        // When
+        let mut chosen_peers = HashSet::new();
+        for _ in 0..peers.len() {
+            let peer = peer_manager
+                .get_peer_id_with_height(
+                    &height,
+                    RequestResponseProtocol::V1,
+                    &HashSet::new(),
+                )
+                .unwrap();
+            peer_manager.handle_request_sent(&peer);
+            chosen_peers.insert(peer);
+        }
+
+        // Then
+        assert_eq!(chosen_peers, peers.into_iter().collect());
+    }
+
+    #[test]
+    fn get_peer_id_with_height__retries_on_another_peer_after_failure() {
+        let height = BlockHeight::from(10);
+        let mut peer_manager = initialize_peer_manager(vec![], 5);
+        let mut punisher = FakePunisher::default();
+        let peers = connect_peers_at_height(&mut peer_manager, 2, height);
+        let (fast_peer, slow_peer) = (peers[0], peers[1]);
+        for (peer_id, latency) in [(fast_peer, 10), (slow_peer, 1000)] {
+            peer_manager.handle_request_sent(&peer_id);
+            peer_manager
+                .handle_request_succeeded(&peer_id, Duration::from_millis(latency));
+        }
+        peer_manager.handle_request_sent(&fast_peer);
+        peer_manager.handle_request_failed(fast_peer, false, &mut punisher);
+
+        // When
+        let peer = peer_manager.get_peer_id_with_height(
+            &height,
+            RequestResponseProtocol::V1,
+            &HashSet::new(),
+        );
+
+        // Then
+        assert_eq!(peer, Some(slow_peer));
+    }
+
+    #[test]
+    fn get_peer_id_with_height__skips_peers_below_the_height() {
+        let mut peer_manager = initialize_peer_manager(vec![], 5);
+        connect_peers_at_height(&mut peer_manager, 2, BlockHeight::from(5));
+        let peers = connect_peers_at_height(&mut peer_manager, 1, BlockHeight::from(10));
+
+        // When
+        let peer = peer_manager.get_peer_id_with_height(
+            &BlockHeight::from(10),
+            RequestResponseProtocol::V1,
+            &HashSet::new(),
+        );
+
+        // Then
+        assert_eq!(peer, Some(peers[0]));
+    }
+
+    #[test]
+    fn get_peer_id_with_height__skips_excluded_peers() {
+        let height = BlockHeight::from(10);
+        let mut peer_manager = initialize_peer_manager(vec![], 5);
+        let peers = connect_peers_at_height(&mut peer_manager, 2, height);
+        let excluded_peers = HashSet::from([peers[0]]);
+
+        // When
+        let peer = peer_manager.get_peer_id_with_height(
+            &height,
+            RequestResponseProtocol::V1,
+            &excluded_peers,
+        );
+
+        // Then
+        assert_eq!(peer, Some(peers[1]));
+    }
+
+    #[test]
+    fn handle_request_failed__penalizes_only_peers_that_keep_stalling() {
+        let mut peer_manager = initialize_peer_manager(vec![], 5);
+        let mut punisher = FakePunisher::default();
+        let peers = connect_peers_at_height(&mut peer_manager, 2, 10.into());
+        let (stalling_peer, failing_peer) = (peers[0], peers[1]);
+
+        // When
+        for _ in 0..MAX_CONSECUTIVE_STALLS + 1 {
+            peer_manager.handle_request_sent(&stalling_peer);
+            peer_manager.handle_request_failed(stalling_peer, true, &mut punisher);
+            peer_manager.handle_request_sent(&failing_peer);
+            peer_manager.handle_request_failed(failing_peer, false, &mut punisher);
+        }
+
+        // Then
+        let score = |peer_id| peer_manager.get_peer_info(peer_id).unwrap().score;
+        assert_eq!(
+            score(&stalling_peer),
+            DEFAULT_APP_SCORE + 2.0 * STALLED_REQUEST_PENALTY
+        );
+        assert_eq!(score(&failing_peer), DEFAULT_APP_SCORE);
+    }
 }
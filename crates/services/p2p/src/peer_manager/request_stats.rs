@@ -0,0 +1,66 @@
+use std::time::Duration;
+
+/// Weight of the latest observation in the moving averages
+const SMOOTHING_FACTOR: f64 = 0.2;
+
+/// Latency assumed for the peer that hasn't answered any request yet
+const UNKNOWN_PEER_LATENCY: Duration = Duration::from_millis(500);
+
+/// The lowest success rate used to estimate the cost of the request,
+/// so the cost stays finite for peers that never answer
+const MIN_SUCCESS_RATE: f64 = 0.05;
+
+/// Performance of the peer serving our requests.
+#[derive(Debug, Clone, Default)]
+pub struct RequestStats {
+    /// Moving average of the time it takes the peer to respond
+    pub average_latency: Option<Duration>,
+    /// Moving average of the share of failed requests, from `0.0` to `1.0`
+    pub failure_rate: f64,
+    /// Number of requests failed in a row since the last response
+    pub consecutive_failures: u32,
+    /// Number of requests sent to the peer and still waiting for a response
+    pub in_flight: u32,
+}
+
+impl RequestStats {
+    pub fn request_sent(&mut self) {
+        self.in_flight = self.in_flight.saturating_add(1);
+    }
+
+    pub fn request_succeeded(&mut self, latency: Duration) {
+        self.in_flight = self.in_flight.saturating_sub(1);
+        self.consecutive_failures = 0;
+        self.failure_rate = moving_average(self.failure_rate, 0.0);
+        let average_latency = match self.average_latency {
+            Some(average) => Duration::from_secs_f64(moving_average(
+                average.as_secs_f64(),
+                latency.as_secs_f64(),
+            )),
+            None => latency,
+        };
+        self.average_latency = Some(average_latency);
+    }
+
+    pub fn request_failed(&mut self) {
+        self.in_flight = self.in_flight.saturating_sub(1);
+        self.consecutive_failures = self.consecutive_failures.saturating_add(1);
+        self.failure_rate = moving_average(self.failure_rate, 1.0);
+    }
+
+    /// The estimated time in seconds to get a response to a new request,
+    /// considering requests already queued to the peer and its failures.
+    pub fn expected_latency(&self) -> f64 {
+        let latency = self
+            .average_latency
+            .unwrap_or(UNKNOWN_PEER_LATENCY)
+            .as_secs_f64();
+        let queued = f64::from(self.in_flight.saturating_add(1));
+        let success_rate = (1.0 - self.failure_rate).max(MIN_SUCCESS_RATE);
+        latency * queued / success_rate
+    }
+}
+
+fn moving_average(average: f64, value: f64) -> f64 {
+    average * (1.0 - SMOOTHING_FACTOR) + value * SMOOTHING_FACTOR
+}
@@ -1,10 +1,20 @@
 use crate::{
-    config::Config,
+    config::{
+        connection_tracker::{
+            ConnectionRejected,
+            ConnectionTracker,
+        },
+        Config,
+    },
     TryPeerId,
 };
+use fuel_core_metrics::p2p_metrics::increment_rejected_connections;
 use libp2p::{
     self,
-    core::Endpoint,
+    core::{
+        ConnectedPoint,
+        Endpoint,
+    },
     swarm::{
         derive_prelude::{
             ConnectionClosed,
@@ -72,14 +82,16 @@ pub struct Behaviour {
     pending_connections: HashSet<ConnectionId>,
     pending_events: VecDeque<ToSwarm<PeerReportEvent, Void>>,
     decay_interval: Interval,
+    connection_tracker: ConnectionTracker,
+    metrics: bool,
 }
 
 impl Behaviour {
-    pub(crate) fn new(_config: &Config) -> Self {
+    pub(crate) fn new(config: &Config, connection_tracker: ConnectionTracker) -> Self {
         let mut reserved_nodes_to_connect = VecDeque::new();
         let mut reserved_nodes_multiaddr = BTreeMap::<PeerId, Vec<Multiaddr>>::new();
 
-        for multiaddr in &_config.reserved_nodes {
+        for multiaddr in &config.reserved_nodes {
             let peer_id = multiaddr.try_to_peer_id().unwrap();
             reserved_nodes_to_connect.push_back((Instant::now(), peer_id));
             reserved_nodes_multiaddr
@@ -97,21 +109,76 @@ impl Behaviour {
             decay_interval: time::interval(Duration::from_secs(
                 REPUTATION_DECAY_INTERVAL_IN_SECONDS,
             )),
+            connection_tracker,
+            metrics: config.metrics,
+        }
+    }
+
+    /// Starts keeping the connection to the reserved node.
+    pub fn add_reserved_node(
+        &mut self,
+        peer_id: PeerId,
+        multiaddr: Multiaddr,
+        connected: bool,
+    ) {
+        let multiaddrs = self.reserved_nodes_multiaddr.entry(peer_id).or_default();
+        if multiaddrs.is_empty() {
+            if connected {
+                self.connected_reserved_nodes.insert(peer_id);
+            } else {
+                self.reserved_nodes_to_connect
+                    .push_back((Instant::now(), peer_id));
+            }
+        }
+        if !multiaddrs.contains(&multiaddr) {
+            multiaddrs.push(multiaddr);
         }
     }
+
+    /// Stops reconnecting to the node when it disconnects.
+    pub fn remove_reserved_node(&mut self, peer_id: &PeerId) {
+        self.reserved_nodes_multiaddr.remove(peer_id);
+        self.connected_reserved_nodes.remove(peer_id);
+        self.reserved_nodes_to_connect
+            .retain(|(_, reserved_peer_id)| reserved_peer_id != peer_id);
+    }
+
+    fn deny_connection(&self, reason: ConnectionRejected) -> ConnectionDenied {
+        tracing::debug!("Connection denied: {reason}");
+        if self.metrics {
+            increment_rejected_connections();
+        }
+        ConnectionDenied::new(reason)
+    }
 }
 
 impl NetworkBehaviour for Behaviour {
     type ConnectionHandler = dummy::ConnectionHandler;
     type ToSwarm = PeerReportEvent;
 
+    fn handle_pending_inbound_connection(
+        &mut self,
+        _connection_id: ConnectionId,
+        _local_addr: &Multiaddr,
+        remote_addr: &Multiaddr,
+    ) -> Result<(), ConnectionDenied> {
+        self.connection_tracker
+            .check_inbound_connection(remote_addr)
+            .map_err(|reason| self.deny_connection(reason))
+    }
+
     fn handle_established_inbound_connection(
         &mut self,
         _connection_id: ConnectionId,
         _peer: PeerId,
         _local_addr: &Multiaddr,
-        _remote_addr: &Multiaddr,
+        remote_addr: &Multiaddr,
     ) -> Result<THandler<Self>, ConnectionDenied> {
+        // Other connections from the same IP could be established
+        // while this one was pending, so the limit is checked again.
+        self.connection_tracker
+            .check_inbound_connection(remote_addr)
+            .map_err(|reason| self.deny_connection(reason))?;
         Ok(dummy::ConnectionHandler)
     }
 
@@ -119,9 +186,12 @@ impl NetworkBehaviour for Behaviour {
         &mut self,
         _connection_id: ConnectionId,
         _peer: PeerId,
-        _addr: &Multiaddr,
+        addr: &Multiaddr,
         _role_override: Endpoint,
     ) -> Result<THandler<Self>, ConnectionDenied> {
+        self.connection_tracker
+            .check_address(addr)
+            .map_err(|reason| self.deny_connection(reason))?;
         Ok(dummy::ConnectionHandler)
     }
 
@@ -131,8 +201,13 @@ impl NetworkBehaviour for Behaviour {
                 let ConnectionEstablished {
                     peer_id,
                     connection_id,
+                    endpoint,
                     ..
                 } = connection_established;
+                if let ConnectedPoint::Listener { send_back_addr, .. } = endpoint {
+                    self.connection_tracker
+                        .inbound_connection_established(send_back_addr);
+                }
                 self.pending_events.push_back(ToSwarm::GenerateEvent(
                     PeerReportEvent::PeerConnected { peer_id },
                 ));
@@ -145,8 +220,13 @@ impl NetworkBehaviour for Behaviour {
                 let ConnectionClosed {
                     remaining_established,
                     peer_id,
+                    endpoint,
                     ..
                 } = connection_closed;
+                if let ConnectedPoint::Listener { send_back_addr, .. } = endpoint {
+                    self.connection_tracker
+                        .inbound_connection_closed(send_back_addr);
+                }
 
                 if remaining_established == 0 {
                     // this was the last connection to a given Peer
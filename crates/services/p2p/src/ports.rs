@@ -1,14 +1,27 @@
+use crate::storage::{
+    KnownPeer,
+    PeerBan,
+};
 use fuel_core_services::stream::BoxStream;
 use fuel_core_storage::Result as StorageResult;
 use fuel_core_types::{
     blockchain::{
         consensus::Genesis,
+        SealedBlock,
         SealedBlockHeader,
     },
+    fuel_tx::{
+        Transaction,
+        TxId,
+    },
     fuel_types::BlockHeight,
     services::p2p::Transactions,
 };
-use std::ops::Range;
+use libp2p::PeerId;
+use std::{
+    collections::HashMap,
+    ops::Range,
+};
 
 pub trait P2pDb: Send + Sync {
     fn get_sealed_headers(
@@ -21,6 +34,11 @@ pub trait P2pDb: Send + Sync {
         block_height_range: Range<u32>,
     ) -> StorageResult<Option<Vec<Transactions>>>;
 
+    fn get_sealed_blocks(
+        &self,
+        block_height_range: Range<u32>,
+    ) -> StorageResult<Option<Vec<SealedBlock>>>;
+
     fn get_genesis(&self) -> StorageResult<Genesis>;
 }
 
@@ -28,3 +46,29 @@ pub trait BlockHeightImporter: Send + Sync {
     /// Creates a stream of next block heights
     fn next_block_height(&self) -> BoxStream<BlockHeight>;
 }
+
+pub trait TxPool: Send + Sync + 'static {
+    /// Returns ids of at most `max_txs` transactions from the `TxPool`.
+    fn get_tx_ids(&self, max_txs: usize) -> Vec<TxId>;
+
+    /// Returns transactions with the given ids, `None` for the unknown ones.
+    fn get_full_txs(&self, tx_ids: Vec<TxId>) -> Vec<Option<Transaction>>;
+}
+
+/// The reputation of peers that is kept between restarts of the node.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct PeersReputation {
+    pub banned_peers: HashMap<PeerId, PeerBan>,
+    pub known_peers: HashMap<PeerId, KnownPeer>,
+}
+
+pub trait PeersReputationDb: Clone + Send + Sync + 'static {
+    /// Returns the reputation of peers stored before the restart.
+    fn get_peers_reputation(&self) -> StorageResult<PeersReputation>;
+
+    /// Replaces the stored reputation of peers with the `reputation`.
+    fn store_peers_reputation(
+        &mut self,
+        reputation: &PeersReputation,
+    ) -> StorageResult<()>;
+}
@@ -1,5 +1,12 @@
 use fuel_core_types::{
-    blockchain::SealedBlockHeader,
+    blockchain::{
+        SealedBlock,
+        SealedBlockHeader,
+    },
+    fuel_tx::{
+        Transaction,
+        TxId,
+    },
     services::p2p::Transactions,
 };
 use libp2p::{
@@ -14,22 +21,106 @@ use std::ops::Range;
 use thiserror::Error;
 use tokio::sync::oneshot;
 
-pub(crate) const REQUEST_RESPONSE_PROTOCOL_ID: &str = "/fuel/req_res/0.0.1";
+pub(crate) const V1_REQUEST_RESPONSE_PROTOCOL_ID: &str = "/fuel/req_res/0.0.1";
+pub(crate) const V2_REQUEST_RESPONSE_PROTOCOL_ID: &str = "/fuel/req_res/0.0.2";
+
+/// Max number of transaction ids returned for the `TxPoolAllTransactionsIds` request
+pub const MAX_TXPOOL_TRANSACTIONS_IDS_PER_REQUEST: usize = 10_000;
+/// Max number of transactions requested by the `TxPoolFullTransactions` request
+pub const MAX_TXPOOL_FULL_TRANSACTIONS_PER_REQUEST: usize = 1_000;
+
+/// Versions of the request/response protocol.
+/// Variants are ordered from the oldest to the latest version.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum RequestResponseProtocol {
+    /// Supports `SealedHeaders` and `Transactions` requests.
+    V1,
+    /// Adds `SealedBlocks` and `TxPool*` requests.
+    V2,
+}
+
+impl RequestResponseProtocol {
+    /// All supported versions, starting from the preferred one.
+    pub const ALL: [Self; 2] = [Self::V2, Self::V1];
+
+    pub fn from_protocol_id(id: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|protocol| protocol.as_ref() == id)
+    }
+}
+
+impl AsRef<str> for RequestResponseProtocol {
+    fn as_ref(&self) -> &str {
+        match self {
+            RequestResponseProtocol::V1 => V1_REQUEST_RESPONSE_PROTOCOL_ID,
+            RequestResponseProtocol::V2 => V2_REQUEST_RESPONSE_PROTOCOL_ID,
+        }
+    }
+}
 
 /// Max Size in Bytes of the Request Message
 #[cfg(test)]
 pub(crate) const MAX_REQUEST_SIZE: usize = core::mem::size_of::<RequestMessage>();
 
+// New variants should be added at the end to keep the encoding
+// of the previous versions of the protocol unchanged.
 #[derive(Serialize, Deserialize, Eq, PartialEq, Debug, Clone)]
 pub enum RequestMessage {
     SealedHeaders(Range<u32>),
     Transactions(Range<u32>),
+    SealedBlocks(Range<u32>),
+    TxPoolAllTransactionsIds,
+    TxPoolFullTransactions(Vec<TxId>),
+}
+
+impl RequestMessage {
+    /// The oldest version of the protocol that supports the request.
+    pub fn min_protocol(&self) -> RequestResponseProtocol {
+        match self {
+            RequestMessage::SealedHeaders(_) | RequestMessage::Transactions(_) => {
+                RequestResponseProtocol::V1
+            }
+            RequestMessage::SealedBlocks(_)
+            | RequestMessage::TxPoolAllTransactionsIds
+            | RequestMessage::TxPoolFullTransactions(_) => RequestResponseProtocol::V2,
+        }
+    }
+
+    /// The number of items expected in the response, if it is known.
+    pub fn expected_items(&self) -> Option<usize> {
+        match self {
+            RequestMessage::SealedHeaders(range)
+            | RequestMessage::Transactions(range)
+            | RequestMessage::SealedBlocks(range) => Some(range.len()),
+            RequestMessage::TxPoolFullTransactions(tx_ids) => Some(tx_ids.len()),
+            RequestMessage::TxPoolAllTransactionsIds => None,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum ResponseMessage {
     SealedHeaders(Option<Vec<SealedBlockHeader>>),
     Transactions(Option<Vec<Transactions>>),
+    SealedBlocks(Option<Vec<SealedBlock>>),
+    TxPoolAllTransactionsIds(Option<Vec<TxId>>),
+    TxPoolFullTransactions(Option<Vec<Option<Transaction>>>),
+}
+
+impl ResponseMessage {
+    /// The number of items in the response. The missing data is zero items.
+    pub fn items(&self) -> usize {
+        match self {
+            ResponseMessage::SealedHeaders(v) => v.as_ref().map_or(0, Vec::len),
+            ResponseMessage::Transactions(v) => v.as_ref().map_or(0, Vec::len),
+            ResponseMessage::SealedBlocks(v) => v.as_ref().map_or(0, Vec::len),
+            ResponseMessage::TxPoolAllTransactionsIds(v) => {
+                v.as_ref().map_or(0, Vec::len)
+            }
+            ResponseMessage::TxPoolFullTransactions(v) => v.as_ref().map_or(0, Vec::len),
+        }
+    }
 }
 
 pub type OnResponse<T> = oneshot::Sender<(PeerId, Result<T, ResponseError>)>;
@@ -38,12 +129,77 @@ pub type OnResponse<T> = oneshot::Sender<(PeerId, Result<T, ResponseError>)>;
 pub enum ResponseSender {
     SealedHeaders(OnResponse<Option<Vec<SealedBlockHeader>>>),
     Transactions(OnResponse<Option<Vec<Transactions>>>),
+    SealedBlocks(OnResponse<Option<Vec<SealedBlock>>>),
+    TxPoolAllTransactionsIds(OnResponse<Option<Vec<TxId>>>),
+    TxPoolFullTransactions(OnResponse<Option<Vec<Option<Transaction>>>>),
+}
+
+impl ResponseSender {
+    /// Sends the response to the requester if its type matches the request.
+    /// Returns `false` if the requester is gone.
+    pub(crate) fn send_response(self, peer: PeerId, response: ResponseMessage) -> bool {
+        fn send<T>(channel: OnResponse<T>, peer: PeerId, response: Option<T>) -> bool {
+            let result = response.ok_or_else(|| {
+                tracing::warn!("Invalid response type received from peer {:?}", peer);
+                ResponseError::TypeMismatch
+            });
+            channel.send((peer, result)).is_ok()
+        }
+
+        match (self, response) {
+            (ResponseSender::SealedHeaders(c), ResponseMessage::SealedHeaders(v)) => {
+                send(c, peer, Some(v))
+            }
+            (ResponseSender::Transactions(c), ResponseMessage::Transactions(v)) => {
+                send(c, peer, Some(v))
+            }
+            (ResponseSender::SealedBlocks(c), ResponseMessage::SealedBlocks(v)) => {
+                send(c, peer, Some(v))
+            }
+            (
+                ResponseSender::TxPoolAllTransactionsIds(c),
+                ResponseMessage::TxPoolAllTransactionsIds(v),
+            ) => send(c, peer, Some(v)),
+            (
+                ResponseSender::TxPoolFullTransactions(c),
+                ResponseMessage::TxPoolFullTransactions(v),
+            ) => send(c, peer, Some(v)),
+            (ResponseSender::SealedHeaders(c), _) => send(c, peer, None),
+            (ResponseSender::Transactions(c), _) => send(c, peer, None),
+            (ResponseSender::SealedBlocks(c), _) => send(c, peer, None),
+            (ResponseSender::TxPoolAllTransactionsIds(c), _) => send(c, peer, None),
+            (ResponseSender::TxPoolFullTransactions(c), _) => send(c, peer, None),
+        }
+    }
+
+    /// Notifies the requester about the failed request.
+    pub(crate) fn send_error(self, peer: PeerId, error: ResponseError) {
+        match self {
+            ResponseSender::SealedHeaders(c) => {
+                let _ = c.send((peer, Err(error)));
+            }
+            ResponseSender::Transactions(c) => {
+                let _ = c.send((peer, Err(error)));
+            }
+            ResponseSender::SealedBlocks(c) => {
+                let _ = c.send((peer, Err(error)));
+            }
+            ResponseSender::TxPoolAllTransactionsIds(c) => {
+                let _ = c.send((peer, Err(error)));
+            }
+            ResponseSender::TxPoolFullTransactions(c) => {
+                let _ = c.send((peer, Err(error)));
+            }
+        }
+    }
 }
 
 #[derive(Debug, Error)]
 pub enum RequestError {
     #[error("Not currently connected to any peers")]
     NoPeersConnected,
+    #[error("No connected peers support the request")]
+    NoPeersSupportingRequest,
 }
 
 #[derive(Debug, Error)]
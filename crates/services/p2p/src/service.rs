@@ -8,27 +8,34 @@ use crate::{
         GossipsubBroadcastRequest,
         GossipsubMessage,
     },
-    heavy_task_processor::HeavyTaskProcessor,
     p2p_service::{
         FuelP2PEvent,
         FuelP2PService,
+        PeerManagementRequest,
     },
     peer_manager::PeerInfo,
     ports::{
         BlockHeightImporter,
         P2pDb,
+        PeersReputation,
+        PeersReputationDb,
+        TxPool,
     },
     request_response::messages::{
         OnResponse,
         RequestMessage,
+        RequestResponseProtocol,
         ResponseMessage,
         ResponseSender,
+        MAX_TXPOOL_FULL_TRANSACTIONS_PER_REQUEST,
+        MAX_TXPOOL_TRANSACTIONS_IDS_PER_REQUEST,
     },
 };
 use anyhow::anyhow;
 use fuel_core_metrics::p2p_metrics::set_blocks_requested;
 use fuel_core_services::{
     stream::BoxStream,
+    HeavyTaskProcessor,
     RunnableService,
     RunnableTask,
     ServiceRunner,
@@ -37,9 +44,14 @@ use fuel_core_services::{
 };
 use fuel_core_storage::transactional::AtomicView;
 use fuel_core_types::{
-    blockchain::SealedBlockHeader,
+    blockchain::{
+        consensus::bft::BftMessage,
+        SealedBlock,
+        SealedBlockHeader,
+    },
     fuel_tx::{
         Transaction,
+        TxId,
         UniqueIdentifier,
     },
     fuel_types::{
@@ -51,7 +63,9 @@ use fuel_core_types::{
             AppScore,
             PeerReport,
         },
+        BlockGossipData,
         BlockHeightHeartbeatData,
+        ConsensusGossipData,
         GossipData,
         GossipsubMessageAcceptance,
         GossipsubMessageInfo,
@@ -67,9 +81,11 @@ use futures::{
 use libp2p::{
     gossipsub::MessageAcceptance,
     request_response::InboundRequestId,
+    Multiaddr,
     PeerId,
 };
 use std::{
+    collections::HashSet,
     fmt::Debug,
     ops::Range,
     sync::Arc,
@@ -80,6 +96,7 @@ use tokio::{
         mpsc,
         oneshot,
     },
+    task::JoinHandle,
     time::{
         Duration,
         Instant,
@@ -87,17 +104,25 @@ use tokio::{
 };
 use tracing::warn;
 
-pub type Service<V> = ServiceRunner<UninitializedTask<V, SharedState>>;
+pub type Service<V, T, D> = ServiceRunner<UninitializedTask<V, SharedState, T, D>>;
 
-enum TaskRequest {
+pub enum TaskRequest {
     // Broadcast requests to p2p network
     BroadcastTransaction(Arc<Transaction>),
+    BroadcastBlock(Arc<SealedBlock>),
+    BroadcastConsensusMessage(Arc<BftMessage>),
     // Request to get information about all connected peers
     GetAllPeerInfo {
         channel: oneshot::Sender<Vec<(PeerId, PeerInfo)>>,
     },
+    // Request from the operator to act on peers
+    ManagePeers {
+        request: PeerManagementRequest,
+        channel: oneshot::Sender<anyhow::Result<()>>,
+    },
     GetSealedHeaders {
         block_height_range: Range<u32>,
+        excluded_peers: HashSet<PeerId>,
         channel: OnResponse<Option<Vec<SealedBlockHeader>>>,
     },
     GetTransactions {
@@ -105,6 +130,20 @@ enum TaskRequest {
         from_peer: PeerId,
         channel: OnResponse<Option<Vec<Transactions>>>,
     },
+    GetSealedBlocks {
+        block_height_range: Range<u32>,
+        excluded_peers: HashSet<PeerId>,
+        channel: OnResponse<Option<Vec<SealedBlock>>>,
+    },
+    TxPoolGetAllTxIds {
+        from_peer: PeerId,
+        channel: OnResponse<Option<Vec<TxId>>>,
+    },
+    TxPoolGetFullTransactions {
+        tx_ids: Vec<TxId>,
+        from_peer: PeerId,
+        channel: OnResponse<Option<Vec<Option<Transaction>>>>,
+    },
     // Responds back to the p2p network
     RespondWithGossipsubMessageReport((GossipsubMessageInfo, GossipsubMessageAcceptance)),
     RespondWithPeerReport {
@@ -120,6 +159,10 @@ enum TaskRequest {
         response: Option<Vec<SealedBlockHeader>>,
         request_id: InboundRequestId,
     },
+    DatabaseSealedBlocksLookUp {
+        response: Option<Vec<SealedBlock>>,
+        request_id: InboundRequestId,
+    },
 }
 
 impl Debug for TaskRequest {
@@ -128,12 +171,27 @@ impl Debug for TaskRequest {
             TaskRequest::BroadcastTransaction(_) => {
                 write!(f, "TaskRequest::BroadcastTransaction")
             }
+            TaskRequest::BroadcastBlock(_) => {
+                write!(f, "TaskRequest::BroadcastBlock")
+            }
+            TaskRequest::BroadcastConsensusMessage(_) => {
+                write!(f, "TaskRequest::BroadcastConsensusMessage")
+            }
             TaskRequest::GetSealedHeaders { .. } => {
                 write!(f, "TaskRequest::GetSealedHeaders")
             }
             TaskRequest::GetTransactions { .. } => {
                 write!(f, "TaskRequest::GetTransactions")
             }
+            TaskRequest::GetSealedBlocks { .. } => {
+                write!(f, "TaskRequest::GetSealedBlocks")
+            }
+            TaskRequest::TxPoolGetAllTxIds { .. } => {
+                write!(f, "TaskRequest::TxPoolGetAllTxIds")
+            }
+            TaskRequest::TxPoolGetFullTransactions { .. } => {
+                write!(f, "TaskRequest::TxPoolGetFullTransactions")
+            }
             TaskRequest::RespondWithGossipsubMessageReport(_) => {
                 write!(f, "TaskRequest::RespondWithGossipsubMessageReport")
             }
@@ -143,12 +201,18 @@ impl Debug for TaskRequest {
             TaskRequest::GetAllPeerInfo { .. } => {
                 write!(f, "TaskRequest::GetPeerInfo")
             }
+            TaskRequest::ManagePeers { .. } => {
+                write!(f, "TaskRequest::ManagePeers")
+            }
             TaskRequest::DatabaseTransactionsLookUp { .. } => {
                 write!(f, "TaskRequest::DatabaseTransactionsLookUp")
             }
             TaskRequest::DatabaseHeaderLookUp { .. } => {
                 write!(f, "TaskRequest::DatabaseHeaderLookUp")
             }
+            TaskRequest::DatabaseSealedBlocksLookUp { .. } => {
+                write!(f, "TaskRequest::DatabaseSealedBlocksLookUp")
+            }
         }
     }
 }
@@ -161,7 +225,12 @@ pub enum HeartBeatPeerReportReason {
 
 pub trait TaskP2PService: Send {
     fn get_all_peer_info(&self) -> Vec<(&PeerId, &PeerInfo)>;
-    fn get_peer_id_with_height(&self, height: &BlockHeight) -> Option<PeerId>;
+    fn get_peer_id_with_height(
+        &self,
+        height: &BlockHeight,
+        protocol: RequestResponseProtocol,
+        excluded_peers: &HashSet<PeerId>,
+    ) -> Option<PeerId>;
 
     fn next_event(&mut self) -> BoxFuture<'_, Option<FuelP2PEvent>>;
 
@@ -198,9 +267,13 @@ pub trait TaskP2PService: Send {
 
     fn update_block_height(&mut self, height: BlockHeight) -> anyhow::Result<()>;
 
+    fn manage_peers(&mut self, request: PeerManagementRequest) -> anyhow::Result<()>;
+
     fn update_metrics<T>(&self, update_fn: T)
     where
         T: FnOnce();
+
+    fn peers_reputation(&self) -> PeersReputation;
 }
 
 impl TaskP2PService for FuelP2PService {
@@ -215,8 +288,14 @@ impl TaskP2PService for FuelP2PService {
         self.peer_manager().get_all_peers().collect()
     }
 
-    fn get_peer_id_with_height(&self, height: &BlockHeight) -> Option<PeerId> {
-        self.peer_manager().get_peer_id_with_height(height)
+    fn get_peer_id_with_height(
+        &self,
+        height: &BlockHeight,
+        protocol: RequestResponseProtocol,
+        excluded_peers: &HashSet<PeerId>,
+    ) -> Option<PeerId> {
+        self.peer_manager()
+            .get_peer_id_with_height(height, protocol, excluded_peers)
     }
 
     fn next_event(&mut self) -> BoxFuture<'_, Option<FuelP2PEvent>> {
@@ -273,6 +352,14 @@ impl TaskP2PService for FuelP2PService {
         self.update_block_height(height);
         Ok(())
     }
+
+    fn manage_peers(&mut self, request: PeerManagementRequest) -> anyhow::Result<()> {
+        self.manage_peers(request)
+    }
+
+    fn peers_reputation(&self) -> PeersReputation {
+        self.peer_manager().peers_reputation()
+    }
 }
 
 pub trait Broadcast: Send {
@@ -289,6 +376,12 @@ pub trait Broadcast: Send {
     ) -> anyhow::Result<()>;
 
     fn tx_broadcast(&self, transaction: TransactionGossipData) -> anyhow::Result<()>;
+
+    fn block_broadcast(&self, block: BlockGossipData) -> anyhow::Result<()>;
+
+    fn consensus_broadcast(&self, message: ConsensusGossipData) -> anyhow::Result<()>;
+
+    fn new_peer_broadcast(&self, peer_id: FuelPeerId) -> anyhow::Result<()>;
 }
 
 impl Broadcast for SharedState {
@@ -313,26 +406,45 @@ impl Broadcast for SharedState {
         self.tx_broadcast.send(transaction)?;
         Ok(())
     }
+
+    fn block_broadcast(&self, block: BlockGossipData) -> anyhow::Result<()> {
+        self.block_broadcast.send(block)?;
+        Ok(())
+    }
+
+    fn consensus_broadcast(&self, message: ConsensusGossipData) -> anyhow::Result<()> {
+        self.consensus_broadcast.send(message)?;
+        Ok(())
+    }
+
+    fn new_peer_broadcast(&self, peer_id: FuelPeerId) -> anyhow::Result<()> {
+        self.new_peers_broadcast.send(peer_id)?;
+        Ok(())
+    }
 }
 
 /// Uninitialized task for the p2p that can be upgraded later into [`Task`].
-pub struct UninitializedTask<V, B> {
+pub struct UninitializedTask<V, B, T, D> {
     chain_id: ChainId,
     view_provider: V,
+    reputation_db: D,
     next_block_height: BoxStream<BlockHeight>,
     /// Receive internal Task Requests
     request_receiver: mpsc::Receiver<TaskRequest>,
     broadcast: B,
+    tx_pool: T,
     config: Config<NotInitialized>,
 }
 
 /// Orchestrates various p2p-related events between the inner `P2pService`
 /// and the top level `NetworkService`.
-pub struct Task<P, V, B> {
+pub struct Task<P, V, B, T, D> {
     chain_id: ChainId,
     response_timeout: Duration,
     p2p_service: P,
     view_provider: V,
+    reputation_db: D,
+    tx_pool: T,
     next_block_height: BoxStream<BlockHeight>,
     /// Receive internal Task Requests
     request_receiver: mpsc::Receiver<TaskRequest>,
@@ -346,51 +458,50 @@ pub struct Task<P, V, B> {
     heartbeat_max_time_since_last: Duration,
     next_check_time: Instant,
     heartbeat_peer_reputation_config: HeartbeatPeerReputationConfig,
+    /// The reputation of peers passed to the last store.
+    stored_reputation: PeersReputation,
+    /// The store of the reputation of peers in progress.
+    reputation_store: Option<JoinHandle<()>>,
+    next_reputation_store_time: Instant,
 }
 
+/// The minimal time between stores of the reputation of peers to the database.
+const REPUTATION_STORE_INTERVAL: Duration = Duration::from_secs(60);
+
 #[derive(Default, Clone)]
 pub struct HeartbeatPeerReputationConfig {
     old_heartbeat_penalty: AppScore,
     low_heartbeat_frequency_penalty: AppScore,
 }
 
-impl<V> UninitializedTask<V, SharedState> {
+impl<V, T, D> UninitializedTask<V, SharedState, T, D> {
+    #[allow(clippy::too_many_arguments)]
     pub fn new<B: BlockHeightImporter>(
         chain_id: ChainId,
         config: Config<NotInitialized>,
+        shared_state: SharedState,
+        request_receiver: mpsc::Receiver<TaskRequest>,
         view_provider: V,
         block_importer: B,
+        tx_pool: T,
+        reputation_db: D,
     ) -> Self {
-        let (request_sender, request_receiver) = mpsc::channel(1024 * 10);
-        let (tx_broadcast, _) = broadcast::channel(1024 * 10);
-        let (block_height_broadcast, _) = broadcast::channel(1024 * 10);
-
-        let (reserved_peers_broadcast, _) = broadcast::channel::<usize>(
-            config
-                .reserved_nodes
-                .len()
-                .saturating_mul(2)
-                .saturating_add(1),
-        );
         let next_block_height = block_importer.next_block_height();
 
         Self {
             chain_id,
             view_provider,
+            reputation_db,
             next_block_height,
             request_receiver,
-            broadcast: SharedState {
-                request_sender,
-                tx_broadcast,
-                reserved_peers_broadcast,
-                block_height_broadcast,
-            },
+            broadcast: shared_state,
+            tx_pool,
             config,
         }
     }
 }
 
-impl<P: TaskP2PService, V, B: Broadcast> Task<P, V, B> {
+impl<P: TaskP2PService, V, B: Broadcast, T, D> Task<P, V, B, T, D> {
     fn peer_heartbeat_reputation_checks(&self) -> anyhow::Result<()> {
         for (peer_id, peer_info) in self.p2p_service.get_all_peer_info() {
             if peer_info.heartbeat_data.duration_since_last_heartbeat()
@@ -433,15 +544,58 @@ impl<P: TaskP2PService, V, B: Broadcast> Task<P, V, B> {
     }
 }
 
-impl<P, V, B> Task<P, V, B>
+impl<P: TaskP2PService, V, B, T, D: PeersReputationDb> Task<P, V, B, T, D> {
+    /// Stores the reputation of peers in the background if it changed
+    /// since the last store, at most once per `REPUTATION_STORE_INTERVAL`.
+    fn store_changed_peers_reputation(&mut self) {
+        let now = Instant::now();
+        let in_progress = self
+            .reputation_store
+            .as_ref()
+            .is_some_and(|store| !store.is_finished());
+        if now < self.next_reputation_store_time || in_progress {
+            return
+        }
+        let reputation = self.p2p_service.peers_reputation();
+        if reputation == self.stored_reputation {
+            return
+        }
+        self.next_reputation_store_time = now
+            .checked_add(REPUTATION_STORE_INTERVAL)
+            .expect("The reputation store interval should be small enough");
+        self.stored_reputation = reputation.clone();
+        self.reputation_store = Some(self.spawn_store(reputation));
+    }
+
+    /// Stores the current reputation of peers after the store in progress.
+    async fn store_peers_reputation(&mut self) {
+        if let Some(store) = self.reputation_store.take() {
+            let _ = store.await;
+        }
+        let reputation = self.p2p_service.peers_reputation();
+        let _ = self.spawn_store(reputation).await;
+    }
+
+    fn spawn_store(&self, reputation: PeersReputation) -> JoinHandle<()> {
+        let mut reputation_db = self.reputation_db.clone();
+        tokio::task::spawn_blocking(move || {
+            if let Err(e) = reputation_db.store_peers_reputation(&reputation) {
+                tracing::error!("Failed to store the reputation of peers: {:?}", e);
+            }
+        })
+    }
+}
+
+impl<P, V, B, T, D> Task<P, V, B, T, D>
 where
     P: TaskP2PService + 'static,
     V: AtomicView + 'static,
     V::LatestView: P2pDb,
+    T: TxPool,
 {
-    fn update_metrics<T>(&self, update_fn: T)
+    fn update_metrics<F>(&self, update_fn: F)
     where
-        T: FnOnce(),
+        F: FnOnce(),
     {
         self.p2p_service.update_metrics(update_fn)
     }
@@ -458,6 +612,15 @@ where
             RequestMessage::SealedHeaders(range) => {
                 self.handle_sealed_headers_request(range, request_id)
             }
+            RequestMessage::SealedBlocks(range) => {
+                self.handle_sealed_blocks_request(range, request_id)
+            }
+            RequestMessage::TxPoolAllTransactionsIds => {
+                self.handle_all_transactions_ids_request(request_id)
+            }
+            RequestMessage::TxPoolFullTransactions(tx_ids) => {
+                self.handle_full_transactions_request(tx_ids, request_id)
+            }
         }
     }
 
@@ -557,6 +720,59 @@ where
             },
         )
     }
+
+    fn handle_sealed_blocks_request(
+        &mut self,
+        range: Range<u32>,
+        request_id: InboundRequestId,
+    ) -> anyhow::Result<()> {
+        self.handle_request(
+            range,
+            request_id,
+            ResponseMessage::SealedBlocks,
+            |view, range| view.get_sealed_blocks(range).map_err(anyhow::Error::from),
+            |response, request_id| TaskRequest::DatabaseSealedBlocksLookUp {
+                response,
+                request_id,
+            },
+        )
+    }
+
+    fn handle_all_transactions_ids_request(
+        &mut self,
+        request_id: InboundRequestId,
+    ) -> anyhow::Result<()> {
+        let tx_ids = self
+            .tx_pool
+            .get_tx_ids(MAX_TXPOOL_TRANSACTIONS_IDS_PER_REQUEST);
+        let _ = self.p2p_service.send_response_msg(
+            request_id,
+            ResponseMessage::TxPoolAllTransactionsIds(Some(tx_ids)),
+        );
+        Ok(())
+    }
+
+    fn handle_full_transactions_request(
+        &mut self,
+        tx_ids: Vec<TxId>,
+        request_id: InboundRequestId,
+    ) -> anyhow::Result<()> {
+        let response = if tx_ids.len() > MAX_TXPOOL_FULL_TRANSACTIONS_PER_REQUEST {
+            tracing::error!(
+                requested_length = tx_ids.len(),
+                max_len = MAX_TXPOOL_FULL_TRANSACTIONS_PER_REQUEST,
+                "Requested too many transactions"
+            );
+            None
+        } else {
+            Some(self.tx_pool.get_full_txs(tx_ids))
+        };
+        let _ = self.p2p_service.send_response_msg(
+            request_id,
+            ResponseMessage::TxPoolFullTransactions(response),
+        );
+        Ok(())
+    }
 }
 
 fn convert_peer_id(peer_id: &PeerId) -> anyhow::Result<FuelPeerId> {
@@ -565,15 +781,17 @@ fn convert_peer_id(peer_id: &PeerId) -> anyhow::Result<FuelPeerId> {
 }
 
 #[async_trait::async_trait]
-impl<V> RunnableService for UninitializedTask<V, SharedState>
+impl<V, T, D> RunnableService for UninitializedTask<V, SharedState, T, D>
 where
     V: AtomicView + 'static,
     V::LatestView: P2pDb,
+    T: TxPool,
+    D: PeersReputationDb,
 {
     const NAME: &'static str = "P2P";
 
     type SharedData = SharedState;
-    type Task = Task<FuelP2PService, V, SharedState>;
+    type Task = Task<FuelP2PService, V, SharedState, T, D>;
     type TaskParams = ();
 
     fn shared_data(&self) -> Self::SharedData {
@@ -588,9 +806,11 @@ where
         let Self {
             chain_id,
             view_provider,
+            reputation_db,
             next_block_height,
             request_receiver,
             broadcast,
+            tx_pool,
             config,
         } = self;
 
@@ -619,6 +839,8 @@ where
             config,
             PostcardCodec::new(max_block_size),
         );
+        let stored_reputation = reputation_db.get_peers_reputation()?;
+        p2p_service.restore_peers_reputation(stored_reputation.clone());
         p2p_service.start().await?;
 
         let next_check_time =
@@ -634,6 +856,8 @@ where
             response_timeout,
             p2p_service,
             view_provider,
+            reputation_db,
+            tx_pool,
             request_receiver,
             request_sender,
             next_block_height,
@@ -645,6 +869,9 @@ where
             heartbeat_max_time_since_last,
             next_check_time,
             heartbeat_peer_reputation_config,
+            stored_reputation,
+            reputation_store: None,
+            next_reputation_store_time: next_check_time,
         };
         Ok(task)
     }
@@ -652,12 +879,14 @@ where
 
 // TODO: Add tests https://github.com/FuelLabs/fuel-core/issues/1275
 #[async_trait::async_trait]
-impl<P, V, B> RunnableTask for Task<P, V, B>
+impl<P, V, B, T, D> RunnableTask for Task<P, V, B, T, D>
 where
     P: TaskP2PService + 'static,
     V: AtomicView + 'static,
     V::LatestView: P2pDb,
     B: Broadcast + 'static,
+    T: TxPool,
+    D: PeersReputationDb,
 {
     async fn run(&mut self, watcher: &mut StateWatcher) -> anyhow::Result<bool> {
         tracing::debug!("P2P task is running");
@@ -688,18 +917,66 @@ where
                             tracing::error!("Got an error during transaction {} broadcasting {}", tx_id, e);
                         }
                     }
-                    Some(TaskRequest::GetSealedHeaders { block_height_range, channel}) => {
+                    Some(TaskRequest::BroadcastBlock(block)) => {
+                        let height = *block.entity.header().height();
+                        let broadcast = GossipsubBroadcastRequest::NewBlock(block);
+                        let result = self.p2p_service.publish_message(broadcast);
+                        if let Err(e) = result {
+                            tracing::error!("Got an error during block {} broadcasting {}", height, e);
+                        }
+                    }
+                    Some(TaskRequest::BroadcastConsensusMessage(message)) => {
+                        let height = message.height();
+                        let broadcast = GossipsubBroadcastRequest::Consensus(message);
+                        let result = self.p2p_service.publish_message(broadcast);
+                        if let Err(e) = result {
+                            tracing::debug!("Got an error during consensus message at {} broadcasting {}", height, e);
+                        }
+                    }
+                    Some(TaskRequest::GetSealedHeaders { block_height_range, excluded_peers, channel}) => {
                         let channel = ResponseSender::SealedHeaders(channel);
                         let request_msg = RequestMessage::SealedHeaders(block_height_range.clone());
 
                         // Note: this range has already been checked for
                         // validity in `SharedState::get_sealed_block_headers`.
                         let height = BlockHeight::from(block_height_range.end.saturating_sub(1));
-                        let peer = self.p2p_service.get_peer_id_with_height(&height);
-                        if self.p2p_service.send_request_msg(peer, request_msg, channel).is_err() {
+                        let peer = self.p2p_service.get_peer_id_with_height(&height, RequestResponseProtocol::V1, &excluded_peers);
+                        // A random peer could be one of the excluded ones,
+                        // so we only fall back to it on the first attempt.
+                        if peer.is_none() && !excluded_peers.is_empty() {
+                            tracing::debug!("No other peers found for block at height {:?}", height);
+                        } else if self.p2p_service.send_request_msg(peer, request_msg, channel).is_err() {
                             tracing::warn!("No peers found for block at height {:?}", height);
                         }
                     }
+                    Some(TaskRequest::GetSealedBlocks { block_height_range, excluded_peers, channel}) => {
+                        let request_msg = RequestMessage::SealedBlocks(block_height_range.clone());
+
+                        // Note: this range has already been checked for
+                        // validity in `SharedState::get_sealed_blocks`.
+                        let height = BlockHeight::from(block_height_range.end.saturating_sub(1));
+                        // Only peers with the latest protocol can serve blocks,
+                        // so we don't fall back to a random peer.
+                        let peer = self.p2p_service.get_peer_id_with_height(&height, request_msg.min_protocol(), &excluded_peers);
+                        if peer.is_some() {
+                            let channel = ResponseSender::SealedBlocks(channel);
+                            if self.p2p_service.send_request_msg(peer, request_msg, channel).is_err() {
+                                tracing::warn!("Failed to request blocks at height {:?}", height);
+                            }
+                        } else {
+                            tracing::debug!("No peers supporting blocks requests found for height {:?}", height);
+                        }
+                    }
+                    Some(TaskRequest::TxPoolGetAllTxIds { from_peer, channel }) => {
+                        let channel = ResponseSender::TxPoolAllTransactionsIds(channel);
+                        let request_msg = RequestMessage::TxPoolAllTransactionsIds;
+                        self.p2p_service.send_request_msg(Some(from_peer), request_msg, channel).expect("We always have a peer here, so send has a target");
+                    }
+                    Some(TaskRequest::TxPoolGetFullTransactions { tx_ids, from_peer, channel }) => {
+                        let channel = ResponseSender::TxPoolFullTransactions(channel);
+                        let request_msg = RequestMessage::TxPoolFullTransactions(tx_ids);
+                        self.p2p_service.send_request_msg(Some(from_peer), request_msg, channel).expect("We always have a peer here, so send has a target");
+                    }
                     Some(TaskRequest::GetTransactions { block_height_range, from_peer, channel }) => {
                         let channel = ResponseSender::Transactions(channel);
                         let request_msg = RequestMessage::Transactions(block_height_range);
@@ -719,12 +996,19 @@ where
                             .collect::<Vec<_>>();
                         let _ = channel.send(peers);
                     }
+                    Some(TaskRequest::ManagePeers { request, channel }) => {
+                        let result = self.p2p_service.manage_peers(request);
+                        let _ = channel.send(result);
+                    }
                     Some(TaskRequest::DatabaseTransactionsLookUp { response, request_id }) => {
                         let _ = self.p2p_service.send_response_msg(request_id, ResponseMessage::Transactions(response));
                     }
                     Some(TaskRequest::DatabaseHeaderLookUp { response, request_id }) => {
                         let _ = self.p2p_service.send_response_msg(request_id, ResponseMessage::SealedHeaders(response));
                     }
+                    Some(TaskRequest::DatabaseSealedBlocksLookUp { response, request_id }) => {
+                        let _ = self.p2p_service.send_response_msg(request_id, ResponseMessage::SealedBlocks(response));
+                    }
                     None => {
                         tracing::error!("The P2P `Task` should be holder of the `Sender`");
                         should_continue = false;
@@ -743,6 +1027,13 @@ where
 
                         let _ = self.broadcast.block_height_broadcast(block_height_data);
                     }
+                    Some(FuelP2PEvent::PeerIdentified { peer_id, req_res_protocol }) => {
+                        let min_protocol = RequestMessage::TxPoolAllTransactionsIds.min_protocol();
+                        if req_res_protocol >= Some(min_protocol) {
+                            let peer_id: Vec<u8> = peer_id.into();
+                            let _ = self.broadcast.new_peer_broadcast(peer_id.into());
+                        }
+                    }
                     Some(FuelP2PEvent::GossipsubMessage { message, message_id, peer_id,.. }) => {
                         let message_id = message_id.0;
 
@@ -751,6 +1042,14 @@ where
                                 let next_transaction = GossipData::new(transaction, peer_id, message_id);
                                 let _ = self.broadcast.tx_broadcast(next_transaction);
                             },
+                            GossipsubMessage::NewBlock(block) => {
+                                let next_block = GossipData::new(block, peer_id, message_id);
+                                let _ = self.broadcast.block_broadcast(next_block);
+                            },
+                            GossipsubMessage::Consensus(consensus_message) => {
+                                let next_message = GossipData::new(consensus_message, peer_id, message_id);
+                                let _ = self.broadcast.consensus_broadcast(next_message);
+                            },
                         }
                     },
                     Some(FuelP2PEvent::InboundRequestMessage { request_message, request_id }) => {
@@ -768,6 +1067,7 @@ where
                         tracing::error!("Failed to perform peer heartbeat reputation checks: {:?}", e);
                     }
                 }
+                self.store_changed_peers_reputation();
                 self.next_check_time += self.heartbeat_check_interval;
             }
         }
@@ -776,9 +1076,10 @@ where
         Ok(should_continue)
     }
 
-    async fn shutdown(self) -> anyhow::Result<()> {
-        // Nothing to shut down because we don't have any temporary state that should be dumped,
+    async fn shutdown(mut self) -> anyhow::Result<()> {
+        // The reputation of peers is the only state that should be dumped,
         // and we don't spawn any sub-tasks that we need to finish or await.
+        self.store_peers_reputation().await;
 
         // `FuelP2PService` doesn't support graceful shutdown(with informing of connected peers).
         // https://github.com/libp2p/specs/blob/master/ROADMAP.md#%EF%B8%8F-polite-peering
@@ -792,12 +1093,18 @@ where
 pub struct SharedState {
     /// Sender of p2p transaction used for subscribing.
     tx_broadcast: broadcast::Sender<TransactionGossipData>,
+    /// Sender of p2p blocks used for subscribing.
+    block_broadcast: broadcast::Sender<BlockGossipData>,
+    /// Sender of p2p consensus messages used for subscribing.
+    consensus_broadcast: broadcast::Sender<ConsensusGossipData>,
     /// Sender of reserved peers connection updates.
     reserved_peers_broadcast: broadcast::Sender<usize>,
     /// Used for communicating with the `Task`.
     request_sender: mpsc::Sender<TaskRequest>,
     /// Sender of p2p blopck height data
     block_height_broadcast: broadcast::Sender<BlockHeightHeartbeatData>,
+    /// Sender of newly identified peers able to share their transaction pool.
+    new_peers_broadcast: broadcast::Sender<FuelPeerId>,
 }
 
 impl SharedState {
@@ -805,6 +1112,30 @@ impl SharedState {
         &self,
         message_info: GossipsubMessageInfo,
         acceptance: GossipsubMessageAcceptance,
+    ) -> anyhow::Result<()> {
+        self.notify_gossip_message_validity(message_info, acceptance)
+    }
+
+    pub fn notify_gossip_block_validity(
+        &self,
+        message_info: GossipsubMessageInfo,
+        acceptance: GossipsubMessageAcceptance,
+    ) -> anyhow::Result<()> {
+        self.notify_gossip_message_validity(message_info, acceptance)
+    }
+
+    pub fn notify_gossip_consensus_validity(
+        &self,
+        message_info: GossipsubMessageInfo,
+        acceptance: GossipsubMessageAcceptance,
+    ) -> anyhow::Result<()> {
+        self.notify_gossip_message_validity(message_info, acceptance)
+    }
+
+    fn notify_gossip_message_validity(
+        &self,
+        message_info: GossipsubMessageInfo,
+        acceptance: GossipsubMessageAcceptance,
     ) -> anyhow::Result<()> {
         self.request_sender
             .try_send(TaskRequest::RespondWithGossipsubMessageReport((
@@ -817,6 +1148,7 @@ impl SharedState {
     pub async fn get_sealed_block_headers(
         &self,
         block_height_range: Range<u32>,
+        excluded_peers: Vec<Vec<u8>>,
     ) -> anyhow::Result<(Vec<u8>, Option<Vec<SealedBlockHeader>>)> {
         let (sender, receiver) = oneshot::channel();
 
@@ -829,6 +1161,10 @@ impl SharedState {
         self.request_sender
             .send(TaskRequest::GetSealedHeaders {
                 block_height_range,
+                excluded_peers: excluded_peers
+                    .iter()
+                    .filter_map(|peer_id| PeerId::from_bytes(peer_id).ok())
+                    .collect(),
                 channel: sender,
             })
             .await?;
@@ -839,6 +1175,90 @@ impl SharedState {
         Ok((peer_id.to_bytes(), data))
     }
 
+    pub async fn get_sealed_blocks(
+        &self,
+        block_height_range: Range<u32>,
+        excluded_peers: Vec<Vec<u8>>,
+    ) -> anyhow::Result<(Vec<u8>, Option<Vec<SealedBlock>>)> {
+        let (sender, receiver) = oneshot::channel();
+
+        if block_height_range.is_empty() {
+            return Err(anyhow!(
+                "Cannot retrieve blocks for an empty range of block heights"
+            ));
+        }
+
+        self.request_sender
+            .send(TaskRequest::GetSealedBlocks {
+                block_height_range,
+                excluded_peers: excluded_peers
+                    .iter()
+                    .filter_map(|peer_id| PeerId::from_bytes(peer_id).ok())
+                    .collect(),
+                channel: sender,
+            })
+            .await?;
+
+        let (peer_id, response) = receiver.await.map_err(|e| anyhow!("{e}"))?;
+
+        let data = response.map_err(|e| anyhow!("Invalid response from peer {e:?}"))?;
+        Ok((peer_id.to_bytes(), data))
+    }
+
+    pub async fn get_all_transactions_ids_from_peer(
+        &self,
+        peer_id: Vec<u8>,
+    ) -> anyhow::Result<Vec<TxId>> {
+        let (sender, receiver) = oneshot::channel();
+        let from_peer = PeerId::from_bytes(&peer_id).expect("Valid PeerId");
+
+        let request = TaskRequest::TxPoolGetAllTxIds {
+            from_peer,
+            channel: sender,
+        };
+        self.request_sender.send(request).await?;
+
+        let (response_from_peer, response) =
+            receiver.await.map_err(|e| anyhow!("{e}"))?;
+        assert_eq!(
+            peer_id,
+            response_from_peer.to_bytes(),
+            "Bug: response from non-requested peer"
+        );
+
+        response
+            .map_err(|e| anyhow!("Invalid response from peer {e:?}"))?
+            .ok_or(anyhow!("Peer didn't return transaction ids"))
+    }
+
+    pub async fn get_full_transactions_from_peer(
+        &self,
+        peer_id: Vec<u8>,
+        tx_ids: Vec<TxId>,
+    ) -> anyhow::Result<Vec<Option<Transaction>>> {
+        let (sender, receiver) = oneshot::channel();
+        let from_peer = PeerId::from_bytes(&peer_id).expect("Valid PeerId");
+
+        let request = TaskRequest::TxPoolGetFullTransactions {
+            tx_ids,
+            from_peer,
+            channel: sender,
+        };
+        self.request_sender.send(request).await?;
+
+        let (response_from_peer, response) =
+            receiver.await.map_err(|e| anyhow!("{e}"))?;
+        assert_eq!(
+            peer_id,
+            response_from_peer.to_bytes(),
+            "Bug: response from non-requested peer"
+        );
+
+        response
+            .map_err(|e| anyhow!("Invalid response from peer {e:?}"))?
+            .ok_or(anyhow!("Peer didn't return transactions"))
+    }
+
     pub async fn get_transactions_from_peer(
         &self,
         peer_id: Vec<u8>,
@@ -874,6 +1294,21 @@ impl SharedState {
         Ok(())
     }
 
+    pub fn broadcast_block(&self, block: Arc<SealedBlock>) -> anyhow::Result<()> {
+        self.request_sender
+            .try_send(TaskRequest::BroadcastBlock(block))?;
+        Ok(())
+    }
+
+    pub fn broadcast_consensus_message(
+        &self,
+        message: Arc<BftMessage>,
+    ) -> anyhow::Result<()> {
+        self.request_sender
+            .try_send(TaskRequest::BroadcastConsensusMessage(message))?;
+        Ok(())
+    }
+
     pub async fn get_all_peers(&self) -> anyhow::Result<Vec<(PeerId, PeerInfo)>> {
         let (sender, receiver) = oneshot::channel();
 
@@ -884,16 +1319,75 @@ impl SharedState {
         receiver.await.map_err(|e| anyhow!("{}", e))
     }
 
+    /// Closes all connections to the peer.
+    pub async fn disconnect_peer(&self, peer_id: PeerId) -> anyhow::Result<()> {
+        self.manage_peers(PeerManagementRequest::DisconnectPeer(peer_id))
+            .await
+    }
+
+    /// Bans the peer, the ban is lifted after it expires or on `unban_peer`.
+    pub async fn ban_peer(&self, peer_id: PeerId) -> anyhow::Result<()> {
+        self.manage_peers(PeerManagementRequest::BanPeer(peer_id))
+            .await
+    }
+
+    pub async fn unban_peer(&self, peer_id: PeerId) -> anyhow::Result<()> {
+        self.manage_peers(PeerManagementRequest::UnbanPeer(peer_id))
+            .await
+    }
+
+    /// Adds the reserved node. The `multiaddr` must contain the peer id.
+    pub async fn add_reserved_node(&self, multiaddr: Multiaddr) -> anyhow::Result<()> {
+        self.manage_peers(PeerManagementRequest::AddReservedNode(multiaddr))
+            .await
+    }
+
+    pub async fn remove_reserved_node(&self, peer_id: PeerId) -> anyhow::Result<()> {
+        self.manage_peers(PeerManagementRequest::RemoveReservedNode(peer_id))
+            .await
+    }
+
+    pub async fn dial(&self, multiaddr: Multiaddr) -> anyhow::Result<()> {
+        self.manage_peers(PeerManagementRequest::Dial(multiaddr))
+            .await
+    }
+
+    async fn manage_peers(&self, request: PeerManagementRequest) -> anyhow::Result<()> {
+        let (sender, receiver) = oneshot::channel();
+
+        self.request_sender
+            .send(TaskRequest::ManagePeers {
+                request,
+                channel: sender,
+            })
+            .await?;
+
+        receiver.await.map_err(|e| anyhow!("{}", e))?
+    }
+
     pub fn subscribe_tx(&self) -> broadcast::Receiver<TransactionGossipData> {
         self.tx_broadcast.subscribe()
     }
 
+    pub fn subscribe_block(&self) -> broadcast::Receiver<BlockGossipData> {
+        self.block_broadcast.subscribe()
+    }
+
+    pub fn subscribe_consensus(&self) -> broadcast::Receiver<ConsensusGossipData> {
+        self.consensus_broadcast.subscribe()
+    }
+
     pub fn subscribe_block_height(
         &self,
     ) -> broadcast::Receiver<BlockHeightHeartbeatData> {
         self.block_height_broadcast.subscribe()
     }
 
+    /// Subscribes to the newly connected peers able to share their transaction pool.
+    pub fn subscribe_new_peers(&self) -> broadcast::Receiver<FuelPeerId> {
+        self.new_peers_broadcast.subscribe()
+    }
+
     pub fn subscribe_reserved_peers_count(&self) -> broadcast::Receiver<usize> {
         self.reserved_peers_broadcast.subscribe()
     }
@@ -925,19 +1419,68 @@ impl SharedState {
     }
 }
 
-pub fn new_service<V, B>(
+/// Builds the [`SharedState`] of the p2p service before the service itself,
+/// so services used by the p2p, like `TxPool`, can depend on it.
+pub fn build_shared_state(
+    config: &Config<NotInitialized>,
+) -> (SharedState, mpsc::Receiver<TaskRequest>) {
+    let (request_sender, request_receiver) = mpsc::channel(1024 * 10);
+    let (tx_broadcast, _) = broadcast::channel(1024 * 10);
+    let (block_broadcast, _) = broadcast::channel(1024);
+    let (consensus_broadcast, _) = broadcast::channel(1024 * 10);
+    let (block_height_broadcast, _) = broadcast::channel(1024 * 10);
+    let (new_peers_broadcast, _) = broadcast::channel(1024);
+
+    let (reserved_peers_broadcast, _) = broadcast::channel::<usize>(
+        config
+            .reserved_nodes
+            .len()
+            .saturating_mul(2)
+            .saturating_add(1),
+    );
+
+    (
+        SharedState {
+            request_sender,
+            tx_broadcast,
+            block_broadcast,
+            consensus_broadcast,
+            reserved_peers_broadcast,
+            block_height_broadcast,
+            new_peers_broadcast,
+        },
+        request_receiver,
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn new_service<V, B, T, D>(
     chain_id: ChainId,
     p2p_config: Config<NotInitialized>,
+    shared_state: SharedState,
+    request_receiver: mpsc::Receiver<TaskRequest>,
     view_provider: V,
     block_importer: B,
-) -> Service<V>
+    tx_pool: T,
+    reputation_db: D,
+) -> Service<V, T, D>
 where
     V: AtomicView + 'static,
     V::LatestView: P2pDb,
     B: BlockHeightImporter,
+    T: TxPool,
+    D: PeersReputationDb,
 {
-    let task =
-        UninitializedTask::new(chain_id, p2p_config, view_provider, block_importer);
+    let task = UninitializedTask::new(
+        chain_id,
+        p2p_config,
+        shared_state,
+        request_receiver,
+        view_provider,
+        block_importer,
+        tx_pool,
+        reputation_db,
+    );
     Service::new(task)
 }
 
@@ -979,7 +1522,10 @@ pub mod tests {
 
     use super::*;
 
-    use crate::peer_manager::heartbeat_data::HeartbeatData;
+    use crate::{
+        peer_manager::heartbeat_data::HeartbeatData,
+        storage::PeerBan,
+    };
     use fuel_core_services::{
         Service,
         State,
@@ -988,6 +1534,7 @@ pub mod tests {
     use fuel_core_types::{
         blockchain::consensus::Genesis,
         fuel_types::BlockHeight,
+        tai64::Tai64,
     };
     use futures::FutureExt;
     use std::{
@@ -1021,6 +1568,13 @@ pub mod tests {
             unimplemented!()
         }
 
+        fn get_sealed_blocks(
+            &self,
+            _block_height_range: Range<u32>,
+        ) -> StorageResult<Option<Vec<SealedBlock>>> {
+            unimplemented!()
+        }
+
         fn get_genesis(&self) -> StorageResult<Genesis> {
             Ok(Default::default())
         }
@@ -1035,11 +1589,52 @@ pub mod tests {
         }
     }
 
+    #[derive(Clone, Debug)]
+    struct FakeTxPool;
+
+    impl TxPool for FakeTxPool {
+        fn get_tx_ids(&self, _max_txs: usize) -> Vec<TxId> {
+            vec![]
+        }
+
+        fn get_full_txs(&self, tx_ids: Vec<TxId>) -> Vec<Option<Transaction>> {
+            tx_ids.iter().map(|_| None).collect()
+        }
+    }
+
+    #[derive(Clone, Default)]
+    struct FakeReputationDb {
+        stored: Arc<std::sync::Mutex<Option<PeersReputation>>>,
+    }
+
+    impl PeersReputationDb for FakeReputationDb {
+        fn get_peers_reputation(&self) -> StorageResult<PeersReputation> {
+            Ok(self.stored.lock().unwrap().clone().unwrap_or_default())
+        }
+
+        fn store_peers_reputation(
+            &mut self,
+            reputation: &PeersReputation,
+        ) -> StorageResult<()> {
+            *self.stored.lock().unwrap() = Some(reputation.clone());
+            Ok(())
+        }
+    }
+
     #[tokio::test]
     async fn start_and_stop_awaits_works() {
         let p2p_config = Config::<NotInitialized>::default("start_stop_works");
-        let service =
-            new_service(ChainId::default(), p2p_config, FakeDb, FakeBlockImporter);
+        let (shared_state, request_receiver) = build_shared_state(&p2p_config);
+        let service = new_service(
+            ChainId::default(),
+            p2p_config,
+            shared_state,
+            request_receiver,
+            FakeDb,
+            FakeBlockImporter,
+            FakeTxPool,
+            FakeReputationDb::default(),
+        );
 
         // Node with p2p service started
         assert!(service.start_and_await().await.unwrap().started());
@@ -1047,6 +1642,44 @@ pub mod tests {
         assert!(service.stop_and_await().await.unwrap().stopped());
     }
 
+    #[tokio::test]
+    async fn reputation_of_peers_is_stored_on_shutdown() {
+        let p2p_config = Config::<NotInitialized>::default("reputation_is_stored");
+        let (shared_state, request_receiver) = build_shared_state(&p2p_config);
+        let reputation_db = FakeReputationDb::default();
+        let banned_peer = PeerId::random();
+        let ban = PeerBan {
+            expires_at: Tai64(Tai64::now().0.saturating_add(60)),
+        };
+        reputation_db
+            .stored
+            .lock()
+            .unwrap()
+            .replace(PeersReputation {
+                banned_peers: [(banned_peer, ban)].into(),
+                known_peers: Default::default(),
+            });
+        let service = new_service(
+            ChainId::default(),
+            p2p_config,
+            shared_state,
+            request_receiver,
+            FakeDb,
+            FakeBlockImporter,
+            FakeTxPool,
+            reputation_db.clone(),
+        );
+        assert!(service.start_and_await().await.unwrap().started());
+        reputation_db.stored.lock().unwrap().take();
+
+        // When
+        assert!(service.stop_and_await().await.unwrap().stopped());
+
+        // Then
+        let stored = reputation_db.stored.lock().unwrap().clone().unwrap();
+        assert_eq!(stored.banned_peers.get(&banned_peer), Some(&ban));
+    }
+
     struct FakeP2PService {
         peer_info: Vec<(PeerId, PeerInfo)>,
         next_event_stream: BoxStream<FuelP2PEvent>,
@@ -1064,7 +1697,12 @@ pub mod tests {
             self.peer_info.iter().map(|tup| (&tup.0, &tup.1)).collect()
         }
 
-        fn get_peer_id_with_height(&self, _height: &BlockHeight) -> Option<PeerId> {
+        fn get_peer_id_with_height(
+            &self,
+            _height: &BlockHeight,
+            _protocol: RequestResponseProtocol,
+            _excluded_peers: &HashSet<PeerId>,
+        ) -> Option<PeerId> {
             todo!()
         }
 
@@ -1116,6 +1754,17 @@ pub mod tests {
         fn update_block_height(&mut self, _height: BlockHeight) -> anyhow::Result<()> {
             Ok(())
         }
+
+        fn manage_peers(
+            &mut self,
+            _request: PeerManagementRequest,
+        ) -> anyhow::Result<()> {
+            todo!()
+        }
+
+        fn peers_reputation(&self) -> PeersReputation {
+            PeersReputation::default()
+        }
     }
 
     #[derive(Clone)]
@@ -1144,6 +1793,13 @@ pub mod tests {
             todo!()
         }
 
+        fn get_sealed_blocks(
+            &self,
+            _block_height_range: Range<u32>,
+        ) -> StorageResult<Option<Vec<SealedBlock>>> {
+            todo!()
+        }
+
         fn get_genesis(&self) -> StorageResult<Genesis> {
             todo!()
         }
@@ -1181,6 +1837,21 @@ pub mod tests {
         ) -> anyhow::Result<()> {
             todo!()
         }
+
+        fn block_broadcast(&self, _block: BlockGossipData) -> anyhow::Result<()> {
+            todo!()
+        }
+
+        fn consensus_broadcast(
+            &self,
+            _message: ConsensusGossipData,
+        ) -> anyhow::Result<()> {
+            todo!()
+        }
+
+        fn new_peer_broadcast(&self, _peer_id: FuelPeerId) -> anyhow::Result<()> {
+            todo!()
+        }
     }
 
     #[tokio::test]
@@ -1204,6 +1875,8 @@ pub mod tests {
             client_version: None,
             heartbeat_data,
             score: 100.0,
+            req_res_protocol: None,
+            request_stats: Default::default(),
         };
         let peer_info = vec![(peer_id, peer_info)];
         let p2p_service = FakeP2PService {
@@ -1233,6 +1906,8 @@ pub mod tests {
             response_timeout: Default::default(),
             p2p_service,
             view_provider: FakeDB,
+            reputation_db: FakeReputationDb::default(),
+            tx_pool: FakeTxPool,
             next_block_height: FakeBlockImporter.next_block_height(),
             request_receiver,
             request_sender,
@@ -1244,6 +1919,9 @@ pub mod tests {
             heartbeat_max_time_since_last,
             next_check_time: Instant::now(),
             heartbeat_peer_reputation_config: heartbeat_peer_reputation_config.clone(),
+            stored_reputation: Default::default(),
+            reputation_store: None,
+            next_reputation_store_time: Instant::now(),
         };
         let (watch_sender, watch_receiver) = tokio::sync::watch::channel(State::Started);
         let mut watcher = StateWatcher::from(watch_receiver);
@@ -1291,6 +1969,8 @@ pub mod tests {
             client_version: None,
             heartbeat_data,
             score: 100.0,
+            req_res_protocol: None,
+            request_stats: Default::default(),
         };
         let peer_info = vec![(peer_id, peer_info)];
         let p2p_service = FakeP2PService {
@@ -1320,6 +2000,8 @@ pub mod tests {
             response_timeout: Default::default(),
             p2p_service,
             view_provider: FakeDB,
+            reputation_db: FakeReputationDb::default(),
+            tx_pool: FakeTxPool,
             next_block_height: FakeBlockImporter.next_block_height(),
             request_receiver,
             request_sender,
@@ -1331,6 +2013,9 @@ pub mod tests {
             heartbeat_max_time_since_last,
             next_check_time: Instant::now(),
             heartbeat_peer_reputation_config: heartbeat_peer_reputation_config.clone(),
+            stored_reputation: Default::default(),
+            reputation_store: None,
+            next_reputation_store_time: Instant::now(),
         };
         let (watch_sender, watch_receiver) = tokio::sync::watch::channel(State::Started);
         let mut watcher = StateWatcher::from(watch_receiver);
@@ -1379,6 +2064,8 @@ pub mod tests {
             response_timeout: Default::default(),
             p2p_service,
             view_provider: FakeDB,
+            reputation_db: FakeReputationDb::default(),
+            tx_pool: FakeTxPool,
             next_block_height,
             request_receiver,
             request_sender,
@@ -1390,6 +2077,9 @@ pub mod tests {
             heartbeat_max_time_since_last: Default::default(),
             next_check_time: Instant::now(),
             heartbeat_peer_reputation_config: Default::default(),
+            stored_reputation: Default::default(),
+            reputation_store: None,
+            next_reputation_store_time: Instant::now(),
         };
         let mut watcher = StateWatcher::started();
         // End of initialization
@@ -1404,4 +2094,71 @@ pub mod tests {
                 .expect("Should process the block height even under p2p pressure");
         }
     }
+
+    #[tokio::test]
+    async fn peer_heartbeat__changed_reputation_is_stored_once_per_interval() {
+        // given
+        let reputation_db = FakeReputationDb::default();
+        let old_reputation = PeersReputation {
+            banned_peers: [(
+                PeerId::random(),
+                PeerBan {
+                    expires_at: Tai64(Tai64::now().0.saturating_add(60)),
+                },
+            )]
+            .into(),
+            known_peers: Default::default(),
+        };
+        let p2p_service = FakeP2PService {
+            peer_info: vec![],
+            next_event_stream: Box::pin(futures::stream::pending()),
+        };
+        let (request_sender, request_receiver) = mpsc::channel(100);
+        let broadcast = FakeBroadcast {
+            peer_reports: mpsc::channel(100).0,
+        };
+        let mut task = Task {
+            chain_id: Default::default(),
+            response_timeout: Default::default(),
+            p2p_service,
+            view_provider: FakeDB,
+            reputation_db: reputation_db.clone(),
+            tx_pool: FakeTxPool,
+            next_block_height: FakeBlockImporter.next_block_height(),
+            request_receiver,
+            request_sender,
+            database_processor: HeavyTaskProcessor::new(1, 1).unwrap(),
+            broadcast,
+            max_headers_per_request: 0,
+            heartbeat_check_interval: Duration::from_secs(0),
+            heartbeat_max_avg_interval: Default::default(),
+            heartbeat_max_time_since_last: Default::default(),
+            next_check_time: Instant::now(),
+            heartbeat_peer_reputation_config: Default::default(),
+            stored_reputation: old_reputation.clone(),
+            reputation_store: None,
+            next_reputation_store_time: Instant::now(),
+        };
+        let mut watcher = StateWatcher::started();
+
+        // when
+        task.run(&mut watcher).await.unwrap();
+        task.reputation_store
+            .take()
+            .expect("The changed reputation should be stored")
+            .await
+            .unwrap();
+        task.stored_reputation = old_reputation;
+        task.run(&mut watcher).await.unwrap();
+
+        // then
+        assert_eq!(
+            reputation_db.stored.lock().unwrap().clone(),
+            Some(PeersReputation::default())
+        );
+        assert!(
+            task.reputation_store.is_none(),
+            "The reputation should not be stored again during the interval"
+        );
+    }
 }
@@ -0,0 +1,196 @@
+//! The storage of the reputation of peers that survives restarts of the node.
+
+use crate::ports::{
+    PeersReputation,
+    PeersReputationDb,
+};
+use fuel_core_storage::{
+    blueprint::plain::Plain,
+    codec::{
+        postcard::Postcard,
+        raw::Raw,
+    },
+    iter::{
+        IterableStore,
+        IterableTable,
+        IteratorOverTable,
+    },
+    kv_store::StorageColumn,
+    structured_storage::{
+        StructuredStorage,
+        TableWithBlueprint,
+    },
+    transactional::{
+        Modifiable,
+        WriteTransaction,
+    },
+    Mappable,
+    Result as StorageResult,
+    StorageAsMut,
+};
+use fuel_core_types::{
+    services::p2p::peer_reputation::AppScore,
+    tai64::Tai64,
+};
+use libp2p::{
+    Multiaddr,
+    PeerId,
+};
+use std::collections::HashMap;
+
+/// The column of the database with the reputation of peers.
+#[repr(u32)]
+#[derive(
+    Copy,
+    Clone,
+    Debug,
+    strum_macros::EnumCount,
+    strum_macros::IntoStaticStr,
+    PartialEq,
+    Eq,
+    enum_iterator::Sequence,
+    Hash,
+)]
+pub enum Column {
+    Metadata = 0,
+    BannedPeers = 1,
+    KnownPeers = 2,
+}
+
+impl Column {
+    /// The total count of variants in the enum.
+    pub const COUNT: usize = <Self as strum::EnumCount>::COUNT;
+
+    /// Returns the `usize` representation of the `Column`.
+    pub fn as_u32(&self) -> u32 {
+        *self as u32
+    }
+}
+
+impl StorageColumn for Column {
+    fn name(&self) -> String {
+        let str: &str = self.into();
+        str.to_string()
+    }
+
+    fn id(&self) -> u32 {
+        self.as_u32()
+    }
+}
+
+/// The ban of the peer, lifted once `expires_at` is reached.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct PeerBan {
+    pub expires_at: Tai64,
+}
+
+/// The peer the node was connected to, with its last known reputation.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct KnownPeer {
+    pub addresses: Vec<Multiaddr>,
+    pub score: AppScore,
+    pub last_seen: Tai64,
+}
+
+/// The table of banned peers, keyed by the bytes of the `PeerId`.
+pub struct BannedPeers;
+
+impl Mappable for BannedPeers {
+    type Key = Self::OwnedKey;
+    type OwnedKey = Vec<u8>;
+    type Value = Self::OwnedValue;
+    type OwnedValue = PeerBan;
+}
+
+impl TableWithBlueprint for BannedPeers {
+    type Blueprint = Plain<Raw, Postcard>;
+    type Column = Column;
+
+    fn column() -> Self::Column {
+        Column::BannedPeers
+    }
+}
+
+/// The table of known peers, keyed by the bytes of the `PeerId`.
+pub struct KnownPeers;
+
+impl Mappable for KnownPeers {
+    type Key = Self::OwnedKey;
+    type OwnedKey = Vec<u8>;
+    type Value = Self::OwnedValue;
+    type OwnedValue = KnownPeer;
+}
+
+impl TableWithBlueprint for KnownPeers {
+    type Blueprint = Plain<Raw, Postcard>;
+    type Column = Column;
+
+    fn column() -> Self::Column {
+        Column::KnownPeers
+    }
+}
+
+impl<Storage> PeersReputationDb for StructuredStorage<Storage>
+where
+    Storage: IterableStore<Column = Column> + Modifiable + Clone,
+    Storage: Send + Sync + 'static,
+{
+    fn get_peers_reputation(&self) -> StorageResult<PeersReputation> {
+        let banned_peers = read_table::<BannedPeers, _>(self)?;
+        let known_peers = read_table::<KnownPeers, _>(self)?;
+        Ok(PeersReputation {
+            banned_peers,
+            known_peers,
+        })
+    }
+
+    fn store_peers_reputation(
+        &mut self,
+        reputation: &PeersReputation,
+    ) -> StorageResult<()> {
+        let stale_bans = self
+            .iter_all_keys::<BannedPeers>(None)
+            .collect::<StorageResult<Vec<_>>>()?;
+        let stale_known_peers = self
+            .iter_all_keys::<KnownPeers>(None)
+            .collect::<StorageResult<Vec<_>>>()?;
+
+        let mut tx = self.write_transaction();
+        for peer_id in stale_bans {
+            tx.storage_as_mut::<BannedPeers>().remove(&peer_id)?;
+        }
+        for peer_id in stale_known_peers {
+            tx.storage_as_mut::<KnownPeers>().remove(&peer_id)?;
+        }
+        for (peer_id, ban) in &reputation.banned_peers {
+            tx.storage_as_mut::<BannedPeers>()
+                .insert(&peer_id.to_bytes(), ban)?;
+        }
+        for (peer_id, known_peer) in &reputation.known_peers {
+            tx.storage_as_mut::<KnownPeers>()
+                .insert(&peer_id.to_bytes(), known_peer)?;
+        }
+        tx.commit()?;
+        Ok(())
+    }
+}
+
+/// Reads the whole table, skipping entries with malformed peer ids.
+fn read_table<M, S>(storage: &S) -> StorageResult<HashMap<PeerId, M::OwnedValue>>
+where
+    M: Mappable<OwnedKey = Vec<u8>>,
+    S: IterableTable<M>,
+{
+    storage
+        .iter_all::<M>(None)
+        .filter_map(|entry| {
+            entry
+                .map(|(peer_id, value)| {
+                    PeerId::from_bytes(&peer_id)
+                        .ok()
+                        .map(|peer_id| (peer_id, value))
+                })
+                .transpose()
+        })
+        .collect()
+}
@@ -39,7 +39,9 @@ use fuel_core_types::{
     services::{
         block_producer::Components,
         executor::{
+            StateOverrides,
             TransactionExecutionStatus,
+            TransactionTrace,
             UncommittedResult,
         },
     },
@@ -272,22 +274,38 @@ where
     /// Simulates multiple transactions without altering any state. Does not acquire the production lock.
     /// since it is basically a "read only" operation and shouldn't get in the way of normal
     /// production.
+    ///
+    /// If the `trace` is `true`, each status is accompanied by the trace of the transaction.
     pub async fn dry_run(
         &self,
         transactions: Vec<Transaction>,
         height: Option<BlockHeight>,
         utxo_validation: Option<bool>,
         gas_price: Option<u64>,
-    ) -> anyhow::Result<Vec<TransactionExecutionStatus>> {
+        state_overrides: StateOverrides,
+        trace: bool,
+    ) -> anyhow::Result<Vec<(TransactionExecutionStatus, Option<TransactionTrace>)>> {
         let view = self.view_provider.latest_view()?;
+        let latest_height = view.latest_height().unwrap_or_default();
         let height = height.unwrap_or_else(|| {
-            view.latest_height()
-                .unwrap_or_default()
+            latest_height
                 .succ()
                 .expect("It is impossible to overflow the current block height")
         });
 
-        let header = self._new_header(height, Tai64::now())?;
+        // If the block at the `height` already exists, the dry run reproduces its
+        // environment: it uses the header of the existing block and
+        // the state at the previous height.
+        let (header, at_height) = if height <= latest_height {
+            let previous_height = height.pred().ok_or(anyhow!(
+                "The dry run can't be executed at the genesis height"
+            ))?;
+            let block = view.get_block(&height)?;
+            let header = PartialBlockHeader::from(block.header());
+            (header, Some(previous_height))
+        } else {
+            (self._new_header(height, Tai64::now())?, None)
+        };
 
         let gas_price = if let Some(inner) = gas_price {
             inner
@@ -311,19 +329,38 @@ where
 
         // use the blocking threadpool for dry_run to avoid clogging up the main async runtime
         let tx_statuses = tokio_rayon::spawn_fifo(
-            move || -> anyhow::Result<Vec<TransactionExecutionStatus>> {
-                Ok(executor.dry_run(component, utxo_validation)?)
+            move || -> anyhow::Result<
+                Vec<(TransactionExecutionStatus, Option<TransactionTrace>)>,
+            > {
+                if trace {
+                    let tx_statuses = executor.dry_run_with_traces(
+                        component,
+                        utxo_validation,
+                        at_height,
+                        state_overrides,
+                    )?;
+                    Ok(tx_statuses
+                        .into_iter()
+                        .map(|(status, trace)| (status, Some(trace)))
+                        .collect())
+                } else {
+                    let tx_statuses = executor.dry_run(
+                        component,
+                        utxo_validation,
+                        at_height,
+                        state_overrides,
+                    )?;
+                    Ok(tx_statuses.into_iter().map(|status| (status, None)).collect())
+                }
             },
         )
         .await?;
 
-        if transactions
-            .iter()
-            .zip(tx_statuses.iter())
-            .any(|(transaction, tx_status)| {
+        if transactions.iter().zip(tx_statuses.iter()).any(
+            |(transaction, (tx_status, _))| {
                 transaction.is_script() && tx_status.result.receipts().is_empty()
-            })
-        {
+            },
+        ) {
             Err(anyhow!("Expected at least one set of receipts"))
         } else {
             Ok(tx_statuses)
@@ -21,7 +21,9 @@ use fuel_core_types::{
         block_producer::Components,
         executor::{
             Result as ExecutorResult,
+            StateOverrides,
             TransactionExecutionStatus,
+            TransactionTrace,
             UncommittedResult,
         },
     },
@@ -86,10 +88,25 @@ pub trait BlockProducer<TxSource>: Send + Sync {
 pub trait DryRunner: Send + Sync {
     /// Executes the block without committing it to the database. During execution collects the
     /// receipts to return them. The `utxo_validation` field can be used to disable the validation
-    /// of utxos during execution.
+    /// of utxos during execution. The `at_height` field specifies the height of the state
+    /// on top of which the block is executed, the latest state is used if it is `None`.
+    /// The `state_overrides` are applied to the state before the execution.
     fn dry_run(
         &self,
         block: Components<Vec<Transaction>>,
         utxo_validation: Option<bool>,
+        at_height: Option<BlockHeight>,
+        state_overrides: StateOverrides,
     ) -> ExecutorResult<Vec<TransactionExecutionStatus>>;
+
+    /// Executes the block in the same way as the [`Self::dry_run`] with
+    /// the debugger enabled and returns the trace of each transaction
+    /// along with its status.
+    fn dry_run_with_traces(
+        &self,
+        block: Components<Vec<Transaction>>,
+        utxo_validation: Option<bool>,
+        at_height: Option<BlockHeight>,
+        state_overrides: StateOverrides,
+    ) -> ExecutorResult<Vec<(TransactionExecutionStatus, TransactionTrace)>>;
 }
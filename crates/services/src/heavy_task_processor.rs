@@ -1,15 +1,20 @@
 use std::sync::Arc;
 use tokio::sync::Semaphore;
 
+/// Executes CPU-heavy tasks on a dedicated thread pool,
+/// limiting the number of tasks waiting for execution.
 pub struct HeavyTaskProcessor {
     rayon_thread_pool: rayon::ThreadPool,
     semaphore: Arc<Semaphore>,
 }
 
+/// The task is not spawned because the limit of pending tasks is reached.
 #[derive(Debug, PartialEq, Eq)]
 pub struct OutOfCapacity;
 
 impl HeavyTaskProcessor {
+    /// Creates the processor with `number_of_threads` threads that can hold
+    /// up to `number_of_pending_tasks` tasks at the same time.
     pub fn new(
         number_of_threads: usize,
         number_of_pending_tasks: usize,
@@ -25,6 +30,7 @@ impl HeavyTaskProcessor {
         })
     }
 
+    /// Spawns the task if the limit of pending tasks is not reached.
     pub fn spawn<OP>(&self, op: OP) -> Result<(), OutOfCapacity>
     where
         OP: FnOnce() + Send + 'static,
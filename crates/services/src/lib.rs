@@ -5,6 +5,7 @@
 #![deny(missing_docs)]
 #![deny(warnings)]
 
+mod heavy_task_processor;
 mod service;
 mod state;
 mod sync;
@@ -58,6 +59,10 @@ where
     }
 }
 
+pub use heavy_task_processor::{
+    HeavyTaskProcessor,
+    OutOfCapacity,
+};
 pub use service::{
     EmptyShared,
     RunnableService,
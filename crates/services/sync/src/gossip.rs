@@ -0,0 +1,146 @@
+//! # Gossip task
+//! Imports blocks gossiped by the block producer right after their production.
+//! Only the block following the committed height is imported this way,
+//! other blocks are left to the import task pulling them from peers.
+
+use std::sync::Arc;
+
+use fuel_core_services::{
+    stream::BoxStream,
+    SharedMutex,
+    TraceErr,
+};
+use fuel_core_types::{
+    blockchain::{
+        SealedBlock,
+        SealedBlockHeader,
+    },
+    services::p2p::{
+        BlockGossipData,
+        GossipsubMessageAcceptance,
+        GossipsubMessageInfo,
+    },
+};
+use futures::stream::StreamExt;
+use tokio::sync::Notify;
+
+use crate::{
+    import::execute_and_commit,
+    ports::{
+        BlockImporterPort,
+        ConsensusPort,
+        PeerToPeerPort,
+    },
+    state::State,
+};
+
+#[cfg(test)]
+mod tests;
+
+pub(crate) struct GossipImport<P, E, C> {
+    block_stream: BoxStream<BlockGossipData>,
+    state: SharedMutex<State>,
+    notify: Arc<Notify>,
+    p2p: Arc<P>,
+    executor: Arc<E>,
+    consensus: Arc<C>,
+}
+
+impl<P, E, C> GossipImport<P, E, C>
+where
+    P: PeerToPeerPort + Send + Sync + 'static,
+    E: BlockImporterPort + Send + Sync + 'static,
+    C: ConsensusPort + Send + Sync + 'static,
+{
+    pub(crate) fn new(
+        block_stream: BoxStream<BlockGossipData>,
+        state: SharedMutex<State>,
+        notify: Arc<Notify>,
+        p2p: Arc<P>,
+        executor: Arc<E>,
+        consensus: Arc<C>,
+    ) -> Self {
+        Self {
+            block_stream,
+            state,
+            notify,
+            p2p,
+            executor,
+            consensus,
+        }
+    }
+
+    #[tracing::instrument(skip(self))]
+    /// Import the next gossiped block and report its validity to the network.
+    pub(crate) async fn import(&mut self) -> Option<()> {
+        let BlockGossipData {
+            data,
+            peer_id,
+            message_id,
+        } = self.block_stream.next().await?;
+
+        let acceptance = match data {
+            Some(block) => self.import_block(block).await,
+            None => GossipsubMessageAcceptance::Ignore,
+        };
+        let message_info = GossipsubMessageInfo {
+            message_id,
+            peer_id,
+        };
+        let _ = self
+            .p2p
+            .notify_gossip_block_validity(message_info, acceptance)
+            .trace_err("Failed to notify the validity of the gossiped block");
+        Some(())
+    }
+
+    async fn import_block(&self, block: SealedBlock) -> GossipsubMessageAcceptance {
+        let height = **block.entity.header().height();
+
+        let header = SealedBlockHeader {
+            entity: block.entity.header().clone(),
+            consensus: block.consensus.clone(),
+        };
+        let valid_consensus = self
+            .consensus
+            .check_sealed_header(&header)
+            .trace_err("Failed to check consensus on the gossiped block")
+            .unwrap_or(false);
+        let valid_transactions = block
+            .entity
+            .header()
+            .validate_transactions(block.entity.transactions());
+        if !valid_consensus || !valid_transactions {
+            tracing::warn!("Received invalid gossiped block at height {}", height);
+            return GossipsubMessageAcceptance::Reject
+        }
+
+        if !self.state.apply(|s| s.claim_next(height)) {
+            // The block doesn't follow the committed height, or the import task is
+            // already processing it. Let the import task pull the missing blocks.
+            if self.state.apply(|s| s.observe(height)) {
+                self.notify.notify_one();
+            }
+            return GossipsubMessageAcceptance::Ignore
+        }
+
+        let _ = self
+            .consensus
+            .await_da_height(&block.entity.header().da_height)
+            .await
+            .trace_err("Failed to wait for DA layer to sync");
+
+        let result = execute_and_commit(self.executor.as_ref(), &self.state, block).await;
+
+        // On failure, the released height is pulled from peers by the import task.
+        // The block is signed by the producer, so the failure is not the fault
+        // of peers relaying it.
+        if self.state.apply(|s| s.release(height)) {
+            self.notify.notify_one();
+        }
+        match result {
+            Ok(()) => GossipsubMessageAcceptance::Accept,
+            Err(_) => GossipsubMessageAcceptance::Ignore,
+        }
+    }
+}
@@ -0,0 +1,201 @@
+#![allow(non_snake_case)]
+
+use fuel_core_services::stream::IntoBoxStream;
+use fuel_core_types::{
+    blockchain::{
+        block::Block,
+        consensus::Consensus,
+    },
+    fuel_tx::Transaction,
+};
+
+use crate::{
+    import::test_helpers::{
+        empty_header,
+        random_peer,
+    },
+    ports::{
+        MockBlockImporterPort,
+        MockConsensusPort,
+        MockPeerToPeerPort,
+    },
+};
+
+use super::*;
+
+fn gossiped_block(height: u32, transactions: Vec<Transaction>) -> BlockGossipData {
+    let header = empty_header(height).entity;
+    let mut block = Block::try_from_executed(header, vec![]).unwrap();
+    *block.transactions_mut() = transactions;
+    BlockGossipData {
+        data: Some(SealedBlock {
+            entity: block,
+            consensus: Consensus::default(),
+        }),
+        peer_id: random_peer(),
+        message_id: vec![1, 2, 3],
+    }
+}
+
+struct Mocks {
+    consensus: MockConsensusPort,
+    executor: MockBlockImporterPort,
+}
+
+impl Mocks {
+    fn new(valid_consensus: bool) -> Self {
+        let mut consensus = MockConsensusPort::default();
+        consensus
+            .expect_check_sealed_header()
+            .returning(move |_| Ok(valid_consensus));
+        consensus.expect_await_da_height().returning(|_| Ok(()));
+        Self {
+            consensus,
+            executor: MockBlockImporterPort::default(),
+        }
+    }
+}
+
+async fn test_gossip_import(
+    state: State,
+    block: BlockGossipData,
+    mocks: Mocks,
+) -> (GossipsubMessageAcceptance, State) {
+    let Mocks {
+        consensus,
+        executor,
+    } = mocks;
+    let (sender, receiver) = std::sync::mpsc::channel();
+    let mut p2p = MockPeerToPeerPort::default();
+    p2p.expect_notify_gossip_block_validity()
+        .times(1)
+        .returning(move |_, acceptance| {
+            sender.send(acceptance).unwrap();
+            Ok(())
+        });
+
+    let state = SharedMutex::new(state);
+    let mut gossip = GossipImport::new(
+        futures::stream::iter([block]).into_boxed(),
+        state.clone(),
+        Arc::new(Notify::new()),
+        Arc::new(p2p),
+        Arc::new(executor),
+        Arc::new(consensus),
+    );
+
+    gossip.import().await.unwrap();
+
+    let acceptance = receiver.recv().unwrap();
+    (acceptance, state.apply(|s| s.clone()))
+}
+
+#[tokio::test]
+async fn import__next_block_is_committed_and_accepted() {
+    // given
+    let mut mocks = Mocks::new(true);
+    mocks
+        .executor
+        .expect_execute_and_commit()
+        .times(1)
+        .returning(|_| Ok(()));
+
+    // when
+    let result =
+        test_gossip_import(State::new(3, None), gossiped_block(4, vec![]), mocks).await;
+
+    // then
+    assert_eq!(
+        result,
+        (GossipsubMessageAcceptance::Accept, State::new(4, None))
+    );
+}
+
+#[tokio::test]
+async fn import__block_with_invalid_consensus_is_rejected() {
+    // given
+    let mut mocks = Mocks::new(false);
+    mocks.executor.expect_execute_and_commit().times(0);
+
+    // when
+    let result =
+        test_gossip_import(State::new(3, None), gossiped_block(4, vec![]), mocks).await;
+
+    // then
+    assert_eq!(
+        result,
+        (GossipsubMessageAcceptance::Reject, State::new(3, None))
+    );
+}
+
+#[tokio::test]
+async fn import__block_with_transactions_not_matching_header_is_rejected() {
+    // given
+    let mut mocks = Mocks::new(true);
+    mocks.executor.expect_execute_and_commit().times(0);
+    let block = gossiped_block(4, vec![Transaction::default_test_tx()]);
+
+    // when
+    let result = test_gossip_import(State::new(3, None), block, mocks).await;
+
+    // then
+    assert_eq!(
+        result,
+        (GossipsubMessageAcceptance::Reject, State::new(3, None))
+    );
+}
+
+#[tokio::test]
+async fn import__future_block_is_ignored_and_left_to_the_import_task() {
+    // given
+    let mut mocks = Mocks::new(true);
+    mocks.executor.expect_execute_and_commit().times(0);
+
+    // when
+    let result =
+        test_gossip_import(State::new(3, None), gossiped_block(6, vec![]), mocks).await;
+
+    // then
+    assert_eq!(
+        result,
+        (GossipsubMessageAcceptance::Ignore, State::new(3, 6))
+    );
+}
+
+#[tokio::test]
+async fn import__block_processed_by_the_import_task_is_ignored() {
+    // given
+    let mut mocks = Mocks::new(true);
+    mocks.executor.expect_execute_and_commit().times(0);
+
+    // when
+    let result =
+        test_gossip_import(State::new(3, 5), gossiped_block(4, vec![]), mocks).await;
+
+    // then
+    assert_eq!(
+        result,
+        (GossipsubMessageAcceptance::Ignore, State::new(3, 5))
+    );
+}
+
+#[tokio::test]
+async fn import__execution_error_is_ignored_and_left_to_the_import_task() {
+    // given
+    let mut mocks = Mocks::new(true);
+    mocks
+        .executor
+        .expect_execute_and_commit()
+        .times(1)
+        .returning(|_| Err(anyhow::anyhow!("Some execution error")));
+
+    // when
+    let result =
+        test_gossip_import(State::new(3, None), gossiped_block(4, vec![]), mocks).await;
+
+    // then
+    assert_eq!(
+        result,
+        (GossipsubMessageAcceptance::Ignore, State::new(3, 4))
+    );
+}
@@ -11,6 +11,7 @@ use fuel_core_types::{
     self,
     blockchain::{
         block::Block,
+        primitives::DaBlockHeight,
         SealedBlock,
         SealedBlockHeader,
     },
@@ -27,6 +28,7 @@ use futures::{
     Stream,
 };
 use std::{
+    collections::HashSet,
     future::Future,
     ops::{
         Range,
@@ -72,6 +74,9 @@ pub struct Config {
     pub block_stream_buffer_size: usize,
     /// The maximum number of headers to request in a single batch.
     pub header_batch_size: usize,
+    /// The maximum number of times to request the rest of the failed batch,
+    /// normally from another peer, before giving up on the range.
+    pub max_batch_retries: usize,
 }
 
 impl Default for Config {
@@ -79,6 +84,7 @@ impl Default for Config {
         Self {
             block_stream_buffer_size: 10,
             header_batch_size: 100,
+            max_batch_retries: 3,
         }
     }
 }
@@ -247,6 +253,8 @@ where
                     .take_until(shutdown_future)
                     .into_scan_none()
                     .scan_none()
+                    // Each range may be split into sub-batches served by different peers.
+                    .flat_map(futures::stream::iter)
                     .into_scan_err()
                     .scan_err();
 
@@ -364,78 +372,108 @@ fn get_block_stream<
     params: Config,
     p2p: Arc<P>,
     consensus: Arc<C>,
-) -> impl Stream<Item = impl Future<Output = SealedBlockBatch>> {
-    let header_stream = get_header_batch_stream(range.clone(), params, p2p.clone());
-    header_stream
-        .map({
-            let p2p = p2p.clone();
-            let consensus = consensus.clone();
-            move |header_batch| {
-                let p2p = p2p.clone();
-                let consensus = consensus.clone();
-                async move {
-                    let Batch {
-                        peer,
-                        range,
-                        results,
-                    } = header_batch.await;
-                    let checked_headers = results
-                        .into_iter()
-                        .take_while(|header| {
-                            check_sealed_header(header, peer.clone(), &p2p, &consensus)
-                        })
-                        .collect::<Vec<_>>();
-                    Batch::new(peer, range, checked_headers)
-                }
-            }
-        })
-        .map({
-            let p2p = p2p.clone();
-            let consensus = consensus.clone();
-            move |headers| {
-                let p2p = p2p.clone();
-                let consensus = consensus.clone();
-                async move {
-                    let Batch {
-                        peer,
-                        range,
-                        results,
-                    } = headers.await;
-                    if results.is_empty() {
-                        SealedBlockBatch::new(peer, range, vec![])
-                    } else {
-                        await_da_height(
-                            results
-                                .last()
-                                .expect("We checked headers are not empty above"),
-                            &consensus,
-                        )
-                        .await;
-                        let headers = SealedHeaderBatch::new(peer, range, results);
-                        get_blocks(&p2p, headers).await
-                    }
-                }
-                .instrument(tracing::debug_span!("consensus_and_transactions"))
-                .in_current_span()
-            }
-        })
-}
-
-fn get_header_batch_stream<P: PeerToPeerPort + Send + Sync + 'static>(
-    range: RangeInclusive<u32>,
-    params: Config,
-    p2p: Arc<P>,
-) -> impl Stream<Item = impl Future<Output = SealedHeaderBatch>> {
+) -> impl Stream<Item = impl Future<Output = Vec<SealedBlockBatch>>> {
     let Config {
-        header_batch_size, ..
+        header_batch_size,
+        max_batch_retries,
+        ..
     } = params;
     let ranges = range_chunks(range, header_batch_size);
     futures::stream::iter(ranges).map(move |range| {
         let p2p = p2p.clone();
-        async move { get_headers_batch(range, &p2p).await }
+        let consensus = consensus.clone();
+        async move {
+            let mut batches = vec![];
+            let mut excluded_peers = HashSet::new();
+            let mut remaining = range;
+            let mut retries = 0;
+            loop {
+                let batch = get_blocks_batch(
+                    remaining.clone(),
+                    &excluded_peers,
+                    &p2p,
+                    &consensus,
+                )
+                .await;
+                if !batch.is_err() || retries >= max_batch_retries {
+                    batches.push(batch);
+                    break
+                }
+                retries = retries.saturating_add(1);
+
+                // Keep the fetched part of the range with the peer that served it,
+                // and retry the rest on the other peers.
+                let Batch { peer, results, .. } = batch;
+                let fetched = u32::try_from(results.len())
+                    .expect("The size of the batch can't exceed `u32`");
+                let fetched_end = remaining.start.saturating_add(fetched);
+                if !results.is_empty() {
+                    batches.push(Batch::new(
+                        peer.clone(),
+                        remaining.start..fetched_end,
+                        results,
+                    ));
+                }
+                excluded_peers.extend(peer);
+                remaining = fetched_end..remaining.end;
+                tracing::debug!(
+                    "retrying to get block range {:?}, attempt {}",
+                    remaining,
+                    retries
+                );
+            }
+            batches
+        }
+        .instrument(tracing::debug_span!("consensus_and_transactions"))
+        .in_current_span()
     })
 }
 
+async fn get_blocks_batch<P, C>(
+    range: Range<u32>,
+    excluded_peers: &HashSet<PeerId>,
+    p2p: &Arc<P>,
+    consensus: &Arc<C>,
+) -> SealedBlockBatch
+where
+    P: PeerToPeerPort + Send + Sync + 'static,
+    C: ConsensusPort + Send + Sync + 'static,
+{
+    match get_sealed_blocks_batch(range.clone(), excluded_peers, p2p, consensus).await {
+        Some(blocks) => blocks,
+        // Peers running the older version of the protocol can't serve
+        // blocks, so request headers and transactions separately.
+        None => get_blocks_by_headers(range, excluded_peers, p2p, consensus).await,
+    }
+}
+
+async fn get_blocks_by_headers<P, C>(
+    range: Range<u32>,
+    excluded_peers: &HashSet<PeerId>,
+    p2p: &Arc<P>,
+    consensus: &Arc<C>,
+) -> SealedBlockBatch
+where
+    P: PeerToPeerPort + Send + Sync + 'static,
+    C: ConsensusPort + Send + Sync + 'static,
+{
+    let Batch {
+        peer,
+        range,
+        results,
+    } = get_headers_batch(range, excluded_peers, p2p).await;
+    let checked_headers = results
+        .into_iter()
+        .take_while(|header| check_sealed_header(header, peer.clone(), p2p, consensus))
+        .collect::<Vec<_>>();
+    let Some(last_header) = checked_headers.last() else {
+        return SealedBlockBatch::new(peer, range, vec![])
+    };
+    await_da_height(&last_header.entity.da_height, consensus).await;
+    let headers = SealedHeaderBatch::new(peer, range, checked_headers);
+    get_blocks(p2p, headers).await
+}
+
 fn range_chunks(
     range: RangeInclusive<u32>,
     chunk_size: usize,
@@ -469,11 +507,11 @@ fn check_sealed_header<
 }
 
 async fn await_da_height<C: ConsensusPort + Send + Sync + 'static>(
-    header: &SealedBlockHeader,
+    da_height: &DaBlockHeight,
     consensus: &Arc<C>,
 ) {
     let _ = consensus
-        .await_da_height(&header.entity.da_height)
+        .await_da_height(da_height)
         .await
         .trace_err("Failed to wait for DA layer to sync");
 }
@@ -498,6 +536,7 @@ async fn wait_for_notify_or_shutdown(
 
 async fn get_sealed_block_headers<P>(
     range: Range<u32>,
+    excluded_peers: &HashSet<PeerId>,
     p2p: &Arc<P>,
 ) -> Option<SourcePeer<Vec<SealedBlockHeader>>>
 where
@@ -508,7 +547,7 @@ where
         range.start,
         range.end
     );
-    p2p.get_sealed_block_headers(range)
+    p2p.get_sealed_block_headers(range, excluded_peers.clone())
         .await
         .trace_err("Failed to get headers")
         .ok()
@@ -541,7 +580,11 @@ where
     }
 }
 
-async fn get_headers_batch<P>(range: Range<u32>, p2p: &Arc<P>) -> SealedHeaderBatch
+async fn get_headers_batch<P>(
+    range: Range<u32>,
+    excluded_peers: &HashSet<PeerId>,
+    p2p: &Arc<P>,
+) -> SealedHeaderBatch
 where
     P: PeerToPeerPort + Send + Sync + 'static,
 {
@@ -550,7 +593,9 @@ where
         range.start,
         range.end
     );
-    let Some(sourced_headers) = get_sealed_block_headers(range.clone(), p2p).await else {
+    let Some(sourced_headers) =
+        get_sealed_block_headers(range.clone(), excluded_peers, p2p).await
+    else {
         return Batch::new(None, range, vec![])
     };
     let SourcePeer {
@@ -591,6 +636,94 @@ where
     }
 }
 
+/// Get full blocks for the range from a single request to the network.
+/// Returns `None` if the network can't serve blocks, so the caller
+/// falls back to requesting headers and transactions separately.
+async fn get_sealed_blocks_batch<P, C>(
+    range: Range<u32>,
+    excluded_peers: &HashSet<PeerId>,
+    p2p: &Arc<P>,
+    consensus: &Arc<C>,
+) -> Option<SealedBlockBatch>
+where
+    P: PeerToPeerPort + Send + Sync + 'static,
+    C: ConsensusPort + Send + Sync + 'static,
+{
+    tracing::debug!(
+        "getting block range from {} to {} inclusive",
+        range.start,
+        range.end
+    );
+    let SourcePeer {
+        peer_id,
+        data: blocks,
+    } = match p2p
+        .get_sealed_blocks(range.clone(), excluded_peers.clone())
+        .await
+    {
+        Ok(sourced_blocks) => sourced_blocks,
+        Err(err) => {
+            tracing::debug!("Failed to get blocks: {:?}", err);
+            return None
+        }
+    };
+
+    let heights = range.clone().map(BlockHeight::from);
+    let blocks = blocks
+        .unwrap_or_default()
+        .into_iter()
+        .zip(heights)
+        .take_while(|(block, expected_height)| {
+            block.entity.header().height() == expected_height
+        })
+        .map(|(block, _)| block)
+        .collect::<Vec<_>>();
+    if blocks.len() != range.len() {
+        report_peer(
+            p2p,
+            Some(peer_id.clone()),
+            PeerReportReason::MissingBlockHeaders,
+        );
+    }
+
+    let mut checked_blocks = vec![];
+    for SealedBlock {
+        entity,
+        consensus: seal,
+    } in blocks
+    {
+        let (header, transactions) = entity.into_inner();
+        let sealed_header = SealedBlockHeader {
+            entity: header,
+            consensus: seal,
+        };
+        if !check_sealed_header(&sealed_header, Some(peer_id.clone()), p2p, consensus) {
+            break
+        }
+        let SealedBlockHeader {
+            entity: header,
+            consensus: seal,
+        } = sealed_header;
+        let Some(block) = Block::try_from_executed(header, transactions) else {
+            report_peer(
+                p2p,
+                Some(peer_id.clone()),
+                PeerReportReason::InvalidTransactions,
+            );
+            break
+        };
+        checked_blocks.push(SealedBlock {
+            entity: block,
+            consensus: seal,
+        });
+    }
+
+    if let Some(last_block) = checked_blocks.last() {
+        await_da_height(&last_block.entity.header().da_height, consensus).await;
+    }
+    Some(Batch::new(Some(peer_id), range, checked_blocks))
+}
+
 /// Get blocks correlating to the headers from a specific peer
 #[tracing::instrument(skip(p2p, headers))]
 async fn get_blocks<P>(p2p: &Arc<P>, headers: SealedHeaderBatch) -> SealedBlockBatch
@@ -646,7 +779,7 @@ where
     ),
     err
 )]
-async fn execute_and_commit<E>(
+pub(crate) async fn execute_and_commit<E>(
     executor: &E,
     state: &SharedMutex<State>,
     block: SealedBlock,
@@ -24,6 +24,7 @@ struct Input {
     Config{
         block_stream_buffer_size: 1,
         header_batch_size: 1,
+        max_batch_retries: 0,
     }
     => Count::default() ; "Empty sanity test"
 )]
@@ -36,6 +37,7 @@ struct Input {
     Config{
         block_stream_buffer_size: 1,
         header_batch_size: 1,
+        max_batch_retries: 0,
     }
     => is less_or_equal_than Count{ headers: 1, consensus: 1, transactions: 1, executes: 1, blocks: 1 }
     ; "Single with slow headers"
@@ -49,6 +51,7 @@ struct Input {
     Config{
         block_stream_buffer_size: 10,
         header_batch_size: 5,
+        max_batch_retries: 0,
     }
     => is less_or_equal_than Count{ headers: 10, consensus: 10, transactions: 10, executes: 1, blocks: 50 }
     ; "1000 headers with max 5 size and max 10 requests when slow headers"
@@ -62,6 +65,7 @@ struct Input {
     Config{
         block_stream_buffer_size: 10,
         header_batch_size: 5,
+        max_batch_retries: 0,
     }
     => is less_or_equal_than Count{ headers: 10, consensus: 10, transactions: 10, executes: 1, blocks: 50 }
     ; "1000 headers with max 5 size and max 10 requests when transactions"
@@ -75,6 +79,7 @@ struct Input {
     Config{
         block_stream_buffer_size: 10,
         header_batch_size: 5,
+        max_batch_retries: 0,
     }
     => is less_or_equal_than Count{ headers: 10, consensus: 10, transactions: 10, executes: 1, blocks: 50 }
     ; "1000 headers with max 5 size and max 10 requests when consensus"
@@ -88,6 +93,7 @@ struct Input {
     Config{
         block_stream_buffer_size: 10,
         header_batch_size: 5,
+        max_batch_retries: 0,
     }
     => is less_or_equal_than Count{ headers: 10, consensus: 10, transactions: 10, executes: 1, blocks: 60 }
     ; "1000 headers with max 5 size and max 10 requests when execution is slow. \
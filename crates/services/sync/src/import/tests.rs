@@ -13,11 +13,23 @@ use crate::{
         PeerReportReason,
     },
 };
-use fuel_core_types::services::p2p::Transactions;
+use fuel_core_types::{
+    fuel_tx::Transaction,
+    services::p2p::Transactions,
+};
 use std::time::Duration;
 
 use super::*;
 
+/// Peers that don't support blocks requests, so headers and transactions are
+/// requested separately.
+fn peer_to_peer_without_blocks_support() -> MockPeerToPeerPort {
+    let mut p2p = MockPeerToPeerPort::default();
+    p2p.expect_get_sealed_blocks()
+        .returning(|_, _| Err(anyhow::anyhow!("Blocks requests are not supported")));
+    p2p
+}
+
 fn div_ceil(divisor: usize, dividend: usize) -> usize {
     (divisor + (dividend - 1)) / dividend
 }
@@ -34,10 +46,10 @@ async fn test_import_0_to_5() {
         .times(1)
         .returning(|_| Ok(()));
 
-    let mut p2p = MockPeerToPeerPort::default();
+    let mut p2p = peer_to_peer_without_blocks_support();
     p2p.expect_get_sealed_block_headers()
         .times(1)
-        .returning(|range| {
+        .returning(|range, _| {
             let peer = random_peer();
             let headers = Some(range.map(empty_header).collect());
             let headers = peer.bind(headers);
@@ -54,6 +66,7 @@ async fn test_import_0_to_5() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        max_batch_retries: 0,
     };
     let mocks = Mocks {
         consensus_port,
@@ -80,10 +93,10 @@ async fn test_import_3_to_5() {
         .times(1)
         .returning(|_| Ok(()));
 
-    let mut p2p = MockPeerToPeerPort::default();
+    let mut p2p = peer_to_peer_without_blocks_support();
     p2p.expect_get_sealed_block_headers()
         .times(1)
-        .returning(|range| {
+        .returning(|range, _| {
             let peer = random_peer();
             let headers = Some(range.map(empty_header).collect());
             let headers = peer.bind(headers);
@@ -100,6 +113,7 @@ async fn test_import_3_to_5() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        max_batch_retries: 0,
     };
     let mocks = Mocks {
         consensus_port,
@@ -140,13 +154,13 @@ async fn test_import_0_to_499() {
         .times(times)
         .returning(|_| Ok(()));
 
-    let mut p2p = MockPeerToPeerPort::default();
+    let mut p2p = peer_to_peer_without_blocks_support();
 
     // Happens once for each batch
     let times = div_ceil(n, header_batch_size);
     p2p.expect_get_sealed_block_headers()
         .times(times)
-        .returning(|range| {
+        .returning(|range, _| {
             let peer = random_peer();
             let headers = Some(range.map(empty_header).collect());
             let headers = peer.bind(headers);
@@ -166,6 +180,7 @@ async fn test_import_0_to_499() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size,
+        max_batch_retries: 0,
     };
     let mocks = Mocks {
         consensus_port,
@@ -192,10 +207,10 @@ async fn import__signature_fails_on_header_5_only() {
         .expect_await_da_height()
         .times(1)
         .returning(|_| Ok(()));
-    let mut p2p = MockPeerToPeerPort::default();
+    let mut p2p = peer_to_peer_without_blocks_support();
     p2p.expect_get_sealed_block_headers()
         .times(1)
-        .returning(|range| {
+        .returning(|range, _| {
             let peer = random_peer();
             let headers = Some(range.map(empty_header).collect());
             let headers = peer.bind(headers);
@@ -218,6 +233,7 @@ async fn import__signature_fails_on_header_5_only() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        max_batch_retries: 0,
     };
 
     // when
@@ -240,10 +256,10 @@ async fn import__signature_fails_on_header_4_only() {
         .times(0)
         .returning(|_| Ok(()));
 
-    let mut p2p = MockPeerToPeerPort::default();
+    let mut p2p = peer_to_peer_without_blocks_support();
     p2p.expect_get_sealed_block_headers()
         .times(1)
-        .returning(|range| {
+        .returning(|range, _| {
             let peer = random_peer();
             let headers = Some(range.map(empty_header).collect());
             let headers = peer.bind(headers);
@@ -266,6 +282,7 @@ async fn import__signature_fails_on_header_4_only() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        max_batch_retries: 0,
     };
 
     // when
@@ -278,10 +295,10 @@ async fn import__signature_fails_on_header_4_only() {
 #[tokio::test]
 async fn import__header_not_found() {
     // given
-    let mut p2p = MockPeerToPeerPort::default();
+    let mut p2p = peer_to_peer_without_blocks_support();
     p2p.expect_get_sealed_block_headers()
         .times(1)
-        .returning(|_| {
+        .returning(|_, _| {
             let peer = random_peer();
             let headers = Some(Vec::new());
             let headers = peer.bind(headers);
@@ -297,6 +314,7 @@ async fn import__header_not_found() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        max_batch_retries: 0,
     };
 
     // when
@@ -309,10 +327,10 @@ async fn import__header_not_found() {
 #[tokio::test]
 async fn import__header_response_incomplete() {
     // given
-    let mut p2p = MockPeerToPeerPort::default();
+    let mut p2p = peer_to_peer_without_blocks_support();
     p2p.expect_get_sealed_block_headers()
         .times(1)
-        .returning(|_| {
+        .returning(|_, _| {
             let peer = random_peer();
             let headers = None;
             let headers = peer.bind(headers);
@@ -328,6 +346,7 @@ async fn import__header_response_incomplete() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        max_batch_retries: 0,
     };
 
     // when
@@ -340,10 +359,10 @@ async fn import__header_response_incomplete() {
 #[tokio::test]
 async fn import__header_5_not_found() {
     // given
-    let mut p2p = MockPeerToPeerPort::default();
+    let mut p2p = peer_to_peer_without_blocks_support();
     p2p.expect_get_sealed_block_headers()
         .times(1)
-        .returning(|_| {
+        .returning(|_, _| {
             let peer = random_peer();
             let headers = Some(vec![empty_header(4)]);
             let headers = peer.bind(headers);
@@ -367,6 +386,7 @@ async fn import__header_5_not_found() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        max_batch_retries: 0,
     };
 
     // when
@@ -379,10 +399,10 @@ async fn import__header_5_not_found() {
 #[tokio::test]
 async fn import__header_4_not_found() {
     // given
-    let mut p2p = MockPeerToPeerPort::default();
+    let mut p2p = peer_to_peer_without_blocks_support();
     p2p.expect_get_sealed_block_headers()
         .times(1)
-        .returning(|_| {
+        .returning(|_, _| {
             let peer = random_peer();
             let headers = Some(vec![empty_header(5)]);
             let headers = peer.bind(headers);
@@ -399,6 +419,7 @@ async fn import__header_4_not_found() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        max_batch_retries: 0,
     };
 
     // when
@@ -421,10 +442,10 @@ async fn import__transactions_not_found() {
         .times(1)
         .returning(|_| Ok(()));
 
-    let mut p2p = MockPeerToPeerPort::default();
+    let mut p2p = peer_to_peer_without_blocks_support();
     p2p.expect_get_sealed_block_headers()
         .times(1)
-        .returning(|range| {
+        .returning(|range, _| {
             let peer = random_peer();
             let headers = Some(range.map(empty_header).collect());
             let headers = peer.bind(headers);
@@ -443,6 +464,7 @@ async fn import__transactions_not_found() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        max_batch_retries: 0,
     };
 
     // when
@@ -465,10 +487,10 @@ async fn import__transactions_not_found_for_header_4() {
         .times(1)
         .returning(|_| Ok(()));
 
-    let mut p2p = MockPeerToPeerPort::default();
+    let mut p2p = peer_to_peer_without_blocks_support();
     p2p.expect_get_sealed_block_headers()
         .times(1)
-        .returning(|range| {
+        .returning(|range, _| {
             let peer = random_peer();
             let headers = Some(range.map(empty_header).collect());
             let headers = peer.bind(headers);
@@ -497,6 +519,7 @@ async fn import__transactions_not_found_for_header_4() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        max_batch_retries: 0,
     };
 
     // when
@@ -519,10 +542,10 @@ async fn import__transactions_not_found_for_header_5() {
         .times(1)
         .returning(|_| Ok(()));
 
-    let mut p2p = MockPeerToPeerPort::default();
+    let mut p2p = peer_to_peer_without_blocks_support();
     p2p.expect_get_sealed_block_headers()
         .times(1)
-        .returning(|range| {
+        .returning(|range, _| {
             let peer = random_peer();
             let headers = Some(range.map(empty_header).collect());
             let headers = peer.bind(headers);
@@ -542,6 +565,7 @@ async fn import__transactions_not_found_for_header_5() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        max_batch_retries: 0,
     };
 
     // when
@@ -554,10 +578,10 @@ async fn import__transactions_not_found_for_header_5() {
 #[tokio::test]
 async fn import__p2p_error() {
     // given
-    let mut p2p = MockPeerToPeerPort::default();
+    let mut p2p = peer_to_peer_without_blocks_support();
     p2p.expect_get_sealed_block_headers()
         .times(1)
-        .returning(|_| Err(anyhow::anyhow!("Some network error")));
+        .returning(|_, _| Err(anyhow::anyhow!("Some network error")));
     p2p.expect_get_transactions().times(0);
 
     let state = State::new(3, 5).into();
@@ -569,6 +593,7 @@ async fn import__p2p_error() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        max_batch_retries: 0,
     };
 
     // when
@@ -591,10 +616,10 @@ async fn import__p2p_error_on_4_transactions() {
         .times(1)
         .returning(|_| Ok(()));
 
-    let mut p2p = MockPeerToPeerPort::default();
+    let mut p2p = peer_to_peer_without_blocks_support();
     p2p.expect_get_sealed_block_headers()
         .times(1)
-        .returning(|range| {
+        .returning(|range, _| {
             let peer = random_peer();
             let headers = Some(range.map(empty_header).collect());
             let headers = peer.bind(headers);
@@ -613,6 +638,7 @@ async fn import__p2p_error_on_4_transactions() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        max_batch_retries: 0,
     };
 
     // when
@@ -641,10 +667,10 @@ async fn import__consensus_error_on_4() {
         .times(0)
         .returning(|_| Ok(()));
 
-    let mut p2p = MockPeerToPeerPort::default();
+    let mut p2p = peer_to_peer_without_blocks_support();
     p2p.expect_get_sealed_block_headers()
         .times(1)
-        .returning(|range| {
+        .returning(|range, _| {
             let peer = random_peer();
             let headers = Some(range.map(empty_header).collect());
             let headers = peer.bind(headers);
@@ -661,6 +687,7 @@ async fn import__consensus_error_on_4() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        max_batch_retries: 0,
     };
 
     // when
@@ -689,10 +716,10 @@ async fn import__consensus_error_on_5() {
         .times(1)
         .returning(|_| Ok(()));
 
-    let mut p2p = MockPeerToPeerPort::default();
+    let mut p2p = peer_to_peer_without_blocks_support();
     p2p.expect_get_sealed_block_headers()
         .times(1)
-        .returning(|range| {
+        .returning(|range, _| {
             let peer = random_peer();
             let headers = Some(range.map(empty_header).collect());
             let headers = peer.bind(headers);
@@ -715,6 +742,7 @@ async fn import__consensus_error_on_5() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        max_batch_retries: 0,
     };
 
     // when
@@ -737,10 +765,10 @@ async fn import__execution_error_on_header_4() {
         .times(1)
         .returning(|_| Ok(()));
 
-    let mut p2p = MockPeerToPeerPort::default();
+    let mut p2p = peer_to_peer_without_blocks_support();
     p2p.expect_get_sealed_block_headers()
         .times(1)
-        .returning(|range| {
+        .returning(|range, _| {
             let peer = random_peer();
             let headers = Some(range.map(empty_header).collect());
             let headers = peer.bind(headers);
@@ -775,6 +803,7 @@ async fn import__execution_error_on_header_4() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        max_batch_retries: 0,
     };
 
     // when
@@ -797,10 +826,10 @@ async fn import__execution_error_on_header_5() {
         .times(1)
         .returning(|_| Ok(()));
 
-    let mut p2p = MockPeerToPeerPort::default();
+    let mut p2p = peer_to_peer_without_blocks_support();
     p2p.expect_get_sealed_block_headers()
         .times(1)
-        .returning(|range| {
+        .returning(|range, _| {
             let peer = random_peer();
             let headers = Some(range.map(empty_header).collect());
             let headers = peer.bind(headers);
@@ -835,6 +864,7 @@ async fn import__execution_error_on_header_5() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        max_batch_retries: 0,
     };
 
     // when
@@ -863,6 +893,7 @@ async fn signature_always_fails() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        max_batch_retries: 0,
     };
 
     // when
@@ -888,10 +919,10 @@ async fn import__can_work_in_two_loops() {
         .times(2)
         .returning(|_| Ok(()));
 
-    let mut p2p = MockPeerToPeerPort::default();
+    let mut p2p = peer_to_peer_without_blocks_support();
     p2p.expect_get_sealed_block_headers()
         .times(2)
-        .returning(move |range| {
+        .returning(move |range, _| {
             state.apply(|s| s.observe(6));
             let peer = random_peer();
             let headers = Some(range.map(empty_header).collect());
@@ -915,6 +946,7 @@ async fn import__can_work_in_two_loops() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        max_batch_retries: 0,
     };
 
     // when
@@ -924,6 +956,278 @@ async fn import__can_work_in_two_loops() {
     assert_eq!((State::new(6, None), true), res);
 }
 
+fn empty_block(height: u32) -> SealedBlock {
+    let SealedBlockHeader {
+        entity: header,
+        consensus,
+    } = empty_header(height);
+    let block = Block::try_from_executed(header, vec![])
+        .expect("Empty transactions match the transactions root");
+    SealedBlock {
+        entity: block,
+        consensus,
+    }
+}
+
+#[tokio::test]
+async fn import__sealed_blocks_are_requested_from_peers_supporting_them() {
+    // given
+    let mut consensus_port = MockConsensusPort::default();
+    consensus_port
+        .expect_check_sealed_header()
+        .times(6)
+        .returning(|_| Ok(true));
+    consensus_port
+        .expect_await_da_height()
+        .times(1)
+        .returning(|_| Ok(()));
+
+    let mut p2p = MockPeerToPeerPort::default();
+    p2p.expect_get_sealed_blocks()
+        .times(1)
+        .returning(|range, _| {
+            let peer = random_peer();
+            let blocks = Some(range.map(empty_block).collect());
+            Ok(peer.bind(blocks))
+        });
+    p2p.expect_get_sealed_block_headers().times(0);
+    p2p.expect_get_transactions().times(0);
+
+    let params = Config {
+        block_stream_buffer_size: 10,
+        header_batch_size: 10,
+        max_batch_retries: 0,
+    };
+    let mocks = Mocks {
+        consensus_port,
+        p2p,
+        executor: DefaultMocks::times([6]),
+    };
+    let state = SharedMutex::new(State::new(None, 5));
+
+    // when
+    let res = test_import_inner(state, mocks, None, params).await;
+
+    // then
+    assert_eq!((State::new(5, None), true), res);
+}
+
+#[tokio::test]
+async fn import__sealed_block_with_invalid_transactions_sends_peer_report() {
+    // given
+    let mut consensus_port = MockConsensusPort::default();
+    consensus_port
+        .expect_check_sealed_header()
+        .returning(|_| Ok(true));
+    consensus_port
+        .expect_await_da_height()
+        .times(1)
+        .returning(|_| Ok(()));
+
+    let mut p2p = MockPeerToPeerPort::default();
+    p2p.expect_get_sealed_blocks()
+        .times(1)
+        .returning(|range, _| {
+            let peer = random_peer();
+            let blocks = range
+                .map(|height| {
+                    let mut block = empty_block(height);
+                    if height == 5 {
+                        block
+                            .entity
+                            .transactions_mut()
+                            .push(Transaction::default_test_tx());
+                    }
+                    block
+                })
+                .collect();
+            Ok(peer.bind(Some(blocks)))
+        });
+    p2p.expect_report_peer()
+        .times(1)
+        .with(
+            mockall::predicate::always(),
+            mockall::predicate::eq(PeerReportReason::InvalidTransactions),
+        )
+        .returning(|_, _| Ok(()));
+
+    let params = Config {
+        block_stream_buffer_size: 10,
+        header_batch_size: 10,
+        max_batch_retries: 0,
+    };
+    let mocks = Mocks {
+        consensus_port,
+        p2p,
+        executor: DefaultMocks::times([5]),
+    };
+    let state = SharedMutex::new(State::new(None, 5));
+
+    // when
+    let res = test_import_inner(state, mocks, None, params).await;
+
+    // then
+    assert_eq!((State::new(4, None), false), res);
+}
+
+#[tokio::test]
+async fn import__rest_of_incomplete_batch_is_requested_again() {
+    // given
+    let mut consensus_port = MockConsensusPort::default();
+    consensus_port
+        .expect_check_sealed_header()
+        .times(6)
+        .returning(|_| Ok(true));
+    consensus_port
+        .expect_await_da_height()
+        .times(2)
+        .returning(|_| Ok(()));
+
+    let mut p2p = MockPeerToPeerPort::default();
+    p2p.expect_get_sealed_blocks()
+        .times(1)
+        .with(mockall::predicate::eq(0..6), mockall::predicate::always())
+        .returning(|_, _| {
+            let peer = random_peer();
+            let blocks = Some((0..3).map(empty_block).collect());
+            Ok(peer.bind(blocks))
+        });
+    p2p.expect_get_sealed_blocks()
+        .times(1)
+        .with(mockall::predicate::eq(3..6), mockall::predicate::always())
+        .returning(|range, _| {
+            let peer = random_peer();
+            let blocks = Some(range.map(empty_block).collect());
+            Ok(peer.bind(blocks))
+        });
+
+    let params = Config {
+        block_stream_buffer_size: 10,
+        header_batch_size: 10,
+        max_batch_retries: 1,
+    };
+    let mocks = Mocks {
+        consensus_port,
+        p2p,
+        executor: DefaultMocks::times([6]),
+    };
+    let state = SharedMutex::new(State::new(None, 5));
+
+    // when
+    let res = test_import_inner(state, mocks, None, params).await;
+
+    // then
+    assert_eq!((State::new(5, None), true), res);
+}
+
+#[tokio::test]
+async fn import__retry_excludes_failed_peer_and_reports_each_peer_for_its_blocks() {
+    // given
+    let mut consensus_port = MockConsensusPort::default();
+    consensus_port
+        .expect_check_sealed_header()
+        .times(6)
+        .returning(|_| Ok(true));
+    consensus_port
+        .expect_await_da_height()
+        .times(2)
+        .returning(|_| Ok(()));
+    let failed_peer = PeerId::from(vec![1]);
+    let retry_peer = PeerId::from(vec![2]);
+    let mut p2p = MockPeerToPeerPort::default();
+    p2p.expect_get_sealed_blocks()
+        .times(1)
+        .with(
+            mockall::predicate::eq(0..6),
+            mockall::predicate::eq(HashSet::new()),
+        )
+        .returning({
+            let peer = failed_peer.clone();
+            move |_, _| {
+                let blocks = Some((0..3).map(empty_block).collect());
+                Ok(peer.clone().bind(blocks))
+            }
+        });
+    p2p.expect_get_sealed_blocks()
+        .times(1)
+        .with(
+            mockall::predicate::eq(3..6),
+            mockall::predicate::eq(HashSet::from([failed_peer.clone()])),
+        )
+        .returning({
+            let peer = retry_peer.clone();
+            move |range, _| {
+                let blocks = Some(range.map(empty_block).collect());
+                Ok(peer.clone().bind(blocks))
+            }
+        });
+    let reports = Arc::new(std::sync::Mutex::new(vec![]));
+    p2p.expect_report_peer().returning({
+        let reports = reports.clone();
+        move |peer, reason| {
+            reports.lock().unwrap().push((peer, reason));
+            Ok(())
+        }
+    });
+
+    let params = Config {
+        block_stream_buffer_size: 10,
+        header_batch_size: 10,
+        max_batch_retries: 1,
+    };
+    let mocks = Mocks {
+        consensus_port,
+        p2p,
+        executor: DefaultMocks::times([6]),
+    };
+    let state = SharedMutex::new(State::new(None, 5));
+
+    // when
+    let res = test_import_inner(state, mocks, None, params).await;
+
+    // then
+    assert_eq!((State::new(5, None), true), res);
+    let successful_imports = reports
+        .lock()
+        .unwrap()
+        .iter()
+        .filter(|(_, reason)| *reason == PeerReportReason::SuccessfulBlockImport)
+        .map(|(peer, _)| peer.clone())
+        .collect::<Vec<_>>();
+    assert_eq!(successful_imports, vec![failed_peer, retry_peer]);
+}
+
+#[tokio::test]
+async fn import__failed_batch_is_given_up_after_max_retries() {
+    // given
+    let max_batch_retries = 2;
+    let mut p2p = MockPeerToPeerPort::default();
+    p2p.expect_get_sealed_blocks()
+        .times(max_batch_retries + 1)
+        .returning(|_, _| {
+            let peer = random_peer();
+            Ok(peer.bind(Some(vec![])))
+        });
+
+    let params = Config {
+        block_stream_buffer_size: 10,
+        header_batch_size: 10,
+        max_batch_retries,
+    };
+    let mocks = Mocks {
+        p2p,
+        consensus_port: DefaultMocks::times([0]),
+        executor: DefaultMocks::times([0]),
+    };
+    let state = State::new(3, 5).into();
+
+    // when
+    let res = test_import_inner(state, mocks, None, params).await;
+
+    // then
+    assert_eq!((State::new(3, None), false), res);
+}
+
 async fn test_import_inner(
     state: SharedMutex<State>,
     mocks: Mocks,
@@ -1038,8 +1342,8 @@ async fn import__execution_error_on_header_4_when_awaits_for_1000000_blocks() {
         .expect_await_da_height()
         .returning(|_| Ok(()));
 
-    let mut p2p = MockPeerToPeerPort::default();
-    p2p.expect_get_sealed_block_headers().returning(|range| {
+    let mut p2p = peer_to_peer_without_blocks_support();
+    p2p.expect_get_sealed_block_headers().returning(|range, _| {
         let peer = random_peer();
         let headers = Some(range.map(empty_header).collect());
         let headers = peer.bind(headers);
@@ -1072,6 +1376,7 @@ async fn import__execution_error_on_header_4_when_awaits_for_1000000_blocks() {
     let params = Config {
         block_stream_buffer_size: 1,
         header_batch_size: 1,
+        max_batch_retries: 0,
     };
 
     // when
@@ -1161,6 +1466,7 @@ impl PeerReportTestBuilder {
         let params = Config {
             block_stream_buffer_size: 10,
             header_batch_size: 10,
+            max_batch_retries: 0,
         };
 
         let import = Import {
@@ -1183,18 +1489,19 @@ impl PeerReportTestBuilder {
     where
         R: IntoIterator<Item = PeerReportReason>,
     {
-        let mut p2p = MockPeerToPeerPort::default();
+        let mut p2p = peer_to_peer_without_blocks_support();
 
         let peer_id = self.shared_peer_id.clone();
         if let Some(get_headers) = self.get_sealed_headers.clone() {
-            p2p.expect_get_sealed_block_headers().returning(move |_| {
-                let peer: PeerId = peer_id.clone().into();
-                let headers = peer.bind(get_headers.clone());
-                Ok(headers)
-            });
+            p2p.expect_get_sealed_block_headers()
+                .returning(move |_, _| {
+                    let peer: PeerId = peer_id.clone().into();
+                    let headers = peer.bind(get_headers.clone());
+                    Ok(headers)
+                });
         } else {
             p2p.expect_get_sealed_block_headers()
-                .returning(move |range| {
+                .returning(move |range, _| {
                     let peer: PeerId = peer_id.clone().into();
                     let headers = Some(range.map(empty_header).collect());
                     let headers = peer.bind(headers);
@@ -1320,12 +1627,12 @@ impl DefaultMocks for MockPeerToPeerPort {
         T: IntoIterator<Item = usize> + Clone,
         <T as IntoIterator>::IntoIter: Clone,
     {
-        let mut p2p = MockPeerToPeerPort::default();
+        let mut p2p = peer_to_peer_without_blocks_support();
         let mut t = t.into_iter().cycle();
 
         p2p.expect_get_sealed_block_headers()
             .times(1)
-            .returning(|range| {
+            .returning(|range, _| {
                 let peer = random_peer();
                 let headers = Some(range.map(empty_header).collect());
                 let headers = peer.bind(headers);
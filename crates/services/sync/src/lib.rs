@@ -8,6 +8,7 @@
 //! # Sync Service
 //! Responsible for syncing the blockchain from the network.
 
+pub mod gossip;
 pub mod import;
 pub mod ports;
 pub mod service;
@@ -9,12 +9,18 @@ use fuel_core_types::{
     },
     fuel_types::BlockHeight,
     services::p2p::{
+        BlockGossipData,
+        GossipsubMessageAcceptance,
+        GossipsubMessageInfo,
         PeerId,
         SourcePeer,
         Transactions,
     },
 };
-use std::ops::Range;
+use std::{
+    collections::HashSet,
+    ops::Range,
+};
 
 /// Possible reasons to report a peer
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -41,12 +47,22 @@ pub trait PeerToPeerPort {
     /// Stream of newly observed block heights.
     fn height_stream(&self) -> BoxStream<BlockHeight>;
 
-    /// Request a range of sealed block headers from the network.
+    /// Request a range of sealed block headers from the network,
+    /// skipping the `excluded_peers`.
     async fn get_sealed_block_headers(
         &self,
         block_height_range: Range<u32>,
+        excluded_peers: HashSet<PeerId>,
     ) -> anyhow::Result<SourcePeer<Option<Vec<SealedBlockHeader>>>>;
 
+    /// Request a range of sealed blocks, including their transactions,
+    /// from the network, skipping the `excluded_peers`.
+    async fn get_sealed_blocks(
+        &self,
+        block_height_range: Range<u32>,
+        excluded_peers: HashSet<PeerId>,
+    ) -> anyhow::Result<SourcePeer<Option<Vec<SealedBlock>>>>;
+
     /// Request transactions from the network for the given block
     /// and source peer.
     async fn get_transactions(
@@ -56,6 +72,16 @@ pub trait PeerToPeerPort {
 
     /// Report a peer for some reason to modify their reputation.
     fn report_peer(&self, peer: PeerId, report: PeerReportReason) -> anyhow::Result<()>;
+
+    /// Stream of blocks gossiped by the block producer.
+    fn gossiped_block_stream(&self) -> BoxStream<BlockGossipData>;
+
+    /// Report the validity of the gossiped block to the network.
+    fn notify_gossip_block_validity(
+        &self,
+        message_info: GossipsubMessageInfo,
+        validity: GossipsubMessageAcceptance,
+    ) -> anyhow::Result<()>;
 }
 
 #[cfg_attr(any(test, feature = "benchmarking"), mockall::automock)]
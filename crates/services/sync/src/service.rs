@@ -2,6 +2,7 @@
 use std::sync::Arc;
 
 use crate::{
+    gossip::GossipImport,
     import::{
         Config,
         Import,
@@ -28,7 +29,10 @@ use fuel_core_services::{
     SharedMutex,
     StateWatcher,
 };
-use fuel_core_types::fuel_types::BlockHeight;
+use fuel_core_types::{
+    fuel_types::BlockHeight,
+    services::p2p::BlockGossipData,
+};
 use futures::StreamExt;
 use tokio::sync::Notify;
 
@@ -50,10 +54,12 @@ where
 {
     let height_stream = p2p.height_stream();
     let committed_height_stream = executor.committed_height_stream();
+    let gossiped_block_stream = p2p.gossiped_block_stream();
     let state = State::new(Some(current_fuel_block_height.into()), None);
     Ok(ServiceRunner::new(SyncTask::new(
         height_stream,
         committed_height_stream,
+        gossiped_block_stream,
         state,
         params,
         p2p,
@@ -63,7 +69,7 @@ where
 }
 
 /// Task for syncing heights.
-/// Contains import task as a child task.
+/// Contains import and gossip tasks as child tasks.
 pub struct SyncTask<P, E, C>
 where
     P: PeerToPeerPort + Send + Sync + 'static,
@@ -72,19 +78,24 @@ where
 {
     sync_heights: SyncHeights,
     import_task_handle: ServiceRunner<ImportTask<P, E, C>>,
+    gossip_task_handle: ServiceRunner<GossipTask<P, E, C>>,
 }
 
 struct ImportTask<P, E, C>(Import<P, E, C>);
 
+struct GossipTask<P, E, C>(GossipImport<P, E, C>);
+
 impl<P, E, C> SyncTask<P, E, C>
 where
     P: PeerToPeerPort + Send + Sync + 'static,
     E: BlockImporterPort + Send + Sync + 'static,
     C: ConsensusPort + Send + Sync + 'static,
 {
+    #[allow(clippy::too_many_arguments)]
     fn new(
         height_stream: BoxStream<BlockHeight>,
         committed_height_stream: BoxStream<BlockHeight>,
+        gossiped_block_stream: BoxStream<BlockGossipData>,
         state: State,
         params: Config,
         p2p: P,
@@ -102,11 +113,21 @@ where
             state.clone(),
             notify.clone(),
         );
+        let gossip = GossipImport::new(
+            gossiped_block_stream,
+            state.clone(),
+            notify.clone(),
+            p2p.clone(),
+            executor.clone(),
+            consensus.clone(),
+        );
+        let gossip_task_handle = ServiceRunner::new(GossipTask(gossip));
         let import = Import::new(state, notify, params, p2p, executor, consensus);
         let import_task_handle = ServiceRunner::new(ImportTask(import));
         Ok(Self {
             sync_heights,
             import_task_handle,
+            gossip_task_handle,
         })
     }
 }
@@ -124,6 +145,7 @@ where
     }
 
     async fn shutdown(self) -> anyhow::Result<()> {
+        self.gossip_task_handle.stop_and_await().await?;
         self.import_task_handle.stop_and_await().await?;
         Ok(())
     }
@@ -152,6 +174,7 @@ where
     ) -> anyhow::Result<Self::Task> {
         let mut sync_watcher = watcher.clone();
         self.import_task_handle.start_and_await().await?;
+        self.gossip_task_handle.start_and_await().await?;
         let mut import_watcher = self.import_task_handle.state_watcher();
         self.sync_heights.map_stream(|height_stream| {
             height_stream
@@ -211,3 +234,51 @@ where
         Ok(self)
     }
 }
+
+#[async_trait::async_trait]
+impl<P, E, C> RunnableTask for GossipTask<P, E, C>
+where
+    P: PeerToPeerPort + Send + Sync + 'static,
+    E: BlockImporterPort + Send + Sync + 'static,
+    C: ConsensusPort + Send + Sync + 'static,
+{
+    #[tracing::instrument(level = "debug", skip_all, err, ret)]
+    async fn run(&mut self, watcher: &mut StateWatcher) -> anyhow::Result<bool> {
+        tokio::select! {
+            biased;
+            _ = watcher.while_started() => Ok(false),
+            imported = self.0.import() => Ok(imported.is_some()),
+        }
+    }
+
+    async fn shutdown(self) -> anyhow::Result<()> {
+        // Nothing to shut down because we don't have any temporary state that should be dumped,
+        // and we don't spawn any sub-tasks that we need to finish or await.
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl<P, E, C> RunnableService for GossipTask<P, E, C>
+where
+    P: PeerToPeerPort + Send + Sync + 'static,
+    E: BlockImporterPort + Send + Sync + 'static,
+    C: ConsensusPort + Send + Sync + 'static,
+{
+    const NAME: &'static str = "GossipTask";
+
+    type SharedData = ();
+    type TaskParams = ();
+
+    type Task = GossipTask<P, E, C>;
+
+    fn shared_data(&self) -> Self::SharedData {}
+
+    async fn into_task(
+        self,
+        _: &StateWatcher,
+        _: Self::TaskParams,
+    ) -> anyhow::Result<Self::Task> {
+        Ok(self)
+    }
+}
@@ -26,6 +26,8 @@ use super::*;
 async fn test_new_service() {
     let mut p2p = MockPeerToPeerPort::default();
     p2p.expect_report_peer().returning(|_, _| Ok(()));
+    p2p.expect_gossiped_block_stream()
+        .returning(|| futures::stream::pending().into_boxed());
     p2p.expect_height_stream().returning(|| {
         stream::iter(
             std::iter::successors(Some(6u32), |n| Some(n + 1)).map(BlockHeight::from),
@@ -38,7 +40,9 @@ async fn test_new_service() {
         })
         .into_boxed()
     });
-    p2p.expect_get_sealed_block_headers().returning(|range| {
+    p2p.expect_get_sealed_blocks()
+        .returning(|_, _| Err(anyhow::anyhow!("Blocks requests are not supported")));
+    p2p.expect_get_sealed_block_headers().returning(|range, _| {
         let peer = random_peer();
         let headers = Some(range.map(empty_header).collect::<Vec<_>>());
         let headers = peer.bind(headers);
@@ -66,6 +70,7 @@ async fn test_new_service() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        max_batch_retries: 0,
     };
     let s = new_service(4u32.into(), p2p, importer, consensus, params).unwrap();
 
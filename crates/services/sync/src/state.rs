@@ -14,6 +14,9 @@ mod test;
 /// The state takes evidence and produces a status.
 pub struct State {
     status: Status,
+    /// The height claimed by the import of the gossiped block.
+    /// Ranges are not processed while the claim is held.
+    claimed: Option<u32>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -60,12 +63,19 @@ impl State {
             (None, None) => Status::Uninitialized,
         };
         tracing::debug!("Initial status: {:?}", status);
-        Self { status }
+        Self {
+            status,
+            claimed: None,
+        }
     }
 
     #[tracing::instrument]
     /// Get the current range to process.
     pub fn process_range(&self) -> Option<RangeInclusive<u32>> {
+        if let Some(claimed) = self.claimed {
+            tracing::debug!("The height {} is claimed, nothing to process", claimed);
+            return None
+        }
         match &self.status {
             Status::Processing(range) => {
                 tracing::debug!("Processing range: {:?}", range);
@@ -117,6 +127,34 @@ impl State {
         self.apply_status(new_status);
     }
 
+    #[tracing::instrument]
+    /// Claims the `height` for the import of the gossiped block.
+    /// Returns `true` if the `height` directly follows the committed height
+    /// and there is no range being processed. The `height` becomes the processed range,
+    /// so the heights observed meanwhile are processed after the claim is released.
+    pub fn claim_next(&mut self, height: u32) -> bool {
+        let next = match &self.status {
+            Status::Committed(committed) => committed.checked_add(1) == Some(height),
+            _ => false,
+        };
+        if !next || self.claimed.is_some() {
+            return false
+        }
+        self.apply_status(Some(Status::Processing(height..=height)));
+        self.claimed = Some(height);
+        true
+    }
+
+    #[tracing::instrument]
+    /// Releases the claim of the `height`. If the claimed block was not committed,
+    /// the `height` is left to be processed. Returns `true` if there is a range to process.
+    pub fn release(&mut self, height: u32) -> bool {
+        if self.claimed == Some(height) {
+            self.claimed = None;
+        }
+        self.process_range().is_some()
+    }
+
     #[tracing::instrument]
     /// Record that a block has been observed.
     pub fn observe(&mut self, height: u32) -> bool {
@@ -1,3 +1,5 @@
+#![allow(non_snake_case)]
+
 use super::*;
 use test_case::test_case;
 
@@ -113,3 +115,42 @@ fn test_failed(mut state: State, range: RangeInclusive<u32>) -> Status {
     state.failed_to_process(range);
     state.status
 }
+
+#[test_case(State::new(None, None), 0 => (false, Status::Uninitialized))]
+#[test_case(State::new(10, None), 10 => (false, Status::Committed(10)))]
+#[test_case(State::new(10, None), 11 => (true, Status::Processing(11..=11)))]
+#[test_case(State::new(10, None), 12 => (false, Status::Committed(10)))]
+#[test_case(State::new(10, 20), 11 => (false, Status::Processing(11..=20)))]
+fn test_claim_next(mut state: State, height: u32) -> (bool, Status) {
+    let claimed = state.claim_next(height);
+    (claimed, state.status)
+}
+
+#[test]
+fn claim_next__range_is_not_processed_until_release() {
+    let mut state = State::new(10, None);
+    assert!(state.claim_next(11));
+    assert!(!state.claim_next(11));
+
+    // When
+    state.observe(15);
+
+    // Then
+    assert_eq!(state.process_range(), None);
+    state.commit(11);
+    assert!(state.release(11));
+    assert_eq!(state.process_range(), Some(12..=15));
+}
+
+#[test]
+fn release__failed_claim_is_left_to_process() {
+    let mut state = State::new(10, None);
+    assert!(state.claim_next(11));
+
+    // When
+    let to_process = state.release(11);
+
+    // Then
+    assert!(to_process);
+    assert_eq!(state.process_range(), Some(11..=11));
+}
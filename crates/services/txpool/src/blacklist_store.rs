@@ -0,0 +1,272 @@
+//! The on-disk storage of the changes of the blacklist made at runtime.
+//!
+//! The changes are stored as a text file where each line is the operation (`add` or `remove`),
+//! the kind of the entry (`owner`, `coin`, `message` or `contract`) and its hex representation.
+//! The blacklist configured at the start of the node is not stored, so removing an entry
+//! from the configuration takes effect after the restart.
+
+use crate::config::BlackList;
+use parking_lot::{
+    Mutex,
+    MutexGuard,
+};
+use std::{
+    fs,
+    io::{
+        self,
+        Write,
+    },
+    path::{
+        Path,
+        PathBuf,
+    },
+    str::FromStr,
+};
+
+const ADD: &str = "add";
+const REMOVE: &str = "remove";
+const OWNER: &str = "owner";
+const COIN: &str = "coin";
+const MESSAGE: &str = "message";
+const CONTRACT: &str = "contract";
+
+/// The entries added to and removed from the configured blacklist at runtime.
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+pub struct BlacklistChanges {
+    pub added: BlackList,
+    pub removed: BlackList,
+}
+
+impl BlacklistChanges {
+    pub fn add(&mut self, entries: &BlackList) {
+        self.removed.remove(entries);
+        self.added.extend(entries.clone());
+    }
+
+    pub fn remove(&mut self, entries: &BlackList) {
+        self.added.remove(entries);
+        self.removed.extend(entries.clone());
+    }
+
+    /// Applies the changes to the configured `blacklist`. The removals of entries
+    /// that are not configured anymore are dropped, so they don't hide the entries
+    /// configured again later.
+    pub fn apply(&mut self, blacklist: &mut BlackList) {
+        self.removed.retain(blacklist);
+        blacklist.extend(self.added.clone());
+        blacklist.remove(&self.removed);
+    }
+}
+
+#[derive(Debug)]
+pub struct BlacklistStore {
+    path: PathBuf,
+    changes: Mutex<BlacklistChanges>,
+}
+
+impl BlacklistStore {
+    /// Loads the changes stored at the `path` and applies them to the configured `blacklist`.
+    pub fn open(path: PathBuf, blacklist: &mut BlackList) -> io::Result<Self> {
+        let mut changes = load(&path)?;
+        changes.apply(blacklist);
+        Ok(Self {
+            path,
+            changes: Mutex::new(changes),
+        })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Locks the changes. The lock is held while the change is applied and persisted,
+    /// so the changes are stored in the same order as applied.
+    pub fn lock(&self) -> MutexGuard<BlacklistChanges> {
+        self.changes.lock()
+    }
+
+    /// Replaces the stored changes with the `changes`.
+    pub fn save(&self, changes: &BlacklistChanges) -> io::Result<()> {
+        let mut content = vec![];
+        write_entries(&mut content, ADD, &changes.added)?;
+        write_entries(&mut content, REMOVE, &changes.removed)?;
+
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let tmp_path = self.path.with_extension("tmp");
+        let mut file = fs::File::create(&tmp_path)?;
+        file.write_all(&content)?;
+        file.sync_all()?;
+        fs::rename(tmp_path, &self.path)
+    }
+}
+
+/// Reads the changes. The missing file is no changes.
+fn load(path: &Path) -> io::Result<BlacklistChanges> {
+    let content = match fs::read_to_string(path) {
+        Ok(content) => content,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => {
+            return Ok(BlacklistChanges::default())
+        }
+        Err(e) => return Err(e),
+    };
+
+    let mut changes = BlacklistChanges::default();
+    for (number, line) in content.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() {
+            continue
+        }
+        let invalid_line = || {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("Invalid entry of the blacklist at line {number}: {line}"),
+            )
+        };
+
+        let mut parts = line.split_whitespace();
+        let (Some(operation), Some(kind), Some(value), None) =
+            (parts.next(), parts.next(), parts.next(), parts.next())
+        else {
+            return Err(invalid_line())
+        };
+        let blacklist = match operation {
+            ADD => &mut changes.added,
+            REMOVE => &mut changes.removed,
+            _ => return Err(invalid_line()),
+        };
+        match kind {
+            OWNER => {
+                blacklist
+                    .owners
+                    .insert(parse(value).ok_or_else(invalid_line)?);
+            }
+            COIN => {
+                blacklist
+                    .coins
+                    .insert(parse(value).ok_or_else(invalid_line)?);
+            }
+            MESSAGE => {
+                blacklist
+                    .messages
+                    .insert(parse(value).ok_or_else(invalid_line)?);
+            }
+            CONTRACT => {
+                blacklist
+                    .contracts
+                    .insert(parse(value).ok_or_else(invalid_line)?);
+            }
+            _ => return Err(invalid_line()),
+        }
+    }
+
+    Ok(changes)
+}
+
+fn write_entries(
+    content: &mut Vec<u8>,
+    operation: &str,
+    blacklist: &BlackList,
+) -> io::Result<()> {
+    for owner in blacklist.owners() {
+        writeln!(content, "{operation} {OWNER} {owner:#x}")?;
+    }
+    for coin in blacklist.coins() {
+        writeln!(content, "{operation} {COIN} {coin:#x}")?;
+    }
+    for message in blacklist.messages() {
+        writeln!(content, "{operation} {MESSAGE} {message:#x}")?;
+    }
+    for contract in blacklist.contracts() {
+        writeln!(content, "{operation} {CONTRACT} {contract:#x}")?;
+    }
+    Ok(())
+}
+
+fn parse<T: FromStr>(value: &str) -> Option<T> {
+    value.trim().parse().ok()
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use super::*;
+    use fuel_core_types::fuel_tx::UtxoId;
+
+    fn blacklist(owners: &[u8]) -> BlackList {
+        BlackList::new(
+            owners.iter().map(|owner| [*owner; 32].into()).collect(),
+            vec![],
+            vec![],
+            vec![],
+        )
+    }
+
+    #[test]
+    fn open__missing_file_keeps_configured_blacklist() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut configured = blacklist(&[1]);
+
+        // When
+        let store =
+            BlacklistStore::open(dir.path().join("blacklist"), &mut configured).unwrap();
+
+        // Then
+        assert_eq!(configured, blacklist(&[1]));
+        assert_eq!(*store.lock(), BlacklistChanges::default());
+    }
+
+    #[test]
+    fn open__applies_saved_changes() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("blacklist");
+        let store =
+            BlacklistStore::open(path.clone(), &mut BlackList::default()).unwrap();
+        let mut changes = BlacklistChanges::default();
+        changes.add(&BlackList::new(
+            vec![[1; 32].into()],
+            vec![UtxoId::new([2; 32].into(), 3)],
+            vec![[4; 32].into()],
+            vec![[5; 32].into()],
+        ));
+        changes.remove(&blacklist(&[6, 7]));
+        store.save(&changes).unwrap();
+        let mut configured = blacklist(&[6, 8]);
+
+        // When
+        let store = BlacklistStore::open(path, &mut configured).unwrap();
+
+        // Then
+        let mut expected = changes.added.clone();
+        expected.extend(blacklist(&[8]));
+        assert_eq!(configured, expected);
+        // The removal of the entry that is not configured is dropped.
+        assert_eq!(store.lock().removed, blacklist(&[6]));
+    }
+
+    #[test]
+    fn changes__last_operation_on_entry_wins() {
+        let mut changes = BlacklistChanges::default();
+
+        // When
+        changes.add(&blacklist(&[1, 2]));
+        changes.remove(&blacklist(&[1]));
+
+        // Then
+        assert_eq!(changes.added, blacklist(&[2]));
+        assert_eq!(changes.removed, blacklist(&[1]));
+    }
+
+    #[test]
+    fn open__fails_on_unknown_entry() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("blacklist");
+
+        // When
+        fs::write(&path, "add unknown 0x01\n").unwrap();
+
+        // Then
+        assert!(BlacklistStore::open(path, &mut BlackList::default()).is_err());
+    }
+}
@@ -1,4 +1,7 @@
-use crate::types::ContractId;
+use crate::{
+    transaction_selector::SelectionStrategy,
+    types::ContractId,
+};
 use fuel_core_types::{
     fuel_tx::{
         Address,
@@ -8,6 +11,7 @@ use fuel_core_types::{
 };
 use std::{
     collections::HashSet,
+    path::PathBuf,
     time::Duration,
 };
 
@@ -53,6 +57,101 @@ impl BlackList {
     pub fn contains_contract(&self, contract_id: &ContractId) -> bool {
         self.contracts.contains(contract_id)
     }
+
+    pub fn owners(&self) -> impl Iterator<Item = &Address> {
+        self.owners.iter()
+    }
+
+    pub fn coins(&self) -> impl Iterator<Item = &UtxoId> {
+        self.coins.iter()
+    }
+
+    pub fn messages(&self) -> impl Iterator<Item = &Nonce> {
+        self.messages.iter()
+    }
+
+    pub fn contracts(&self) -> impl Iterator<Item = &ContractId> {
+        self.contracts.iter()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.owners.is_empty()
+            && self.coins.is_empty()
+            && self.messages.is_empty()
+            && self.contracts.is_empty()
+    }
+
+    /// Adds all entries of the `other` blacklist.
+    pub fn extend(&mut self, other: BlackList) {
+        self.owners.extend(other.owners);
+        self.coins.extend(other.coins);
+        self.messages.extend(other.messages);
+        self.contracts.extend(other.contracts);
+    }
+
+    /// Keeps only entries of the `other` blacklist.
+    pub fn retain(&mut self, other: &BlackList) {
+        self.owners.retain(|owner| other.owners.contains(owner));
+        self.coins.retain(|coin| other.coins.contains(coin));
+        self.messages
+            .retain(|message| other.messages.contains(message));
+        self.contracts
+            .retain(|contract| other.contracts.contains(contract));
+    }
+
+    /// Removes all entries of the `other` blacklist.
+    pub fn remove(&mut self, other: &BlackList) {
+        self.owners.retain(|owner| !other.owners.contains(owner));
+        self.coins.retain(|coin| !other.coins.contains(coin));
+        self.messages
+            .retain(|message| !other.messages.contains(message));
+        self.contracts
+            .retain(|contract| !other.contracts.contains(contract));
+    }
+}
+
+/// The limits applied to the pending transactions of a single owner or a single peer.
+/// `None` means that the limit is not applied.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Quota {
+    /// The maximum number of pending transactions.
+    pub max_txs: Option<usize>,
+    /// The maximum total `max_gas` of pending transactions.
+    pub max_gas: Option<u64>,
+    /// The maximum number of transactions accepted during the last minute.
+    pub max_submissions_per_minute: Option<usize>,
+}
+
+/// The configuration of the verification of transactions received via gossip.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GossipValidationConfig {
+    /// The maximum number of gossiped transactions verified together.
+    pub batch_size: usize,
+    /// The time to wait for more gossiped transactions before verifying an incomplete batch.
+    pub batch_timeout: Duration,
+    /// The time since the transaction is received until its acceptance is reported to gossipsub.
+    /// Transactions that are not verified in time are ignored.
+    pub validation_timeout: Duration,
+    /// The number of threads verifying signatures and predicates.
+    pub number_of_threads: usize,
+    /// The maximum number of transactions waiting for the verification.
+    /// Transactions received above this limit are ignored.
+    pub number_of_pending_tasks: usize,
+    /// The number of recently rejected transactions remembered to drop their duplicates early.
+    pub rejected_cache_size: usize,
+}
+
+impl Default for GossipValidationConfig {
+    fn default() -> Self {
+        Self {
+            batch_size: 64,
+            batch_timeout: Duration::from_millis(10),
+            validation_timeout: Duration::from_secs(5),
+            number_of_threads: 2,
+            number_of_pending_tasks: 1024 * 10,
+            rejected_cache_size: 10_000,
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -71,6 +170,23 @@ pub struct Config {
     pub number_of_active_subscription: usize,
     /// The blacklist used to validate transaction.
     pub blacklist: BlackList,
+    /// The strategy used to select transactions for the next block.
+    pub selection_strategy: SelectionStrategy,
+    /// The minimum increase of the tip in percents required to replace
+    /// a transaction spending the same inputs.
+    pub min_replacement_tip_bump_percent: u64,
+    /// The path to the journal of accepted transactions. If set, the pending
+    /// transactions are restored from the journal after the restart of the node.
+    pub journal_path: Option<PathBuf>,
+    /// The quota of each owner of the inputs of transactions.
+    pub owner_quota: Quota,
+    /// The quota of each peer gossiping transactions.
+    pub peer_quota: Quota,
+    /// The path to the file where the changes of the blacklist made at runtime are persisted.
+    /// The persisted changes are applied to the `blacklist` after the restart of the node.
+    pub blacklist_path: Option<PathBuf>,
+    /// The configuration of the verification of gossiped transactions.
+    pub gossip_validation: GossipValidationConfig,
 }
 
 #[cfg(feature = "test-helpers")]
@@ -91,6 +207,13 @@ impl Default for Config {
             transaction_ttl,
             number_of_active_subscription,
             Default::default(),
+            Default::default(),
+            0,
+            None,
+            Default::default(),
+            Default::default(),
+            None,
+            Default::default(),
         )
     }
 }
@@ -105,6 +228,13 @@ impl Config {
         transaction_ttl: Duration,
         number_of_active_subscription: usize,
         blacklist: BlackList,
+        selection_strategy: SelectionStrategy,
+        min_replacement_tip_bump_percent: u64,
+        journal_path: Option<PathBuf>,
+        owner_quota: Quota,
+        peer_quota: Quota,
+        blacklist_path: Option<PathBuf>,
+        gossip_validation: GossipValidationConfig,
     ) -> Self {
         // # Dev-note: If you add a new field, be sure that this field is propagated correctly
         //  in all places where `new` is used.
@@ -116,6 +246,13 @@ impl Config {
             transaction_ttl,
             number_of_active_subscription,
             blacklist,
+            selection_strategy,
+            min_replacement_tip_bump_percent,
+            journal_path,
+            owner_quota,
+            peer_quota,
+            blacklist_path,
+            gossip_validation,
         }
     }
 }
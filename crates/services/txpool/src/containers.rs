@@ -1,4 +1,5 @@
 pub mod dependency;
+pub mod quota;
 pub mod sort;
 pub mod time_sort;
 pub mod tip_per_gas_sort;
@@ -52,6 +52,8 @@ pub struct Dependency {
     max_depth: usize,
     /// utxo-validation feature flag
     utxo_validation: bool,
+    /// The minimum increase of the tip in percents required to replace a transaction.
+    min_replacement_tip_bump_percent: u64,
 }
 
 #[derive(Debug, Clone)]
@@ -103,7 +105,11 @@ pub struct BlobState {
 }
 
 impl Dependency {
-    pub fn new(max_depth: usize, utxo_validation: bool) -> Self {
+    pub fn new(
+        max_depth: usize,
+        utxo_validation: bool,
+        min_replacement_tip_bump_percent: u64,
+    ) -> Self {
         Self {
             coins: HashMap::new(),
             contracts: HashMap::new(),
@@ -111,9 +117,31 @@ impl Dependency {
             messages: HashMap::new(),
             max_depth,
             utxo_validation,
+            min_replacement_tip_bump_percent,
         }
     }
 
+    /// Checks that the `tip` is high enough to replace the transaction with the
+    /// `replaced_tip`, according to the `min_replacement_tip_bump_percent`.
+    fn check_replacement_tip(
+        &self,
+        replaced_tx_id: TxId,
+        replaced_tip: Word,
+        tip: Word,
+    ) -> Result<(), Error> {
+        let bump = replaced_tip
+            .saturating_mul(self.min_replacement_tip_bump_percent)
+            .div_ceil(100);
+        let min_tip = replaced_tip.saturating_add(bump);
+        if tip < min_tip {
+            return Err(Error::NotInsertedReplacementTipTooLow(
+                replaced_tx_id,
+                min_tip,
+            ))
+        }
+        Ok(())
+    }
+
     fn check_if_coin_input_can_spend_output(
         output: &Output,
         input: &Input,
@@ -238,6 +266,7 @@ impl Dependency {
                 if state.tip >= tx.tip() {
                     return Err(Error::NotInsertedCollisionBlobId(*blob_id))
                 } else {
+                    self.check_replacement_tip(state.origin_tx_id, state.tip, tx.tip())?;
                     collided.push(state.origin_tx_id);
                 }
             }
@@ -274,7 +303,13 @@ impl Dependency {
                                 return Err(Error::NotInsertedCollision(
                                     *spend_by, *utxo_id,
                                 ))
-                            } else if state.is_in_database() {
+                            }
+                            self.check_replacement_tip(
+                                *spend_by,
+                                txpool_tx.tip(),
+                                tx.tip(),
+                            )?;
+                            if state.is_in_database() {
                                 // this means it is loaded from db. Get tx to compare output.
                                 if self.utxo_validation {
                                     let coin = db
@@ -365,6 +400,11 @@ impl Dependency {
                                 *nonce,
                             ))
                         } else {
+                            self.check_replacement_tip(
+                                state.spent_by,
+                                state.tip,
+                                tx.tip(),
+                            )?;
                             collided.push(state.spent_by);
                         }
                     }
@@ -436,6 +476,7 @@ impl Dependency {
                     let origin = contract.origin.expect(
                         "Only contract without origin are the ones that are inside DB. And we check depth for that, so we are okay to just unwrap"
                         );
+                    self.check_replacement_tip(*origin.tx_id(), contract.tip, tx.tip())?;
                     collided.push(*origin.tx_id());
                 }
             }
@@ -452,14 +493,66 @@ impl Dependency {
         ))
     }
 
+    /// Returns the transactions that would be removed from the txpool by the insertion of
+    /// the `tx`: the transactions colliding with it and all transactions depending on them.
+    /// It doesn't change any dependency.
+    pub(crate) fn removed_by_insertion<DB>(
+        &self,
+        txs: &HashMap<TxId, TxInfo>,
+        db: &DB,
+        tx: &ArcPoolTx,
+    ) -> Result<Vec<TxId>, Error>
+    where
+        DB: TxPoolDb,
+    {
+        let (_, _, _, _, _, collided) = self.check_for_collision(txs, db, tx)?;
+
+        let mut removed = HashSet::new();
+        let mut pending = collided;
+        while let Some(tx_id) = pending.pop() {
+            if !removed.insert(tx_id) {
+                continue
+            }
+            let Some(info) = txs.get(&tx_id) else {
+                continue
+            };
+            for (index, output) in info.tx().outputs().iter().enumerate() {
+                match output {
+                    Output::Coin { .. }
+                    | Output::Change { .. }
+                    | Output::Variable { .. } => {
+                        let Ok(index) = u16::try_from(index) else {
+                            continue
+                        };
+                        let utxo_id = UtxoId::new(tx_id, index);
+                        if let Some(spend_by) =
+                            self.coins.get(&utxo_id).and_then(|state| state.is_spend_by)
+                        {
+                            pending.push(spend_by);
+                        }
+                    }
+                    Output::ContractCreated { contract_id, .. } => {
+                        if let Some(state) = self.contracts.get(contract_id) {
+                            pending.extend(state.used_by.iter().copied());
+                        }
+                    }
+                    Output::Contract(_) => {}
+                }
+            }
+        }
+
+        Ok(removed.into_iter().collect())
+    }
+
     /// insert tx inside dependency
-    /// return list of transactions that are removed from txpool
+    /// return list of replaced transactions and list of transactions that are removed from txpool
+    #[allow(clippy::type_complexity)]
     pub(crate) fn insert<'a, DB>(
         &'a mut self,
         txs: &'a HashMap<TxId, TxInfo>,
         db: &DB,
         tx: &'a ArcPoolTx,
-    ) -> Result<Vec<ArcPoolTx>, Error>
+    ) -> Result<(Vec<TxId>, Vec<ArcPoolTx>), Error>
     where
         DB: TxPoolDb,
     {
@@ -468,9 +561,9 @@ impl Dependency {
 
         // now we are sure that transaction can be included. remove all collided transactions
         let mut removed_tx = Vec::new();
-        for collided in collided.into_iter() {
+        for collided in collided.iter() {
             let collided = txs
-                .get(&collided)
+                .get(collided)
                 .expect("Collided should be present in txpool");
             removed_tx.extend(
                 self.recursively_remove_all_dependencies(txs, collided.tx().clone()),
@@ -550,7 +643,7 @@ impl Dependency {
             };
         }
 
-        Ok(removed_tx)
+        Ok((collided, removed_tx))
     }
 
     /// Remove all pending txs that depend on the outputs of the provided tx
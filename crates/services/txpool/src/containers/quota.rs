@@ -0,0 +1,288 @@
+use crate::{
+    config::Quota,
+    types::*,
+    Error,
+    QuotaLimit,
+    TxSource,
+};
+use fuel_core_types::{
+    fuel_tx::Address,
+    services::p2p::PeerId,
+};
+use std::{
+    collections::{
+        HashMap,
+        VecDeque,
+    },
+    hash::Hash,
+    time::Duration,
+};
+use tokio::time::Instant;
+
+/// The window of the submission rate limit.
+const SUBMISSION_WINDOW: Duration = Duration::from_secs(60);
+
+/// The usage of the `TxPool` by one owner or one peer.
+#[derive(Debug, Clone, Default)]
+struct Usage {
+    txs: usize,
+    gas: u64,
+    /// The time of the submissions during the last `SUBMISSION_WINDOW`.
+    submissions: VecDeque<Instant>,
+}
+
+impl Usage {
+    fn prune_submissions(&mut self, now: Instant) {
+        while let Some(time) = self.submissions.front() {
+            if now.duration_since(*time) >= SUBMISSION_WINDOW {
+                self.submissions.pop_front();
+            } else {
+                break
+            }
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.txs == 0 && self.submissions.is_empty()
+    }
+
+    /// Checks the quota as if the `released` usage was already returned.
+    fn check(
+        &self,
+        quota: &Quota,
+        gas: u64,
+        released: Released,
+    ) -> Result<(), QuotaLimit> {
+        if let Some(max_txs) = quota.max_txs {
+            if self.txs.saturating_sub(released.txs) >= max_txs {
+                return Err(QuotaLimit::PendingTxs)
+            }
+        }
+        if let Some(max_gas) = quota.max_gas {
+            if self.gas.saturating_sub(released.gas).saturating_add(gas) > max_gas {
+                return Err(QuotaLimit::Gas)
+            }
+        }
+        if let Some(max_submissions) = quota.max_submissions_per_minute {
+            if self.submissions.len() >= max_submissions {
+                return Err(QuotaLimit::SubmissionRate)
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The usage returned by the transactions removed along with the insertion.
+#[derive(Debug, Clone, Copy, Default)]
+struct Released {
+    txs: usize,
+    gas: u64,
+}
+
+impl Released {
+    fn add(&mut self, gas: u64) {
+        self.txs = self.txs.saturating_add(1);
+        self.gas = self.gas.saturating_add(gas);
+    }
+}
+
+/// The usages of the `TxPool` with their quota.
+#[derive(Debug, Clone)]
+struct Usages<Key> {
+    quota: Quota,
+    usages: HashMap<Key, Usage>,
+}
+
+impl<Key> Usages<Key>
+where
+    Key: Clone + Eq + Hash,
+{
+    fn new(quota: Quota) -> Self {
+        Self {
+            quota,
+            usages: HashMap::new(),
+        }
+    }
+
+    fn is_unlimited(&self) -> bool {
+        self.quota == Quota::default()
+    }
+
+    fn check(
+        &mut self,
+        key: &Key,
+        gas: u64,
+        released: Released,
+        now: Instant,
+    ) -> Result<(), QuotaLimit> {
+        let Some(usage) = self.usages.get_mut(key) else {
+            return Usage::default().check(&self.quota, gas, released)
+        };
+        usage.prune_submissions(now);
+        usage.check(&self.quota, gas, released)
+    }
+
+    fn insert(&mut self, key: Key, gas: u64, now: Instant) {
+        let usage = self.usages.entry(key).or_default();
+        usage.txs = usage.txs.saturating_add(1);
+        usage.gas = usage.gas.saturating_add(gas);
+        if self.quota.max_submissions_per_minute.is_some() {
+            usage.submissions.push_back(now);
+        }
+    }
+
+    fn prune(&mut self, now: Instant) {
+        self.usages.retain(|_, usage| {
+            usage.prune_submissions(now);
+            !usage.is_empty()
+        });
+    }
+
+    fn remove(&mut self, key: &Key, gas: u64, now: Instant) {
+        if let Some(usage) = self.usages.get_mut(key) {
+            usage.txs = usage.txs.saturating_sub(1);
+            usage.gas = usage.gas.saturating_sub(gas);
+            usage.prune_submissions(now);
+            if usage.is_empty() {
+                self.usages.remove(key);
+            }
+        }
+    }
+}
+
+/// What was charged from the quotas for the transaction.
+#[derive(Debug, Clone)]
+struct Charge {
+    owners: Vec<Address>,
+    peer: Option<PeerId>,
+    gas: u64,
+}
+
+/// Tracks the pending transactions of each owner and each peer to
+/// enforce the `owner_quota` and the `peer_quota`.
+///
+/// The owners of the transaction are owners of coins and recipients of messages
+/// from its inputs. The transaction is charged from the quota of each owner.
+#[derive(Debug, Clone)]
+pub struct QuotaTracker {
+    owners: Usages<Address>,
+    peers: Usages<PeerId>,
+    charges: HashMap<TxId, Charge>,
+}
+
+impl QuotaTracker {
+    pub fn new(owner_quota: Quota, peer_quota: Quota) -> Self {
+        Self {
+            owners: Usages::new(owner_quota),
+            peers: Usages::new(peer_quota),
+            charges: HashMap::new(),
+        }
+    }
+
+    fn owners(tx: &PoolTransaction) -> Vec<Address> {
+        let mut owners: Vec<_> = tx
+            .inputs()
+            .iter()
+            .filter_map(|input| input.input_owner().or_else(|| input.recipient()))
+            .copied()
+            .collect();
+        owners.sort();
+        owners.dedup();
+        owners
+    }
+
+    /// Checks that the transaction from the `source` fits into the quotas.
+    ///
+    /// The `removed` transactions leave the `TxPool` along with the insertion
+    /// (e.g. they are replaced by the transaction), so their charge is not counted.
+    pub fn check(
+        &mut self,
+        tx: &PoolTransaction,
+        source: &TxSource,
+        removed: &[TxId],
+    ) -> Result<(), Error> {
+        let now = Instant::now();
+        let gas = tx.max_gas();
+        let removed: Vec<_> = removed
+            .iter()
+            .filter_map(|tx_id| self.charges.get(tx_id))
+            .collect();
+
+        if let TxSource::Peer(peer_id) = source {
+            if !self.peers.is_unlimited() {
+                let mut released = Released::default();
+                removed
+                    .iter()
+                    .filter(|charge| charge.peer.as_ref() == Some(peer_id))
+                    .for_each(|charge| released.add(charge.gas));
+                self.peers
+                    .check(peer_id, gas, released, now)
+                    .map_err(|limit| {
+                        Error::NotInsertedPeerQuotaExceeded(peer_id.clone(), limit)
+                    })?;
+            }
+        }
+
+        if !self.owners.is_unlimited() {
+            for owner in Self::owners(tx) {
+                let mut released = Released::default();
+                removed
+                    .iter()
+                    .filter(|charge| charge.owners.contains(&owner))
+                    .for_each(|charge| released.add(charge.gas));
+                self.owners
+                    .check(&owner, gas, released, now)
+                    .map_err(|limit| {
+                        Error::NotInsertedOwnerQuotaExceeded(owner, limit)
+                    })?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Charges the inserted transaction from the quotas.
+    pub fn insert(&mut self, tx: &PoolTransaction, source: &TxSource) {
+        if self.owners.is_unlimited() && self.peers.is_unlimited() {
+            return
+        }
+
+        let now = Instant::now();
+        let gas = tx.max_gas();
+        let owners = Self::owners(tx);
+        for owner in owners.iter() {
+            self.owners.insert(*owner, gas, now);
+        }
+        let peer = match source {
+            TxSource::Peer(peer_id) => {
+                self.peers.insert(peer_id.clone(), gas, now);
+                Some(peer_id.clone())
+            }
+            TxSource::Api => None,
+        };
+
+        self.charges.insert(tx.id(), Charge { owners, peer, gas });
+    }
+
+    /// Returns the charge of the removed transaction back to the quotas.
+    pub fn remove(&mut self, tx_id: &TxId) {
+        let Some(Charge { owners, peer, gas }) = self.charges.remove(tx_id) else {
+            return
+        };
+
+        let now = Instant::now();
+        for owner in owners.iter() {
+            self.owners.remove(owner, gas, now);
+        }
+        if let Some(peer) = peer {
+            self.peers.remove(&peer, gas, now);
+        }
+    }
+
+    /// Forgets the owners and peers without pending transactions and recent submissions.
+    pub fn prune(&mut self) {
+        let now = Instant::now();
+        self.owners.prune(now);
+        self.peers.prune(now);
+    }
+}
@@ -6,14 +6,29 @@ use fuel_core_types::{
         TxId,
         UtxoId,
     },
-    fuel_types::Nonce,
+    fuel_types::{
+        Nonce,
+        Word,
+    },
     fuel_vm::checked_transaction::CheckError,
+    services::p2p::PeerId,
 };
 
 use crate::ports::WasmValidityError;
 
 pub type Result<T> = core::result::Result<T, Error>;
 
+/// The limit of the quota exceeded by the owner or the peer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, derive_more::Display)]
+pub enum QuotaLimit {
+    #[display(fmt = "the maximum number of pending transactions")]
+    PendingTxs,
+    #[display(fmt = "the maximum total gas of pending transactions")]
+    Gas,
+    #[display(fmt = "the maximum number of submissions per minute")]
+    SubmissionRate,
+}
+
 #[allow(missing_docs)]
 #[derive(Debug, Clone, derive_more::Display)]
 #[non_exhaustive]
@@ -50,6 +65,10 @@ pub enum Error {
         fmt = "Transaction is not inserted. Higher priced tx {_0:#x} has already spent this UTXO output: {_1:#x}"
     )]
     NotInsertedCollision(TxId, UtxoId),
+    #[display(
+        fmt = "Transaction is not inserted. Replacing tx {_0:#x} requires the tip of at least {_1}"
+    )]
+    NotInsertedReplacementTipTooLow(TxId, Word),
     #[display(
         fmt = "Transaction is not inserted. Higher priced tx has created contract with ContractId {_0:#x}"
     )]
@@ -108,6 +127,10 @@ pub enum Error {
         fmt = "Transaction is not inserted. Maximum depth of dependent transaction chain reached"
     )]
     NotInsertedMaxDepth,
+    #[display(fmt = "Transaction is not inserted. The owner `{_0}` reached {_1}")]
+    NotInsertedOwnerQuotaExceeded(Address, QuotaLimit),
+    #[display(fmt = "Transaction is not inserted. The peer `{_0}` reached {_1}")]
+    NotInsertedPeerQuotaExceeded(PeerId, QuotaLimit),
     // small todo for now it can pass but in future we should include better messages
     #[display(fmt = "Transaction removed.")]
     Removed,
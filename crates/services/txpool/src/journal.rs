@@ -0,0 +1,337 @@
+//! The on-disk journal of the transactions accepted by the `TxPool`.
+//!
+//! Each record of the journal is the submission time of the transaction in seconds
+//! since the `UNIX_EPOCH`, followed by the length and the canonical bytes of the
+//! transaction. Records are appended when transactions are accepted, and the journal
+//! is periodically rewritten with the content of the pool, so it doesn't grow forever.
+//!
+//! The writes are done by the [`JournalWriter`] on the dedicated thread,
+//! so the file I/O doesn't block the async runtime.
+
+use fuel_core_types::{
+    fuel_tx::Transaction,
+    fuel_types::canonical::{
+        Deserialize,
+        Serialize,
+    },
+};
+use std::{
+    fs::{
+        self,
+        File,
+        OpenOptions,
+    },
+    io::{
+        self,
+        BufReader,
+        BufWriter,
+        Read,
+        Write,
+    },
+    path::{
+        Path,
+        PathBuf,
+    },
+    sync::mpsc,
+    thread,
+    time::Duration,
+};
+use tokio::sync::oneshot;
+
+/// The transaction stored in the journal.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JournalEntry {
+    /// The time when the transaction was submitted since the `UNIX_EPOCH`.
+    pub submitted_time: Duration,
+    /// The transaction itself.
+    pub tx: Transaction,
+}
+
+#[derive(Debug, Clone)]
+pub struct Journal {
+    path: PathBuf,
+}
+
+impl Journal {
+    pub fn new(path: PathBuf) -> Self {
+        Self { path }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Reads all entries of the journal. The missing journal is empty.
+    ///
+    /// The last record can be partially written if the node was stopped
+    /// in the middle of the write, in this case it is ignored.
+    pub fn load(&self) -> io::Result<Vec<JournalEntry>> {
+        let file = match File::open(&self.path) {
+            Ok(file) => file,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(vec![]),
+            Err(e) => return Err(e),
+        };
+        let mut reader = BufReader::new(file);
+
+        let mut entries = vec![];
+        loop {
+            match Self::read_entry(&mut reader) {
+                Ok(Some(entry)) => entries.push(entry),
+                Ok(None) => break,
+                Err(e) => {
+                    tracing::warn!(
+                        "The journal of the `TxPool` at {} has a corrupted record: {e}",
+                        self.path.display()
+                    );
+                    break
+                }
+            }
+        }
+
+        Ok(entries)
+    }
+
+    fn read_entry(reader: &mut impl Read) -> io::Result<Option<JournalEntry>> {
+        let mut submitted_time = [0u8; 8];
+        match reader.read_exact(&mut submitted_time) {
+            Ok(()) => {}
+            Err(e) if e.kind() == io::ErrorKind::UnexpectedEof => return Ok(None),
+            Err(e) => return Err(e),
+        }
+        let submitted_time = Duration::from_secs(u64::from_be_bytes(submitted_time));
+
+        let mut len = [0u8; 4];
+        reader.read_exact(&mut len)?;
+        let mut bytes = vec![0u8; u32::from_be_bytes(len) as usize];
+        reader.read_exact(&mut bytes)?;
+        let tx = Transaction::from_bytes(&bytes)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, format!("{e:?}")))?;
+
+        Ok(Some(JournalEntry { submitted_time, tx }))
+    }
+
+    fn write_entry(writer: &mut impl Write, entry: &JournalEntry) -> io::Result<()> {
+        let bytes = entry.tx.to_bytes();
+        let len = u32::try_from(bytes.len())
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+        writer.write_all(&entry.submitted_time.as_secs().to_be_bytes())?;
+        writer.write_all(&len.to_be_bytes())?;
+        writer.write_all(&bytes)
+    }
+
+    fn create_dir(&self) -> io::Result<()> {
+        match self.path.parent() {
+            Some(dir) => fs::create_dir_all(dir),
+            None => Ok(()),
+        }
+    }
+
+    /// Appends entries to the end of the journal.
+    pub fn append<'a>(
+        &self,
+        entries: impl IntoIterator<Item = &'a JournalEntry>,
+    ) -> io::Result<()> {
+        self.create_dir()?;
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?;
+        let mut writer = BufWriter::new(file);
+        for entry in entries {
+            Self::write_entry(&mut writer, entry)?;
+        }
+        writer.flush()
+    }
+
+    /// Replaces the content of the journal with the `entries`.
+    ///
+    /// The new journal is written to the temporary file first, so the journal is
+    /// never left in the half-written state.
+    pub fn rewrite<'a>(
+        &self,
+        entries: impl IntoIterator<Item = &'a JournalEntry>,
+    ) -> io::Result<()> {
+        self.create_dir()?;
+        let tmp_path = self.path.with_extension("tmp");
+        {
+            let mut writer = BufWriter::new(File::create(&tmp_path)?);
+            for entry in entries {
+                Self::write_entry(&mut writer, entry)?;
+            }
+            writer.flush()?;
+        }
+        fs::rename(tmp_path, &self.path)
+    }
+}
+
+enum JournalRequest {
+    Append(Vec<JournalEntry>),
+    Rewrite(Vec<JournalEntry>),
+    Flush(oneshot::Sender<()>),
+}
+
+/// Applies the changes to the [`Journal`] on the dedicated thread
+/// in the order of the requests.
+#[derive(Debug, Clone)]
+pub struct JournalWriter {
+    journal: Journal,
+    sender: mpsc::Sender<JournalRequest>,
+}
+
+impl JournalWriter {
+    /// Spawns the thread writing to the `journal`.
+    /// The thread stops when all copies of the writer are dropped.
+    pub fn spawn(journal: Journal) -> io::Result<Self> {
+        let (sender, receiver) = mpsc::channel();
+        let thread_journal = journal.clone();
+        thread::Builder::new()
+            .name("txpool-journal".to_string())
+            .spawn(move || {
+                for request in receiver {
+                    let result = match request {
+                        JournalRequest::Append(entries) => {
+                            thread_journal.append(&entries)
+                        }
+                        JournalRequest::Rewrite(entries) => {
+                            thread_journal.rewrite(&entries)
+                        }
+                        JournalRequest::Flush(sender) => {
+                            let _ = sender.send(());
+                            Ok(())
+                        }
+                    };
+                    if let Err(e) = result {
+                        tracing::error!(
+                            "Unable to write the journal at {}: {e}",
+                            thread_journal.path().display()
+                        );
+                    }
+                }
+            })?;
+
+        Ok(Self { journal, sender })
+    }
+
+    pub fn journal(&self) -> &Journal {
+        &self.journal
+    }
+
+    /// Appends entries to the end of the journal.
+    pub fn append(&self, entries: Vec<JournalEntry>) {
+        self.send(JournalRequest::Append(entries))
+    }
+
+    /// Replaces the content of the journal with the `entries`.
+    pub fn rewrite(&self, entries: Vec<JournalEntry>) {
+        self.send(JournalRequest::Rewrite(entries))
+    }
+
+    /// Waits until all previous requests are written.
+    pub async fn flush(&self) {
+        let (sender, receiver) = oneshot::channel();
+        self.send(JournalRequest::Flush(sender));
+        let _ = receiver.await;
+    }
+
+    fn send(&self, request: JournalRequest) {
+        if self.sender.send(request).is_err() {
+            tracing::error!("The writer of the journal is stopped");
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use super::*;
+    use fuel_core_types::fuel_tx::TransactionBuilder;
+
+    fn entry(tip: u64, submitted_time: u64) -> JournalEntry {
+        JournalEntry {
+            submitted_time: Duration::from_secs(submitted_time),
+            tx: TransactionBuilder::script(vec![], vec![])
+                .tip(tip)
+                .finalize_as_transaction(),
+        }
+    }
+
+    #[test]
+    fn load__missing_journal_is_empty() {
+        let dir = tempfile::tempdir().unwrap();
+        let journal = Journal::new(dir.path().join("journal"));
+
+        let entries = journal.load().unwrap();
+
+        assert!(entries.is_empty());
+    }
+
+    #[test]
+    fn load__returns_appended_entries() {
+        let dir = tempfile::tempdir().unwrap();
+        let journal = Journal::new(dir.path().join("journal"));
+        let first = entry(1, 10);
+        let second = entry(2, 20);
+
+        // When
+        journal.append([&first]).unwrap();
+        journal.append([&second]).unwrap();
+
+        // Then
+        assert_eq!(journal.load().unwrap(), vec![first, second]);
+    }
+
+    #[test]
+    fn load__rewrite_replaces_the_content() {
+        let dir = tempfile::tempdir().unwrap();
+        let journal = Journal::new(dir.path().join("journal"));
+        let first = entry(1, 10);
+        let second = entry(2, 20);
+        journal.append([&first, &second]).unwrap();
+
+        // When
+        journal.rewrite([&second]).unwrap();
+
+        // Then
+        assert_eq!(journal.load().unwrap(), vec![second]);
+    }
+
+    #[test]
+    fn load__ignores_partially_written_last_record() {
+        let dir = tempfile::tempdir().unwrap();
+        let journal = Journal::new(dir.path().join("journal"));
+        let first = entry(1, 10);
+        let second = entry(2, 20);
+        journal.append([&first, &second]).unwrap();
+        let len = fs::metadata(journal.path()).unwrap().len();
+
+        // When
+        OpenOptions::new()
+            .write(true)
+            .open(journal.path())
+            .unwrap()
+            .set_len(len.saturating_sub(1))
+            .unwrap();
+
+        // Then
+        assert_eq!(journal.load().unwrap(), vec![first]);
+    }
+
+    #[tokio::test]
+    async fn journal_writer__applies_requests_in_order() {
+        let dir = tempfile::tempdir().unwrap();
+        let writer =
+            JournalWriter::spawn(Journal::new(dir.path().join("journal"))).unwrap();
+        let first = entry(1, 10);
+        let second = entry(2, 20);
+        let third = entry(3, 30);
+
+        // When
+        writer.append(vec![first.clone(), second.clone()]);
+        writer.rewrite(vec![second.clone()]);
+        writer.append(vec![third.clone()]);
+        writer.flush().await;
+
+        // Then
+        assert_eq!(writer.journal().load().unwrap(), vec![second, third]);
+    }
+}
@@ -4,9 +4,12 @@
 #![deny(warnings)]
 
 use fuel_core_types::{
-    services::txpool::{
-        ArcPoolTx,
-        TransactionStatus,
+    services::{
+        p2p::PeerId,
+        txpool::{
+            ArcPoolTx,
+            TransactionStatus,
+        },
     },
     tai64::Tai64,
 };
@@ -15,12 +18,14 @@ use std::{
     time::Duration,
 };
 
+pub mod blacklist_store;
 pub mod config;
 mod containers;
 pub mod error;
+pub mod journal;
 pub mod ports;
 pub mod service;
-mod transaction_selector;
+pub mod transaction_selector;
 pub mod txpool;
 pub mod types;
 
@@ -32,6 +37,7 @@ pub use mock_db::MockDb;
 pub use config::Config;
 pub use error::{
     Error,
+    QuotaLimit,
     Result,
 };
 pub use service::{
@@ -46,6 +52,15 @@ pub(crate) mod test_helpers;
 #[cfg(test)]
 fuel_core_trace::enable_tracing!();
 
+/// The source of the transaction inserted into the txpool.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TxSource {
+    /// The transaction was submitted via the API of the node.
+    Api,
+    /// The transaction was gossiped by the peer.
+    Peer(PeerId),
+}
+
 /// Information of a transaction fetched from the txpool.
 #[derive(Debug, Clone)]
 pub struct TxInfo {
@@ -68,6 +83,22 @@ impl TxInfo {
         }
     }
 
+    /// Creates the information of the transaction submitted at the `submitted_time`
+    /// since the `UNIX_EPOCH`, so the TTL of the transaction counts from that time.
+    pub fn with_submitted_time(tx: ArcPoolTx, submitted_time: Duration) -> Self {
+        let since_epoch = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .expect("Now is bellow of the `UNIX_EPOCH`");
+        let now = tokio::time::Instant::now();
+        let age = since_epoch.saturating_sub(submitted_time);
+
+        Self {
+            tx,
+            submitted_time,
+            creation_instant: now.checked_sub(age).unwrap_or(now),
+        }
+    }
+
     pub fn tx(&self) -> &ArcPoolTx {
         &self.tx
     }
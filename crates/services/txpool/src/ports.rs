@@ -14,6 +14,7 @@ use fuel_core_types::{
         Bytes32,
         ConsensusParameters,
         Transaction,
+        TxId,
         UtxoId,
     },
     fuel_types::{
@@ -28,6 +29,7 @@ use fuel_core_types::{
             GossipsubMessageAcceptance,
             GossipsubMessageInfo,
             NetworkData,
+            PeerId,
         },
     },
 };
@@ -36,6 +38,18 @@ use std::{
     sync::Arc,
 };
 
+/// Possible reasons to report a peer
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PeerReportReason {
+    /// The peer gossiped transactions exceeding the quota of the peer or
+    /// the quota of the owner of transactions.
+    QuotaExceeded,
+    /// The peer responded to the synchronization of the `TxPool` with
+    /// transactions that were not requested or are invalid.
+    InvalidTransactions,
+}
+
+#[async_trait::async_trait]
 pub trait PeerToPeer: Send + Sync {
     type GossipedTransaction: NetworkData<Transaction>;
 
@@ -51,6 +65,27 @@ pub trait PeerToPeer: Send + Sync {
         message_info: GossipsubMessageInfo,
         validity: GossipsubMessageAcceptance,
     ) -> anyhow::Result<()>;
+
+    /// Report a peer for some reason to modify their reputation.
+    fn report_peer(
+        &self,
+        peer_id: PeerId,
+        reason: PeerReportReason,
+    ) -> anyhow::Result<()>;
+
+    /// Creates a stream of newly connected peers able to share their pending transactions.
+    fn subscribe_new_peers(&self) -> BoxStream<PeerId>;
+
+    /// Requests the ids of the pending transactions from the peer.
+    async fn request_tx_ids(&self, peer_id: PeerId) -> anyhow::Result<Vec<TxId>>;
+
+    /// Requests the full transactions from the peer.
+    /// `None` is returned for the transactions unknown to the peer.
+    async fn request_txs(
+        &self,
+        peer_id: PeerId,
+        tx_ids: Vec<TxId>,
+    ) -> anyhow::Result<Vec<Option<Transaction>>>;
 }
 
 pub trait BlockImporter: Send + Sync {
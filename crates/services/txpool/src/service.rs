@@ -1,13 +1,22 @@
 use std::{
+    collections::HashMap,
     sync::Arc,
-    time::Duration,
+    time::{
+        Duration,
+        SystemTime,
+        UNIX_EPOCH,
+    },
 };
 
 use anyhow::anyhow;
 use parking_lot::Mutex as ParkingMutex;
 use tokio::{
     sync::broadcast,
-    time::MissedTickBehavior,
+    task::JoinSet,
+    time::{
+        Instant,
+        MissedTickBehavior,
+    },
 };
 use tokio_stream::StreamExt;
 
@@ -35,6 +44,7 @@ use fuel_core_types::{
             GossipData,
             GossipsubMessageAcceptance,
             GossipsubMessageInfo,
+            PeerId,
             TransactionGossipData,
         },
         txpool::{
@@ -48,31 +58,51 @@ use fuel_core_types::{
 use update_sender::UpdateSender;
 
 use crate::{
+    blacklist_store::{
+        BlacklistChanges,
+        BlacklistStore,
+    },
+    config::BlackList,
+    journal::{
+        Journal,
+        JournalEntry,
+        JournalWriter,
+    },
     ports::{
         BlockImporter,
         ConsensusParametersProvider,
         GasPriceProvider as GasPriceProviderConstraint,
         MemoryPool,
+        PeerReportReason,
         PeerToPeer,
         TxPoolDb,
         WasmChecker as WasmCheckerConstraint,
     },
-    transaction_selector::select_transactions,
-    txpool::{
-        check_single_tx,
-        check_transactions,
+    transaction_selector::{
+        SelectionLimits,
+        TransactionSelectionStrategy,
     },
+    txpool::check_transactions,
     Config,
     Error as TxPoolError,
     TxInfo,
     TxPool,
+    TxSource,
 };
 
-use self::update_sender::{
-    MpscChannel,
-    TxStatusStream,
+use self::{
+    gossip_validation::{
+        tx_hash,
+        GossipValidator,
+        GossipedTx,
+    },
+    update_sender::{
+        MpscChannel,
+        TxStatusStream,
+    },
 };
 
+mod gossip_validation;
 mod update_sender;
 
 pub type Service<P2P, DB, WC, GP, CP, MP> = ServiceRunner<Task<P2P, DB, WC, GP, CP, MP>>;
@@ -139,6 +169,9 @@ pub struct SharedState<
     consensus_parameters_provider: Arc<ConsensusProvider>,
     gas_price_provider: Arc<GasPriceProvider>,
     memory_pool: Arc<MP>,
+    selection_strategy: Arc<dyn TransactionSelectionStrategy>,
+    journal: Option<JournalWriter>,
+    blacklist_store: Option<Arc<BlacklistStore>>,
 }
 
 impl<P2P, ViewProvider, GasPriceProvider, WasmChecker, ConsensusProvider, MP> Clone
@@ -161,13 +194,30 @@ impl<P2P, ViewProvider, GasPriceProvider, WasmChecker, ConsensusProvider, MP> Cl
             consensus_parameters_provider: self.consensus_parameters_provider.clone(),
             gas_price_provider: self.gas_price_provider.clone(),
             memory_pool: self.memory_pool.clone(),
+            selection_strategy: self.selection_strategy.clone(),
+            journal: self.journal.clone(),
+            blacklist_store: self.blacklist_store.clone(),
         }
     }
 }
 
+/// The maximum number of peers the `TxPool` synchronizes its transactions with at the same time.
+/// Newly connected peers are skipped while the limit is reached.
+const MAX_CONCURRENT_POOL_SYNCS: usize = 4;
+
+/// The time since the synchronization with the peer before the `TxPool` synchronizes
+/// with it again. Peers reconnecting more often are skipped.
+const POOL_SYNC_COOLDOWN: Duration = Duration::from_secs(60);
+
 pub struct Task<P2P, ViewProvider, WasmChecker, GasPriceProvider, ConsensusProvider, MP> {
     gossiped_tx_stream: BoxStream<TransactionGossipData>,
     committed_block_stream: BoxStream<SharedImportResult>,
+    new_peers_stream: BoxStream<PeerId>,
+    pool_syncs: JoinSet<()>,
+    /// The time of the last synchronization with each peer.
+    synced_peers: HashMap<PeerId, Instant>,
+    gossip_validator: GossipValidator,
+    gossip_validations: JoinSet<()>,
     tx_pool_shared_state: SharedState<
         P2P,
         ViewProvider,
@@ -184,13 +234,13 @@ impl<P2P, ViewProvider, View, WasmChecker, GasPriceProvider, ConsensusProvider,
     RunnableService
     for Task<P2P, ViewProvider, WasmChecker, GasPriceProvider, ConsensusProvider, MP>
 where
-    P2P: PeerToPeer<GossipedTransaction = TransactionGossipData>,
-    ViewProvider: AtomicView<LatestView = View>,
+    P2P: PeerToPeer<GossipedTransaction = TransactionGossipData> + 'static,
+    ViewProvider: AtomicView<LatestView = View> + 'static,
     View: TxPoolDb,
-    WasmChecker: WasmCheckerConstraint + Send + Sync,
-    GasPriceProvider: GasPriceProviderConstraint + Send + Sync,
-    ConsensusProvider: ConsensusParametersProvider + Send + Sync,
-    MP: MemoryPool + Send + Sync,
+    WasmChecker: WasmCheckerConstraint + Send + Sync + 'static,
+    GasPriceProvider: GasPriceProviderConstraint + Send + Sync + 'static,
+    ConsensusProvider: ConsensusParametersProvider + Send + Sync + 'static,
+    MP: MemoryPool + Send + Sync + 'static,
 {
     const NAME: &'static str = "TxPool";
 
@@ -216,6 +266,9 @@ where
         _: Self::TaskParams,
     ) -> anyhow::Result<Self::Task> {
         self.ttl_timer.reset();
+        self.tx_pool_shared_state
+            .replay_journal(self.ttl_timer.period())
+            .await;
         Ok(self)
     }
 }
@@ -225,16 +278,17 @@ impl<P2P, ViewProvider, View, WasmChecker, GasPriceProvider, ConsensusProvider,
     RunnableTask
     for Task<P2P, ViewProvider, WasmChecker, GasPriceProvider, ConsensusProvider, MP>
 where
-    P2P: PeerToPeer<GossipedTransaction = TransactionGossipData>,
-    ViewProvider: AtomicView<LatestView = View>,
+    P2P: PeerToPeer<GossipedTransaction = TransactionGossipData> + 'static,
+    ViewProvider: AtomicView<LatestView = View> + 'static,
     View: TxPoolDb,
-    WasmChecker: WasmCheckerConstraint + Send + Sync,
-    GasPriceProvider: GasPriceProviderConstraint + Send + Sync,
-    ConsensusProvider: ConsensusParametersProvider + Send + Sync,
-    MP: MemoryPool + Send + Sync,
+    WasmChecker: WasmCheckerConstraint + Send + Sync + 'static,
+    GasPriceProvider: GasPriceProviderConstraint + Send + Sync + 'static,
+    ConsensusProvider: ConsensusParametersProvider + Send + Sync + 'static,
+    MP: MemoryPool + Send + Sync + 'static,
 {
     async fn run(&mut self, watcher: &mut StateWatcher) -> anyhow::Result<bool> {
         let should_continue;
+        let gossip_batch_deadline = self.gossip_validator.batch_deadline();
 
         tokio::select! {
             biased;
@@ -248,6 +302,7 @@ where
                 for tx in removed {
                     self.tx_pool_shared_state.tx_status_sender.send_squeezed_out(tx.id(), TxPoolError::TTLReason);
                 }
+                self.tx_pool_shared_state.rewrite_journal();
 
                 should_continue = true
             }
@@ -271,63 +326,48 @@ where
                 }
             }
 
+            peer_id = self.new_peers_stream.next() => {
+                if let Some(peer_id) = peer_id {
+                    self.sync_with_peer(peer_id);
+                    should_continue = true;
+                } else {
+                    should_continue = false;
+                }
+            }
+
+            _ = self.pool_syncs.join_next(), if !self.pool_syncs.is_empty() => {
+                should_continue = true;
+            }
+
+            _ = tokio::time::sleep_until(gossip_batch_deadline.unwrap_or_else(Instant::now)), if gossip_batch_deadline.is_some() => {
+                self.validate_gossiped_batch();
+                should_continue = true;
+            }
+
+            _ = self.gossip_validations.join_next(), if !self.gossip_validations.is_empty() => {
+                should_continue = true;
+            }
+
             new_transaction = self.gossiped_tx_stream.next() => {
                 if let Some(GossipData { data: Some(tx), message_id, peer_id }) = new_transaction {
-                    let current_height = *self.tx_pool_shared_state.current_height.lock();
-                    let (version, params) = self
+                    let (_, params) = self
                         .tx_pool_shared_state
                         .consensus_parameters_provider
                         .latest_consensus_parameters();
-
-                    // verify tx
-                    let checked_tx = check_single_tx(
-                        tx,
-                        current_height,
-                        self.tx_pool_shared_state.utxo_validation,
-                        params.as_ref(),
-                        &self.tx_pool_shared_state.gas_price_provider,
-                        self.tx_pool_shared_state.memory_pool.get_memory().await,
-                    ).await;
-
-                    let acceptance = match checked_tx {
-                        Ok(tx) => {
-                            let id = tx.transaction().cached_id().expect("`Checked` tx should have cached id");
-                            let txs = vec![tx];
-
-                            // insert tx
-                            let mut result = tracing::info_span!("Received tx via gossip", %id)
-                                .in_scope(|| {
-                                    self.tx_pool_shared_state.txpool.lock().insert(
-                                        &self.tx_pool_shared_state.tx_status_sender,
-                                        version,
-                                        txs
-                                    )
-                                });
-
-                            match result.pop() {
-                                Some(Ok(_)) => {
-                                    GossipsubMessageAcceptance::Accept
-                                },
-                                // Use similar p2p punishment rules as bitcoin
-                                // https://github.com/bitcoin/bitcoin/blob/6ff0aa089c01ff3e610ecb47814ed739d685a14c/src/net_processing.cpp#L1856
-                                Some(Err(TxPoolError::ConsensusValidity(_))) | Some(Err(TxPoolError::MintIsDisallowed)) => {
-                                    GossipsubMessageAcceptance::Reject
-                                },
-                                _ => GossipsubMessageAcceptance::Ignore
-                            }
-                        }
-                        Err(_) => {
-                            GossipsubMessageAcceptance::Reject
-                        }
-                    };
-
-                    // notify p2p layer about whether this tx was accepted
+                    let tx_id = tx.id(&params.chain_id());
                     let message_info = GossipsubMessageInfo {
                         message_id,
                         peer_id,
                     };
 
-                    let _ = self.tx_pool_shared_state.p2p.notify_gossip_transaction_validity(message_info, acceptance);
+                    let tx_hash = tx_hash(&tx);
+
+                    if self.gossip_validator.is_rejected(&tx_hash) {
+                        // the duplicate of the invalid transaction is dropped without the verification
+                        self.tx_pool_shared_state.notify_gossip_acceptance(message_info, GossipsubMessageAcceptance::Reject);
+                    } else if self.gossip_validator.push(GossipedTx { tx_id, tx_hash, tx, message_info }) {
+                        self.validate_gossiped_batch();
+                    }
 
                     should_continue = true;
                 } else {
@@ -339,14 +379,69 @@ where
     }
 
     async fn shutdown(self) -> anyhow::Result<()> {
-        // Nothing to shut down because we don't have any temporary state that should be dumped,
-        // and we don't spawn any sub-tasks that we need to finish or await.
-        // Maybe we will save and load the previous list of transactions in the future to
-        // avoid losing them.
+        // We don't spawn any sub-tasks that we need to finish or await.
+        // The synchronizations with peers in progress are aborted on drop.
+        // The pending transactions are dumped into the journal(if it is enabled)
+        // to restore them after the restart.
+        self.tx_pool_shared_state.rewrite_journal();
+        if let Some(journal) = &self.tx_pool_shared_state.journal {
+            journal.flush().await;
+        }
         Ok(())
     }
 }
 
+impl<P2P, ViewProvider, View, WasmChecker, GasPriceProvider, ConsensusProvider, MP>
+    Task<P2P, ViewProvider, WasmChecker, GasPriceProvider, ConsensusProvider, MP>
+where
+    P2P: PeerToPeer<GossipedTransaction = TransactionGossipData> + 'static,
+    ViewProvider: AtomicView<LatestView = View> + 'static,
+    View: TxPoolDb,
+    WasmChecker: WasmCheckerConstraint + Send + Sync + 'static,
+    GasPriceProvider: GasPriceProviderConstraint + Send + Sync + 'static,
+    ConsensusProvider: ConsensusParametersProvider + Send + Sync + 'static,
+    MP: MemoryPool + Send + Sync + 'static,
+{
+    /// Spawns the synchronization of the `TxPool` with the newly connected peer,
+    /// unless the limit of concurrent synchronizations is reached
+    /// or the peer was synchronized recently.
+    fn sync_with_peer(&mut self, peer_id: PeerId) {
+        let now = Instant::now();
+        self.synced_peers
+            .retain(|_, synced_at| now.duration_since(*synced_at) < POOL_SYNC_COOLDOWN);
+        if self.synced_peers.contains_key(&peer_id) {
+            tracing::debug!(
+                "Skipping the synchronization with the peer {peer_id}, \
+                it was synchronized recently"
+            );
+            return;
+        }
+        if self.pool_syncs.len() >= MAX_CONCURRENT_POOL_SYNCS {
+            tracing::debug!(
+                "Skipping the synchronization with the peer {peer_id}, \
+                too many synchronizations are in progress"
+            );
+            return;
+        }
+        self.synced_peers.insert(peer_id.clone(), now);
+
+        let shared_state = self.tx_pool_shared_state.clone();
+        self.pool_syncs.spawn(async move {
+            if let Err(e) = shared_state.sync_with_peer(peer_id.clone()).await {
+                tracing::debug!("Unable to synchronize with the peer {peer_id}: {e}");
+            }
+        });
+    }
+
+    /// Spawns the verification of the collected batch of gossiped transactions.
+    fn validate_gossiped_batch(&mut self) {
+        if let Some(validation) = self.gossip_validator.take_batch() {
+            let shared_state = self.tx_pool_shared_state.clone();
+            self.gossip_validations.spawn(validation.run(shared_state));
+        }
+    }
+}
+
 // TODO: Remove `find` and `find_one` methods from `txpool`. It is used only by GraphQL.
 //  Instead, `fuel-core` can create a `DatabaseWithTxPool` that aggregates `TxPool` and
 //  storage `Database` together. GraphQL will retrieve data from this `DatabaseWithTxPool` via
@@ -366,6 +461,16 @@ impl<P2P, ViewProvider, WasmChecker, GasPriceProvider, ConsensusProvider, MP>
         self.txpool.lock().remove(&self.tx_status_sender, ids)
     }
 
+    pub fn tx_ids(&self, max_txs: usize) -> Vec<TxId> {
+        self.txpool
+            .lock()
+            .txs()
+            .keys()
+            .take(max_txs)
+            .copied()
+            .collect()
+    }
+
     pub fn find(&self, ids: Vec<TxId>) -> Vec<Option<TxInfo>> {
         self.txpool.lock().find(&ids)
     }
@@ -374,17 +479,6 @@ impl<P2P, ViewProvider, WasmChecker, GasPriceProvider, ConsensusProvider, MP>
         self.txpool.lock().find_one(&id)
     }
 
-    pub fn select_transactions(&self, max_gas: u64) -> Vec<ArcPoolTx> {
-        let mut guard = self.txpool.lock();
-        let txs = guard.includable();
-        let sorted_txs = select_transactions(txs, max_gas);
-
-        for tx in sorted_txs.iter() {
-            guard.remove_committed_tx(&tx.id());
-        }
-        sorted_txs
-    }
-
     pub fn remove(&self, ids: Vec<(TxId, String)>) -> Vec<ArcPoolTx> {
         self.txpool.lock().remove(&self.tx_status_sender, ids)
     }
@@ -412,6 +506,124 @@ impl<P2P, ViewProvider, WasmChecker, GasPriceProvider, ConsensusProvider, MP>
             TxStatusMessage::Status(status),
         )
     }
+
+    pub fn blacklist(&self) -> BlackList {
+        self.txpool.lock().blacklist().clone()
+    }
+
+    /// Adds entries to the blacklist and evicts pending transactions that use them.
+    /// Returns the evicted transactions.
+    pub fn add_to_blacklist(&self, entries: BlackList) -> anyhow::Result<Vec<ArcPoolTx>> {
+        let Some(store) = &self.blacklist_store else {
+            return Ok(self
+                .txpool
+                .lock()
+                .add_to_blacklist(&self.tx_status_sender, entries))
+        };
+        let mut changes = store.lock();
+        let removed = self
+            .txpool
+            .lock()
+            .add_to_blacklist(&self.tx_status_sender, entries.clone());
+        changes.add(&entries);
+        Self::save_blacklist(store, &changes)?;
+        Ok(removed)
+    }
+
+    /// Removes entries from the blacklist.
+    pub fn remove_from_blacklist(&self, entries: BlackList) -> anyhow::Result<()> {
+        let Some(store) = &self.blacklist_store else {
+            self.txpool.lock().remove_from_blacklist(&entries);
+            return Ok(())
+        };
+        let mut changes = store.lock();
+        self.txpool.lock().remove_from_blacklist(&entries);
+        changes.remove(&entries);
+        Self::save_blacklist(store, &changes)
+    }
+
+    /// Persists the `changes` after the `TxPool` lock is released.
+    fn save_blacklist(
+        store: &BlacklistStore,
+        changes: &BlacklistChanges,
+    ) -> anyhow::Result<()> {
+        store.save(changes).map_err(|e| {
+            anyhow!(
+                "The blacklist is updated, but it was not persisted at {}: {e}",
+                store.path().display()
+            )
+        })
+    }
+
+    /// Appends the transactions accepted by the `TxPool` to the journal.
+    fn append_to_journal(&self, insertion: &[Result<InsertionResult, TxPoolError>]) {
+        let Some(journal) = &self.journal else {
+            return;
+        };
+        let entries: Vec<_> = insertion
+            .iter()
+            .filter_map(|result| result.as_ref().ok())
+            .map(|result| JournalEntry {
+                submitted_time: result.submitted_time,
+                tx: result.inserted.as_ref().into(),
+            })
+            .collect();
+        if entries.is_empty() {
+            return;
+        }
+
+        journal.append(entries);
+    }
+
+    /// Replaces the content of the journal with the pending transactions,
+    /// dropping the ones that left the `TxPool`.
+    fn rewrite_journal(&self) {
+        let Some(journal) = &self.journal else {
+            return;
+        };
+        let txpool = self.txpool.lock();
+        let entries: Vec<_> = txpool
+            .txs()
+            .values()
+            .map(|info| JournalEntry {
+                submitted_time: info.submitted_time(),
+                tx: info.tx().as_ref().into(),
+            })
+            .collect();
+        // The request is sent under the lock, so it is ordered
+        // with the appends of transactions inserted after the snapshot.
+        journal.rewrite(entries);
+    }
+}
+
+impl<P2P, ViewProvider, WasmChecker, GasPriceProvider, ConsensusProvider, MP>
+    SharedState<P2P, ViewProvider, WasmChecker, GasPriceProvider, ConsensusProvider, MP>
+where
+    ConsensusProvider: ConsensusParametersProvider,
+{
+    pub fn select_transactions(&self, max_gas: u64) -> Vec<ArcPoolTx> {
+        let (_, params) = self
+            .consensus_parameters_provider
+            .latest_consensus_parameters();
+        // Each byte of the transaction costs `gas_per_byte`, so the block can't be bigger.
+        let max_block_bytes = params
+            .block_gas_limit()
+            .checked_div(params.fee_params().gas_per_byte())
+            .unwrap_or(u64::MAX);
+        let limits = SelectionLimits {
+            max_gas,
+            max_block_bytes,
+        };
+
+        let mut guard = self.txpool.lock();
+        let txs = guard.includable().collect();
+        let sorted_txs = self.selection_strategy.select(txs, limits);
+
+        for tx in sorted_txs.iter() {
+            guard.remove_committed_tx(&tx.id());
+        }
+        sorted_txs
+    }
 }
 
 impl<P2P, ViewProvider, WasmChecker, GasPriceProvider, ConsensusProvider, View, MP>
@@ -461,10 +673,14 @@ where
 
         // insert txs
         let insertion = {
-            self.txpool
-                .lock()
-                .insert(&self.tx_status_sender, version, valid_txs)
+            self.txpool.lock().insert(
+                &self.tx_status_sender,
+                version,
+                TxSource::Api,
+                valid_txs,
+            )
         };
+        self.append_to_journal(&insertion);
 
         for (ret, tx) in insertion.iter().zip(txs.into_iter()) {
             match ret {
@@ -496,6 +712,161 @@ where
             })
             .collect()
     }
+
+    /// Inserts the transactions from the journal into the `TxPool`.
+    ///
+    /// Transactions are verified again against the current state, so the ones
+    /// that became invalid or exceeded the `ttl` while the node was offline are dropped.
+    async fn replay_journal(&self, ttl: Duration) {
+        let Some(journal) = &self.journal else {
+            return;
+        };
+        let reader = journal.journal().clone();
+        let entries = match tokio::task::spawn_blocking(move || reader.load()).await {
+            Ok(Ok(entries)) => entries,
+            Ok(Err(e)) => {
+                tracing::error!("Unable to load the journal: {e}");
+                return;
+            }
+            Err(e) => {
+                tracing::error!("Unable to load the journal: {e}");
+                return;
+            }
+        };
+
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default();
+        let loaded = entries.len();
+        let (txs, submitted_times): (Vec<_>, Vec<_>) = entries
+            .into_iter()
+            .filter(|entry| now.saturating_sub(entry.submitted_time) < ttl)
+            .map(|entry| (Arc::new(entry.tx), entry.submitted_time))
+            .unzip();
+
+        let current_height = *self.current_height.lock();
+        let (version, params) = self
+            .consensus_parameters_provider
+            .latest_consensus_parameters();
+        let valid_txs: Vec<_> = check_transactions(
+            &txs,
+            current_height,
+            self.utxo_validation,
+            params.as_ref(),
+            &self.gas_price_provider,
+            self.memory_pool.clone(),
+        )
+        .await
+        .into_iter()
+        .zip(submitted_times)
+        .filter_map(|(tx, submitted_time)| Some((tx.ok()?, submitted_time)))
+        .collect();
+
+        // Transactions keep the submission time from the journal,
+        // so they don't live in the pool longer than the `ttl`.
+        let restored = self
+            .txpool
+            .lock()
+            .restore(&self.tx_status_sender, version, TxSource::Api, valid_txs)
+            .into_iter()
+            .filter(Result::is_ok)
+            .count();
+        tracing::info!(
+            "Restored {restored} out of {loaded} transactions from the journal at {}",
+            journal.journal().path().display()
+        );
+
+        self.rewrite_journal();
+    }
+
+    /// Requests the pending transactions of the `peer_id` missing in the `TxPool`
+    /// and inserts them into the pool.
+    ///
+    /// The number of requested transactions doesn't exceed the free space of the pool.
+    async fn sync_with_peer(&self, peer_id: PeerId) -> anyhow::Result<()> {
+        let tx_ids = self.p2p.request_tx_ids(peer_id.clone()).await?;
+        let missing_tx_ids: Vec<_> = {
+            let txpool = self.txpool.lock();
+            let free_space = txpool.config().max_tx.saturating_sub(txpool.txs().len());
+            tx_ids
+                .into_iter()
+                .filter(|tx_id| !txpool.txs().contains_key(tx_id))
+                .take(free_space)
+                .collect()
+        };
+        if missing_tx_ids.is_empty() {
+            return Ok(());
+        }
+
+        let (version, params) = self
+            .consensus_parameters_provider
+            .latest_consensus_parameters();
+        let requested = missing_tx_ids.len();
+        let response = self
+            .p2p
+            .request_txs(peer_id.clone(), missing_tx_ids.clone())
+            .await?;
+
+        // The peer responds with the requested transactions in the requested order.
+        let mut misbehaved = response.len() > requested;
+        let mut txs = vec![];
+        for (tx, requested_id) in response.into_iter().zip(missing_tx_ids) {
+            let Some(tx) = tx else {
+                continue;
+            };
+            if tx.id(&params.chain_id()) == requested_id {
+                txs.push(Arc::new(tx));
+            } else {
+                misbehaved = true;
+            }
+        }
+
+        let current_height = *self.current_height.lock();
+        let checked_txs = check_transactions(
+            &txs,
+            current_height,
+            self.utxo_validation,
+            params.as_ref(),
+            &self.gas_price_provider,
+            self.memory_pool.clone(),
+        )
+        .await;
+        let mut valid_txs = vec![];
+        for checked_tx in checked_txs {
+            match checked_tx {
+                Ok(tx) => valid_txs.push(tx),
+                // The same rules as for the gossiped transactions.
+                Err(TxPoolError::ConsensusValidity(_))
+                | Err(TxPoolError::MintIsDisallowed) => misbehaved = true,
+                Err(_) => {}
+            }
+        }
+
+        if misbehaved {
+            tracing::debug!(
+                "The peer {peer_id} responded with unrequested or invalid transactions"
+            );
+            let result = self
+                .p2p
+                .report_peer(peer_id.clone(), PeerReportReason::InvalidTransactions);
+            if let Err(e) = result {
+                tracing::error!("Unable to report the peer {peer_id}, got an {e} error");
+            }
+        }
+
+        let insertion = self.txpool.lock().insert(
+            &self.tx_status_sender,
+            version,
+            TxSource::Peer(peer_id.clone()),
+            valid_txs,
+        );
+        self.append_to_journal(&insertion);
+        let inserted = insertion.iter().filter(|result| result.is_ok()).count();
+        tracing::debug!(
+            "Inserted {inserted} out of {requested} missing transactions from the peer {peer_id}"
+        );
+        Ok(())
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -543,7 +914,9 @@ pub fn new_service<
     gas_price_provider: GasPriceProvider,
     consensus_parameters_provider: ConsensusProvider,
     memory_pool: MP,
-) -> Service<P2P, ViewProvider, WasmChecker, GasPriceProvider, ConsensusProvider, MP>
+) -> anyhow::Result<
+    Service<P2P, ViewProvider, WasmChecker, GasPriceProvider, ConsensusProvider, MP>,
+>
 where
     Importer: BlockImporter,
     P2P: PeerToPeer<GossipedTransaction = TransactionGossipData> + 'static,
@@ -557,9 +930,21 @@ where
     let p2p = Arc::new(p2p);
     let gossiped_tx_stream = p2p.gossiped_transaction_events();
     let committed_block_stream = importer.block_events();
+    let new_peers_stream = p2p.subscribe_new_peers();
     let mut ttl_timer = tokio::time::interval(config.transaction_ttl);
     ttl_timer.set_missed_tick_behavior(MissedTickBehavior::Skip);
     let number_of_active_subscription = config.number_of_active_subscription;
+    let mut config = config;
+    let blacklist_store = config
+        .blacklist_path
+        .clone()
+        .map(|path| {
+            BlacklistStore::open(path.clone(), &mut config.blacklist).map_err(|e| {
+                anyhow!("Unable to load the blacklist from {}: {e}", path.display())
+            })
+        })
+        .transpose()?
+        .map(Arc::new);
     let txpool = Arc::new(ParkingMutex::new(TxPool::new(
         config.clone(),
         provider,
@@ -568,6 +953,11 @@ where
     let task = Task {
         gossiped_tx_stream,
         committed_block_stream,
+        new_peers_stream,
+        pool_syncs: JoinSet::new(),
+        synced_peers: HashMap::new(),
+        gossip_validator: GossipValidator::new(config.gossip_validation)?,
+        gossip_validations: JoinSet::new(),
         tx_pool_shared_state: SharedState {
             tx_status_sender: TxStatusChange::new(
                 number_of_active_subscription,
@@ -584,11 +974,18 @@ where
             consensus_parameters_provider: Arc::new(consensus_parameters_provider),
             gas_price_provider: Arc::new(gas_price_provider),
             memory_pool: Arc::new(memory_pool),
+            selection_strategy: config.selection_strategy.strategy(),
+            journal: config
+                .journal_path
+                .clone()
+                .map(|path| JournalWriter::spawn(Journal::new(path)))
+                .transpose()?,
+            blacklist_store,
         },
         ttl_timer,
     };
 
-    Service::new(task)
+    Ok(Service::new(task))
 }
 
 impl<E> From<Result<TransactionStatus, E>> for TxStatusMessage {
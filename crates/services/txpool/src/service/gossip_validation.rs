@@ -0,0 +1,417 @@
+use super::SharedState;
+use crate::{
+    config::GossipValidationConfig,
+    ports::{
+        ConsensusParametersProvider,
+        GasPriceProvider as GasPriceProviderConstraint,
+        MemoryPool,
+        PeerReportReason,
+        PeerToPeer,
+        TxPoolDb,
+        WasmChecker as WasmCheckerConstraint,
+    },
+    txpool::{
+        check_single_tx_blocking,
+        verify_tx_min_gas_price,
+    },
+    Error as TxPoolError,
+    TxSource,
+};
+use fuel_core_services::HeavyTaskProcessor;
+use fuel_core_storage::transactional::AtomicView;
+use fuel_core_types::{
+    blockchain::header::ConsensusParametersVersion,
+    fuel_crypto::Hasher,
+    fuel_tx::{
+        Bytes32,
+        Transaction,
+        TxId,
+        UniqueIdentifier,
+        ValidityError,
+    },
+    fuel_types::canonical::Serialize,
+    fuel_vm::{
+        checked_transaction::{
+            CheckError,
+            Checked,
+        },
+        PredicateVerificationFailed,
+    },
+    services::p2p::{
+        GossipsubMessageAcceptance,
+        GossipsubMessageInfo,
+        PeerId,
+        TransactionGossipData,
+    },
+};
+use parking_lot::Mutex as ParkingMutex;
+use std::{
+    collections::{
+        HashSet,
+        VecDeque,
+    },
+    sync::Arc,
+};
+use tokio::{
+    sync::oneshot,
+    time::Instant,
+};
+
+/// The transaction received via gossip and waiting for the verification.
+pub(crate) struct GossipedTx {
+    pub tx_id: TxId,
+    pub tx_hash: Bytes32,
+    pub tx: Transaction,
+    pub message_info: GossipsubMessageInfo,
+}
+
+/// The hash of the whole serialized transaction. Unlike the id, it covers
+/// the witnesses, so a copy of the transaction with invalid signatures
+/// doesn't get the valid transaction rejected.
+pub(crate) fn tx_hash(tx: &Transaction) -> Bytes32 {
+    Hasher::hash(tx.to_bytes())
+}
+
+/// Returns `true` if the transaction can't become valid later,
+/// so its duplicates can be rejected without the verification.
+fn is_permanent_failure(error: &TxPoolError) -> bool {
+    match error {
+        // The transaction becomes valid at the higher block height.
+        TxPoolError::ConsensusValidity(CheckError::Validity(
+            ValidityError::TransactionMaturity,
+        ))
+        // The failure of the local node, not of the transaction.
+        | TxPoolError::ConsensusValidity(CheckError::PredicateVerificationFailed(
+            PredicateVerificationFailed::Bug(_),
+        )) => false,
+        TxPoolError::ConsensusValidity(_) | TxPoolError::NotSupportedTransactionType => {
+            true
+        }
+        _ => false,
+    }
+}
+
+/// The hashes of recently rejected transactions, the oldest ones are forgotten first.
+pub(crate) struct RejectedTxs {
+    capacity: usize,
+    hashes: HashSet<Bytes32>,
+    order: VecDeque<Bytes32>,
+}
+
+impl RejectedTxs {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            hashes: HashSet::new(),
+            order: VecDeque::new(),
+        }
+    }
+
+    pub fn contains(&self, tx_hash: &Bytes32) -> bool {
+        self.hashes.contains(tx_hash)
+    }
+
+    pub fn insert(&mut self, tx_hash: Bytes32) {
+        if self.capacity == 0 || !self.hashes.insert(tx_hash) {
+            return;
+        }
+        self.order.push_back(tx_hash);
+        if self.order.len() > self.capacity {
+            if let Some(oldest) = self.order.pop_front() {
+                self.hashes.remove(&oldest);
+            }
+        }
+    }
+}
+
+/// Collects transactions received via gossip into batches. Transactions of the batch
+/// are verified in parallel on the `HeavyTaskProcessor`.
+pub(crate) struct GossipValidator {
+    config: GossipValidationConfig,
+    processor: Arc<HeavyTaskProcessor>,
+    rejected: Arc<ParkingMutex<RejectedTxs>>,
+    batch: Vec<GossipedTx>,
+    batch_received_at: Option<Instant>,
+}
+
+impl GossipValidator {
+    pub fn new(config: GossipValidationConfig) -> anyhow::Result<Self> {
+        let now = Instant::now();
+        if now.checked_add(config.batch_timeout).is_none()
+            || now.checked_add(config.validation_timeout).is_none()
+        {
+            return Err(anyhow::anyhow!(
+                "The timeouts of the gossip validation are too large"
+            ));
+        }
+        let processor = HeavyTaskProcessor::new(
+            config.number_of_threads,
+            config.number_of_pending_tasks,
+        )?;
+        Ok(Self {
+            config,
+            processor: Arc::new(processor),
+            rejected: Arc::new(ParkingMutex::new(RejectedTxs::new(
+                config.rejected_cache_size,
+            ))),
+            batch: Vec::with_capacity(config.batch_size),
+            batch_received_at: None,
+        })
+    }
+
+    /// Returns `true` if the transaction was rejected recently,
+    /// so its duplicate can be rejected without the verification.
+    pub fn is_rejected(&self, tx_hash: &Bytes32) -> bool {
+        self.rejected.lock().contains(tx_hash)
+    }
+
+    /// Adds the transaction to the batch. Returns `true` if the batch is full.
+    pub fn push(&mut self, tx: GossipedTx) -> bool {
+        if self.batch.is_empty() {
+            self.batch_received_at = Some(Instant::now());
+        }
+        self.batch.push(tx);
+        self.batch.len() >= self.config.batch_size
+    }
+
+    /// The time when the incomplete batch should be verified.
+    pub fn batch_deadline(&self) -> Option<Instant> {
+        self.batch_received_at
+            .map(|received_at| deadline(received_at, self.config.batch_timeout))
+    }
+
+    /// Takes the collected batch for the verification.
+    pub fn take_batch(&mut self) -> Option<BatchValidation> {
+        let received_at = self.batch_received_at.take()?;
+        let txs = core::mem::replace(
+            &mut self.batch,
+            Vec::with_capacity(self.config.batch_size),
+        );
+        Some(BatchValidation {
+            txs,
+            processor: self.processor.clone(),
+            rejected: self.rejected.clone(),
+            deadline: deadline(received_at, self.config.validation_timeout),
+        })
+    }
+}
+
+fn deadline(start: Instant, timeout: core::time::Duration) -> Instant {
+    start
+        .checked_add(timeout)
+        .expect("The timeouts are checked during the creation of the `GossipValidator`")
+}
+
+/// The verification of the batch of gossiped transactions.
+pub(crate) struct BatchValidation {
+    txs: Vec<GossipedTx>,
+    processor: Arc<HeavyTaskProcessor>,
+    rejected: Arc<ParkingMutex<RejectedTxs>>,
+    deadline: Instant,
+}
+
+impl BatchValidation {
+    /// Verifies the transactions, inserts valid ones into the `TxPool`
+    /// and reports the acceptance of each transaction to the p2p layer.
+    /// Transactions not verified before the deadline are ignored.
+    pub async fn run<
+        P2P,
+        ViewProvider,
+        WasmChecker,
+        GasPriceProvider,
+        ConsensusProvider,
+        MP,
+    >(
+        self,
+        shared_state: SharedState<
+            P2P,
+            ViewProvider,
+            WasmChecker,
+            GasPriceProvider,
+            ConsensusProvider,
+            MP,
+        >,
+    ) where
+        P2P: PeerToPeer<GossipedTransaction = TransactionGossipData>,
+        ViewProvider: AtomicView,
+        ViewProvider::LatestView: TxPoolDb,
+        WasmChecker: WasmCheckerConstraint + Send + Sync,
+        GasPriceProvider: GasPriceProviderConstraint + Send + Sync,
+        ConsensusProvider: ConsensusParametersProvider,
+        MP: MemoryPool + Send + Sync,
+    {
+        let current_height = *shared_state.current_height.lock();
+        let (version, params) = shared_state
+            .consensus_parameters_provider
+            .latest_consensus_parameters();
+        let utxo_validation = shared_state.utxo_validation;
+
+        // verify signatures and predicates of all transactions in parallel
+        let mut verifications = Vec::with_capacity(self.txs.len());
+        for GossipedTx {
+            tx_id,
+            tx_hash,
+            tx,
+            message_info,
+        } in self.txs
+        {
+            let (sender, receiver) = oneshot::channel();
+            let params = params.clone();
+            let memory = shared_state.memory_pool.get_memory().await;
+            let spawned = self.processor.spawn(move || {
+                let result = check_single_tx_blocking(
+                    tx,
+                    current_height,
+                    utxo_validation,
+                    &params,
+                    memory,
+                );
+                let _ = sender.send(result);
+            });
+            if spawned.is_err() {
+                tracing::debug!(
+                    "Too many gossiped transactions are waiting for the verification, \
+                    the transaction {tx_id} is ignored"
+                );
+            }
+            verifications.push((
+                tx_id,
+                tx_hash,
+                message_info,
+                spawned.ok().map(|_| receiver),
+            ));
+        }
+
+        let gas_price = shared_state.gas_price_provider.next_gas_price().await;
+
+        for (tx_id, tx_hash, message_info, receiver) in verifications {
+            let Some(receiver) = receiver else {
+                shared_state.notify_gossip_acceptance(
+                    message_info,
+                    GossipsubMessageAcceptance::Ignore,
+                );
+                continue;
+            };
+
+            let acceptance = match tokio::time::timeout_at(self.deadline, receiver).await
+            {
+                Ok(Ok(Ok(checked_tx))) => match &gas_price {
+                    Ok(gas_price) => {
+                        match verify_tx_min_gas_price(checked_tx, &params, *gas_price) {
+                            Ok(checked_tx) => shared_state.insert_gossiped_tx(
+                                version,
+                                checked_tx,
+                                &message_info.peer_id,
+                            ),
+                            Err(_) => GossipsubMessageAcceptance::Reject,
+                        }
+                    }
+                    Err(e) => {
+                        tracing::error!(
+                            "Unable to get the gas price to verify the transaction {tx_id}: {e}"
+                        );
+                        GossipsubMessageAcceptance::Ignore
+                    }
+                },
+                Ok(Ok(Err(e))) => {
+                    if is_permanent_failure(&e) {
+                        self.rejected.lock().insert(tx_hash);
+                    }
+                    GossipsubMessageAcceptance::Reject
+                }
+                Ok(Err(_)) | Err(_) => {
+                    tracing::debug!(
+                        "The gossiped transaction {tx_id} is not verified in time"
+                    );
+                    GossipsubMessageAcceptance::Ignore
+                }
+            };
+
+            shared_state.notify_gossip_acceptance(message_info, acceptance);
+        }
+    }
+}
+
+impl<P2P, ViewProvider, WasmChecker, GasPriceProvider, ConsensusProvider, MP>
+    SharedState<P2P, ViewProvider, WasmChecker, GasPriceProvider, ConsensusProvider, MP>
+where
+    P2P: PeerToPeer<GossipedTransaction = TransactionGossipData>,
+    ViewProvider: AtomicView,
+    ViewProvider::LatestView: TxPoolDb,
+    WasmChecker: WasmCheckerConstraint + Send + Sync,
+{
+    fn insert_gossiped_tx(
+        &self,
+        version: ConsensusParametersVersion,
+        tx: Checked<Transaction>,
+        peer_id: &PeerId,
+    ) -> GossipsubMessageAcceptance {
+        let id = tx
+            .transaction()
+            .cached_id()
+            .expect("`Checked` tx should have cached id");
+
+        let mut result =
+            tracing::info_span!("Received tx via gossip", %id).in_scope(|| {
+                self.txpool.lock().insert(
+                    &self.tx_status_sender,
+                    version,
+                    TxSource::Peer(peer_id.clone()),
+                    vec![tx],
+                )
+            });
+        self.append_to_journal(&result);
+
+        match result.pop() {
+            Some(Ok(_)) => GossipsubMessageAcceptance::Accept,
+            // Use similar p2p punishment rules as bitcoin
+            // https://github.com/bitcoin/bitcoin/blob/6ff0aa089c01ff3e610ecb47814ed739d685a14c/src/net_processing.cpp#L1856
+            Some(Err(TxPoolError::ConsensusValidity(_)))
+            | Some(Err(TxPoolError::MintIsDisallowed)) => {
+                GossipsubMessageAcceptance::Reject
+            }
+            Some(Err(TxPoolError::NotInsertedPeerQuotaExceeded(_, _)))
+            | Some(Err(TxPoolError::NotInsertedOwnerQuotaExceeded(_, _))) => {
+                let result = self
+                    .p2p
+                    .report_peer(peer_id.clone(), PeerReportReason::QuotaExceeded);
+                if let Err(e) = result {
+                    tracing::error!(
+                        "Unable to report the peer {peer_id}, got an {e} error"
+                    );
+                }
+                GossipsubMessageAcceptance::Ignore
+            }
+            _ => GossipsubMessageAcceptance::Ignore,
+        }
+    }
+
+    pub(crate) fn notify_gossip_acceptance(
+        &self,
+        message_info: GossipsubMessageInfo,
+        acceptance: GossipsubMessageAcceptance,
+    ) {
+        let _ = self
+            .p2p
+            .notify_gossip_transaction_validity(message_info, acceptance);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rejected_txs_forgets_the_oldest_tx_when_full() {
+        let mut rejected = RejectedTxs::new(2);
+        let [first, second, third] = [[1u8; 32], [2u8; 32], [3u8; 32]].map(Bytes32::from);
+
+        rejected.insert(first);
+        rejected.insert(second);
+        rejected.insert(third);
+
+        assert!(!rejected.contains(&first));
+        assert!(rejected.contains(&second));
+        assert!(rejected.contains(&third));
+    }
+}
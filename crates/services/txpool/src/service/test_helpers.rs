@@ -4,6 +4,7 @@ use crate::{
     ports::{
         BlockImporter,
         MockConsensusParametersProvider,
+        PeerReportReason,
     },
     test_helpers::MockWasmChecker,
     types::GasPrice,
@@ -31,7 +32,10 @@ use fuel_core_types::{
     fuel_vm::interpreter::MemoryInstance,
     services::{
         block_importer::ImportResult,
-        p2p::GossipsubMessageAcceptance,
+        p2p::{
+            GossipsubMessageAcceptance,
+            PeerId,
+        },
     },
 };
 use std::cell::RefCell;
@@ -123,11 +127,16 @@ impl TestContext {
     pub fn setup_coin(&self) -> (Coin, Input) {
         crate::test_helpers::setup_coin(&mut self.rng.borrow_mut(), Some(&self.mock_db))
     }
+
+    pub fn mock_db(&self) -> &MockDb {
+        &self.mock_db
+    }
 }
 
 mockall::mock! {
     pub P2P {}
 
+    #[async_trait::async_trait]
     impl PeerToPeer for P2P {
         type GossipedTransaction = GossipedTransaction;
 
@@ -140,6 +149,18 @@ mockall::mock! {
             message_info: GossipsubMessageInfo,
             validity: GossipsubMessageAcceptance,
         ) -> anyhow::Result<()>;
+
+        fn report_peer(&self, peer_id: PeerId, reason: PeerReportReason) -> anyhow::Result<()>;
+
+        fn subscribe_new_peers(&self) -> BoxStream<PeerId>;
+
+        async fn request_tx_ids(&self, peer_id: PeerId) -> anyhow::Result<Vec<TxId>>;
+
+        async fn request_txs(
+            &self,
+            peer_id: PeerId,
+            tx_ids: Vec<TxId>,
+        ) -> anyhow::Result<Vec<Option<Transaction>>>;
     }
 }
 
@@ -224,6 +245,11 @@ impl TestContextBuilder {
         self
     }
 
+    pub fn with_mock_db(mut self, mock_db: MockDb) -> Self {
+        self.mock_db = mock_db;
+        self
+    }
+
     pub fn with_importer(&mut self, importer: MockImporter) {
         self.importer = Some(importer)
     }
@@ -247,6 +273,11 @@ impl TestContextBuilder {
     }
 
     pub fn build(self) -> TestContext {
+        self.try_build()
+            .expect("Failed to create the txpool service")
+    }
+
+    pub fn try_build(self) -> anyhow::Result<TestContext> {
         let rng = RefCell::new(self.rng);
         let gas_price = 0;
         let config = self.config.unwrap_or_default();
@@ -259,6 +290,9 @@ impl TestContextBuilder {
             .returning(move |_, _| Ok(()));
         p2p.expect_broadcast_transaction()
             .returning(move |_| Ok(()));
+        p2p.expect_report_peer().returning(move |_, _| Ok(()));
+        p2p.expect_subscribe_new_peers()
+            .returning(|| Box::pin(fuel_core_services::stream::pending()));
 
         let importer = self
             .importer
@@ -280,13 +314,13 @@ impl TestContextBuilder {
             gas_price_provider,
             consensus_parameters_provider,
             DummyPool,
-        );
+        )?;
 
-        TestContext {
+        Ok(TestContext {
             service,
             mock_db,
             rng,
-        }
+        })
     }
 
     pub async fn build_and_start(self) -> TestContext {
@@ -5,7 +5,12 @@ use crate::service::test_helpers::{
 };
 use fuel_core_services::Service as ServiceTrait;
 use fuel_core_types::{
-    fuel_tx::UniqueIdentifier,
+    fuel_tx::{
+        Address,
+        Cacheable,
+        TransactionBuilder,
+        UniqueIdentifier,
+    },
     fuel_types::ChainId,
 };
 use std::time::Duration;
@@ -279,3 +284,301 @@ async fn simple_insert_removal_subscription() {
 
     service.stop_and_await().await.unwrap();
 }
+
+#[tokio::test]
+async fn replaced_tx_is_squeezed_out_with_the_id_of_the_replacement() {
+    let ctx = TestContextBuilder::new().build_and_start().await;
+
+    let (_, gas_coin) = ctx.setup_coin();
+    let script_tx = |tip| {
+        let mut tx = TransactionBuilder::script(vec![], vec![])
+            .tip(tip)
+            .max_fee_limit(tip)
+            .script_gas_limit(1000)
+            .add_input(gas_coin.clone())
+            .finalize_as_transaction();
+        tx.precompute(&Default::default())
+            .expect("Should be able to cache");
+        Arc::new(tx)
+    };
+    let tx1 = script_tx(10);
+    let tx2 = script_tx(20);
+    let service = ctx.service();
+    let mut tx1_subscribe_updates = service
+        .shared
+        .tx_update_subscribe(tx1.cached_id().unwrap())
+        .unwrap();
+    service.shared.insert(vec![tx1.clone()]).await[0]
+        .as_ref()
+        .expect("Tx1 should be OK");
+
+    // When
+    service.shared.insert(vec![tx2.clone()]).await[0]
+        .as_ref()
+        .expect("Tx2 should be OK");
+
+    // Then
+    let update = tx1_subscribe_updates.next().await.unwrap();
+    assert!(matches!(
+        update,
+        TxStatusMessage::Status(TransactionStatus::Submitted { .. })
+    ));
+    let update = tx1_subscribe_updates.next().await.unwrap();
+    assert_eq!(
+        update,
+        TxStatusMessage::Status(TransactionStatus::SqueezedOut {
+            reason: format!(
+                "Transaction squeezed out because replaced by {}",
+                tx2.cached_id().unwrap()
+            )
+        })
+    );
+
+    service.stop_and_await().await.unwrap();
+}
+
+#[tokio::test]
+async fn test_journal_restores_transactions_after_restart() {
+    let dir = tempfile::tempdir().unwrap();
+    let config = Config {
+        journal_path: Some(dir.path().join("txpool.journal")),
+        ..Default::default()
+    };
+    let ctx = TestContextBuilder::new()
+        .with_config(config.clone())
+        .build_and_start()
+        .await;
+    let tx1 = Arc::new(ctx.setup_script_tx(10));
+    let tx2 = Arc::new(ctx.setup_script_tx(20));
+    let service = ctx.service();
+    let out = service.shared.insert(vec![tx1.clone(), tx2.clone()]).await;
+    assert!(out.iter().all(Result::is_ok), "Txs should be OK: {out:?}");
+    service.stop_and_await().await.unwrap();
+
+    // When
+    let restarted = TestContextBuilder::new()
+        .with_config(config)
+        .with_mock_db(ctx.mock_db().clone())
+        .build_and_start()
+        .await;
+
+    // Then
+    let out = restarted.service().shared.find(vec![
+        tx1.id(&Default::default()),
+        tx2.id(&Default::default()),
+    ]);
+    assert!(
+        out.iter().all(Option::is_some),
+        "Txs should be restored: {out:?}"
+    );
+    restarted.service().stop_and_await().await.unwrap();
+}
+
+#[tokio::test]
+async fn test_journal_drops_expired_transactions() {
+    let dir = tempfile::tempdir().unwrap();
+    let config = Config {
+        journal_path: Some(dir.path().join("txpool.journal")),
+        ..Default::default()
+    };
+    let ctx = TestContextBuilder::new()
+        .with_config(config.clone())
+        .build();
+    let tx = ctx.setup_script_tx(10);
+    let submitted_time = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .saturating_sub(config.transaction_ttl.saturating_mul(2));
+    Journal::new(dir.path().join("txpool.journal"))
+        .append(&[JournalEntry {
+            submitted_time,
+            tx: tx.clone(),
+        }])
+        .unwrap();
+
+    // When
+    ctx.service().start_and_await().await.unwrap();
+
+    // Then
+    let out = ctx.service().shared.find_one(tx.id(&Default::default()));
+    assert!(out.is_none(), "Expired tx should be dropped: {out:?}");
+    ctx.service().stop_and_await().await.unwrap();
+}
+
+#[tokio::test]
+async fn test_journal_restores_the_submission_time() {
+    let dir = tempfile::tempdir().unwrap();
+    let config = Config {
+        journal_path: Some(dir.path().join("txpool.journal")),
+        ..Default::default()
+    };
+    let ctx = TestContextBuilder::new()
+        .with_config(config.clone())
+        .build();
+    let tx = ctx.setup_script_tx(10);
+    let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap();
+    let submitted_time =
+        Duration::from_secs(now.saturating_sub(config.transaction_ttl / 2).as_secs());
+    Journal::new(dir.path().join("txpool.journal"))
+        .append(&[JournalEntry {
+            submitted_time,
+            tx: tx.clone(),
+        }])
+        .unwrap();
+
+    // When
+    ctx.service().start_and_await().await.unwrap();
+
+    // Then
+    let info = ctx
+        .service()
+        .shared
+        .find_one(tx.id(&Default::default()))
+        .expect("The tx should be restored");
+    assert_eq!(info.submitted_time(), submitted_time);
+    assert!(info.created().elapsed() >= config.transaction_ttl / 3);
+    ctx.service().stop_and_await().await.unwrap();
+}
+
+#[tokio::test]
+async fn test_journal_drops_invalid_transactions() {
+    let dir = tempfile::tempdir().unwrap();
+    let config = Config {
+        journal_path: Some(dir.path().join("txpool.journal")),
+        ..Default::default()
+    };
+    let ctx = TestContextBuilder::new().with_config(config).build();
+    // The coin of the transaction is only in the database of another node.
+    let tx = TestContext::new().await.setup_script_tx(10);
+    Journal::new(dir.path().join("txpool.journal"))
+        .append(&[JournalEntry {
+            submitted_time: SystemTime::now().duration_since(UNIX_EPOCH).unwrap(),
+            tx: tx.clone(),
+        }])
+        .unwrap();
+
+    // When
+    ctx.service().start_and_await().await.unwrap();
+
+    // Then
+    let out = ctx.service().shared.find_one(tx.id(&Default::default()));
+    assert!(out.is_none(), "Invalid tx should be dropped: {out:?}");
+    ctx.service().stop_and_await().await.unwrap();
+}
+
+#[tokio::test]
+async fn test_blacklisted_tx_is_squeezed_out_and_blacklist_is_persisted() {
+    let dir = tempfile::tempdir().unwrap();
+    let config = Config {
+        blacklist_path: Some(dir.path().join("txpool.blacklist")),
+        ..Default::default()
+    };
+    let ctx = TestContextBuilder::new()
+        .with_config(config.clone())
+        .build_and_start()
+        .await;
+    let (_, coin) = ctx.setup_coin();
+    let owner = *coin.input_owner().unwrap();
+    let mut tx = TransactionBuilder::script(vec![], vec![])
+        .script_gas_limit(1000)
+        .add_input(coin)
+        .finalize_as_transaction();
+    tx.precompute(&Default::default()).unwrap();
+    let tx_id = tx.id(&Default::default());
+    let service = ctx.service();
+    let mut tx_updates = service.shared.tx_update_subscribe(tx_id).unwrap();
+    service.shared.insert(vec![Arc::new(tx)]).await[0]
+        .as_ref()
+        .expect("Tx should be OK");
+
+    // When
+    let removed = service
+        .shared
+        .add_to_blacklist(BlackList::new(vec![owner], vec![], vec![], vec![]))
+        .unwrap();
+
+    // Then
+    assert_eq!(removed.len(), 1);
+    let update = tx_updates.next().await.unwrap();
+    assert!(matches!(
+        update,
+        TxStatusMessage::Status(TransactionStatus::Submitted { .. })
+    ));
+    let update = tx_updates.next().await.unwrap();
+    assert_eq!(
+        update,
+        TxStatusMessage::Status(TransactionStatus::SqueezedOut {
+            reason: format!("The owner `{owner}` is blacklisted"),
+        })
+    );
+    service.stop_and_await().await.unwrap();
+
+    let restarted = TestContextBuilder::new()
+        .with_config(config)
+        .build_and_start()
+        .await;
+    assert!(restarted
+        .service()
+        .shared
+        .blacklist()
+        .contains_address(&owner));
+    restarted.service().stop_and_await().await.unwrap();
+}
+
+#[tokio::test]
+async fn test_blacklist_persists_only_runtime_changes() {
+    let dir = tempfile::tempdir().unwrap();
+    let configured: Address = [1; 32].into();
+    let added: Address = [2; 32].into();
+    let config = Config {
+        blacklist: BlackList::new(vec![configured], vec![], vec![], vec![]),
+        blacklist_path: Some(dir.path().join("txpool.blacklist")),
+        ..Default::default()
+    };
+    let ctx = TestContextBuilder::new()
+        .with_config(config.clone())
+        .build_and_start()
+        .await;
+    let shared = &ctx.service().shared;
+
+    // When
+    shared
+        .add_to_blacklist(BlackList::new(vec![added], vec![], vec![], vec![]))
+        .unwrap();
+    shared
+        .remove_from_blacklist(BlackList::new(vec![configured], vec![], vec![], vec![]))
+        .unwrap();
+    ctx.service().stop_and_await().await.unwrap();
+
+    // Then
+    let content = std::fs::read_to_string(dir.path().join("txpool.blacklist")).unwrap();
+    assert_eq!(
+        content,
+        format!("add owner {added:#x}\nremove owner {configured:#x}\n")
+    );
+    let restarted = TestContextBuilder::new()
+        .with_config(config)
+        .build_and_start()
+        .await;
+    let blacklist = restarted.service().shared.blacklist();
+    assert!(blacklist.contains_address(&added));
+    assert!(!blacklist.contains_address(&configured));
+    restarted.service().stop_and_await().await.unwrap();
+}
+
+#[tokio::test]
+async fn test_corrupted_blacklist_fails_startup() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("txpool.blacklist");
+    std::fs::write(&path, "owner 0x01\n").unwrap();
+    let config = Config {
+        blacklist_path: Some(path),
+        ..Default::default()
+    };
+
+    // When
+    let result = TestContextBuilder::new().with_config(config).try_build();
+
+    // Then
+    assert!(result.is_err());
+}
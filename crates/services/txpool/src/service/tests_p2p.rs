@@ -1,5 +1,10 @@
 use super::*;
 use crate::{
+    config::{
+        GossipValidationConfig,
+        Quota,
+    },
+    ports::PeerReportReason,
     service::test_helpers::{
         MockP2P,
         TestContextBuilder,
@@ -12,8 +17,12 @@ use fuel_core_storage::rand::{
     SeedableRng,
 };
 use fuel_core_types::fuel_tx::{
-    field::Inputs,
+    field::{
+        Inputs,
+        Maturity,
+    },
     AssetId,
+    Input,
     Transaction,
     TransactionBuilder,
     UniqueIdentifier,
@@ -22,6 +31,8 @@ use std::{
     ops::Deref,
     time::Duration,
 };
+use tokio::sync::mpsc;
+use tokio_stream::wrappers::ReceiverStream;
 
 #[tokio::test]
 async fn can_insert_from_p2p() {
@@ -183,6 +194,203 @@ async fn test_gossipped_transaction_with_check_error_rejected() {
     )
 }
 
+#[tokio::test]
+async fn test_duplicate_of_rejected_gossipped_transaction_is_rejected_without_verification(
+) {
+    let mut ctx_builder = TestContextBuilder::new().with_config(Config {
+        gossip_validation: GossipValidationConfig {
+            batch_size: 2,
+            // the incomplete batch is never verified during the test
+            batch_timeout: Duration::from_secs(3600),
+            ..Default::default()
+        },
+        ..Default::default()
+    });
+    let valid_tx = ctx_builder.setup_script_tx(10);
+    // the transaction returns a CheckError because of the duplicated input
+    let mut invalid_tx = ctx_builder.setup_script_tx(20);
+    let script = invalid_tx.as_script_mut().unwrap();
+    let input = script.inputs()[0].clone();
+    script.inputs_mut().push(input);
+
+    // setup p2p mock - with txs incoming from p2p one by one
+    let mut p2p = MockP2P::default();
+    let (txs_sender, txs_receiver) = mpsc::channel(3);
+    p2p.expect_gossiped_transaction_events()
+        .return_once(move || Box::pin(ReceiverStream::new(txs_receiver)));
+    let (validity_sender, mut validity_receiver) = mpsc::unbounded_channel();
+    p2p.expect_notify_gossip_transaction_validity()
+        .returning(move |_, validity| {
+            validity_sender.send(validity).unwrap();
+            Ok(())
+        });
+    ctx_builder.with_p2p(p2p);
+
+    // build and start the txpool service
+    let ctx = ctx_builder.build();
+    let service = ctx.service();
+    service.start_and_await().await.unwrap();
+
+    // the full batch is verified
+    for tx in [invalid_tx.clone(), valid_tx] {
+        txs_sender
+            .send(GossipData::new(tx, vec![], vec![]))
+            .await
+            .unwrap();
+    }
+    for expected in [
+        GossipsubMessageAcceptance::Reject,
+        GossipsubMessageAcceptance::Accept,
+    ] {
+        let validity =
+            tokio::time::timeout(Duration::from_secs(1), validity_receiver.recv())
+                .await
+                .expect("expected to receive gossip validity notification");
+        assert_eq!(validity, Some(expected));
+    }
+
+    // when
+    txs_sender
+        .send(GossipData::new(invalid_tx, vec![], vec![]))
+        .await
+        .unwrap();
+
+    // then
+    // the duplicate is rejected without waiting for the rest of the batch
+    let validity = tokio::time::timeout(Duration::from_secs(1), validity_receiver.recv())
+        .await
+        .expect("expected to receive gossip validity notification");
+    assert_eq!(validity, Some(GossipsubMessageAcceptance::Reject));
+}
+
+#[tokio::test]
+async fn test_rejected_copy_does_not_reject_the_valid_transaction_with_the_same_id() {
+    let mut ctx_builder = TestContextBuilder::new().with_config(Config {
+        gossip_validation: GossipValidationConfig {
+            batch_size: 1,
+            ..Default::default()
+        },
+        ..Default::default()
+    });
+    let valid_tx = ctx_builder.setup_script_tx(10);
+    // the copy differs only in the predicate gas, which is not a part of the id
+    let mut invalid_copy = valid_tx.clone();
+    let script = invalid_copy.as_script_mut().unwrap();
+    let Input::CoinPredicate(coin) = &mut script.inputs_mut()[0] else {
+        panic!("The test transaction spends a predicate coin");
+    };
+    coin.predicate_gas_used += 1;
+    let chain_id = Default::default();
+    assert_eq!(valid_tx.id(&chain_id), invalid_copy.id(&chain_id));
+
+    // setup p2p mock - with txs incoming from p2p one by one
+    let mut p2p = MockP2P::default();
+    let (txs_sender, txs_receiver) = mpsc::channel(2);
+    p2p.expect_gossiped_transaction_events()
+        .return_once(move || Box::pin(ReceiverStream::new(txs_receiver)));
+    let (validity_sender, mut validity_receiver) = mpsc::unbounded_channel();
+    p2p.expect_notify_gossip_transaction_validity()
+        .returning(move |_, validity| {
+            validity_sender.send(validity).unwrap();
+            Ok(())
+        });
+    ctx_builder.with_p2p(p2p);
+
+    // build and start the txpool service
+    let ctx = ctx_builder.build();
+    let service = ctx.service();
+    service.start_and_await().await.unwrap();
+
+    // when
+    for (tx, expected) in [
+        (invalid_copy, GossipsubMessageAcceptance::Reject),
+        (valid_tx, GossipsubMessageAcceptance::Accept),
+    ] {
+        txs_sender
+            .send(GossipData::new(tx, vec![], vec![]))
+            .await
+            .unwrap();
+
+        // then
+        let validity =
+            tokio::time::timeout(Duration::from_secs(1), validity_receiver.recv())
+                .await
+                .expect("expected to receive gossip validity notification");
+        assert_eq!(validity, Some(expected));
+    }
+}
+
+#[tokio::test]
+async fn test_duplicate_of_immature_gossipped_transaction_is_verified_again() {
+    let mut ctx_builder = TestContextBuilder::new().with_config(Config {
+        gossip_validation: GossipValidationConfig {
+            batch_size: 2,
+            // the incomplete batch is never verified during the test
+            batch_timeout: Duration::from_secs(3600),
+            ..Default::default()
+        },
+        ..Default::default()
+    });
+    let valid_tx = ctx_builder.setup_script_tx(10);
+    // the transaction becomes valid only at the higher block height
+    let mut immature_tx = ctx_builder.setup_script_tx(20);
+    immature_tx
+        .as_script_mut()
+        .unwrap()
+        .set_maturity(1_000.into());
+
+    // setup p2p mock - with txs incoming from p2p one by one
+    let mut p2p = MockP2P::default();
+    let (txs_sender, txs_receiver) = mpsc::channel(3);
+    p2p.expect_gossiped_transaction_events()
+        .return_once(move || Box::pin(ReceiverStream::new(txs_receiver)));
+    let (validity_sender, mut validity_receiver) = mpsc::unbounded_channel();
+    p2p.expect_notify_gossip_transaction_validity()
+        .returning(move |_, validity| {
+            validity_sender.send(validity).unwrap();
+            Ok(())
+        });
+    ctx_builder.with_p2p(p2p);
+
+    // build and start the txpool service
+    let ctx = ctx_builder.build();
+    let service = ctx.service();
+    service.start_and_await().await.unwrap();
+
+    // the full batch is verified
+    for tx in [immature_tx.clone(), valid_tx] {
+        txs_sender
+            .send(GossipData::new(tx, vec![], vec![]))
+            .await
+            .unwrap();
+    }
+    for expected in [
+        GossipsubMessageAcceptance::Reject,
+        GossipsubMessageAcceptance::Accept,
+    ] {
+        let validity =
+            tokio::time::timeout(Duration::from_secs(1), validity_receiver.recv())
+                .await
+                .expect("expected to receive gossip validity notification");
+        assert_eq!(validity, Some(expected));
+    }
+
+    // when
+    txs_sender
+        .send(GossipData::new(immature_tx, vec![], vec![]))
+        .await
+        .unwrap();
+
+    // then
+    // the duplicate waits for the verification in the next batch
+    let validity =
+        tokio::time::timeout(Duration::from_millis(100), validity_receiver.recv()).await;
+    assert!(
+        validity.is_err(),
+        "expected the duplicate to be verified again"
+    );
+}
+
 #[tokio::test]
 async fn test_gossipped_mint_rejected() {
     // verify that gossipped mint transactions are rejected (punished)
@@ -267,3 +475,215 @@ async fn test_gossipped_transaction_with_transient_error_ignored() {
         "expected to receive gossip validity notification"
     )
 }
+
+#[tokio::test]
+async fn test_gossipped_transactions_exceeding_peer_quota_ignored_and_reported() {
+    // verify that the peer exceeding its quota is reported, but its transactions are only ignored
+    let mut ctx_builder = TestContextBuilder::new().with_config(Config {
+        peer_quota: Quota {
+            max_txs: Some(1),
+            ..Default::default()
+        },
+        ..Default::default()
+    });
+    let tx1 = ctx_builder.setup_script_tx(10);
+    let tx2 = ctx_builder.setup_script_tx(20);
+    // setup p2p mock - with txs incoming from the same peer
+    let txs = vec![tx1.clone(), tx2.clone()];
+    let mut p2p = MockP2P::new_with_txs(txs);
+    let (send, mut receive) = broadcast::channel::<()>(1);
+    p2p.expect_report_peer()
+        .times(1)
+        .returning(move |_, reason| {
+            assert_eq!(reason, PeerReportReason::QuotaExceeded);
+            // Notify test that the peer was reported
+            send.send(()).unwrap();
+            Ok(())
+        });
+    p2p.expect_notify_gossip_transaction_validity()
+        .returning(move |_, validity| {
+            // Expect the transactions to not be rejected
+            assert_ne!(validity, GossipsubMessageAcceptance::Reject);
+            Ok(())
+        });
+    ctx_builder.with_p2p(p2p);
+
+    // build and start the txpool service
+    let ctx = ctx_builder.build();
+    let service = ctx.service();
+    service.start_and_await().await.unwrap();
+    // verify p2p was notified about the peer exceeding the quota
+    let peer_reported =
+        tokio::time::timeout(Duration::from_millis(100), receive.recv()).await;
+    assert!(peer_reported.is_ok(), "expected the peer to be reported");
+}
+
+#[tokio::test]
+async fn test_missing_transactions_of_new_peer_are_requested_and_inserted() {
+    let mut ctx_builder = TestContextBuilder::new();
+    let known_tx = ctx_builder.setup_script_tx(10);
+    let missing_tx = ctx_builder.setup_script_tx(20);
+    let known_tx_id = known_tx.id(&Default::default());
+    let missing_tx_id = missing_tx.id(&Default::default());
+
+    // setup p2p mock - the new peer knows both transactions
+    let mut p2p = MockP2P::new_with_txs(vec![]);
+    let (new_peers_sender, new_peers_receiver) = mpsc::channel(1);
+    p2p.expect_subscribe_new_peers()
+        .return_once(move || Box::pin(ReceiverStream::new(new_peers_receiver)));
+    p2p.expect_request_tx_ids()
+        .times(1)
+        .returning(move |_| Ok(vec![known_tx_id, missing_tx_id]));
+    let peer_tx = missing_tx.clone();
+    p2p.expect_request_txs()
+        .times(1)
+        .withf(move |_, tx_ids| tx_ids == &[missing_tx_id])
+        .returning(move |_, _| Ok(vec![Some(peer_tx.clone())]));
+    ctx_builder.with_p2p(p2p);
+
+    // build and start the txpool service
+    let ctx = ctx_builder.build_and_start().await;
+    let service = ctx.service();
+    let inserted = service.shared.insert(vec![Arc::new(known_tx)]).await;
+    assert!(inserted[0].is_ok());
+    let mut new_tx_notification = service.shared.new_tx_notification_subscribe();
+
+    // the new peer is connected
+    new_peers_sender.send(PeerId::from(vec![1])).await.unwrap();
+
+    // verify only the missing transaction was requested and inserted
+    let synced_tx =
+        tokio::time::timeout(Duration::from_secs(1), new_tx_notification.recv())
+            .await
+            .expect("expected the missing transaction to be inserted")
+            .unwrap();
+    assert_eq!(synced_tx, missing_tx_id);
+    let out = service.shared.find(vec![missing_tx_id]);
+    let got_tx: Transaction = out[0].as_ref().unwrap().tx().clone().deref().into();
+    assert_eq!(missing_tx, got_tx);
+}
+
+#[tokio::test]
+async fn test_transactions_requested_from_new_peer_are_capped_by_max_tx() {
+    let mut ctx_builder = TestContextBuilder::new().with_config(Config {
+        max_tx: 1,
+        ..Default::default()
+    });
+    let tx1 = ctx_builder.setup_script_tx(10);
+    let tx2 = ctx_builder.setup_script_tx(20);
+    let tx_ids = vec![tx1.id(&Default::default()), tx2.id(&Default::default())];
+
+    // setup p2p mock - the new peer knows more transactions than the pool can hold
+    let mut p2p = MockP2P::new_with_txs(vec![]);
+    let (new_peers_sender, new_peers_receiver) = mpsc::channel(1);
+    p2p.expect_subscribe_new_peers()
+        .return_once(move || Box::pin(ReceiverStream::new(new_peers_receiver)));
+    p2p.expect_request_tx_ids()
+        .times(1)
+        .returning(move |_| Ok(tx_ids.clone()));
+    let (send, mut receive) = broadcast::channel::<usize>(1);
+    p2p.expect_request_txs()
+        .times(1)
+        .returning(move |_, tx_ids| {
+            // Notify test about the number of requested transactions
+            send.send(tx_ids.len()).unwrap();
+            Ok(vec![Some(tx1.clone())])
+        });
+    ctx_builder.with_p2p(p2p);
+
+    // build and start the txpool service
+    let ctx = ctx_builder.build_and_start().await;
+
+    // the new peer is connected
+    new_peers_sender.send(PeerId::from(vec![1])).await.unwrap();
+
+    // verify the number of requested transactions doesn't exceed `max_tx`
+    let requested = tokio::time::timeout(Duration::from_secs(1), receive.recv())
+        .await
+        .expect("expected the transactions to be requested")
+        .unwrap();
+    assert_eq!(requested, 1);
+    drop(ctx);
+}
+
+#[tokio::test]
+async fn test_unrequested_transactions_of_new_peer_are_dropped_and_reported() {
+    let mut ctx_builder = TestContextBuilder::new();
+    let requested_tx = ctx_builder.setup_script_tx(10);
+    let unrequested_tx = ctx_builder.setup_script_tx(20);
+    let requested_tx_id = requested_tx.id(&Default::default());
+    let unrequested_tx_id = unrequested_tx.id(&Default::default());
+
+    // setup p2p mock - the new peer responds with another transaction
+    let mut p2p = MockP2P::new_with_txs(vec![]);
+    let (new_peers_sender, new_peers_receiver) = mpsc::channel(1);
+    p2p.expect_subscribe_new_peers()
+        .return_once(move || Box::pin(ReceiverStream::new(new_peers_receiver)));
+    p2p.expect_request_tx_ids()
+        .times(1)
+        .returning(move |_| Ok(vec![requested_tx_id]));
+    p2p.expect_request_txs()
+        .times(1)
+        .returning(move |_, _| Ok(vec![Some(unrequested_tx.clone())]));
+    let (send, mut receive) = broadcast::channel::<PeerReportReason>(1);
+    p2p.expect_report_peer()
+        .times(1)
+        .returning(move |_, reason| {
+            // Notify test that the peer was reported
+            send.send(reason).unwrap();
+            Ok(())
+        });
+    ctx_builder.with_p2p(p2p);
+
+    // build and start the txpool service
+    let ctx = ctx_builder.build_and_start().await;
+
+    // the new peer is connected
+    new_peers_sender.send(PeerId::from(vec![1])).await.unwrap();
+
+    // verify the peer was reported and the transaction was not inserted
+    let reason = tokio::time::timeout(Duration::from_secs(1), receive.recv())
+        .await
+        .expect("expected the peer to be reported")
+        .unwrap();
+    assert_eq!(reason, PeerReportReason::InvalidTransactions);
+    let out = ctx.service().shared.find(vec![unrequested_tx_id]);
+    assert!(out[0].is_none(), "Unrequested tx should be dropped");
+}
+
+#[tokio::test]
+async fn test_reconnected_peer_is_not_synchronized_again_during_cooldown() {
+    let mut ctx_builder = TestContextBuilder::new();
+
+    // setup p2p mock - the new peer has no transactions
+    let mut p2p = MockP2P::new_with_txs(vec![]);
+    let (new_peers_sender, new_peers_receiver) = mpsc::channel(2);
+    p2p.expect_subscribe_new_peers()
+        .return_once(move || Box::pin(ReceiverStream::new(new_peers_receiver)));
+    let (send, mut receive) = broadcast::channel::<PeerId>(2);
+    p2p.expect_request_tx_ids().returning(move |peer_id| {
+        // Notify test about the synchronization
+        send.send(peer_id).unwrap();
+        Ok(vec![])
+    });
+    ctx_builder.with_p2p(p2p);
+
+    // build and start the txpool service
+    let ctx = ctx_builder.build_and_start().await;
+
+    // the peer is connected twice
+    let peer_id = PeerId::from(vec![1]);
+    new_peers_sender.send(peer_id.clone()).await.unwrap();
+    new_peers_sender.send(peer_id.clone()).await.unwrap();
+
+    // verify the peer is synchronized only once
+    let synced = tokio::time::timeout(Duration::from_secs(1), receive.recv())
+        .await
+        .expect("expected the peer to be synchronized")
+        .unwrap();
+    assert_eq!(synced, peer_id);
+    let synced_again =
+        tokio::time::timeout(Duration::from_millis(100), receive.recv()).await;
+    assert!(synced_again.is_err(), "expected the peer to be skipped");
+    drop(ctx);
+}
@@ -1,42 +1,295 @@
 use fuel_core_types::{
+    fuel_tx::Address,
     fuel_types::Word,
     services::txpool::ArcPoolTx,
 };
+use std::{
+    collections::{
+        HashMap,
+        VecDeque,
+    },
+    sync::Arc,
+};
+
+/// The type of the index for the transaction is `u16`, so we need to
+/// limit it to `MAX` value minus 1(because of the `Mint` transaction).
+const MAX_TRANSACTIONS_PER_BLOCK: usize = (u16::MAX - 1) as usize;
+
+/// The limits of the block that the selected transactions should fit into.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SelectionLimits {
+    /// The maximum gas of all selected transactions combined.
+    pub max_gas: Word,
+    /// The maximum size in bytes of all selected transactions combined.
+    pub max_block_bytes: u64,
+}
+
+impl SelectionLimits {
+    /// The limits that only restrict the gas of the block.
+    pub fn gas_only(max_gas: Word) -> Self {
+        Self {
+            max_gas,
+            max_block_bytes: u64::MAX,
+        }
+    }
+}
+
+/// The strategy used by the `TxPool` to select transactions for the next block.
+///
+/// The transactions are provided sorted by the tip per gas, highest first.
+/// The strategy returns the subset of them that fits into the `SelectionLimits`.
+/// The relative order of the selected transactions should be preserved,
+/// because the dependent transactions go after the transactions they depend on.
+pub trait TransactionSelectionStrategy: Send + Sync {
+    /// Selects the transactions for the next block.
+    fn select(
+        &self,
+        includable_txs: Vec<ArcPoolTx>,
+        limits: SelectionLimits,
+    ) -> Vec<ArcPoolTx>;
+}
+
+/// The built-in transaction selection strategies.
+#[derive(
+    Debug,
+    Default,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    strum_macros::Display,
+    strum_macros::EnumString,
+    strum_macros::EnumVariantNames,
+)]
+#[strum(serialize_all = "kebab_case")]
+pub enum SelectionStrategy {
+    /// Greedily fills the block with the transactions with the highest tip per gas.
+    #[default]
+    TipPerGas,
+    /// Maximizes the total tip of the block, respecting both the gas and the size
+    /// of the block.
+    Knapsack,
+    /// Gives each owner of transactions an equal share of the block.
+    FairShare,
+}
+
+impl SelectionStrategy {
+    /// Returns the implementation of the strategy.
+    pub fn strategy(&self) -> Arc<dyn TransactionSelectionStrategy> {
+        match self {
+            SelectionStrategy::TipPerGas => Arc::new(TipPerGasSelection),
+            SelectionStrategy::Knapsack => Arc::new(KnapsackSelection),
+            SelectionStrategy::FairShare => Arc::new(FairShareSelection),
+        }
+    }
+}
+
+/// Tracks the space used by the selected transactions.
+#[derive(Default)]
+struct UsedSpace {
+    gas: Word,
+    bytes: u64,
+    txs: usize,
+}
+
+impl UsedSpace {
+    /// Reserves the space for the transaction if it fits into the `limits`.
+    fn try_reserve(&mut self, tx: &ArcPoolTx, limits: &SelectionLimits) -> bool {
+        if self.txs >= MAX_TRANSACTIONS_PER_BLOCK {
+            return false
+        }
+
+        let gas = self.gas.checked_add(tx.max_gas());
+        let bytes = self.bytes.checked_add(tx.metered_bytes_size() as u64);
+        match (gas, bytes) {
+            (Some(gas), Some(bytes))
+                if gas <= limits.max_gas && bytes <= limits.max_block_bytes =>
+            {
+                self.gas = gas;
+                self.bytes = bytes;
+                self.txs = self.txs.saturating_add(1);
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+/// Selects all transactions that fit into the block, preferring ones with higher tip per gas.
+///
+/// Future improvements to this algorithm may take into account the parallel nature of
+/// transactions to maximize throughput.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct TipPerGasSelection;
+
+impl TransactionSelectionStrategy for TipPerGasSelection {
+    fn select(
+        &self,
+        includable_txs: Vec<ArcPoolTx>,
+        limits: SelectionLimits,
+    ) -> Vec<ArcPoolTx> {
+        let mut used_space = UsedSpace::default();
+
+        // Pick as many transactions as we can fit into the block (greedy)
+        includable_txs
+            .into_iter()
+            .filter(|tx| used_space.try_reserve(tx, &limits))
+            .collect()
+    }
+}
+
+/// Selects transactions maximizing the total tip of the block.
+///
+/// Each transaction is weighted by the largest share of the block's gas or size
+/// it takes, rounded up to [`KNAPSACK_RESOLUTION`] units. The 0/1 knapsack problem is
+/// solved over these weights, and the space left because of the rounding is filled
+/// greedily by the tip per gas.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct KnapsackSelection;
+
+/// The number of units the block is split into by the `KnapsackSelection`.
+pub const KNAPSACK_RESOLUTION: usize = 1000;
 
-// transaction selection could use a plugin based approach in the
-// future for block producers to customize block building (e.g. alternative priorities besides gas fees)
-
-// Expects sorted by gas price transactions, highest first
-pub fn select_transactions(
-    includable_txs: impl Iterator<Item = ArcPoolTx>,
-    max_gas: u64,
-) -> Vec<ArcPoolTx> {
-    // Select all txs that fit into the block, preferring ones with higher gas price.
-    //
-    // Future improvements to this algorithm may take into account the parallel nature of
-    // transactions to maximize throughput.
-    let mut used_block_space: Word = 0;
-    // The type of the index for the transaction is `u16`, so we need to
-    // limit it to `MAX` value minus 1(because of the `Mint` transaction).
-    let takes_txs = u16::MAX - 1;
-
-    // Pick as many transactions as we can fit into the block (greedy)
-    includable_txs
-        .filter(|tx| {
-            let tx_block_space = tx.max_gas();
-            if let Some(new_used_space) = used_block_space.checked_add(tx_block_space) {
-                if new_used_space <= max_gas {
-                    used_block_space = new_used_space;
-                    true
-                } else {
-                    false
+impl KnapsackSelection {
+    /// The share of the `limit` taken by the `used` value in the `KNAPSACK_RESOLUTION`
+    /// units, rounded up. `None` if it doesn't fit into the block.
+    fn share(used: u64, limit: u64) -> Option<usize> {
+        let share = match (used, limit) {
+            (0, _) => 0,
+            (_, 0) => return None,
+            (used, limit) => u128::from(used)
+                .saturating_mul(KNAPSACK_RESOLUTION as u128)
+                .div_ceil(u128::from(limit)),
+        };
+        usize::try_from(share)
+            .ok()
+            .filter(|share| *share <= KNAPSACK_RESOLUTION)
+    }
+
+    fn weight(tx: &ArcPoolTx, limits: &SelectionLimits) -> Option<usize> {
+        let gas = Self::share(tx.max_gas(), limits.max_gas)?;
+        let bytes = Self::share(tx.metered_bytes_size() as u64, limits.max_block_bytes)?;
+        Some(gas.max(bytes))
+    }
+}
+
+impl TransactionSelectionStrategy for KnapsackSelection {
+    fn select(
+        &self,
+        includable_txs: Vec<ArcPoolTx>,
+        limits: SelectionLimits,
+    ) -> Vec<ArcPoolTx> {
+        // `best_tips[c]` is the highest total tip of transactions with the weight `c` or less.
+        let mut best_tips = vec![0u64; KNAPSACK_RESOLUTION.saturating_add(1)];
+        let mut taken = Vec::with_capacity(includable_txs.len());
+        for tx in includable_txs.iter() {
+            let mut taken_by_tx = vec![false; best_tips.len()];
+            if let Some(weight) = Self::weight(tx, &limits) {
+                for capacity in (weight..=KNAPSACK_RESOLUTION).rev() {
+                    let candidate = best_tips[capacity.saturating_sub(weight)]
+                        .saturating_add(tx.tip());
+                    if candidate > best_tips[capacity] {
+                        best_tips[capacity] = candidate;
+                        taken_by_tx[capacity] = true;
+                    }
                 }
-            } else {
-                false
             }
-        })
-        .take(takes_txs as usize)
-        .collect()
+            taken.push(taken_by_tx);
+        }
+
+        let mut selected = vec![false; includable_txs.len()];
+        let mut capacity = KNAPSACK_RESOLUTION;
+        for (index, tx) in includable_txs.iter().enumerate().rev() {
+            if taken[index][capacity] {
+                selected[index] = true;
+                let weight = Self::weight(tx, &limits).unwrap_or_default();
+                capacity = capacity.saturating_sub(weight);
+            }
+        }
+
+        // The rounding of weights guarantees that the selected transactions fit
+        // into the limits, but the limits are checked again to respect the number
+        // of transactions and to fill the rest of the block.
+        let mut used_space = UsedSpace::default();
+        for (index, tx) in includable_txs.iter().enumerate() {
+            if selected[index] {
+                selected[index] = used_space.try_reserve(tx, &limits);
+            }
+        }
+        for (index, tx) in includable_txs.iter().enumerate() {
+            if !selected[index] {
+                selected[index] = used_space.try_reserve(tx, &limits);
+            }
+        }
+
+        includable_txs
+            .into_iter()
+            .zip(selected)
+            .filter_map(|(tx, selected)| selected.then_some(tx))
+            .collect()
+    }
+}
+
+/// Selects transactions in rounds, taking the next best transaction of each owner
+/// in each round, so owners with many transactions can't take the whole block.
+///
+/// The owner of the transaction is the owner of its first coin or message input.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct FairShareSelection;
+
+impl FairShareSelection {
+    fn owner(tx: &ArcPoolTx) -> Option<Address> {
+        tx.inputs()
+            .iter()
+            .find_map(|input| input.input_owner().or_else(|| input.recipient()))
+            .copied()
+    }
+}
+
+impl TransactionSelectionStrategy for FairShareSelection {
+    fn select(
+        &self,
+        includable_txs: Vec<ArcPoolTx>,
+        limits: SelectionLimits,
+    ) -> Vec<ArcPoolTx> {
+        // Owners are ordered by their best transaction.
+        let mut owners = Vec::new();
+        let mut queues = HashMap::<Option<Address>, VecDeque<usize>>::new();
+        for (index, tx) in includable_txs.iter().enumerate() {
+            let owner = Self::owner(tx);
+            queues
+                .entry(owner)
+                .or_insert_with(|| {
+                    owners.push(owner);
+                    VecDeque::new()
+                })
+                .push_back(index);
+        }
+
+        let mut used_space = UsedSpace::default();
+        let mut selected = vec![false; includable_txs.len()];
+        while !owners.is_empty() {
+            owners.retain(|owner| {
+                let Some(queue) = queues.get_mut(owner) else {
+                    return false
+                };
+                if let Some(index) = queue.pop_front() {
+                    if let Some(tx) = includable_txs.get(index) {
+                        if used_space.try_reserve(tx, &limits) {
+                            selected[index] = true;
+                        }
+                    }
+                }
+                !queue.is_empty()
+            });
+        }
+
+        includable_txs
+            .into_iter()
+            .zip(selected)
+            .filter_map(|(tx, selected)| selected.then_some(tx))
+            .collect()
+    }
 }
 
 #[cfg(test)]
@@ -75,50 +328,45 @@ mod tests {
         pub limit: u64,
     }
 
-    /// A test helper that generates set of txs with given gas prices and limits and runs
-    /// `select_transactions` against that, returning the list of selected gas price, limit pairs
-    fn make_txs_and_select(txs: &[TxGas], block_gas_limit: Word) -> Vec<TxGas> {
+    fn make_tx(tx_gas: &TxGas, owner: &SecretKey) -> ArcPoolTx {
         let mut rng = thread_rng();
 
         let fee_params = FeeParameters::default()
             .with_gas_per_byte(0)
             .with_gas_price_factor(1);
 
-        let mut txs = txs
-            .iter()
-            .map(|tx_gas| {
-                let script = TransactionBuilder::script(
-                    vec![op::ret(RegId::ONE)].into_iter().collect(),
-                    vec![],
-                )
-                .tip(tx_gas.tip)
-                .script_gas_limit(tx_gas.limit)
-                .add_unsigned_coin_input(
-                    SecretKey::random(&mut rng),
-                    rng.gen(),
-                    1_000_000,
-                    Default::default(),
-                    Default::default(),
-                )
-                .add_output(Output::Change {
-                    to: Default::default(),
-                    amount: 0,
-                    asset_id: Default::default(),
-                })
-                .with_fee_params(fee_params)
-                .with_gas_costs(GasCosts::free())
-                // The block producer assumes transactions are already checked
-                // so it doesn't need to compute valid sigs for tests
-                .finalize_checked_basic(Default::default());
+        let script = TransactionBuilder::script(
+            vec![op::ret(RegId::ONE)].into_iter().collect(),
+            vec![],
+        )
+        .tip(tx_gas.tip)
+        .script_gas_limit(tx_gas.limit)
+        .add_unsigned_coin_input(
+            *owner,
+            rng.gen(),
+            1_000_000,
+            Default::default(),
+            Default::default(),
+        )
+        .add_output(Output::Change {
+            to: Default::default(),
+            amount: 0,
+            asset_id: Default::default(),
+        })
+        .with_fee_params(fee_params)
+        .with_gas_costs(GasCosts::free())
+        // The block producer assumes transactions are already checked
+        // so it doesn't need to compute valid sigs for tests
+        .finalize_checked_basic(Default::default());
 
-                PoolTransaction::Script(script, ConsensusParametersVersion::MIN)
-            })
-            .map(Arc::new)
-            .collect::<Vec<ArcPoolTx>>();
-        txs.sort_by_key(|a| core::cmp::Reverse(a.tip()));
+        Arc::new(PoolTransaction::Script(
+            script,
+            ConsensusParametersVersion::MIN,
+        ))
+    }
 
-        select_transactions(txs.into_iter(), block_gas_limit)
-            .into_iter()
+    fn to_tx_gas(txs: Vec<ArcPoolTx>) -> Vec<TxGas> {
+        txs.into_iter()
             .map(|tx| TxGas {
                 limit: tx.script_gas_limit().unwrap_or_default(),
                 tip: tx.tip(),
@@ -126,6 +374,22 @@ mod tests {
             .collect()
     }
 
+    /// A test helper that generates set of txs with given gas prices and limits and runs
+    /// `TipPerGasSelection` against that, returning the list of selected gas price, limit pairs
+    fn make_txs_and_select(txs: &[TxGas], block_gas_limit: Word) -> Vec<TxGas> {
+        let mut rng = thread_rng();
+
+        let mut txs = txs
+            .iter()
+            .map(|tx_gas| make_tx(tx_gas, &SecretKey::random(&mut rng)))
+            .collect::<Vec<ArcPoolTx>>();
+        txs.sort_by_key(|a| core::cmp::Reverse(a.tip()));
+
+        to_tx_gas(
+            TipPerGasSelection.select(txs, SelectionLimits::gas_only(block_gas_limit)),
+        )
+    }
+
     #[test]
     fn selector_works_with_empty_input() {
         let selected = make_txs_and_select(&[], 1_000_000);
@@ -205,4 +469,144 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn knapsack_selection_prefers_higher_total_tip() {
+        let mut rng = thread_rng();
+        // Sorted by tip per gas, the greedy selection takes only the first transaction.
+        #[rustfmt::skip]
+        let txs = [
+            TxGas { tip: 60, limit: 600 },
+            TxGas { tip: 45, limit: 500 },
+            TxGas { tip: 45, limit: 500 },
+        ]
+        .iter()
+        .map(|tx_gas| make_tx(tx_gas, &SecretKey::random(&mut rng)))
+        .collect::<Vec<_>>();
+        let limits = SelectionLimits::gas_only(1000);
+
+        // When
+        let greedy = to_tx_gas(TipPerGasSelection.select(txs.clone(), limits));
+        let knapsack = to_tx_gas(KnapsackSelection.select(txs, limits));
+
+        // Then
+        assert_eq!(
+            greedy,
+            vec![TxGas {
+                tip: 60,
+                limit: 600
+            }]
+        );
+        assert_eq!(
+            knapsack,
+            vec![
+                TxGas {
+                    tip: 45,
+                    limit: 500
+                },
+                TxGas {
+                    tip: 45,
+                    limit: 500
+                }
+            ]
+        );
+    }
+
+    #[test]
+    fn knapsack_selection_respects_max_block_bytes() {
+        let mut rng = thread_rng();
+        let txs = (1..=5)
+            .map(|tip| make_tx(&TxGas { tip, limit: 10 }, &SecretKey::random(&mut rng)))
+            .collect::<Vec<_>>();
+        let tx_size = txs[0].metered_bytes_size() as u64;
+        let limits = SelectionLimits {
+            max_gas: 1_000_000,
+            max_block_bytes: tx_size * 2,
+        };
+
+        // When
+        let selected = KnapsackSelection.select(txs, limits);
+
+        // Then
+        let total_size: u64 = selected
+            .iter()
+            .map(|tx| tx.metered_bytes_size() as u64)
+            .sum();
+        assert!(total_size <= limits.max_block_bytes);
+        assert_eq!(
+            to_tx_gas(selected),
+            vec![TxGas { tip: 4, limit: 10 }, TxGas { tip: 5, limit: 10 }]
+        );
+    }
+
+    #[test]
+    fn fair_share_selection_gives_each_owner_a_share_of_the_block() {
+        let mut rng = thread_rng();
+        let whale = SecretKey::random(&mut rng);
+        let small_owner = SecretKey::random(&mut rng);
+        let mut txs = (0..5)
+            .map(|i| {
+                make_tx(
+                    &TxGas {
+                        tip: 100 - i,
+                        limit: 1000,
+                    },
+                    &whale,
+                )
+            })
+            .collect::<Vec<_>>();
+        txs.push(make_tx(
+            &TxGas {
+                tip: 1,
+                limit: 1000,
+            },
+            &small_owner,
+        ));
+        let limits = SelectionLimits::gas_only(3000);
+
+        // When
+        let greedy = to_tx_gas(TipPerGasSelection.select(txs.clone(), limits));
+        let fair_share = to_tx_gas(FairShareSelection.select(txs, limits));
+
+        // Then
+        assert!(!greedy.contains(&TxGas {
+            tip: 1,
+            limit: 1000
+        }));
+        assert_eq!(
+            fair_share,
+            vec![
+                TxGas {
+                    tip: 100,
+                    limit: 1000
+                },
+                TxGas {
+                    tip: 99,
+                    limit: 1000
+                },
+                TxGas {
+                    tip: 1,
+                    limit: 1000
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn selection_strategy_parses_from_kebab_case() {
+        use std::str::FromStr;
+
+        assert_eq!(
+            SelectionStrategy::from_str("tip-per-gas"),
+            Ok(SelectionStrategy::TipPerGas)
+        );
+        assert_eq!(
+            SelectionStrategy::from_str("knapsack"),
+            Ok(SelectionStrategy::Knapsack)
+        );
+        assert_eq!(
+            SelectionStrategy::from_str("fair-share"),
+            Ok(SelectionStrategy::FairShare)
+        );
+    }
 }
@@ -1,6 +1,8 @@
 use crate::{
+    config::BlackList,
     containers::{
         dependency::Dependency,
+        quota::QuotaTracker,
         time_sort::TimeSort,
         tip_per_gas_sort::RatioGasTipSort,
     },
@@ -13,6 +15,7 @@ use crate::{
     Config,
     Error,
     TxInfo,
+    TxSource,
 };
 use fuel_core_types::{
     fuel_tx::{
@@ -59,6 +62,7 @@ use fuel_core_types::{
         },
         ConsensusParameters,
         Input,
+        Output,
     },
     fuel_vm::{
         checked_transaction::CheckPredicateParams,
@@ -67,9 +71,13 @@ use fuel_core_types::{
     services::executor::TransactionExecutionStatus,
 };
 use std::{
-    collections::HashMap,
+    collections::{
+        HashMap,
+        HashSet,
+    },
     ops::Deref,
     sync::Arc,
+    time::Duration,
 };
 
 #[cfg(test)]
@@ -83,6 +91,7 @@ pub struct TxPool<ViewProvider, WasmChecker> {
     by_ratio_gas_tip: RatioGasTipSort,
     by_time: TimeSort,
     by_dependency: Dependency,
+    by_quota: QuotaTracker,
     config: Config,
     database: ViewProvider,
     wasm_checker: WasmChecker,
@@ -100,14 +109,18 @@ impl<ViewProvider, WasmChecker> TxPool<ViewProvider, WasmChecker> {
             by_hash: HashMap::new(),
             by_ratio_gas_tip: RatioGasTipSort::default(),
             by_time: TimeSort::default(),
-            by_dependency: Dependency::new(max_depth, config.utxo_validation),
+            by_dependency: Dependency::new(
+                max_depth,
+                config.utxo_validation,
+                config.min_replacement_tip_bump_percent,
+            ),
+            by_quota: QuotaTracker::new(config.owner_quota, config.peer_quota),
             config,
             database,
             wasm_checker,
         }
     }
 
-    #[cfg(test)]
     pub fn config(&self) -> &Config {
         &self.config
     }
@@ -158,6 +171,7 @@ impl<ViewProvider, WasmChecker> TxPool<ViewProvider, WasmChecker> {
         if let Some(info) = &info {
             self.by_time.remove(info);
             self.by_ratio_gas_tip.remove(info);
+            self.by_quota.remove(tx_id);
         }
 
         info
@@ -238,6 +252,56 @@ impl<ViewProvider, WasmChecker> TxPool<ViewProvider, WasmChecker> {
         removed
     }
 
+    pub fn blacklist(&self) -> &BlackList {
+        &self.config.blacklist
+    }
+
+    /// Adds entries to the blacklist and removes pending transactions that use them,
+    /// together with their dependents.
+    pub fn add_to_blacklist(
+        &mut self,
+        tx_status_sender: &TxStatusChange,
+        entries: BlackList,
+    ) -> Vec<ArcPoolTx> {
+        self.config.blacklist.extend(entries);
+
+        let blacklisted: Vec<_> = self
+            .by_hash
+            .values()
+            .filter_map(|info| {
+                self.check_blacklisting(info.tx())
+                    .err()
+                    .map(|reason| (info.tx().id(), reason))
+            })
+            .collect();
+
+        let mut removed = Vec::new();
+        for (tx_id, reason) in blacklisted {
+            // The transaction is already removed if it was a dependent of another one.
+            let rem = self.remove_by_tx_id(&tx_id);
+            for tx in rem.iter() {
+                let removed_id = tx.id();
+                if removed_id == tx_id {
+                    tx_status_sender.send_squeezed_out(tx_id, reason.clone());
+                } else {
+                    tx_status_sender.send_squeezed_out(
+                        removed_id,
+                        Error::SqueezedOut(format!(
+                            "the parent transaction {tx_id} was removed because {reason}"
+                        )),
+                    );
+                }
+            }
+            removed.extend(rem);
+        }
+        removed
+    }
+
+    /// Removes entries from the blacklist.
+    pub fn remove_from_blacklist(&mut self, entries: &BlackList) {
+        self.config.blacklist.remove(entries);
+    }
+
     /// Remove all old transactions from the pool.
     pub fn prune_old_txs(&mut self) -> Vec<ArcPoolTx> {
         let Some(deadline) =
@@ -258,6 +322,7 @@ impl<ViewProvider, WasmChecker> TxPool<ViewProvider, WasmChecker> {
                 break
             }
         }
+        self.by_quota.prune();
 
         result
     }
@@ -334,9 +399,18 @@ where
     fn insert_single(
         &mut self,
         tx: Checked<Transaction>,
+    ) -> Result<InsertionResult, Error> {
+        self.insert_single_from(tx, TxSource::Api)
+    }
+
+    #[cfg(test)]
+    fn insert_single_from(
+        &mut self,
+        tx: Checked<Transaction>,
+        source: TxSource,
     ) -> Result<InsertionResult, Error> {
         let view = self.database.latest_view().unwrap();
-        self.insert_inner(tx, ConsensusParametersVersion::MIN, &view)
+        self.insert_inner(tx, ConsensusParametersVersion::MIN, &source, None, &view)
     }
 
     #[tracing::instrument(level = "debug", skip_all, fields(tx_id = %tx.id()), ret, err)]
@@ -345,6 +419,8 @@ where
         &mut self,
         tx: Checked<Transaction>,
         version: ConsensusParametersVersion,
+        source: &TxSource,
+        submitted_time: Option<Duration>,
         view: &View,
     ) -> Result<InsertionResult, Error> {
         let tx: CheckedTransaction = tx.into();
@@ -368,6 +444,13 @@ where
             return Err(Error::NotInsertedTxKnown)
         }
 
+        // The transactions replaced by this one return their charge to the quotas,
+        // so the replacement by the higher tip works for owners at the quota.
+        let removed =
+            self.by_dependency
+                .removed_by_insertion(&self.by_hash, view, &tx)?;
+        self.by_quota.check(tx.as_ref(), source, &removed)?;
+
         // If upgrading transition function, at least check that it is valid wasm
         if let PoolTransaction::Upgrade(upgrade, _) = tx.as_ref() {
             if let UpgradePurpose::StateTransition { root } =
@@ -393,12 +476,24 @@ where
                 .observe(tx.metered_bytes_size() as f64);
         }
         // check and insert dependency
-        let rem = self.by_dependency.insert(&self.by_hash, view, &tx)?;
-        let info = TxInfo::new(tx.clone());
+        let (mut replaced, rem) = self.by_dependency.insert(&self.by_hash, view, &tx)?;
+        // The same transaction can collide on several inputs.
+        replaced.sort();
+        replaced.dedup();
+        if self.config.metrics {
+            txpool_metrics().replaced_txs.inc_by(replaced.len() as u64);
+        }
+        let info = match submitted_time {
+            Some(submitted_time) => {
+                TxInfo::with_submitted_time(tx.clone(), submitted_time)
+            }
+            None => TxInfo::new(tx.clone()),
+        };
         let submitted_time = info.submitted_time();
         self.by_ratio_gas_tip.insert(&info);
         self.by_time.insert(&info);
         self.by_hash.insert(tx.id(), info);
+        self.by_quota.insert(tx.as_ref(), source);
 
         // if some transaction were removed so we don't need to check limit
         let removed = if rem.is_empty() {
@@ -423,6 +518,7 @@ where
             inserted: tx,
             submitted_time,
             removed,
+            replaced,
         })
     }
 
@@ -432,7 +528,36 @@ where
         &mut self,
         tx_status_sender: &TxStatusChange,
         version: ConsensusParametersVersion,
+        source: TxSource,
         txs: Vec<Checked<Transaction>>,
+    ) -> Vec<Result<InsertionResult, Error>> {
+        let txs = txs.into_iter().map(|tx| (tx, None)).collect();
+        self.insert_with_submitted_time(tx_status_sender, version, source, txs)
+    }
+
+    /// Restores the transactions submitted earlier at the specified time since
+    /// the `UNIX_EPOCH`, like the ones from the journal.
+    #[tracing::instrument(level = "info", skip_all)]
+    pub fn restore(
+        &mut self,
+        tx_status_sender: &TxStatusChange,
+        version: ConsensusParametersVersion,
+        source: TxSource,
+        txs: Vec<(Checked<Transaction>, Duration)>,
+    ) -> Vec<Result<InsertionResult, Error>> {
+        let txs = txs
+            .into_iter()
+            .map(|(tx, submitted_time)| (tx, Some(submitted_time)))
+            .collect();
+        self.insert_with_submitted_time(tx_status_sender, version, source, txs)
+    }
+
+    fn insert_with_submitted_time(
+        &mut self,
+        tx_status_sender: &TxStatusChange,
+        version: ConsensusParametersVersion,
+        source: TxSource,
+        txs: Vec<(Checked<Transaction>, Option<Duration>)>,
     ) -> Vec<Result<InsertionResult, Error>> {
         // Check if that data is okay (witness match input/output, and if recovered signatures ara valid).
         // should be done before transaction comes to txpool, or before it enters RwLocked region.
@@ -442,8 +567,8 @@ where
             Err(e) => return vec![Err(Error::Other(e.to_string()))],
         };
 
-        for tx in txs.into_iter() {
-            res.push(self.insert_inner(tx, version, &view));
+        for (tx, submitted_time) in txs.into_iter() {
+            res.push(self.insert_inner(tx, version, &source, submitted_time, &view));
         }
 
         // announce to subscribers
@@ -453,11 +578,22 @@ where
                     removed,
                     inserted,
                     submitted_time,
+                    replaced,
                 }) => {
+                    let inserted_id = inserted.id();
+                    let dependents = dependents_of_replaced(removed, replaced);
                     for removed in removed {
-                        // small todo there is possibility to have removal reason (ReplacedByHigherGas, DependencyRemoved)
-                        // but for now it is okay to just use Error::Removed.
-                        tx_status_sender.send_squeezed_out(removed.id(), Error::Removed);
+                        let removed_id = removed.id();
+                        let reason = if replaced.contains(&removed_id) {
+                            Error::SqueezedOut(format!("replaced by {inserted_id}"))
+                        } else if dependents.contains(&removed_id) {
+                            Error::SqueezedOut(format!(
+                                "the parent transaction was replaced by {inserted_id}"
+                            ))
+                        } else {
+                            Error::Removed
+                        };
+                        tx_status_sender.send_squeezed_out(removed_id, reason);
                     }
                     tx_status_sender.send_submitted(
                         inserted.id(),
@@ -473,6 +609,41 @@ where
     }
 }
 
+/// Returns the transactions from the `removed` that spend the outputs of the `replaced`
+/// transactions, directly or through other removed transactions.
+fn dependents_of_replaced(removed: &[ArcPoolTx], replaced: &[TxId]) -> HashSet<TxId> {
+    let mut chain: HashSet<TxId> = replaced.iter().copied().collect();
+    let mut created_contracts = HashSet::<ContractId>::new();
+    let mut dependents = HashSet::new();
+    // The dependent transactions are removed after the transactions they depend on.
+    for tx in removed {
+        let tx_id = tx.id();
+        let is_dependent = tx.inputs().iter().any(|input| match input {
+            Input::CoinSigned(CoinSigned { utxo_id, .. })
+            | Input::CoinPredicate(CoinPredicate { utxo_id, .. }) => {
+                chain.contains(utxo_id.tx_id())
+            }
+            Input::Contract(contract) => {
+                created_contracts.contains(&contract.contract_id)
+            }
+            _ => false,
+        });
+        if is_dependent {
+            dependents.insert(tx_id);
+            chain.insert(tx_id);
+        }
+        if chain.contains(&tx_id) {
+            created_contracts.extend(tx.outputs().iter().filter_map(
+                |output| match output {
+                    Output::ContractCreated { contract_id, .. } => Some(*contract_id),
+                    _ => None,
+                },
+            ));
+        }
+    }
+    dependents
+}
+
 pub async fn check_transactions<Provider, MP>(
     txs: &[Arc<Transaction>],
     current_height: BlockHeight,
@@ -544,7 +715,37 @@ where
     Ok(tx)
 }
 
-fn verify_tx_min_gas_price(
+/// Performs the checks of the transaction that don't depend on the gas price:
+/// the basic checks and, if `utxo_validation` is enabled, signatures and predicates.
+/// It is CPU-heavy and blocks the current thread.
+pub(crate) fn check_single_tx_blocking<M>(
+    tx: Transaction,
+    current_height: BlockHeight,
+    utxo_validation: bool,
+    consensus_params: &ConsensusParameters,
+    memory: M,
+) -> Result<Checked<Transaction>, Error>
+where
+    M: Memory,
+{
+    if tx.is_mint() {
+        return Err(Error::NotSupportedTransactionType)
+    }
+
+    let tx = tx.into_checked_basic(current_height, consensus_params)?;
+
+    if utxo_validation {
+        let tx = tx
+            .check_signatures(&consensus_params.chain_id())?
+            .check_predicates(&CheckPredicateParams::from(consensus_params), memory)?;
+        debug_assert!(tx.checks().contains(Checks::all()));
+        Ok(tx)
+    } else {
+        Ok(tx)
+    }
+}
+
+pub(crate) fn verify_tx_min_gas_price(
     tx: Checked<Transaction>,
     consensus_params: &ConsensusParameters,
     gas_price: GasPrice,
@@ -1,9 +1,17 @@
 #![allow(non_snake_case)]
 
 use crate::{
+    config::{
+        BlackList,
+        Quota,
+    },
     ports::WasmValidityError,
-    service::test_helpers::MockTxPoolGasPrice,
+    service::{
+        test_helpers::MockTxPoolGasPrice,
+        TxStatusChange,
+    },
     test_helpers::{
+        add_coin_to_state,
         IntoEstimated,
         MockWasmChecker,
         TextContext,
@@ -18,6 +26,8 @@ use crate::{
     types::GasPrice,
     Config,
     Error,
+    QuotaLimit,
+    TxSource,
 };
 use fuel_core_types::{
     fuel_asm::{
@@ -54,6 +64,7 @@ use fuel_core_types::{
         },
         interpreter::MemoryInstance,
     },
+    services::p2p::PeerId,
 };
 use std::vec;
 
@@ -715,6 +726,156 @@ async fn more_priced_tx2_removes_tx1_and_more_priced_tx3_removes_tx2() {
     );
 }
 
+#[tokio::test]
+async fn insert__replacement_with_tip_below_min_bump_fails() {
+    let mut context = TextContext::default().config(Config {
+        min_replacement_tip_bump_percent: 10,
+        ..Default::default()
+    });
+
+    let (_, gas_coin) = context.setup_coin();
+    let tx1 = TransactionBuilder::script(vec![], vec![])
+        .tip(100)
+        .max_fee_limit(100)
+        .script_gas_limit(GAS_LIMIT)
+        .add_input(gas_coin.clone())
+        .finalize_as_transaction();
+
+    let tx2 = TransactionBuilder::script(vec![], vec![])
+        .tip(109)
+        .max_fee_limit(109)
+        .script_gas_limit(GAS_LIMIT)
+        .add_input(gas_coin)
+        .finalize_as_transaction();
+
+    let tx1_id = tx1.id(&ChainId::default());
+    let mut txpool = context.build();
+    let tx1 = check_unwrap_tx(tx1, &txpool.config).await;
+    let tx2 = check_unwrap_tx(tx2, &txpool.config).await;
+    txpool
+        .insert_single(tx1)
+        .expect("Tx1 should be OK, got Err");
+
+    // When
+    let err = txpool
+        .insert_single(tx2)
+        .expect_err("Tx2 should be Err, got Ok");
+
+    // Then
+    assert!(
+        matches!(
+            err,
+            Error::NotInsertedReplacementTipTooLow(id, 110) if id == tx1_id
+        ),
+        "wrong err {err:?}"
+    );
+}
+
+#[tokio::test]
+async fn insert__replacement_with_min_bump_replaces_tx_and_evicts_dependents() {
+    let mut context = TextContext::default().config(Config {
+        min_replacement_tip_bump_percent: 10,
+        ..Default::default()
+    });
+
+    let (_, gas_coin) = context.setup_coin();
+    let (output, unset_input) = context.create_output_and_input(1000);
+    let tx1 = TransactionBuilder::script(vec![], vec![])
+        .tip(100)
+        .max_fee_limit(100)
+        .script_gas_limit(GAS_LIMIT)
+        .add_input(gas_coin.clone())
+        .add_output(output)
+        .finalize_as_transaction();
+
+    let input = unset_input.into_input(UtxoId::new(tx1.id(&Default::default()), 0));
+    let tx2 = TransactionBuilder::script(vec![], vec![])
+        .tip(90)
+        .max_fee_limit(90)
+        .script_gas_limit(GAS_LIMIT)
+        .add_input(input)
+        .finalize_as_transaction();
+
+    let tx3 = TransactionBuilder::script(vec![], vec![])
+        .tip(110)
+        .max_fee_limit(110)
+        .script_gas_limit(GAS_LIMIT)
+        .add_input(gas_coin)
+        .finalize_as_transaction();
+
+    let tx1_id = tx1.id(&ChainId::default());
+    let tx2_id = tx2.id(&ChainId::default());
+    let mut txpool = context.build();
+    let tx1 = check_unwrap_tx(tx1, &txpool.config).await;
+    let tx2 = check_unwrap_tx(tx2, &txpool.config).await;
+    let tx3 = check_unwrap_tx(tx3, &txpool.config).await;
+    txpool
+        .insert_single(tx1)
+        .expect("Tx1 should be OK, got Err");
+    txpool
+        .insert_single(tx2)
+        .expect("Tx2 should be OK, got Err");
+
+    // When
+    let result = txpool
+        .insert_single(tx3)
+        .expect("Tx3 should be OK, got Err");
+
+    // Then
+    assert_eq!(result.replaced, vec![tx1_id]);
+    let removed = result.removed.iter().map(|tx| tx.id()).collect::<Vec<_>>();
+    assert_eq!(removed, vec![tx1_id, tx2_id]);
+    assert_eq!(txpool.pending_number(), 1);
+}
+
+#[tokio::test]
+async fn dependents_of_replaced__ignores_removed_transactions_outside_of_the_chain() {
+    let mut context = TextContext::default();
+
+    let (_, gas_coin) = context.setup_coin();
+    let (output, unset_input) = context.create_output_and_input(1000);
+    let tx1 = TransactionBuilder::script(vec![], vec![])
+        .tip(100)
+        .max_fee_limit(100)
+        .script_gas_limit(GAS_LIMIT)
+        .add_input(gas_coin.clone())
+        .add_output(output)
+        .finalize_as_transaction();
+
+    let input = unset_input.into_input(UtxoId::new(tx1.id(&Default::default()), 0));
+    let tx2 = TransactionBuilder::script(vec![], vec![])
+        .tip(90)
+        .max_fee_limit(90)
+        .script_gas_limit(GAS_LIMIT)
+        .add_input(input)
+        .finalize_as_transaction();
+
+    let (_, unrelated_coin) = context.setup_coin();
+    let unrelated = TransactionBuilder::script(vec![], vec![])
+        .script_gas_limit(GAS_LIMIT)
+        .add_input(unrelated_coin)
+        .finalize_as_transaction();
+
+    let tx1_id = tx1.id(&ChainId::default());
+    let tx2_id = tx2.id(&ChainId::default());
+    let unrelated_id = unrelated.id(&ChainId::default());
+    let mut txpool = context.build();
+    for tx in [tx1, tx2, unrelated] {
+        let tx = check_unwrap_tx(tx, &txpool.config).await;
+        txpool.insert_single(tx).expect("Tx should be OK, got Err");
+    }
+    let removed = [tx1_id, tx2_id, unrelated_id]
+        .iter()
+        .map(|id| txpool.txs().get(id).unwrap().tx().clone())
+        .collect::<Vec<_>>();
+
+    // When
+    let dependents = super::dependents_of_replaced(&removed, &[tx1_id]);
+
+    // Then
+    assert_eq!(dependents, [tx2_id].into_iter().collect());
+}
+
 #[tokio::test]
 async fn tx_limit_hit() {
     let mut context = TextContext::default().config(Config {
@@ -1551,3 +1712,306 @@ async fn insert_inner__rejects_upgrade_tx_with_invalid_wasm() {
     // Then
     assert_eq!(result, Err(Error::NotInsertedInvalidWasm));
 }
+
+/// All transactions with the same `owner_seed` spend coins of the same predicate.
+fn predicate_of_owner(owner_seed: u8) -> Vec<u8> {
+    let mut predicate: Vec<u8> = vec![op::ret(1)].into_iter().collect();
+    predicate.push(owner_seed);
+    predicate
+}
+
+fn coin_of_owner(context: &mut TextContext, owner_seed: u8) -> Input {
+    let predicate = predicate_of_owner(owner_seed);
+    let coin = context
+        .custom_predicate(AssetId::BASE, TEST_COIN_AMOUNT, predicate, None)
+        .into_default_estimated();
+    let (_, coin) = add_coin_to_state(coin, Some(context.database_mut()));
+    coin
+}
+
+fn tx_of_owner(context: &mut TextContext, owner_seed: u8, tip: Word) -> Transaction {
+    let coin = coin_of_owner(context, owner_seed);
+    TransactionBuilder::script(vec![], vec![])
+        .tip(tip)
+        .max_fee_limit(tip)
+        .script_gas_limit(GAS_LIMIT)
+        .add_input(coin)
+        .finalize_as_transaction()
+}
+
+#[tokio::test]
+async fn insert__owner_quota_of_pending_txs_exceeded_fails() {
+    let mut context = TextContext::default().config(Config {
+        owner_quota: Quota {
+            max_txs: Some(1),
+            ..Default::default()
+        },
+        ..Default::default()
+    });
+    let tx1 = tx_of_owner(&mut context, 1, 10);
+    let tx2 = tx_of_owner(&mut context, 1, 20);
+    let tx3 = tx_of_owner(&mut context, 2, 30);
+    let owner = Input::predicate_owner(predicate_of_owner(1));
+
+    let mut txpool = context.build();
+    let tx1 = check_unwrap_tx(tx1, &txpool.config).await;
+    let tx2 = check_unwrap_tx(tx2, &txpool.config).await;
+    let tx3 = check_unwrap_tx(tx3, &txpool.config).await;
+    txpool.insert_single(tx1).expect("Tx1 should be OK");
+
+    // When
+    let err = txpool.insert_single(tx2).expect_err("Tx2 should be Err");
+    let result = txpool.insert_single(tx3);
+
+    // Then
+    assert!(
+        matches!(
+            err,
+            Error::NotInsertedOwnerQuotaExceeded(o, QuotaLimit::PendingTxs) if o == owner
+        ),
+        "wrong err {err:?}"
+    );
+    assert!(result.is_ok(), "Tx3 of another owner should be OK");
+}
+
+#[tokio::test]
+async fn insert__owner_quota_of_gas_exceeded_fails() {
+    let mut context = TextContext::default().config(Config {
+        owner_quota: Quota {
+            max_gas: Some(GAS_LIMIT.saturating_mul(2)),
+            ..Default::default()
+        },
+        ..Default::default()
+    });
+    let tx1 = tx_of_owner(&mut context, 1, 10);
+    let tx2 = tx_of_owner(&mut context, 1, 20);
+
+    let mut txpool = context.build();
+    let tx1 = check_unwrap_tx(tx1, &txpool.config).await;
+    let tx2 = check_unwrap_tx(tx2, &txpool.config).await;
+    txpool.insert_single(tx1).expect("Tx1 should be OK");
+
+    // When
+    let err = txpool.insert_single(tx2).expect_err("Tx2 should be Err");
+
+    // Then
+    assert!(
+        matches!(
+            err,
+            Error::NotInsertedOwnerQuotaExceeded(_, QuotaLimit::Gas)
+        ),
+        "wrong err {err:?}"
+    );
+}
+
+#[tokio::test]
+async fn insert__removed_tx_frees_owner_quota() {
+    let mut context = TextContext::default().config(Config {
+        owner_quota: Quota {
+            max_txs: Some(1),
+            ..Default::default()
+        },
+        ..Default::default()
+    });
+    let tx1 = tx_of_owner(&mut context, 1, 10);
+    let tx2 = tx_of_owner(&mut context, 1, 20);
+    let tx1_id = tx1.id(&ChainId::default());
+
+    let mut txpool = context.build();
+    let tx1 = check_unwrap_tx(tx1, &txpool.config).await;
+    let tx2 = check_unwrap_tx(tx2, &txpool.config).await;
+    txpool.insert_single(tx1).expect("Tx1 should be OK");
+
+    // When
+    txpool.remove_committed_tx(&tx1_id);
+    let result = txpool.insert_single(tx2);
+
+    // Then
+    assert!(result.is_ok(), "Tx2 should be OK, got {result:?}");
+}
+
+#[tokio::test]
+async fn insert__replacement_at_owner_quota_succeeds() {
+    let mut context = TextContext::default().config(Config {
+        owner_quota: Quota {
+            max_txs: Some(1),
+            max_gas: Some(GAS_LIMIT),
+            ..Default::default()
+        },
+        ..Default::default()
+    });
+    let coin = coin_of_owner(&mut context, 1);
+    let tx1 = TransactionBuilder::script(vec![], vec![])
+        .tip(10)
+        .max_fee_limit(10)
+        .script_gas_limit(GAS_LIMIT / 2)
+        .add_input(coin.clone())
+        .finalize_as_transaction();
+    let tx2 = TransactionBuilder::script(vec![], vec![])
+        .tip(20)
+        .max_fee_limit(20)
+        .script_gas_limit(GAS_LIMIT / 2)
+        .add_input(coin)
+        .finalize_as_transaction();
+    let tx1_id = tx1.id(&ChainId::default());
+
+    let mut txpool = context.build();
+    let tx1 = check_unwrap_tx(tx1, &txpool.config).await;
+    let tx2 = check_unwrap_tx(tx2, &txpool.config).await;
+    txpool.insert_single(tx1).expect("Tx1 should be OK");
+
+    // When
+    let result = txpool.insert_single(tx2).expect("Tx2 should replace Tx1");
+
+    // Then
+    assert_eq!(result.replaced, vec![tx1_id]);
+}
+
+#[tokio::test(start_paused = true)]
+async fn insert__owner_quota_of_submission_rate_is_reset_after_a_minute() {
+    let mut context = TextContext::default().config(Config {
+        owner_quota: Quota {
+            max_submissions_per_minute: Some(1),
+            ..Default::default()
+        },
+        ..Default::default()
+    });
+    let tx1 = tx_of_owner(&mut context, 1, 10);
+    let tx2 = tx_of_owner(&mut context, 1, 20);
+    let tx1_id = tx1.id(&ChainId::default());
+
+    let mut txpool = context.build();
+    let tx1 = check_unwrap_tx(tx1, &txpool.config).await;
+    let tx2 = check_unwrap_tx(tx2, &txpool.config).await;
+    txpool.insert_single(tx1).expect("Tx1 should be OK");
+    txpool.remove_committed_tx(&tx1_id);
+    let err = txpool
+        .insert_single(tx2.clone())
+        .expect_err("Tx2 should be Err");
+    assert!(
+        matches!(
+            err,
+            Error::NotInsertedOwnerQuotaExceeded(_, QuotaLimit::SubmissionRate)
+        ),
+        "wrong err {err:?}"
+    );
+
+    // When
+    tokio::time::advance(std::time::Duration::from_secs(60)).await;
+    let result = txpool.insert_single(tx2);
+
+    // Then
+    assert!(result.is_ok(), "Tx2 should be OK, got {result:?}");
+}
+
+#[tokio::test]
+async fn insert__peer_quota_exceeded_fails() {
+    let mut context = TextContext::default().config(Config {
+        peer_quota: Quota {
+            max_txs: Some(1),
+            ..Default::default()
+        },
+        ..Default::default()
+    });
+    let tx1 = tx_of_owner(&mut context, 1, 10);
+    let tx2 = tx_of_owner(&mut context, 2, 20);
+    let tx3 = tx_of_owner(&mut context, 3, 30);
+    let tx4 = tx_of_owner(&mut context, 4, 40);
+    let peer = PeerId::from(vec![1]);
+    let other_peer = PeerId::from(vec![2]);
+
+    let mut txpool = context.build();
+    let tx1 = check_unwrap_tx(tx1, &txpool.config).await;
+    let tx2 = check_unwrap_tx(tx2, &txpool.config).await;
+    let tx3 = check_unwrap_tx(tx3, &txpool.config).await;
+    let tx4 = check_unwrap_tx(tx4, &txpool.config).await;
+    txpool
+        .insert_single_from(tx1, TxSource::Peer(peer.clone()))
+        .expect("Tx1 should be OK");
+
+    // When
+    let err = txpool
+        .insert_single_from(tx2, TxSource::Peer(peer.clone()))
+        .expect_err("Tx2 should be Err");
+    let other_peer_result = txpool.insert_single_from(tx3, TxSource::Peer(other_peer));
+    let api_result = txpool.insert_single(tx4);
+
+    // Then
+    assert!(
+        matches!(
+            err,
+            Error::NotInsertedPeerQuotaExceeded(ref p, QuotaLimit::PendingTxs) if p == &peer
+        ),
+        "wrong err {err:?}"
+    );
+    assert!(
+        other_peer_result.is_ok(),
+        "Tx3 of another peer should be OK"
+    );
+    assert!(api_result.is_ok(), "Tx4 from the API should be OK");
+}
+
+#[tokio::test]
+async fn add_to_blacklist__evicts_pending_txs_of_owner_and_their_dependents() {
+    let mut context = TextContext::default();
+    let (output, unset_input) = context.create_output_and_input(1000);
+    let tx1 = TransactionBuilder::script(vec![], vec![])
+        .script_gas_limit(GAS_LIMIT)
+        .add_input(coin_of_owner(&mut context, 1))
+        .add_output(output)
+        .finalize_as_transaction();
+    let input = unset_input.into_input(UtxoId::new(tx1.id(&Default::default()), 0));
+    let tx2 = TransactionBuilder::script(vec![], vec![])
+        .script_gas_limit(GAS_LIMIT)
+        .add_input(input)
+        .finalize_as_transaction();
+    let tx3 = tx_of_owner(&mut context, 2, 10);
+    let owner = Input::predicate_owner(predicate_of_owner(1));
+
+    let mut txpool = context.build();
+    let tx1 = check_unwrap_tx(tx1, &txpool.config).await;
+    let tx2 = check_unwrap_tx(tx2, &txpool.config).await;
+    let tx3 = check_unwrap_tx(tx3, &txpool.config).await;
+    let tx1_id = tx1.id();
+    let tx2_id = tx2.id();
+    let tx3_id = tx3.id();
+    txpool.insert_single(tx1).expect("Tx1 should be OK");
+    txpool.insert_single(tx2).expect("Tx2 should be OK");
+    txpool.insert_single(tx3).expect("Tx3 should be OK");
+
+    // When
+    let removed = txpool.add_to_blacklist(
+        &TxStatusChange::new(100, std::time::Duration::from_secs(60)),
+        BlackList::new(vec![owner], vec![], vec![], vec![]),
+    );
+
+    // Then
+    let mut removed: Vec<_> = removed.iter().map(|tx| tx.id()).collect();
+    removed.sort();
+    let mut expected = vec![tx1_id, tx2_id];
+    expected.sort();
+    assert_eq!(removed, expected);
+    assert!(txpool.find_one(&tx3_id).is_some());
+}
+
+#[tokio::test]
+async fn remove_from_blacklist__allows_insertion_of_txs_of_owner() {
+    let mut context = TextContext::default();
+    let tx = tx_of_owner(&mut context, 1, 10);
+    let owner = Input::predicate_owner(predicate_of_owner(1));
+    let entries = BlackList::new(vec![owner], vec![], vec![], vec![]);
+
+    let mut txpool = context.build();
+    let tx = check_unwrap_tx(tx, &txpool.config).await;
+    txpool.add_to_blacklist(
+        &TxStatusChange::new(100, std::time::Duration::from_secs(60)),
+        entries.clone(),
+    );
+
+    // When
+    txpool.remove_from_blacklist(&entries);
+    let result = txpool.insert_single(tx);
+
+    // Then
+    assert!(result.is_ok(), "Tx should be OK, got {result:?}");
+}
@@ -16,12 +16,23 @@ use fuel_core_executor::{
 use fuel_core_storage::{
     column::Column,
     kv_store::KeyValueInspect,
+    tables::{
+        Coins,
+        ContractsAssets,
+        ContractsRawCode,
+        ContractsState,
+    },
     transactional::{
         AtomicView,
         Changes,
         HistoricalView,
+        IntoTransaction,
         Modifiable,
+        StorageTransaction,
     },
+    ContractsAssetKey,
+    ContractsStateKey,
+    StorageAsMut,
 };
 #[cfg(feature = "wasm-executor")]
 use fuel_core_types::fuel_types::Bytes32;
@@ -41,13 +52,18 @@ use fuel_core_types::{
             Error as ExecutorError,
             ExecutionResult,
             Result as ExecutorResult,
+            StateOverrides,
             TransactionExecutionStatus,
+            TransactionTrace,
             ValidationResult,
         },
         Uncommitted,
     },
 };
-use std::sync::Arc;
+use std::{
+    collections::HashMap,
+    sync::Arc,
+};
 
 #[cfg(feature = "wasm-executor")]
 use fuel_core_storage::{
@@ -282,7 +298,7 @@ where
         };
 
         let options = self.config.as_ref().into();
-        self.produce_inner(component, options, false)
+        self.produce_inner(component, options, false, None, None)
     }
 
     /// Executes a dry-run of the block and returns the result of the execution without committing the changes.
@@ -294,7 +310,7 @@ where
         TxSource: TransactionsSource + Send + Sync + 'static,
     {
         let options = self.config.as_ref().into();
-        self.produce_inner(block, options, true)
+        self.produce_inner(block, options, true, None, None)
     }
 }
 
@@ -315,15 +331,21 @@ where
         TxSource: TransactionsSource + Send + Sync + 'static,
     {
         let options = self.config.as_ref().into();
-        self.produce_inner(components, options, false)
+        self.produce_inner(components, options, false, None, None)
     }
 
     /// Executes the block and returns the result of the execution without committing
     /// the changes in the dry run mode.
+    ///
+    /// The block is executed on top of the state at the `at_height`
+    /// or on top of the latest state if the `at_height` is `None`.
+    /// The `state_overrides` are applied to this state before the execution.
     pub fn dry_run(
         &self,
         component: Components<Vec<Transaction>>,
         utxo_validation: Option<bool>,
+        at_height: Option<BlockHeight>,
+        state_overrides: StateOverrides,
     ) -> ExecutorResult<Vec<TransactionExecutionStatus>> {
         // fallback to service config value if no utxo_validation override is provided
         let utxo_validation =
@@ -347,7 +369,9 @@ where
             skipped_transactions,
             tx_status,
             ..
-        } = self.produce_inner(component, options, true)?.into_result();
+        } = self
+            .produce_inner(component, options, true, at_height, Some(&state_overrides))?
+            .into_result();
 
         // If one of the transactions fails, return an error.
         if let Some((_, err)) = skipped_transactions.into_iter().next() {
@@ -357,6 +381,78 @@ where
         Ok(tx_status)
     }
 
+    /// Executes the block in the dry run mode like the [`Self::dry_run`]
+    /// and collects the trace of each transaction during the execution.
+    ///
+    /// The tracing is supported only by the native executor, so the block
+    /// must use the version of the native executor.
+    pub fn dry_run_with_traces(
+        &self,
+        component: Components<Vec<Transaction>>,
+        utxo_validation: Option<bool>,
+        at_height: Option<BlockHeight>,
+        state_overrides: StateOverrides,
+    ) -> ExecutorResult<Vec<(TransactionExecutionStatus, TransactionTrace)>> {
+        let block_version = component
+            .header_to_produce
+            .state_transition_bytecode_version;
+        let native_executor_version = self.native_executor_version();
+        if block_version != native_executor_version {
+            return Err(ExecutorError::Other(format!(
+                "The tracing is supported only by the native executor of version \
+                `{native_executor_version}`, but the block uses version `{block_version}`"
+            )))
+        }
+
+        let utxo_validation =
+            utxo_validation.unwrap_or(self.config.utxo_validation_default);
+        let options = ExecutionOptions {
+            extra_tx_checks: utxo_validation,
+            backtrace: self.config.backtrace,
+        };
+        let component = Components {
+            header_to_produce: component.header_to_produce,
+            transactions_source: OnceTransactionsSource::new(
+                component.transactions_source,
+            ),
+            coinbase_recipient: Default::default(),
+            gas_price: component.gas_price,
+        };
+
+        let relayer = self.relayer_view_provider.latest_view()?;
+        let (result, traces) = if let Some(at_height) = at_height {
+            let database = self.storage_view_provider.view_at(&at_height)?;
+            let database = apply_state_overrides(database, &state_overrides)?;
+            ExecutionInstance::new(relayer, database, options)
+                .dry_run_with_traces(component)?
+        } else {
+            let database = self.storage_view_provider.latest_view()?;
+            let database = apply_state_overrides(database, &state_overrides)?;
+            ExecutionInstance::new(relayer, database, options)
+                .dry_run_with_traces(component)?
+        };
+
+        let ExecutionResult {
+            skipped_transactions,
+            tx_status,
+            ..
+        } = result.into_result();
+
+        // If one of the transactions fails, return an error.
+        if let Some((_, err)) = skipped_transactions.into_iter().next() {
+            return Err(err)
+        }
+
+        let mut traces = traces.into_iter().collect::<HashMap<_, _>>();
+        Ok(tx_status
+            .into_iter()
+            .map(|status| {
+                let trace = traces.remove(&status.id).unwrap_or_default();
+                (status, trace)
+            })
+            .collect())
+    }
+
     pub fn validate(
         &self,
         block: &Block,
@@ -371,6 +467,8 @@ where
         block: Components<TxSource>,
         options: ExecutionOptions,
         dry_run: bool,
+        at_height: Option<BlockHeight>,
+        state_overrides: Option<&StateOverrides>,
     ) -> ExecutorResult<Uncommitted<ExecutionResult, Changes>>
     where
         TxSource: TransactionsSource + Send + Sync + 'static,
@@ -379,16 +477,32 @@ where
         let native_executor_version = self.native_executor_version();
         if block_version == native_executor_version {
             match &self.execution_strategy {
-                ExecutionStrategy::Native => {
-                    self.native_produce_inner(block, options, dry_run)
-                }
-                ExecutionStrategy::Wasm { module } => {
-                    self.wasm_produce_inner(module, block, options, dry_run)
-                }
+                ExecutionStrategy::Native => self.native_produce_inner(
+                    block,
+                    options,
+                    dry_run,
+                    at_height,
+                    state_overrides,
+                ),
+                ExecutionStrategy::Wasm { module } => self.wasm_produce_inner(
+                    module,
+                    block,
+                    options,
+                    dry_run,
+                    at_height,
+                    state_overrides,
+                ),
             }
         } else {
             let module = self.get_module(block_version)?;
-            self.wasm_produce_inner(&module, block, options, dry_run)
+            self.wasm_produce_inner(
+                &module,
+                block,
+                options,
+                dry_run,
+                at_height,
+                state_overrides,
+            )
         }
     }
 
@@ -398,6 +512,8 @@ where
         block: Components<TxSource>,
         options: ExecutionOptions,
         dry_run: bool,
+        at_height: Option<BlockHeight>,
+        state_overrides: Option<&StateOverrides>,
     ) -> ExecutorResult<Uncommitted<ExecutionResult, Changes>>
     where
         TxSource: TransactionsSource + Send + Sync + 'static,
@@ -405,7 +521,7 @@ where
         let block_version = block.header_to_produce.state_transition_bytecode_version;
         let native_executor_version = self.native_executor_version();
         if block_version == native_executor_version {
-            self.native_produce_inner(block, options, dry_run)
+            self.native_produce_inner(block, options, dry_run, at_height, state_overrides)
         } else {
             Err(ExecutorError::Other(format!(
                 "Not supported version `{block_version}`. Expected version is `{}`",
@@ -470,6 +586,8 @@ where
         component: Components<TxSource>,
         options: ExecutionOptions,
         dry_run: bool,
+        at_height: Option<BlockHeight>,
+        state_overrides: Option<&StateOverrides>,
     ) -> ExecutorResult<Uncommitted<ExecutionResult, Changes>>
     where
         TxSource: TransactionsSource + Send + Sync + 'static,
@@ -497,7 +615,7 @@ where
             block.header_to_produce.height().pred()
         } else {
             // TODO: https://github.com/FuelLabs/fuel-core/issues/2062
-            None
+            at_height
         };
 
         let instance_without_input =
@@ -507,10 +625,10 @@ where
             previous_block_height
         {
             let storage = self.storage_view_provider.view_at(&previous_block_height)?;
-            instance_without_input.add_storage(storage)?
+            Self::wasm_add_storage(instance_without_input, storage, state_overrides)?
         } else {
             let storage = self.storage_view_provider.latest_view()?;
-            instance_without_input.add_storage(storage)?
+            Self::wasm_add_storage(instance_without_input, storage, state_overrides)?
         };
 
         let relayer = self.relayer_view_provider.latest_view()?;
@@ -570,6 +688,8 @@ where
         block: Components<TxSource>,
         options: ExecutionOptions,
         dry_run: bool,
+        at_height: Option<BlockHeight>,
+        state_overrides: Option<&StateOverrides>,
     ) -> ExecutorResult<Uncommitted<ExecutionResult, Changes>>
     where
         TxSource: TransactionsSource + Send + Sync + 'static,
@@ -578,18 +698,71 @@ where
             block.header_to_produce.height().pred()
         } else {
             // TODO: https://github.com/FuelLabs/fuel-core/issues/2062
-            None
+            at_height
         };
         let relayer = self.relayer_view_provider.latest_view()?;
 
         if let Some(previous_block_height) = previous_block_height {
             let database = self.storage_view_provider.view_at(&previous_block_height)?;
-            ExecutionInstance::new(relayer, database, options)
-                .produce_without_commit(block, dry_run)
+            Self::native_produce_on_storage(
+                relayer,
+                database,
+                block,
+                options,
+                dry_run,
+                state_overrides,
+            )
         } else {
             let database = self.storage_view_provider.latest_view()?;
-            ExecutionInstance::new(relayer, database, options)
-                .produce_without_commit(block, dry_run)
+            Self::native_produce_on_storage(
+                relayer,
+                database,
+                block,
+                options,
+                dry_run,
+                state_overrides,
+            )
+        }
+    }
+
+    fn native_produce_on_storage<TxSource, D>(
+        relayer: R::LatestView,
+        database: D,
+        block: Components<TxSource>,
+        options: ExecutionOptions,
+        dry_run: bool,
+        state_overrides: Option<&StateOverrides>,
+    ) -> ExecutorResult<Uncommitted<ExecutionResult, Changes>>
+    where
+        TxSource: TransactionsSource + Send + Sync + 'static,
+        D: KeyValueInspect<Column = Column>,
+    {
+        match state_overrides {
+            Some(state_overrides) if !state_overrides.is_empty() => {
+                let database = apply_state_overrides(database, state_overrides)?;
+                ExecutionInstance::new(relayer, database, options)
+                    .produce_without_commit(block, dry_run)
+            }
+            _ => ExecutionInstance::new(relayer, database, options)
+                .produce_without_commit(block, dry_run),
+        }
+    }
+
+    #[cfg(feature = "wasm-executor")]
+    fn wasm_add_storage<D>(
+        instance: crate::instance::Instance<crate::instance::Source>,
+        storage: D,
+        state_overrides: Option<&StateOverrides>,
+    ) -> ExecutorResult<crate::instance::Instance<crate::instance::Storage>>
+    where
+        D: KeyValueInspect<Column = Column> + Send + Sync + 'static,
+    {
+        match state_overrides {
+            Some(state_overrides) if !state_overrides.is_empty() => {
+                let storage = apply_state_overrides(storage, state_overrides)?;
+                instance.add_storage(storage)
+            }
+            _ => instance.add_storage(storage),
         }
     }
 
@@ -691,6 +864,50 @@ where
     }
 }
 
+/// Applies the `state_overrides` on top of the `storage`.
+/// The overrides live only in the returned in-memory transaction.
+fn apply_state_overrides<S>(
+    storage: S,
+    state_overrides: &StateOverrides,
+) -> ExecutorResult<StorageTransaction<S>>
+where
+    S: KeyValueInspect<Column = Column>,
+{
+    let mut storage = storage.into_transaction();
+
+    for contract in &state_overrides.contracts {
+        let contract_id = &contract.contract_id;
+
+        if let Some(bytecode) = &contract.bytecode {
+            storage
+                .storage_as_mut::<ContractsRawCode>()
+                .insert(contract_id, bytecode.as_slice())?;
+        }
+
+        for (asset_id, amount) in &contract.balances {
+            let key = ContractsAssetKey::new(contract_id, asset_id);
+            storage
+                .storage_as_mut::<ContractsAssets>()
+                .insert(&key, amount)?;
+        }
+
+        for (slot, value) in &contract.storage {
+            let key = ContractsStateKey::new(contract_id, slot);
+            storage
+                .storage_as_mut::<ContractsState>()
+                .insert(&key, value.as_slice())?;
+        }
+    }
+
+    for coin in &state_overrides.coins {
+        storage
+            .storage_as_mut::<Coins>()
+            .insert(&coin.utxo_id, &coin.compress())?;
+    }
+
+    Ok(storage)
+}
+
 #[allow(clippy::cast_possible_truncation)]
 #[allow(unexpected_cfgs)] // for cfg(coverage)
 #[cfg(test)]
@@ -706,6 +923,7 @@ mod test {
         transactional::WriteTransaction,
         Result as StorageResult,
         StorageAsMut,
+        StorageAsRef,
     };
     use fuel_core_types::{
         blockchain::{
@@ -724,12 +942,18 @@ mod test {
                 Empty,
             },
         },
+        entities::coins::coin::Coin,
         fuel_tx::{
             AssetId,
             Bytes32,
+            ContractId,
             Transaction,
+            UtxoId,
+        },
+        services::{
+            executor::ContractStateOverride,
+            relayer::Event,
         },
-        services::relayer::Event,
         tai64::Tai64,
     };
     use std::collections::{
@@ -898,6 +1122,71 @@ mod test {
         .unwrap()
     }
 
+    #[test]
+    fn apply_state_overrides_modifies_only_the_transaction() {
+        let storage = storage();
+        let contract_id = ContractId::from([1; 32]);
+        let coin = Coin {
+            utxo_id: UtxoId::new([2; 32].into(), 0),
+            owner: [3; 32].into(),
+            amount: 100,
+            asset_id: AssetId::BASE,
+            tx_pointer: Default::default(),
+        };
+        let state_overrides = StateOverrides {
+            contracts: vec![ContractStateOverride {
+                contract_id,
+                bytecode: Some(vec![4; 8]),
+                balances: vec![(AssetId::BASE, 500)],
+                storage: vec![(Bytes32::zeroed(), vec![5; 32])],
+            }],
+            coins: vec![coin],
+        };
+
+        // When
+        let transaction =
+            apply_state_overrides(storage.latest_view().unwrap(), &state_overrides)
+                .unwrap();
+
+        // Then
+        let asset_key = ContractsAssetKey::new(&contract_id, &AssetId::BASE);
+        let slot_key = ContractsStateKey::new(&contract_id, &Bytes32::zeroed());
+        let balance = transaction
+            .storage::<ContractsAssets>()
+            .get(&asset_key)
+            .unwrap()
+            .map(|balance| *balance);
+        assert_eq!(balance, Some(500));
+        let slot = transaction
+            .storage::<ContractsState>()
+            .get(&slot_key)
+            .unwrap()
+            .map(|slot| slot.0.clone());
+        assert_eq!(slot, Some(vec![5; 32]));
+        let bytecode = transaction
+            .storage::<ContractsRawCode>()
+            .get(&contract_id)
+            .unwrap()
+            .map(|bytecode| bytecode.as_ref().as_ref().to_vec());
+        assert_eq!(bytecode, Some(vec![4; 8]));
+        let stored_coin = transaction
+            .storage::<Coins>()
+            .get(&coin.utxo_id)
+            .unwrap()
+            .map(|coin| coin.into_owned());
+        assert_eq!(stored_coin, Some(coin.compress()));
+
+        let view = storage.latest_view().unwrap().into_transaction();
+        assert!(!view
+            .storage_as_ref::<ContractsRawCode>()
+            .contains_key(&contract_id)
+            .unwrap());
+        assert!(!view
+            .storage_as_ref::<Coins>()
+            .contains_key(&coin.utxo_id)
+            .unwrap());
+    }
+
     #[cfg(not(feature = "wasm-executor"))]
     mod native {
         use super::*;
@@ -10,8 +10,10 @@ use crate::{
 };
 
 // Different types of consensus are represented as separate modules
+pub mod bft;
 pub mod poa;
 
+use bft::BftConsensus;
 use poa::PoAConsensus;
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -24,6 +26,8 @@ pub enum Consensus {
     Genesis(Genesis),
     /// Proof of authority consensus
     PoA(PoAConsensus),
+    /// Byzantine fault tolerant consensus
+    Bft(BftConsensus),
 }
 
 impl Consensus {
@@ -39,6 +43,14 @@ impl Consensus {
                 let address = Input::owner(&public_key);
                 Ok(address)
             }
+            Consensus::Bft(bft_data) => {
+                let public_key = bft_data
+                    .producer_signature
+                    .recover(block_id.as_message())
+                    .map_err(|e| anyhow::anyhow!("Can't recover public key: {:?}", e))?;
+                let address = Input::owner(&public_key);
+                Ok(address)
+            }
         }
     }
 }
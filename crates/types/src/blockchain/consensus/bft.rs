@@ -0,0 +1,221 @@
+//! Byzantine fault tolerant consensus
+
+use crate::{
+    blockchain::{
+        block::Block,
+        primitives::BlockId,
+    },
+    fuel_crypto::{
+        Message,
+        Signature,
+    },
+    fuel_tx::Input,
+    fuel_types::{
+        Address,
+        BlockHeight,
+    },
+};
+use alloc::vec::Vec;
+
+/// The domain separator of the votes, so a vote signature can't be reused
+/// as a signature of something else.
+const VOTE_DOMAIN: &[u8] = b"FUEL_BFT_VOTE";
+
+/// The domain separator of the proposals.
+const PROPOSAL_DOMAIN: &[u8] = b"FUEL_BFT_PROPOSAL";
+
+#[derive(Default, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// The consensus related data that doesn't live on the
+/// header.
+pub struct BftConsensus {
+    /// The round in which validators committed the block.
+    pub round: u32,
+    /// The signature of the `BlockId` by the proposer of the block.
+    pub producer_signature: Signature,
+    /// The precommit signatures of validators for the block in the `round`.
+    pub signatures: Vec<Signature>,
+}
+
+impl BftConsensus {
+    /// Create a new block consensus.
+    pub fn new(
+        round: u32,
+        producer_signature: Signature,
+        signatures: Vec<Signature>,
+    ) -> Self {
+        Self {
+            round,
+            producer_signature,
+            signatures,
+        }
+    }
+}
+
+/// The step of the round at which the vote is cast.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum VoteStep {
+    /// The vote for the proposal of the round.
+    Prevote,
+    /// The vote to commit the block.
+    Precommit,
+}
+
+/// The vote of the validator. The `None` block means the vote for nothing.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Vote {
+    /// The height of the block.
+    pub height: BlockHeight,
+    /// The round at the `height`.
+    pub round: u32,
+    /// The step of the `round`.
+    pub step: VoteStep,
+    /// The block the validator votes for.
+    pub block_id: Option<BlockId>,
+}
+
+impl Vote {
+    /// The message signed by the validator.
+    pub fn signing_message(&self) -> Message {
+        let step = match self.step {
+            VoteStep::Prevote => 0u8,
+            VoteStep::Precommit => 1u8,
+        };
+        let mut bytes = VOTE_DOMAIN.to_vec();
+        bytes.extend_from_slice(&self.height.to_bytes());
+        bytes.extend_from_slice(&self.round.to_be_bytes());
+        bytes.push(step);
+        match &self.block_id {
+            Some(block_id) => {
+                bytes.push(1);
+                bytes.extend_from_slice(block_id.as_slice());
+            }
+            None => bytes.push(0),
+        }
+        Message::new(bytes)
+    }
+
+    /// Recovers the address of the validator that signed the vote.
+    pub fn signer(&self, signature: &Signature) -> anyhow::Result<Address> {
+        recover(signature, &self.signing_message())
+    }
+}
+
+/// The vote with the signature of the validator.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SignedVote {
+    /// The vote.
+    pub vote: Vote,
+    /// The signature of the `Vote::signing_message`.
+    pub signature: Signature,
+}
+
+impl SignedVote {
+    /// Recovers the address of the validator that signed the vote.
+    pub fn signer(&self) -> anyhow::Result<Address> {
+        self.vote.signer(&self.signature)
+    }
+}
+
+/// The block proposed by the proposer of the round.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Proposal {
+    /// The round of the proposal.
+    pub round: u32,
+    /// The round in which validators prevoted for the `block`,
+    /// if the proposer re-proposes the block from the previous round.
+    pub valid_round: Option<u32>,
+    /// The proposed block.
+    pub block: Block,
+    /// The signature of the `BlockId` by the proposer, it becomes
+    /// the `BftConsensus::producer_signature` of the committed block.
+    pub block_signature: Signature,
+    /// The signature of the `Proposal::signing_message` by the proposer.
+    pub signature: Signature,
+}
+
+impl Proposal {
+    /// The height of the proposed block.
+    pub fn height(&self) -> BlockHeight {
+        *self.block.header().height()
+    }
+
+    /// The message signed by the proposer. It covers the round and the valid round,
+    /// so they can't be changed by peers relaying the proposal.
+    pub fn signing_message(&self) -> Message {
+        let mut bytes = PROPOSAL_DOMAIN.to_vec();
+        bytes.extend_from_slice(&self.height().to_bytes());
+        bytes.extend_from_slice(&self.round.to_be_bytes());
+        match self.valid_round {
+            Some(valid_round) => {
+                bytes.push(1);
+                bytes.extend_from_slice(&valid_round.to_be_bytes());
+            }
+            None => bytes.push(0),
+        }
+        bytes.extend_from_slice(self.block.id().as_slice());
+        Message::new(bytes)
+    }
+
+    /// Recovers the address of the proposer.
+    /// Fails if the block and the proposal are signed by different keys.
+    pub fn signer(&self) -> anyhow::Result<Address> {
+        let signer = recover(&self.signature, &self.signing_message())?;
+        let block_signer = recover(&self.block_signature, self.block.id().as_message())?;
+        if signer != block_signer {
+            return Err(anyhow::anyhow!(
+                "The block is signed by {}, but the proposal by {}",
+                block_signer,
+                signer
+            ))
+        }
+        Ok(signer)
+    }
+}
+
+/// The message exchanged by validators.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum BftMessage {
+    /// The proposal of the block.
+    Proposal(Proposal),
+    /// The vote for the block.
+    Vote(SignedVote),
+}
+
+impl BftMessage {
+    /// The height of the block the message is about.
+    pub fn height(&self) -> BlockHeight {
+        match self {
+            BftMessage::Proposal(proposal) => proposal.height(),
+            BftMessage::Vote(vote) => vote.vote.height,
+        }
+    }
+
+    /// The round at the height the message is about.
+    pub fn round(&self) -> u32 {
+        match self {
+            BftMessage::Proposal(proposal) => proposal.round,
+            BftMessage::Vote(vote) => vote.vote.round,
+        }
+    }
+
+    /// Recovers the address of the validator that signed the message.
+    pub fn signer(&self) -> anyhow::Result<Address> {
+        match self {
+            BftMessage::Proposal(proposal) => proposal.signer(),
+            BftMessage::Vote(vote) => vote.signer(),
+        }
+    }
+}
+
+fn recover(signature: &Signature, message: &Message) -> anyhow::Result<Address> {
+    let public_key = signature
+        .recover(message)
+        .map_err(|e| anyhow::anyhow!("Can't recover public key: {:?}", e))?;
+    Ok(Input::owner(&public_key))
+}
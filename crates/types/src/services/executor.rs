@@ -15,6 +15,7 @@ use crate::{
             transaction::RelayedTransactionId,
         },
     },
+    fuel_asm::Word,
     fuel_tx::{
         Receipt,
         TxId,
@@ -22,6 +23,7 @@ use crate::{
         ValidityError,
     },
     fuel_types::{
+        AssetId,
         BlockHeight,
         Bytes32,
         ContractId,
@@ -166,6 +168,96 @@ pub enum ForcedTransactionFailure {
     },
 }
 
+/// The modifications of the state applied on top of the
+/// storage before the dry run of transactions.
+/// Overrides are never committed into the database.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StateOverrides {
+    /// The overrides of the contracts' state.
+    pub contracts: Vec<ContractStateOverride>,
+    /// The coins that are inserted or replaced before the execution.
+    pub coins: Vec<Coin>,
+}
+
+impl StateOverrides {
+    /// Returns `true` if there are no overrides.
+    pub fn is_empty(&self) -> bool {
+        self.contracts.is_empty() && self.coins.is_empty()
+    }
+}
+
+/// The override of the contract's state.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ContractStateOverride {
+    /// The id of the contract.
+    pub contract_id: ContractId,
+    /// The new bytecode of the contract, if any.
+    pub bytecode: Option<Vec<u8>>,
+    /// The new balances of the contract.
+    pub balances: Vec<(AssetId, Word)>,
+    /// The new values of the contract's storage slots.
+    pub storage: Vec<(Bytes32, Vec<u8>)>,
+}
+
+/// The trace of the transaction execution collected with the debugger of the VM.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TransactionTrace {
+    /// The executed instructions in the order of execution.
+    pub steps: Vec<TraceStep>,
+    /// The accesses to the contracts' storage slots in the order of execution.
+    pub storage_accesses: Vec<StorageAccess>,
+    /// `true` if the execution was longer than the limit of the trace,
+    /// and the remaining instructions were not recorded.
+    pub truncated: bool,
+}
+
+/// The executed instruction with the state of the VM around it.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TraceStep {
+    /// The program counter relative to the start of the executed code.
+    pub pc: Word,
+    /// The raw executed instruction.
+    pub instruction: u32,
+    /// The gas remaining in the current context before the execution of the instruction.
+    pub gas_remaining: Word,
+    /// The contract that executed the instruction, `None` for the script.
+    pub contract_id: Option<ContractId>,
+    /// The registers modified by the instruction with their new values.
+    pub changed_registers: Vec<(u8, Word)>,
+}
+
+/// The kind of access to the contract's storage slot.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum StorageAccessKind {
+    /// The slot was read.
+    Read,
+    /// The slot was written.
+    Write,
+    /// The slot was cleared.
+    Clear,
+}
+
+/// The access to the contract's storage slot.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StorageAccess {
+    /// The index of the step in the [`TransactionTrace::steps`] that accessed the slot.
+    pub step: u32,
+    /// The kind of the access.
+    pub kind: StorageAccessKind,
+    /// The contract that owns the slot.
+    pub contract_id: ContractId,
+    /// The key of the slot.
+    pub key: Bytes32,
+    /// The value of the slot after the access, `None` if the slot is not set.
+    pub value: Option<Vec<u8>>,
+}
+
 /// The status of a transaction after it is executed.
 #[derive(Debug, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -1,6 +1,10 @@
 //! Contains types related to P2P data
 
 use crate::{
+    blockchain::{
+        consensus::bft::BftMessage,
+        SealedBlock,
+    },
     fuel_tx::Transaction,
     fuel_types::BlockHeight,
 };
@@ -63,6 +67,12 @@ pub struct GossipData<T> {
 /// Transactions gossiped by peers for inclusion into a block
 pub type TransactionGossipData = GossipData<Transaction>;
 
+/// Blocks gossiped by peers right after their production
+pub type BlockGossipData = GossipData<SealedBlock>;
+
+/// Consensus messages gossiped by validators
+pub type ConsensusGossipData = GossipData<BftMessage>;
+
 #[derive(Default, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 /// The source of some network data.
 pub struct SourcePeer<T> {
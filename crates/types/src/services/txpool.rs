@@ -202,6 +202,9 @@ pub struct InsertionResult {
     pub submitted_time: Duration,
     /// These were removed during the insertion
     pub removed: Vec<ArcPoolTx>,
+    /// The transactions replaced by the `inserted` transaction because they spent
+    /// the same inputs. They and their dependents are also part of `removed`.
+    pub replaced: Vec<TxId>,
 }
 
 /// The status of the transaction during its life from the tx pool until the block.
@@ -39,6 +39,7 @@ use fuel_core_types::{
     secrecy::ExposeSecret,
     tai64::Tai64,
 };
+use futures::StreamExt;
 use itertools::{
     rev,
     Itertools,
@@ -171,6 +172,60 @@ async fn produce_block_manually() {
     }
 }
 
+#[tokio::test]
+async fn subscribe_blocks__returns_existing_and_new_blocks() {
+    let srv = FuelService::from_database(Database::default(), Config::local_node())
+        .await
+        .unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // given
+    client.produce_blocks(3, None).await.unwrap();
+
+    // when
+    let stream = client.subscribe_blocks(Some(1.into())).await.unwrap();
+    client.produce_blocks(2, None).await.unwrap();
+
+    // then
+    let heights: Vec<u32> = tokio::time::timeout(
+        Duration::from_secs(10),
+        stream
+            .take(5)
+            .map(|block| block.unwrap().header.height)
+            .collect(),
+    )
+    .await
+    .unwrap();
+    assert_eq!(heights, vec![1, 2, 3, 4, 5]);
+}
+
+#[tokio::test]
+async fn subscribe_headers__returns_headers_from_start_height() {
+    let srv = FuelService::from_database(Database::default(), Config::local_node())
+        .await
+        .unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // given
+    client.produce_blocks(2, None).await.unwrap();
+
+    // when
+    let stream = client.subscribe_headers(Some(2.into())).await.unwrap();
+    client.produce_blocks(2, None).await.unwrap();
+
+    // then
+    let heights: Vec<u32> = tokio::time::timeout(
+        Duration::from_secs(10),
+        stream
+            .take(3)
+            .map(|header| header.unwrap().height)
+            .collect(),
+    )
+    .await
+    .unwrap();
+    assert_eq!(heights, vec![2, 3, 4]);
+}
+
 #[tokio::test]
 async fn produce_block_negative() {
     let db = Database::default();
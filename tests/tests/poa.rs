@@ -365,6 +365,12 @@ mod p2p {
             ConsensusConfig::PoAV2(poa) => {
                 poa.set_genesis_signing_key(key);
             }
+            ConsensusConfig::PoAV3(poa) => {
+                poa.set_producers(vec![key]);
+            }
+            ConsensusConfig::Bft { validators } => {
+                *validators = vec![key];
+            }
         }
         config.snapshot_reader = snapshot_reader.clone().with_chain_config(chain_config)
     }
@@ -8,16 +8,19 @@ use fuel_core::{
         FuelService,
     },
 };
+use fuel_core_client::client::types::TransactionStatus as ClientTransactionStatus;
 use fuel_core_storage::transactional::AtomicView;
 use fuel_core_txpool::types::TxId;
 use fuel_core_types::{
     fuel_tx::{
+        Address,
         AssetId,
         Input,
         Output,
         Transaction,
         TransactionBuilder,
         UniqueIdentifier,
+        UtxoId,
     },
     fuel_types::BlockHeight,
 };
@@ -63,6 +66,107 @@ fn transfer_transaction(min_amount: u64, rng: &mut StdRng) -> Transaction {
     builder.finalize_as_transaction()
 }
 
+fn transfer_to_owner(
+    utxo_id: UtxoId,
+    owner: Address,
+    amount: u64,
+    recipient: Address,
+) -> Transaction {
+    TransactionBuilder::script(vec![], vec![])
+        .add_input(Input::coin_predicate(
+            utxo_id,
+            owner,
+            amount,
+            AssetId::BASE,
+            Default::default(),
+            0,
+            vec![0],
+            vec![],
+        ))
+        .add_output(Output::coin(recipient, amount, AssetId::BASE))
+        .finalize_as_transaction()
+}
+
+async fn submit_and_get_height(
+    driver: &FuelCoreDriver,
+    tx: &Transaction,
+) -> anyhow::Result<BlockHeight> {
+    let status = driver.client.submit_and_await_commit(tx).await?;
+    match status {
+        ClientTransactionStatus::Success { block_height, .. } => Ok(block_height),
+        _ => Err(anyhow::anyhow!("Unexpected transaction status: {status:?}")),
+    }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn balance_and_coin_at_height__return_state_at_that_height() -> anyhow::Result<()> {
+    let mut rng = StdRng::seed_from_u64(1234);
+    let driver = FuelCoreDriver::spawn_feeless(&[
+        "--debug",
+        "--poa-instant",
+        "true",
+        "--state-rewind-duration",
+        "7d",
+    ])
+    .await?;
+    let owner: Address = rng.gen();
+    let recipient: Address = rng.gen();
+    const AMOUNT: u64 = 123456;
+
+    // Given
+    let receive_tx = transfer_to_owner(rng.gen(), rng.gen(), AMOUNT, owner);
+    let receive_height = submit_and_get_height(&driver, &receive_tx).await?;
+    let coin_id = UtxoId::new(receive_tx.id(&Default::default()), 0);
+    let spend_tx = transfer_to_owner(coin_id, owner, AMOUNT, recipient);
+    submit_and_get_height(&driver, &spend_tx).await?;
+
+    // When
+    let balance_at_height = driver
+        .client
+        .balance_at_height(&owner, Some(&AssetId::BASE), receive_height)
+        .await?;
+    let latest_balance = driver.client.balance(&owner, Some(&AssetId::BASE)).await?;
+    let coin_at_height = driver
+        .client
+        .coin_at_height(&coin_id, receive_height)
+        .await?;
+    let latest_coin = driver.client.coin(&coin_id).await?;
+
+    // Then
+    assert_eq!(balance_at_height, AMOUNT);
+    assert_eq!(latest_balance, 0);
+    assert_eq!(coin_at_height.map(|coin| coin.amount), Some(AMOUNT));
+    assert_eq!(latest_coin, None);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn balance_at_height__fails_for_unknown_height() -> anyhow::Result<()> {
+    let driver = FuelCoreDriver::spawn_feeless(&[
+        "--debug",
+        "--poa-instant",
+        "true",
+        "--state-rewind-duration",
+        "7d",
+    ])
+    .await?;
+    let latest_height = driver.client.produce_blocks(1, None).await?;
+    let future_height = latest_height.succ().unwrap();
+
+    // When
+    let result = driver
+        .client
+        .balance_at_height(&Address::default(), None, future_height)
+        .await;
+
+    // Then
+    let err = result.expect_err("The state at the future height is unavailable");
+    assert!(err.to_string().contains("is unavailable"), "{err}");
+
+    Ok(())
+}
+
 #[tokio::test(flavor = "multi_thread")]
 async fn validate_block_at_any_height__only_transfers() -> anyhow::Result<()> {
     let mut rng = StdRng::seed_from_u64(1234);
@@ -0,0 +1,132 @@
+use fuel_core::{
+    database::Database,
+    service::{
+        Config,
+        FuelService,
+    },
+};
+use fuel_core_client::client::{
+    pagination::{
+        PageDirection,
+        PaginationRequest,
+    },
+    FuelClient,
+};
+use fuel_core_poa::{
+    signer::SignMode,
+    Trigger,
+};
+use fuel_core_types::{
+    fuel_asm::*,
+    fuel_crypto::SecretKey,
+    fuel_tx::TransactionBuilder,
+    secrecy::Secret,
+};
+use rand::{
+    rngs::StdRng,
+    SeedableRng,
+};
+use std::time::Duration;
+
+async fn block_count(client: &FuelClient) -> usize {
+    client
+        .blocks(PaginationRequest {
+            cursor: None,
+            results: 1024,
+            direction: PageDirection::Forward,
+        })
+        .await
+        .expect("blocks request failed")
+        .results
+        .len()
+}
+
+#[tokio::test(start_paused = true)]
+async fn poa_hybrid_produces_empty_blocks_at_max_block_time() {
+    let rounds = 16;
+    let max_block_time_seconds = 2;
+
+    let mut rng = StdRng::seed_from_u64(10);
+
+    let db = Database::default();
+    let mut config = Config::local_node();
+    config.consensus_signer =
+        SignMode::Key(Secret::new(SecretKey::random(&mut rng).into()));
+    config.block_production = Trigger::Hybrid {
+        gas_threshold: u64::MAX,
+        min_block_time: Duration::ZERO,
+        max_block_time: Duration::new(max_block_time_seconds, 0),
+    };
+
+    let srv = FuelService::from_database(db.clone(), config)
+        .await
+        .unwrap();
+
+    let client = FuelClient::from(srv.bound_address);
+
+    let time_start = tokio::time::Instant::now();
+    let count_start = block_count(&client).await;
+
+    loop {
+        let count_now = block_count(&client).await;
+        if count_now > count_start + rounds {
+            break
+        }
+    }
+
+    let time_end = tokio::time::Instant::now();
+
+    // Require at least minimum time, allow up to one round time of error
+    let secs_per_round = (time_end - time_start).as_secs() / (rounds as u64);
+    assert!(
+        max_block_time_seconds <= secs_per_round
+            && secs_per_round
+                <= max_block_time_seconds + 2 * (rounds as u64) / max_block_time_seconds,
+        "Round time not within threshold"
+    );
+}
+
+#[tokio::test(start_paused = true)]
+async fn poa_hybrid_produces_block_when_gas_threshold_is_reached() {
+    let max_block_time = Duration::from_secs(60 * 60);
+
+    let mut rng = StdRng::seed_from_u64(10);
+
+    let db = Database::default();
+    let mut config = Config::local_node();
+    config.consensus_signer =
+        SignMode::Key(Secret::new(SecretKey::random(&mut rng).into()));
+    config.block_production = Trigger::Hybrid {
+        gas_threshold: 1,
+        min_block_time: Duration::ZERO,
+        max_block_time,
+    };
+
+    let srv = FuelService::from_database(db.clone(), config)
+        .await
+        .unwrap();
+
+    let client = FuelClient::from(srv.bound_address);
+    let time_start = tokio::time::Instant::now();
+
+    for i in 0..10usize {
+        let tx = TransactionBuilder::script(
+            [op::movi(0x10, i.try_into().unwrap())]
+                .into_iter()
+                .collect(),
+            vec![],
+        )
+        .add_random_fee_input()
+        .finalize_as_transaction();
+        client.submit_and_await_commit(&tx).await.unwrap();
+
+        let block_number = i + 1;
+        assert_eq!(
+            block_count(&client).await,
+            block_number + 1 // genesis block
+        );
+    }
+
+    // Busy periods don't wait for the max block time
+    assert!(time_start.elapsed() < max_block_time);
+}
@@ -1,4 +1,9 @@
-use crate::helpers::TestContext;
+#![allow(non_snake_case)]
+
+use crate::helpers::{
+    TestContext,
+    TestSetupBuilder,
+};
 use fuel_core::{
     schema::tx::receipt::all_receipts,
     service::{
@@ -16,13 +21,24 @@ use fuel_core_client::client::{
 };
 use fuel_core_poa::service::Mode;
 use fuel_core_types::{
+    entities::coins::coin::Coin,
     fuel_asm::*,
     fuel_crypto::SecretKey,
     fuel_tx::{
         field::ReceiptsRoot,
         *,
     },
-    fuel_types::ChainId,
+    fuel_types::{
+        canonical::Serialize,
+        ChainId,
+    },
+    services::executor::{
+        ContractStateOverride,
+        StateOverrides,
+        StorageAccessKind,
+        TransactionExecutionResult,
+        TransactionExecutionStatus,
+    },
 };
 use futures::StreamExt;
 use itertools::Itertools;
@@ -145,6 +161,230 @@ async fn dry_run_create() {
     assert_eq!(err.kind(), NotFound);
 }
 
+#[tokio::test]
+async fn dry_run__state_overrides__coin_override_makes_input_spendable() {
+    let mut rng = StdRng::seed_from_u64(2322);
+    let context = TestSetupBuilder::new(2322).finalize().await;
+    let client = context.client;
+
+    let secret = SecretKey::random(&mut rng);
+    let owner = Input::owner(&secret.public_key());
+    let utxo_id: UtxoId = rng.gen();
+    let amount = 1000;
+    let tx = TransactionBuilder::script(vec![], vec![])
+        .script_gas_limit(10000)
+        .add_unsigned_coin_input(
+            secret,
+            utxo_id,
+            amount,
+            AssetId::BASE,
+            Default::default(),
+        )
+        .add_output(Output::change(owner, 0, AssetId::BASE))
+        .finalize_as_transaction();
+    let state_overrides = StateOverrides {
+        coins: vec![Coin {
+            utxo_id,
+            owner,
+            amount,
+            asset_id: AssetId::BASE,
+            tx_pointer: Default::default(),
+        }],
+        ..Default::default()
+    };
+
+    // Given
+    let result_without_overrides = client
+        .dry_run_opt(&[tx.clone()], Some(true), Some(0), None, None)
+        .await;
+    assert!(result_without_overrides.is_err());
+
+    // When
+    let tx_statuses = client
+        .dry_run_opt(&[tx], Some(true), Some(0), None, Some(state_overrides))
+        .await
+        .expect("The overridden coin should be spendable");
+
+    // Then
+    assert!(matches!(
+        tx_statuses[0].result,
+        TransactionExecutionResult::Success { .. }
+    ));
+    let coin = client.coin(&utxo_id).await.unwrap();
+    assert!(coin.is_none(), "The override should not be committed");
+}
+
+#[tokio::test]
+async fn dry_run__state_overrides__contract_reads_overridden_storage_slot() {
+    let mut test_builder = TestSetupBuilder::new(2322);
+    // The contract logs the value of the storage slot with zeroed key.
+    let contract_code = [
+        op::movi(0x10, Bytes32::LEN.try_into().unwrap()),
+        op::aloc(0x10),
+        op::move_(0x11, RegId::HP),
+        op::srw(0x12, 0x13, 0x11),
+        op::log(0x12, 0x13, RegId::ZERO, RegId::ZERO),
+        op::ret(RegId::ONE),
+    ]
+    .into_iter()
+    .collect::<Vec<u8>>();
+    let (_, contract_id) = test_builder.setup_contract(contract_code, vec![], None);
+    let TestContext {
+        client,
+        srv: _dont_drop,
+        mut rng,
+    } = test_builder.finalize().await;
+
+    let script = [
+        op::gtf_args(0x10, 0x00, GTFArgs::ScriptData),
+        op::call(0x10, RegId::ZERO, RegId::ZERO, RegId::CGAS),
+        op::ret(RegId::ONE),
+    ]
+    .into_iter()
+    .collect::<Vec<u8>>();
+    let script_data = fuel_core_types::fuel_vm::Call::new(contract_id, 0, 0)
+        .to_bytes()
+        .to_vec();
+    let tx = TransactionBuilder::script(script, script_data)
+        .script_gas_limit(1_000_000)
+        .add_input(Input::contract(
+            rng.gen(),
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            contract_id,
+        ))
+        .add_random_fee_input()
+        .add_output(Output::contract(0, Default::default(), Default::default()))
+        .finalize_as_transaction();
+
+    let value: u64 = 42;
+    let mut slot_value = [0u8; 32];
+    slot_value[..8].copy_from_slice(&value.to_be_bytes());
+    let state_overrides = StateOverrides {
+        contracts: vec![ContractStateOverride {
+            contract_id,
+            storage: vec![(Bytes32::zeroed(), slot_value.to_vec())],
+            ..Default::default()
+        }],
+        ..Default::default()
+    };
+
+    // When
+    let tx_statuses = client
+        .dry_run_opt(
+            &[tx.clone()],
+            Some(false),
+            None,
+            None,
+            Some(state_overrides),
+        )
+        .await
+        .unwrap();
+    let tx_statuses_without_overrides = client
+        .dry_run_opt(&[tx], Some(false), None, None, None)
+        .await
+        .unwrap();
+
+    // Then
+    let logged_slot = |statuses: &[TransactionExecutionStatus]| {
+        statuses[0]
+            .result
+            .receipts()
+            .iter()
+            .find_map(|receipt| match receipt {
+                Receipt::Log { ra, rb, .. } => Some((*ra, *rb)),
+                _ => None,
+            })
+            .expect("The contract should log the storage slot")
+    };
+    assert_eq!(logged_slot(&tx_statuses), (value, 1));
+    assert_eq!(logged_slot(&tx_statuses_without_overrides), (0, 0));
+}
+
+#[tokio::test]
+async fn dry_run__trace__records_executed_instructions_and_storage_reads() {
+    let mut test_builder = TestSetupBuilder::new(2322);
+    // The contract reads the storage slot with zeroed key.
+    let contract_code = [
+        op::movi(0x10, Bytes32::LEN.try_into().unwrap()),
+        op::aloc(0x10),
+        op::move_(0x11, RegId::HP),
+        op::srw(0x12, 0x13, 0x11),
+        op::ret(RegId::ONE),
+    ]
+    .into_iter()
+    .collect::<Vec<u8>>();
+    let (_, contract_id) = test_builder.setup_contract(contract_code, vec![], None);
+    let TestContext {
+        client,
+        srv: _dont_drop,
+        mut rng,
+    } = test_builder.finalize().await;
+
+    let script = [
+        op::gtf_args(0x10, 0x00, GTFArgs::ScriptData),
+        op::call(0x10, RegId::ZERO, RegId::ZERO, RegId::CGAS),
+        op::ret(RegId::ONE),
+    ]
+    .into_iter()
+    .collect::<Vec<u8>>();
+    let script_data = fuel_core_types::fuel_vm::Call::new(contract_id, 0, 0)
+        .to_bytes()
+        .to_vec();
+    let tx = TransactionBuilder::script(script, script_data)
+        .script_gas_limit(1_000_000)
+        .add_input(Input::contract(
+            rng.gen(),
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            contract_id,
+        ))
+        .add_random_fee_input()
+        .add_output(Output::contract(0, Default::default(), Default::default()))
+        .finalize_as_transaction();
+
+    let slot_value = [7u8; 32];
+    let state_overrides = StateOverrides {
+        contracts: vec![ContractStateOverride {
+            contract_id,
+            storage: vec![(Bytes32::zeroed(), slot_value.to_vec())],
+            ..Default::default()
+        }],
+        ..Default::default()
+    };
+
+    // When
+    let tx_statuses = client
+        .dry_run_with_traces(&[tx], Some(false), None, None, Some(state_overrides))
+        .await
+        .unwrap();
+
+    // Then
+    let (status, trace) = &tx_statuses[0];
+    assert!(matches!(
+        status.result,
+        TransactionExecutionResult::Success { .. }
+    ));
+    let trace = trace.as_ref().expect("The trace was requested");
+    assert!(!trace.truncated);
+    // 3 instructions of the script and 5 instructions of the contract.
+    assert_eq!(trace.steps.len(), 8);
+    assert_eq!(trace.steps[0].contract_id, None);
+    assert_eq!(trace.steps[2].contract_id, Some(contract_id));
+    assert_eq!(trace.storage_accesses.len(), 1);
+    let access = &trace.storage_accesses[0];
+    assert_eq!(access.kind, StorageAccessKind::Read);
+    assert_eq!(access.contract_id, contract_id);
+    assert_eq!(access.key, Bytes32::zeroed());
+    assert_eq!(access.value, Some(slot_value.to_vec()));
+    assert_eq!(
+        trace.steps[access.step as usize].contract_id,
+        Some(contract_id)
+    );
+}
+
 #[tokio::test]
 async fn submit() {
     let srv = FuelService::new_node(Config::local_node()).await.unwrap();
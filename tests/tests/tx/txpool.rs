@@ -4,6 +4,17 @@ use crate::helpers::{
     TestContext,
     TestSetupBuilder,
 };
+use fuel_core::{
+    fuel_core_graphql_api::AdminToken,
+    service::{
+        Config,
+        FuelService,
+    },
+};
+use fuel_core_client::client::{
+    types::TxPoolBlacklist,
+    FuelClient,
+};
 use fuel_core_types::{
     fuel_asm::*,
     fuel_crypto::*,
@@ -68,3 +79,70 @@ async fn txs_max_script_gas_limit() {
         transactions.len() + 1 // coinbase
     )
 }
+
+#[tokio::test]
+async fn tx_pool_blacklist__requires_admin_token() {
+    let mut config = Config::local_node();
+    config.graphql_config.admin_token = Some(AdminToken::new("secret".to_string()));
+    let srv = FuelService::new_node(config).await.unwrap();
+
+    // When
+    let without_token = FuelClient::from(srv.bound_address)
+        .tx_pool_blacklist()
+        .await;
+    let wrong_token = FuelClient::from(srv.bound_address)
+        .with_admin_token("wrong")
+        .tx_pool_blacklist()
+        .await;
+
+    // Then
+    assert!(without_token.is_err());
+    assert!(wrong_token.is_err());
+}
+
+#[tokio::test]
+async fn tx_pool_blacklist__blocks_and_unblocks_owner_at_runtime() {
+    let mut rng = StdRng::seed_from_u64(2322);
+    let mut config = Config::local_node();
+    config.graphql_config.admin_token = Some(AdminToken::new("secret".to_string()));
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address).with_admin_token("secret");
+
+    let secret = SecretKey::random(&mut rng);
+    let owner = Input::owner(&secret.public_key());
+    let tx: Transaction = TransactionBuilder::script(vec![], vec![])
+        .script_gas_limit(10000)
+        .add_unsigned_coin_input(
+            secret,
+            rng.gen(),
+            1000,
+            Default::default(),
+            Default::default(),
+        )
+        .finalize_as_transaction();
+    let entries = TxPoolBlacklist {
+        owners: vec![owner],
+        ..Default::default()
+    };
+
+    // When
+    client
+        .add_to_tx_pool_blacklist(entries.clone())
+        .await
+        .unwrap();
+
+    // Then
+    assert_eq!(client.tx_pool_blacklist().await.unwrap(), entries);
+    let result = client.submit(&tx).await;
+    assert!(result.is_err());
+
+    // When
+    client.remove_from_tx_pool_blacklist(entries).await.unwrap();
+
+    // Then
+    assert_eq!(
+        client.tx_pool_blacklist().await.unwrap(),
+        TxPoolBlacklist::default()
+    );
+    client.submit(&tx).await.unwrap();
+}